@@ -22,7 +22,10 @@ const assertionTypes: { value: AssertionType; label: string }[] = [
   { value: "status", label: "Status Code" },
   { value: "header", label: "Header" },
   { value: "jsonpath", label: "JSONPath" },
+  { value: "xpath", label: "XPath" },
   { value: "body-contains", label: "Body Contains" },
+  { value: "response-time", label: "Response Time (ms)" },
+  { value: "json-schema", label: "JSON Schema" },
 ];
 
 const comparisonTypes: { value: TestAssertion["comparison"]; label: string }[] = [
@@ -232,13 +235,17 @@ export function AssertionEditor() {
                 </SelectContent>
               </Select>
 
-              {(assertion.type === "header" || assertion.type === "jsonpath") && (
+              {(assertion.type === "header" ||
+                assertion.type === "jsonpath" ||
+                assertion.type === "xpath") && (
                 <input
                   className="h-7 flex-1 min-w-0 rounded border border-border bg-[hsl(var(--surface-1))] px-2 font-mono text-[11px] text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
                   placeholder={
                     assertion.type === "header"
                       ? "Header name"
-                      : "$.path.to.value"
+                      : assertion.type === "xpath"
+                        ? "/root/item/@id"
+                        : "$.path.to.value"
                   }
                   value={assertion.property}
                   onChange={(e) =>
@@ -247,27 +254,31 @@ export function AssertionEditor() {
                 />
               )}
 
-              <Select
-                value={assertion.comparison}
-                onValueChange={(v) =>
-                  updateAssertion(assertion.id, {
-                    comparison: v as TestAssertion["comparison"],
-                  })
-                }
-              >
-                <SelectTrigger className="h-7 w-[120px] border-border bg-[hsl(var(--surface-1))] text-[11px]">
-                  <SelectValue />
-                </SelectTrigger>
-                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
-                  {comparisonTypes.map((c) => (
-                    <SelectItem key={c.value} value={c.value} className="text-[11px]">
-                      {c.label}
-                    </SelectItem>
-                  ))}
-                </SelectContent>
-              </Select>
+              {assertion.type !== "json-schema" && (
+                <Select
+                  value={assertion.comparison}
+                  onValueChange={(v) =>
+                    updateAssertion(assertion.id, {
+                      comparison: v as TestAssertion["comparison"],
+                    })
+                  }
+                >
+                  <SelectTrigger className="h-7 w-[120px] border-border bg-[hsl(var(--surface-1))] text-[11px]">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                    {comparisonTypes.map((c) => (
+                      <SelectItem key={c.value} value={c.value} className="text-[11px]">
+                        {c.label}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              )}
 
-              {assertion.comparison !== "exists" && (
+              {assertion.type === "json-schema" && <div className="flex-1" />}
+
+              {assertion.type !== "json-schema" && assertion.comparison !== "exists" && (
                 <input
                   className="h-7 flex-1 min-w-0 rounded border border-border bg-[hsl(var(--surface-1))] px-2 font-mono text-[11px] text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
                   placeholder="Expected value"
@@ -287,18 +298,30 @@ export function AssertionEditor() {
               </button>
             </div>
 
+            {assertion.type === "json-schema" && (
+              <textarea
+                className="min-h-[96px] w-full resize-y rounded border border-border bg-[hsl(var(--surface-1))] px-2 py-1.5 font-mono text-[11px] text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
+                placeholder={'{ "type": "object", "required": ["id"] }'}
+                spellCheck={false}
+                value={assertion.expected}
+                onChange={(e) =>
+                  updateAssertion(assertion.id, { expected: e.target.value })
+                }
+              />
+            )}
+
             {/* Assertion result */}
             {result && (
-              <div className="flex items-center gap-1.5 text-[10px] font-mono">
+              <div className="flex items-start gap-1.5 text-[10px] font-mono">
                 {result.passed ? (
                   <CheckCircle2 className="h-3 w-3 text-green-500 shrink-0" />
                 ) : (
                   <XCircle className="h-3 w-3 text-red-500 shrink-0" />
                 )}
                 <span
-                  className={
+                  className={`whitespace-pre-wrap ${
                     result.passed ? "text-green-600" : "text-red-600"
-                  }
+                  }`}
                 >
                   {result.message}
                 </span>
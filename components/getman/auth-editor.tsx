@@ -198,6 +198,12 @@ export function AuthEditor() {
             onChange={(v) => updateActiveTab({ ntlmDomain: v })}
             placeholder="DOMAIN"
           />
+          <InputField
+            label="Workstation (Optional)"
+            value={tab.ntlmWorkstation || ""}
+            onChange={(v) => updateActiveTab({ ntlmWorkstation: v })}
+            placeholder="Defaults to this machine's host name"
+          />
           <p className="text-[10px] text-muted-foreground">
             Performs NTLM handshake; domain can be provided separately or in username.
           </p>
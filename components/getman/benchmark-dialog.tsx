@@ -3,8 +3,11 @@
 import { useEffect, useMemo, useRef, useState } from "react";
 import { BarChart3, Download, Play, Save, Square } from "lucide-react";
 import {
+  applyEnvironmentBaseUrl,
+  applyPathParams,
   type CollectionFolder,
   defaultSettings,
+  proxyPayloadFields,
   type RequestTab,
   resolveEnvVariables,
   useGetmanStore,
@@ -15,8 +18,13 @@ import {
   getBenchmarkRun,
   listBenchmarkRuns,
   startBenchmark,
+  validateBenchmarkSpec,
+  type BenchmarkExportFormat,
   type BenchmarkRunDetail,
   type BenchmarkRunSummary,
+  type BenchmarkSlaConfig,
+  type BenchmarkSlaVerdict,
+  type BenchmarkSpecIssue,
   type BenchmarkSpecPayload,
 } from "@/lib/benchmark";
 import {
@@ -54,8 +62,43 @@ interface BenchmarkPreset {
   followRedirects: boolean;
   warmupSec: number;
   sampleErrorsTopK: number;
+  maxRpsPerHost?: number;
+  bodyCapture?: BodyCaptureMode;
+  sampleRatePct?: number;
+  sla?: BenchmarkSlaConfig;
 }
 
+const SLA_FIELDS: { key: keyof BenchmarkSlaConfig; label: string }[] = [
+  { key: "maxP95Ms", label: "SLA: Max P95 (ms)" },
+  { key: "maxP99Ms", label: "SLA: Max P99 (ms)" },
+  { key: "maxErrorRate", label: "SLA: Max Error Rate (%)" },
+  { key: "minRps", label: "SLA: Min RPS" },
+];
+
+const SLA_CRITERION_LABELS: Record<keyof BenchmarkSlaConfig, string> = {
+  maxP50Ms: "P50",
+  maxP90Ms: "P90",
+  maxP95Ms: "P95",
+  maxP99Ms: "P99",
+  maxErrorRate: "Error rate",
+  minRps: "RPS",
+};
+
+function describeSlaVerdict(verdict: BenchmarkSlaVerdict): string {
+  if (verdict.passed) return "All SLA thresholds met";
+  return verdict.violations
+    .map((violation) => {
+      const key = violation.criterion as keyof BenchmarkSlaConfig;
+      const label = SLA_CRITERION_LABELS[key] ?? violation.criterion;
+      const unit = key === "maxErrorRate" ? "%" : key === "minRps" ? " rps" : " ms";
+      const relation = key === "minRps" ? "<" : ">";
+      return `${label} ${formatNumber(violation.actual)}${unit} ${relation} ${formatNumber(violation.threshold)}${unit}`;
+    })
+    .join(" · ");
+}
+
+type BodyCaptureMode = "none" | "errors" | "all" | "sampled";
+
 interface CompareMetricRow {
   label: string;
   left: number;
@@ -107,7 +150,11 @@ function buildRequestSnapshot(tab: RequestTab) {
     headers.Cookie = cookieValues.join("; ");
   }
 
-  let url = resolve(tab.url);
+  let url = applyPathParams(
+    applyEnvironmentBaseUrl(resolve(tab.url), resolve),
+    tab.pathParams,
+    resolve
+  );
   try {
     const parsed = new URL(url);
     for (const param of tab.params) {
@@ -200,6 +247,12 @@ function buildCompareRows(left?: BenchmarkRunDetail | null, right?: BenchmarkRun
       right: rightSummary?.latency.p99Ms || 0,
       unit: "ms",
     },
+    {
+      label: "p95 Body Size",
+      left: leftSummary?.responseBodySize?.p95Bytes || 0,
+      right: rightSummary?.responseBodySize?.p95Bytes || 0,
+      unit: "B",
+    },
   ];
 }
 
@@ -215,8 +268,13 @@ export function BenchmarkDialog() {
   const [followRedirects, setFollowRedirects] = useState(true);
   const [warmupSec, setWarmupSec] = useState(3);
   const [sampleErrorsTopK, setSampleErrorsTopK] = useState(10);
+  const [maxRpsPerHost, setMaxRpsPerHost] = useState(0);
+  const [bodyCapture, setBodyCapture] = useState<BodyCaptureMode>("errors");
+  const [sampleRatePct, setSampleRatePct] = useState(10);
+  const [sla, setSla] = useState<BenchmarkSlaConfig>({});
   const [isRunning, setIsRunning] = useState(false);
   const [runningRunId, setRunningRunId] = useState<string | null>(null);
+  const [isDraining, setIsDraining] = useState(false);
   const [activeRun, setActiveRun] = useState<BenchmarkRunDetail | null>(null);
   const [runHistory, setRunHistory] = useState<BenchmarkRunSummary[]>([]);
   const [compareLeftId, setCompareLeftId] = useState("");
@@ -224,6 +282,7 @@ export function BenchmarkDialog() {
   const [compareLeftRun, setCompareLeftRun] = useState<BenchmarkRunDetail | null>(null);
   const [compareRightRun, setCompareRightRun] = useState<BenchmarkRunDetail | null>(null);
   const [errorMessage, setErrorMessage] = useState("");
+  const [specIssues, setSpecIssues] = useState<BenchmarkSpecIssue[]>([]);
   const pollTokenRef = useRef<string | null>(null);
 
   const targetOptions = useMemo<TargetOption[]>(() => {
@@ -285,6 +344,21 @@ export function BenchmarkDialog() {
       if (typeof preset.sampleErrorsTopK === "number") {
         setSampleErrorsTopK(Math.max(1, preset.sampleErrorsTopK));
       }
+      if (typeof preset.maxRpsPerHost === "number") {
+        setMaxRpsPerHost(Math.max(0, preset.maxRpsPerHost));
+      }
+      if (
+        preset.bodyCapture === "none" ||
+        preset.bodyCapture === "errors" ||
+        preset.bodyCapture === "all" ||
+        preset.bodyCapture === "sampled"
+      ) {
+        setBodyCapture(preset.bodyCapture);
+      }
+      if (typeof preset.sampleRatePct === "number") {
+        setSampleRatePct(Math.min(100, Math.max(1, preset.sampleRatePct)));
+      }
+      if (preset.sla && typeof preset.sla === "object") setSla(preset.sla);
     } catch {
       // Ignore malformed presets.
     }
@@ -335,6 +409,10 @@ export function BenchmarkDialog() {
       followRedirects,
       warmupSec,
       sampleErrorsTopK,
+      maxRpsPerHost,
+      bodyCapture,
+      sampleRatePct,
+      sla,
     };
     window.localStorage.setItem(PRESET_KEY, JSON.stringify(preset));
   };
@@ -374,8 +452,10 @@ export function BenchmarkDialog() {
       transport: {
         keepAlive,
         followRedirects,
-        proxyUrl: requestSettings.proxyUrl || undefined,
+        ...proxyPayloadFields(requestSettings),
         verifySsl: requestSettings.verifySsl,
+        maxRpsPerHost: maxRpsPerHost > 0 ? maxRpsPerHost : undefined,
+        userAgent: store.userAgent.trim() || undefined,
       },
       timing: {
         timeoutMs: Math.max(100, timeoutMs),
@@ -383,14 +463,44 @@ export function BenchmarkDialog() {
       },
       logging: {
         sampleErrorsTopK: Math.max(1, sampleErrorsTopK),
-        saveBodies: "errors",
+        saveBodies:
+          bodyCapture === "sampled"
+            ? { sampled: { rate: Math.min(100, Math.max(1, sampleRatePct)) / 100 } }
+            : bodyCapture,
       },
       env: {
         variablesSnapshot,
       },
+      sla: Object.values(sla).some((value) => value !== undefined) ? sla : undefined,
     };
   };
 
+  // Validate as the form changes; keyed on the serialized spec so only real edits re-check.
+  const specKey = JSON.stringify(buildSpec());
+  useEffect(() => {
+    const spec = JSON.parse(specKey) as BenchmarkSpecPayload | null;
+    if (!spec) {
+      setSpecIssues([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = setTimeout(() => {
+      validateBenchmarkSpec(spec).then(
+        (issues) => {
+          if (!cancelled) setSpecIssues(issues);
+        },
+        () => {
+          if (!cancelled) setSpecIssues([]);
+        }
+      );
+    }, 250);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [specKey]);
+  const hasSpecErrors = specIssues.some((issue) => issue.severity === "error");
+
   const pollRun = async (runId: string, requestId?: string) => {
     pollTokenRef.current = runId;
     setIsRunning(true);
@@ -403,6 +513,7 @@ export function BenchmarkDialog() {
           setActiveRun(detail);
           if (["completed", "failed", "cancelled"].includes(detail.run.status)) {
             setIsRunning(false);
+            setIsDraining(false);
             setRunningRunId(null);
             pollTokenRef.current = null;
             await refreshHistory(requestId);
@@ -447,10 +558,24 @@ export function BenchmarkDialog() {
     } finally {
       pollTokenRef.current = null;
       setIsRunning(false);
+      setIsDraining(false);
       setRunningRunId(null);
     }
   };
 
+  // Keep polling while draining so the final metrics show up once in-flight
+  // requests have finished.
+  const handleDrain = async () => {
+    if (!runningRunId) return;
+    try {
+      if (await cancelBenchmarkRun(runningRunId, "drain")) {
+        setIsDraining(true);
+      }
+    } catch (error) {
+      setErrorMessage(error instanceof Error ? error.message : "Failed to drain benchmark");
+    }
+  };
+
   const handleRunClick = async (runId: string) => {
     setErrorMessage("");
     try {
@@ -461,7 +586,7 @@ export function BenchmarkDialog() {
     }
   };
 
-  const handleExport = async (format: "json" | "csv") => {
+  const handleExport = async (format: BenchmarkExportFormat) => {
     if (!activeRun) return;
     try {
       const exported = await exportBenchmarkRun(activeRun.run.runId, format);
@@ -478,6 +603,7 @@ export function BenchmarkDialog() {
   };
 
   const summary = activeRun?.metrics?.summary;
+  const slaVerdict = activeRun?.metrics?.sla;
   const compareRows = buildCompareRows(compareLeftRun, compareRightRun);
   const maxHistogramCount = Math.max(
     1,
@@ -600,6 +726,75 @@ export function BenchmarkDialog() {
             />
           </div>
 
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">
+              Max RPS per Host (0 = unlimited)
+            </label>
+            <input
+              type="number"
+              min={0}
+              value={maxRpsPerHost}
+              onChange={(event) => setMaxRpsPerHost(Math.max(0, Number(event.target.value) || 0))}
+              className="h-8 rounded border border-border bg-[hsl(var(--surface-2))] px-2 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+            />
+          </div>
+
+          {SLA_FIELDS.map((field) => (
+            <div key={field.key} className="flex flex-col gap-1.5">
+              <label className="text-[11px] font-medium text-muted-foreground">
+                {field.label}
+              </label>
+              <input
+                type="number"
+                min={0}
+                placeholder="off"
+                value={sla[field.key] ?? ""}
+                onChange={(event) => {
+                  const raw = event.target.value.trim();
+                  setSla((current) => ({
+                    ...current,
+                    [field.key]: raw === "" ? undefined : Math.max(0, Number(raw) || 0),
+                  }));
+                }}
+                className="h-8 rounded border border-border bg-[hsl(var(--surface-2))] px-2 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+              />
+            </div>
+          ))}
+
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">Save Bodies</label>
+            <Select
+              value={bodyCapture}
+              onValueChange={(value) => setBodyCapture(value as BodyCaptureMode)}
+            >
+              <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                <SelectItem value="none" className="text-xs">None</SelectItem>
+                <SelectItem value="errors" className="text-xs">Errors Only</SelectItem>
+                <SelectItem value="sampled" className="text-xs">Errors + Sampled Success</SelectItem>
+                <SelectItem value="all" className="text-xs">All Responses</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          {bodyCapture === "sampled" && (
+            <div className="flex flex-col gap-1.5">
+              <label className="text-[11px] font-medium text-muted-foreground">Success Sample Rate (%)</label>
+              <input
+                type="number"
+                min={1}
+                max={100}
+                value={sampleRatePct}
+                onChange={(event) =>
+                  setSampleRatePct(Math.min(100, Math.max(1, Number(event.target.value) || 1)))
+                }
+                className="h-8 rounded border border-border bg-[hsl(var(--surface-2))] px-2 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+              />
+            </div>
+          )}
+
           <div className="col-span-2 rounded border border-border/70 bg-[hsl(var(--surface-2))] px-3 py-2">
             <div className="flex items-center gap-4 text-[11px]">
               <label className="flex items-center gap-2 text-foreground">
@@ -632,19 +827,30 @@ export function BenchmarkDialog() {
 
         <div className="flex items-center gap-2 pt-2">
           {isRunning ? (
-            <button
-              type="button"
-              onClick={handleStop}
-              className="flex items-center gap-1.5 bg-destructive text-destructive-foreground text-xs font-medium px-4 py-2 rounded hover:bg-destructive/90 transition-colors"
-            >
-              <Square className="h-3 w-3" />
-              Stop
-            </button>
+            <>
+              <button
+                type="button"
+                onClick={handleStop}
+                className="flex items-center gap-1.5 bg-destructive text-destructive-foreground text-xs font-medium px-4 py-2 rounded hover:bg-destructive/90 transition-colors"
+              >
+                <Square className="h-3 w-3" />
+                Stop
+              </button>
+              <button
+                type="button"
+                onClick={handleDrain}
+                disabled={isDraining}
+                title="Stop starting new requests and let in-flight requests finish"
+                className="flex items-center gap-1.5 border border-border text-xs font-medium px-4 py-2 rounded text-muted-foreground hover:text-foreground transition-colors disabled:opacity-50"
+              >
+                {isDraining ? "Draining…" : "Drain"}
+              </button>
+            </>
           ) : (
             <button
               type="button"
               onClick={handleRun}
-              disabled={!selectedTarget}
+              disabled={!selectedTarget || hasSpecErrors}
               className="flex items-center gap-1.5 bg-primary text-primary-foreground text-xs font-medium px-4 py-2 rounded hover:bg-primary/90 transition-colors disabled:opacity-50"
             >
               <Play className="h-3 w-3" />
@@ -674,11 +880,31 @@ export function BenchmarkDialog() {
                 <Download className="h-3 w-3" />
                 CSV
               </button>
+              <button
+                type="button"
+                onClick={() => handleExport("prometheus")}
+                className="flex items-center gap-1 text-xs text-muted-foreground hover:text-foreground transition-colors"
+              >
+                <Download className="h-3 w-3" />
+                Prometheus
+              </button>
             </div>
           )}
         </div>
 
         {errorMessage && <div className="text-[11px] text-red-500">{errorMessage}</div>}
+        {specIssues.length > 0 && (
+          <ul className="flex flex-col gap-0.5 text-[11px]">
+            {specIssues.map((issue) => (
+              <li
+                key={`${issue.field}:${issue.message}`}
+                className={issue.severity === "error" ? "text-red-500" : "text-amber-500"}
+              >
+                <span className="font-mono">{issue.field}</span> · {issue.message}
+              </li>
+            ))}
+          </ul>
+        )}
 
         <div className="grid grid-cols-3 gap-3 min-h-0 flex-1 overflow-hidden">
           <div className="col-span-2 flex flex-col gap-3 min-h-0 overflow-hidden">
@@ -719,6 +945,50 @@ export function BenchmarkDialog() {
               </div>
             )}
 
+            {slaVerdict && (
+              <div
+                className={`rounded border px-2 py-1.5 text-[11px] ${
+                  slaVerdict.passed
+                    ? "border-green-500/40 bg-green-500/10 text-green-500"
+                    : "border-red-500/40 bg-red-500/10 text-red-500"
+                }`}
+              >
+                <span className="font-semibold">SLA {slaVerdict.passed ? "PASS" : "FAIL"}</span>
+                {" — "}
+                {describeSlaVerdict(slaVerdict)}
+              </div>
+            )}
+
+            {summary?.responseBodySize && summary.responseBodySize.maxBytes > 0 && (
+              <div className="text-[11px] text-muted-foreground font-mono">
+                Body size (B) p50 {summary.responseBodySize.p50Bytes} · p95{" "}
+                {summary.responseBodySize.p95Bytes} · p99 {summary.responseBodySize.p99Bytes} · max{" "}
+                {summary.responseBodySize.maxBytes}
+                {summary.responseHeaderSize && (
+                  <> · headers p95 {summary.responseHeaderSize.p95Bytes}</>
+                )}
+              </div>
+            )}
+
+            {summary && (summary.rateLimitedRequests || 0) > 0 && (
+              <div className="text-[11px] text-muted-foreground">
+                Rate limit delayed {summary.rateLimitedRequests} requests for{" "}
+                {formatNumber(summary.rateLimitWaitMs || 0)} ms in total (excluded from latency).
+              </div>
+            )}
+
+            {summary && summary.newConnections !== undefined && (
+              <div className="text-[11px] text-muted-foreground">
+                Opened {summary.newConnections} connection{summary.newConnections === 1 ? "" : "s"}
+                {summary.newConnections > 0 && (
+                  <>
+                    {" "}· setup avg {formatNumber(summary.connectTimeAvgMs || 0)} ms, max{" "}
+                    {formatNumber(summary.connectTimeMaxMs || 0)} ms (included in latency)
+                  </>
+                )}
+              </div>
+            )}
+
             <div className="grid grid-cols-2 gap-3 min-h-0 overflow-hidden">
               <div className="rounded border border-border/70 bg-[hsl(var(--surface-2))] p-2 min-h-0 overflow-hidden">
                 <div className="text-[11px] font-medium text-muted-foreground mb-2">Top Errors</div>
@@ -763,6 +1033,26 @@ export function BenchmarkDialog() {
               </div>
             </div>
 
+            {(activeRun?.metrics?.successSamples || []).length > 0 && (
+              <div className="rounded border border-border/70 bg-[hsl(var(--surface-2))] p-2">
+                <div className="text-[11px] font-medium text-muted-foreground mb-2">Success Body Samples</div>
+                <ScrollArea className="h-[120px]">
+                  <div className="space-y-1">
+                    {(activeRun?.metrics?.successSamples || []).map((item, index) => (
+                      <div key={`${item.statusCode}-${index}`} className="rounded border border-border/60 px-2 py-1">
+                        <div className="text-[11px] font-mono text-green-600">
+                          {item.statusCode} · {item.count}
+                        </div>
+                        <div className="text-[10px] font-mono text-muted-foreground break-all line-clamp-3">
+                          {item.body || "(empty body)"}
+                        </div>
+                      </div>
+                    ))}
+                  </div>
+                </ScrollArea>
+              </div>
+            )}
+
             <div className="rounded border border-border/70 bg-[hsl(var(--surface-2))] p-2">
               <div className="text-[11px] font-medium text-muted-foreground mb-2">Compare Runs</div>
               <div className="grid grid-cols-2 gap-2 mb-2">
@@ -836,7 +1126,17 @@ export function BenchmarkDialog() {
                   >
                     <div className="flex items-center justify-between gap-2">
                       <span className="font-mono">{shortRunId(run.runId)}</span>
-                      <span className="text-[10px] text-muted-foreground">{run.status}</span>
+                      <span className="text-[10px] text-muted-foreground">
+                        {run.status}
+                        {run.sla && (
+                          <span
+                            className={run.sla.passed ? "text-green-500" : "text-red-500"}
+                          >
+                            {" · SLA "}
+                            {run.sla.passed ? "pass" : "fail"}
+                          </span>
+                        )}
+                      </span>
                     </div>
                     <div className="text-[10px] text-muted-foreground">
                       {new Date(run.createdAt).toLocaleString()}
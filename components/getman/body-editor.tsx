@@ -1,10 +1,18 @@
 "use client";
 
+import { useEffect, useState } from "react";
 import {
   useActiveTab,
   updateActiveTab,
   type RequestTab,
 } from "@/lib/getman-store";
+import {
+  compactJson,
+  describeJsonFormatError,
+  formatJson,
+  type JsonFormatResult,
+} from "@/lib/json-format";
+import { inspectBodyFile, type BodyFileInfo } from "@/lib/tauri";
 import { KVEditor } from "./kv-editor";
 
 const bodyTypes: { value: RequestTab["bodyType"]; label: string }[] = [
@@ -15,12 +23,106 @@ const bodyTypes: { value: RequestTab["bodyType"]; label: string }[] = [
   { value: "raw", label: "Raw" },
   { value: "graphql", label: "GraphQL" },
   { value: "binary", label: "Binary" },
+  { value: "file", label: "File" },
 ];
 
+function formatBytes(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1048576) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / 1048576).toFixed(1)} MB`;
+}
+
+/** Path input for a streamed file body, with the file's name and size once found. */
+function FileBodyEditor({ path }: { path: string }) {
+  const [info, setInfo] = useState<BodyFileInfo | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const trimmed = path.trim();
+
+  useEffect(() => {
+    setInfo(null);
+    setError(null);
+    // Paths with variables are only known at send time.
+    if (!trimmed || trimmed.includes("{{")) return;
+    let cancelled = false;
+    const timer = setTimeout(() => {
+      inspectBodyFile(trimmed).then(
+        (found) => {
+          if (cancelled) return;
+          if (found) setInfo(found);
+          else setError("File bodies can only be sent from the desktop app");
+        },
+        (err) => {
+          if (!cancelled) setError(String(err));
+        }
+      );
+    }, 300);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [trimmed]);
+
+  return (
+    <div className="flex flex-col items-center justify-center h-full gap-3 text-muted-foreground">
+      <p className="text-sm">Stream a local file as the body</p>
+      <input
+        className="w-[90%] max-w-xl rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
+        placeholder="/path/to/upload.bin"
+        value={path}
+        onChange={(e) => updateActiveTab({ bodyFilePath: e.target.value })}
+        spellCheck={false}
+      />
+      {info && (
+        <p className="font-mono text-[11px] text-foreground">
+          {info.name} · {formatBytes(info.size)} · {info.contentType}
+        </p>
+      )}
+      {error && <p className="font-mono text-[11px] text-red-500">{error}</p>}
+      <p className="text-[10px] text-muted-foreground/60">
+        The file is read when the request is sent; a Content-Type header overrides the guessed type
+      </p>
+    </div>
+  );
+}
+
+function declaredContentType(tab: RequestTab): string | null {
+  const header = tab.headers.find(
+    (h) => h.enabled && h.key.trim().toLowerCase() === "content-type"
+  );
+  return header ? header.value.trim() : null;
+}
+
 export function BodyEditor() {
   const tab = useActiveTab();
+  const [formatError, setFormatError] = useState<string | null>(null);
+  const [formatNotice, setFormatNotice] = useState<string | null>(null);
   if (!tab) return null;
 
+  const canFormat = tab.bodyType === "json" || tab.bodyType === "raw";
+
+  const applyFormat = (result: JsonFormatResult, minified: boolean) => {
+    if (result.ok) {
+      setFormatError(null);
+      if (minified) {
+        const before = new TextEncoder().encode(tab.bodyContent).length;
+        const after = new TextEncoder().encode(result.formatted).length;
+        setFormatNotice(`Minified: ${before} → ${after} bytes`);
+      } else {
+        setFormatNotice(null);
+      }
+      updateActiveTab({ bodyContent: result.formatted });
+      return;
+    }
+
+    setFormatNotice(null);
+    const contentType = declaredContentType(tab);
+    if (tab.bodyType === "raw" && contentType && !contentType.toLowerCase().includes("json")) {
+      setFormatError(`Body is not JSON (Content-Type: ${contentType})`);
+      return;
+    }
+    setFormatError(describeJsonFormatError(result));
+  };
+
   return (
     <div className="flex flex-col h-full">
       <div className="flex items-center gap-1 border-b border-border/60 bg-[hsl(var(--surface-1))] px-3 py-2">
@@ -38,8 +140,39 @@ export function BodyEditor() {
             {bt.label}
           </button>
         ))}
+        {canFormat && (
+          <button
+            type="button"
+            onClick={() => applyFormat(formatJson(tab.bodyContent), false)}
+            className="ml-auto rounded-md border border-border/70 px-2.5 py-1 text-[11px] text-muted-foreground transition-colors hover:text-foreground"
+            title="Validate and pretty-print the body as JSON"
+          >
+            Beautify
+          </button>
+        )}
+        {canFormat && (
+          <button
+            type="button"
+            onClick={() => applyFormat(compactJson(tab.bodyContent), true)}
+            className="rounded-md border border-border/70 px-2.5 py-1 text-[11px] text-muted-foreground transition-colors hover:text-foreground"
+            title="Validate the body as JSON and strip whitespace between tokens"
+          >
+            Minify
+          </button>
+        )}
       </div>
 
+      {canFormat && formatError && (
+        <div className="border-b border-red-500/20 bg-red-500/5 px-3 py-1.5 font-mono text-[11px] text-red-500">
+          {formatError}
+        </div>
+      )}
+      {canFormat && formatNotice && (
+        <div className="border-b border-border/60 px-3 py-1.5 font-mono text-[11px] text-muted-foreground">
+          {formatNotice}
+        </div>
+      )}
+
       <div className="flex-1 min-h-0 overflow-auto">
         {tab.bodyType === "none" && (
           <div className="flex items-center justify-center h-full text-muted-foreground text-sm">
@@ -56,7 +189,11 @@ export function BodyEditor() {
                 : "Enter raw body content..."
             }
             value={tab.bodyContent}
-            onChange={(e) => updateActiveTab({ bodyContent: e.target.value })}
+            onChange={(e) => {
+              setFormatError(null);
+              setFormatNotice(null);
+              updateActiveTab({ bodyContent: e.target.value });
+            }}
             spellCheck={false}
           />
         )}
@@ -119,6 +256,8 @@ export function BodyEditor() {
             </p>
           </div>
         )}
+
+        {tab.bodyType === "file" && <FileBodyEditor path={tab.bodyFilePath} />}
       </div>
     </div>
   );
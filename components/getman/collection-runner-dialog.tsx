@@ -25,6 +25,7 @@ export function CollectionRunnerDialog() {
   const { collections } = useGetmanStore();
   const [selectedCollectionId, setSelectedCollectionId] = useState<string>("");
   const [mode, setMode] = useState<"serial" | "parallel">("serial");
+  const [orderCaptures, setOrderCaptures] = useState(true);
   const [delayMs, setDelayMs] = useState(0);
   const [iterations, setIterations] = useState(1);
   const [dataType, setDataType] = useState<"none" | "csv" | "json">("none");
@@ -47,6 +48,7 @@ export function CollectionRunnerDialog() {
 
   const buildOptions = (): RunnerOptions => ({
     mode,
+    orderCaptures,
     delayMs,
     iterations,
     dataSource: dataType !== "none" ? { type: dataType, content: dataContent } : undefined,
@@ -178,6 +180,19 @@ export function CollectionRunnerDialog() {
                   <SelectItem value="parallel" className="text-xs">Parallel</SelectItem>
                 </SelectContent>
               </Select>
+              {mode === "parallel" && (
+                <label
+                  className="flex items-center gap-2 text-[11px] text-foreground"
+                  title="Requests that read a variable another request's script sets wait for that request"
+                >
+                  <input
+                    type="checkbox"
+                    checked={orderCaptures}
+                    onChange={(e) => setOrderCaptures(e.target.checked)}
+                  />
+                  Order captured variables
+                </label>
+              )}
             </div>
 
             <div className="flex flex-col gap-1.5">
@@ -428,6 +443,12 @@ export function CollectionRunnerDialog() {
                  </div>
                )}
 
+               {result.captureWaves > 1 && (
+                 <div className="text-[10px] text-center text-muted-foreground">
+                   Capture ordering • {result.captureWaves} waves
+                 </div>
+               )}
+
                {result.contractGuardUsed && (
                  <div className={`text-[10px] text-center ${result.contractGateFailed ? "text-red-500" : "text-muted-foreground"}`}>
                    Contract drift: {result.contractDrifts.length}
@@ -7,7 +7,15 @@ import {
   ResizablePanel,
   ResizablePanelGroup,
 } from "@/components/ui/resizable";
-import { hydrateStore, useGetmanStore, addTab, setCommandPaletteOpen } from "@/lib/getman-store";
+import {
+  flushPersistedState,
+  hydrateStore,
+  useGetmanStore,
+  addTab,
+  setCommandPaletteOpen,
+} from "@/lib/getman-store";
+import { onWindowClose } from "@/lib/tauri";
+import { isModalOpen } from "@/lib/utils";
 import { GetmanHeader } from "./getman-header";
 import { RequestBar } from "./request-bar";
 
@@ -30,16 +38,35 @@ const SaveRequestDialog = dynamic(
 const OPEN_SAVE_REQUEST_DIALOG_EVENT = "getman:open-save-request-dialog";
 
 export function GetmanApp() {
-  const { sidebarOpen } = useGetmanStore();
+  const { sidebarOpen, theme } = useGetmanStore();
 
   useEffect(() => {
     void hydrateStore();
   }, []);
 
+  // Edits made within the save debounce would otherwise be lost on close
+  useEffect(() => {
+    let disposed = false;
+    let unlisten: (() => void) | undefined;
+    void onWindowClose(flushPersistedState).then((remove) => {
+      if (disposed) remove();
+      else unlisten = remove;
+    });
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, []);
+
+  useEffect(() => {
+    document.documentElement.classList.toggle("dark", theme === "dark");
+  }, [theme]);
+
   const handleKeyDown = useCallback((e: KeyboardEvent) => {
     const mod = e.metaKey || e.ctrlKey;
     if (!mod) return;
     const key = e.key.toLowerCase();
+    if (key !== "k" && isModalOpen()) return;
 
     if (key === "k") {
       e.preventDefault();
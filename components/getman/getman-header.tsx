@@ -1,11 +1,12 @@
 "use client";
 
 import dynamic from "next/dynamic";
-import { PanelLeftClose, PanelLeft, Zap } from "lucide-react";
+import { PanelLeftClose, PanelLeft, Zap, Sun, Moon } from "lucide-react";
 import {
   useGetmanStore,
   setSidebarOpen,
   setActiveEnvironment,
+  setTheme,
 } from "@/lib/getman-store";
 import {
   Select,
@@ -27,9 +28,13 @@ const BenchmarkDialog = dynamic(
   () => import("./benchmark-dialog").then((mod) => mod.BenchmarkDialog),
   { ssr: false }
 );
+const SettingsDialog = dynamic(
+  () => import("./settings-dialog").then((mod) => mod.SettingsDialog),
+  { ssr: false }
+);
 
 export function GetmanHeader() {
-  const { sidebarOpen, environments, activeEnvironmentId } = useGetmanStore();
+  const { sidebarOpen, environments, activeEnvironmentId, theme } = useGetmanStore();
 
   return (
     <header className="flex h-12 shrink-0 items-center gap-3 border-b border-border bg-[hsl(var(--surface-1))] px-3">
@@ -68,6 +73,17 @@ export function GetmanHeader() {
 
       <div className="flex-1" />
 
+      <SettingsDialog />
+
+      <button
+        type="button"
+        onClick={() => setTheme(theme === "dark" ? "light" : "dark")}
+        className="flex h-8 w-8 items-center justify-center rounded-md border border-border bg-[hsl(var(--surface-1))] text-muted-foreground transition-colors hover:text-foreground"
+        title={theme === "dark" ? "Switch to light theme" : "Switch to dark theme"}
+      >
+        {theme === "dark" ? <Sun className="h-4 w-4" /> : <Moon className="h-4 w-4" />}
+      </button>
+
       <Select
         value={activeEnvironmentId || "none"}
         onValueChange={(v) =>
@@ -1,6 +1,6 @@
 "use client";
 
-import { useEffect, useMemo, useState } from "react";
+import { useEffect, useMemo, useRef, useState } from "react";
 import {
   FileText,
   FolderOpen,
@@ -17,6 +17,15 @@ import {
   Pencil,
   X,
   Copy,
+  Workflow as WorkflowIcon,
+  Play,
+  ArrowUp,
+  ArrowDown,
+  Tag,
+  Download,
+  Upload,
+  AlertTriangle,
+  Square,
 } from "lucide-react";
 import {
   useGetmanStore,
@@ -29,11 +38,27 @@ import {
   loadSavedRequest,
   loadHistoryItem,
   clearHistory,
+  deleteFilteredHistory,
+  deleteHistoryItem,
+  setHistoryLimit,
+  setRequestLoggingEnabled,
+  grpcStatusName,
+  isSlowResponse,
+  HISTORY_LIMIT_OPTIONS,
+  setHistoryFilter,
+  resetHistoryFilter,
+  isHistoryFilterActive,
+  queryHistoryItems,
   addCollection,
   deleteCollection,
   renameCollection,
   deleteRequestFromCollection,
+  duplicateRequestInCollection,
   renameRequestInCollection,
+  setSavedRequestTags,
+  collectRequestTags,
+  filterCollections,
+  importIntoCollection,
   addFolderToCollection,
   deleteFolderFromCollection,
   renameFolderInCollection,
@@ -48,11 +73,45 @@ import {
   removeCookieEntry,
   clearCookieJar,
   createEmptyKV,
+  uid,
+  addWorkflow,
+  deleteWorkflow,
+  updateWorkflow,
+  type Collection,
   type GetmanState,
   type CollectionFolder,
+  type HistoryFilter,
+  type HistoryItem,
+  type SavedRequest,
+  type Workflow,
+  type WorkflowStep,
 } from "@/lib/getman-store";
+import {
+  buildFolderRunReport,
+  findFolderChain,
+  requestOutcome,
+  runFolder,
+  runWorkflow,
+  type FolderRunNode,
+  type FolderRunTotals,
+  type WorkflowReport,
+} from "@/lib/runner";
+import {
+  exportRequestItem,
+  parseRequestExport,
+  remapRequestExportIds,
+  requestExportFilename,
+  type RequestExportItem,
+} from "@/lib/request-export";
 import { MethodBadge } from "./method-badge";
 import { ScrollArea } from "@/components/ui/scroll-area";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
 import {
   Dialog,
   DialogContent,
@@ -70,6 +129,7 @@ function SidebarNav() {
     { id: "environments", icon: Globe, label: "Environments" },
     { id: "cookies", icon: Cookie, label: "Cookies" },
     { id: "history", icon: History, label: "History" },
+    { id: "flows", icon: WorkflowIcon, label: "Flows" },
   ];
 
   return (
@@ -346,6 +406,99 @@ function RequestsView() {
   );
 }
 
+function downloadRequestExport(item: RequestExportItem) {
+  const blob = new Blob([exportRequestItem(item)], { type: "application/json" });
+  const url = URL.createObjectURL(blob);
+  const a = document.createElement("a");
+  a.href = url;
+  a.download = requestExportFilename(item);
+  a.click();
+  URL.revokeObjectURL(url);
+}
+
+const MAX_INLINE_TAGS = 3;
+
+function RequestTagChips({ tags }: { tags?: string[] }) {
+  if (!tags || tags.length === 0) return null;
+  const hidden = tags.length - MAX_INLINE_TAGS;
+  return (
+    <span className="flex shrink-0 items-center gap-0.5" title={tags.join(", ")}>
+      {tags.slice(0, MAX_INLINE_TAGS).map((tag) => (
+        <span
+          key={tag}
+          className="max-w-16 truncate rounded bg-[hsl(var(--surface-3))] px-1 text-[10px] text-muted-foreground"
+        >
+          {tag}
+        </span>
+      ))}
+      {hidden > 0 && <span className="text-[10px] text-muted-foreground">+{hidden}</span>}
+    </span>
+  );
+}
+
+function RequestTagEditor({
+  collectionId,
+  request,
+  className,
+  onDone,
+}: {
+  collectionId: string;
+  request: SavedRequest;
+  className: string;
+  onDone: () => void;
+}) {
+  const [draft, setDraft] = useState("");
+  const tags = request.tags ?? [];
+  const save = (next: string[]) => setSavedRequestTags(collectionId, request.id, next);
+  const addDraft = () => {
+    if (draft.trim()) save([...tags, draft]);
+    setDraft("");
+  };
+
+  return (
+    <div className={`flex flex-wrap items-center gap-1 pr-2 pb-1.5 ${className}`}>
+      {tags.map((tag) => (
+        <span
+          key={tag}
+          className="flex items-center gap-0.5 rounded bg-[hsl(var(--surface-3))] px-1 text-[10px] text-foreground/80"
+        >
+          {tag}
+          <button
+            type="button"
+            onClick={() => save(tags.filter((t) => t !== tag))}
+            className="text-muted-foreground hover:text-destructive"
+            title={`Remove tag ${tag}`}
+          >
+            <X className="h-2.5 w-2.5" />
+          </button>
+        </span>
+      ))}
+      <input
+        className="min-w-20 flex-1 bg-[hsl(var(--surface-2))] border border-primary/50 rounded text-[11px] text-foreground px-1.5 py-0.5 outline-none"
+        placeholder="Add tag..."
+        list="saved-request-tags"
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onKeyDown={(e) => {
+          if (e.key === "Enter" || e.key === ",") {
+            e.preventDefault();
+            addDraft();
+          } else if (e.key === "Escape") {
+            onDone();
+          } else if (e.key === "Backspace" && !draft && tags.length > 0) {
+            save(tags.slice(0, -1));
+          }
+        }}
+        onBlur={() => {
+          addDraft();
+          onDone();
+        }}
+        autoFocus
+      />
+    </div>
+  );
+}
+
 function CollectionsView() {
   const { collections } = useGetmanStore();
   const [expandedIds, setExpandedIds] = useState<Set<string>>(
@@ -358,6 +511,21 @@ function CollectionsView() {
   const [editingCollectionName, setEditingCollectionName] = useState("");
   const [editingRequestId, setEditingRequestId] = useState<string | null>(null);
   const [editingRequestName, setEditingRequestName] = useState("");
+  const [editingTagsRequestId, setEditingTagsRequestId] = useState<string | null>(null);
+  const [selectedTags, setSelectedTags] = useState<string[]>([]);
+  const [folderRuns, setFolderRuns] = useState<Record<string, FolderRunState>>({});
+  const folderRunAborts = useRef(new Map<string, AbortController>());
+  const importInputRef = useRef<HTMLInputElement>(null);
+  const importTargetRef = useRef<string | null>(null);
+  const allTags = useMemo(() => collectRequestTags(collections), [collections]);
+  // A tag that no request uses any more stops filtering
+  const activeTags = selectedTags.filter((tag) => allTags.includes(tag));
+
+  const toggleTag = (tag: string) => {
+    setSelectedTags((prev) =>
+      prev.includes(tag) ? prev.filter((t) => t !== tag) : [...prev, tag]
+    );
+  };
 
   const toggleExpand = (id: string) => {
     setExpandedIds((prev) => {
@@ -368,6 +536,53 @@ function CollectionsView() {
     });
   };
 
+  // A dismissed run keeps finishing its current request; drop its updates.
+  const updateFolderRun = (folderId: string, partial: Partial<FolderRunState>) => {
+    setFolderRuns((prev) =>
+      prev[folderId] ? { ...prev, [folderId]: { ...prev[folderId], ...partial } } : prev
+    );
+  };
+
+  const handleRunFolder = async (collectionId: string, folderId: string) => {
+    // Run the whole folder even while a filter narrows it
+    const collection = collections.find((c) => c.id === collectionId);
+    const folder = collection && findFolderChain(collection.folders, folderId)?.at(-1);
+    if (!collection || !folder || folderRunAborts.current.has(folderId)) return;
+
+    const controller = new AbortController();
+    folderRunAborts.current.set(folderId, controller);
+    setFolderRuns((prev) => ({ ...prev, [folderId]: { running: true, current: 0, total: 0 } }));
+    try {
+      const result = await runFolder(
+        collection,
+        folderId,
+        (current, total) => updateFolderRun(folderId, { current, total }),
+        controller.signal
+      );
+      updateFolderRun(folderId, {
+        running: false,
+        report: buildFolderRunReport(folder, result.results),
+        duration: result.totalDuration,
+      });
+    } catch (err) {
+      updateFolderRun(folderId, {
+        running: false,
+        error: err instanceof Error ? err.message : "Folder run failed",
+      });
+    } finally {
+      folderRunAborts.current.delete(folderId);
+    }
+  };
+
+  const dismissFolderRun = (folderId: string) => {
+    folderRunAborts.current.get(folderId)?.abort();
+    setFolderRuns((prev) => {
+      const next = { ...prev };
+      delete next[folderId];
+      return next;
+    });
+  };
+
   const handleCreateCollection = () => {
     if (newCollectionName.trim()) {
       addCollection(newCollectionName.trim());
@@ -376,22 +591,34 @@ function CollectionsView() {
     }
   };
 
-  const filteredCollections = searchQuery.trim()
-    ? collections
-        .map((col) => ({
-          ...col,
-          requests: col.requests.filter(
-            (req) =>
-              req.name.toLowerCase().includes(searchQuery.toLowerCase()) ||
-              req.url.toLowerCase().includes(searchQuery.toLowerCase())
-          ),
-        }))
-        .filter(
-          (col) =>
-            col.name.toLowerCase().includes(searchQuery.toLowerCase()) ||
-            col.requests.length > 0
-        )
-    : collections;
+  const filteredCollections = filterCollections(collections, searchQuery, activeTags);
+
+  const startImport = (collectionId: string) => {
+    importTargetRef.current = collectionId;
+    importInputRef.current?.click();
+  };
+
+  const handleImportFile = (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    const collectionId = importTargetRef.current;
+    e.target.value = "";
+    if (!file || !collectionId) return;
+    const reader = new FileReader();
+    reader.onload = (ev) => {
+      try {
+        const item = remapRequestExportIds(parseRequestExport(ev.target?.result as string));
+        if (item.type === "request") {
+          importIntoCollection(collectionId, { requests: [item.request] });
+        } else {
+          importIntoCollection(collectionId, { folders: [item.folder] });
+          setExpandedIds((prev) => new Set(prev).add(collectionId));
+        }
+      } catch (err) {
+        alert(err instanceof Error ? err.message : "Failed to import");
+      }
+    };
+    reader.readAsText(file);
+  };
 
   return (
     <div className="flex flex-col h-full">
@@ -453,6 +680,41 @@ function CollectionsView() {
             </button>
           )}
         </div>
+        {allTags.length > 0 && (
+          <div className="mt-1.5 flex flex-wrap items-center gap-1">
+            <Tag className="h-3 w-3 text-muted-foreground shrink-0" />
+            {allTags.map((tag) => {
+              const selected = activeTags.includes(tag);
+              return (
+                <button
+                  key={tag}
+                  type="button"
+                  onClick={() => toggleTag(tag)}
+                  className={`rounded px-1.5 py-0.5 text-[10px] transition-colors ${
+                    selected
+                      ? "bg-primary/15 text-primary"
+                      : "bg-[hsl(var(--surface-2))] text-muted-foreground hover:text-foreground"
+                  }`}
+                  title={selected ? `Stop filtering by ${tag}` : `Show requests tagged ${tag}`}
+                >
+                  {tag}
+                </button>
+              );
+            })}
+          </div>
+        )}
+        <input
+          ref={importInputRef}
+          type="file"
+          accept=".json,application/json"
+          onChange={handleImportFile}
+          className="hidden"
+        />
+        <datalist id="saved-request-tags">
+          {allTags.map((tag) => (
+            <option key={tag} value={tag} />
+          ))}
+        </datalist>
       </div>
 
       <ScrollArea className="flex-1">
@@ -530,6 +792,17 @@ function CollectionsView() {
                   >
                     <Trash2 className="h-3 w-3" />
                   </button>
+                  <button
+                    type="button"
+                    onClick={(e) => {
+                      e.stopPropagation();
+                      startImport(col.id);
+                    }}
+                    className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                    title="Import a shared request or folder"
+                  >
+                    <Upload className="h-3 w-3" />
+                  </button>
                   <button
                     type="button"
                     onClick={(e) => {
@@ -569,6 +842,31 @@ function CollectionsView() {
                             <span className="text-[10px] text-muted-foreground">
                               {folder.requests.length}
                             </span>
+                            {folderRuns[folder.id]?.running ? (
+                              <button
+                                type="button"
+                                onClick={(e) => {
+                                  e.stopPropagation();
+                                  folderRunAborts.current.get(folder.id)?.abort();
+                                }}
+                                className="text-muted-foreground hover:text-destructive transition-colors"
+                                title="Stop folder run"
+                              >
+                                <Square className="h-2.5 w-2.5" />
+                              </button>
+                            ) : (
+                              <button
+                                type="button"
+                                onClick={(e) => {
+                                  e.stopPropagation();
+                                  void handleRunFolder(col.id, folder.id);
+                                }}
+                                className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                                title="Run all requests in folder"
+                              >
+                                <Play className="h-2.5 w-2.5" />
+                              </button>
+                            )}
                             <button
                               type="button"
                               onClick={(e) => {
@@ -580,6 +878,21 @@ function CollectionsView() {
                             >
                               <Pencil className="h-2.5 w-2.5" />
                             </button>
+                            <button
+                              type="button"
+                              onClick={(e) => {
+                                e.stopPropagation();
+                                // Export the whole folder even while a filter narrows it
+                                const original = collections
+                                  .find((c) => c.id === col.id)
+                                  ?.folders.find((f) => f.id === folder.id);
+                                downloadRequestExport({ type: "folder", folder: original ?? folder });
+                              }}
+                              className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                              title="Export folder"
+                            >
+                              <Download className="h-2.5 w-2.5" />
+                            </button>
                             <button
                               type="button"
                               onClick={(e) => {
@@ -591,20 +904,108 @@ function CollectionsView() {
                               <Trash2 className="h-2.5 w-2.5" />
                             </button>
                           </div>
+                          {folderRuns[folder.id] && (
+                            <FolderRunPanel
+                              run={folderRuns[folder.id]}
+                              onDismiss={() => dismissFolderRun(folder.id)}
+                            />
+                          )}
                           {isFolderExpanded &&
-                            folder.requests.map((req) => (
+                            folder.requests.map((req) => {
+                              const isEditingReq = editingRequestId === req.id;
+                              return (
+                              <div key={req.id}>
                               <div
-                                key={req.id}
                                 className="group flex items-center gap-2 pl-12 pr-2 py-1.5 hover:bg-[hsl(var(--surface-2))] cursor-pointer"
-                                onClick={() => loadSavedRequest(req)}
-                                onKeyDown={(e) => e.key === "Enter" && loadSavedRequest(req)}
+                                onClick={() => !isEditingReq && loadSavedRequest(req)}
+                                onKeyDown={(e) => e.key === "Enter" && !isEditingReq && loadSavedRequest(req)}
                                 role="button"
                                 tabIndex={0}
                               >
                                 <MethodBadge method={req.method} size="sm" />
-                                <span className="text-xs text-foreground/80 flex-1 truncate font-mono">
-                                  {req.name}
-                                </span>
+                                {isEditingReq ? (
+                                  <input
+                                    className="flex-1 bg-[hsl(var(--surface-2))] border border-primary/50 rounded text-xs text-foreground/80 font-mono px-1.5 py-0.5 outline-none"
+                                    value={editingRequestName}
+                                    onChange={(e) => setEditingRequestName(e.target.value)}
+                                    onClick={(e) => e.stopPropagation()}
+                                    onKeyDown={(e) => {
+                                      e.stopPropagation();
+                                      if (e.key === "Enter" && editingRequestName.trim()) {
+                                        renameRequestInCollection(col.id, req.id, editingRequestName.trim());
+                                        setEditingRequestId(null);
+                                      } else if (e.key === "Escape") {
+                                        setEditingRequestId(null);
+                                      }
+                                    }}
+                                    onBlur={() => {
+                                      if (editingRequestName.trim()) {
+                                        renameRequestInCollection(col.id, req.id, editingRequestName.trim());
+                                      }
+                                      setEditingRequestId(null);
+                                    }}
+                                    autoFocus
+                                  />
+                                ) : (
+                                  <span
+                                    className="text-xs text-foreground/80 flex-1 truncate font-mono"
+                                    onDoubleClick={(e) => {
+                                      e.stopPropagation();
+                                      setEditingRequestId(req.id);
+                                      setEditingRequestName(req.name);
+                                    }}
+                                  >
+                                    {req.name}
+                                  </span>
+                                )}
+                                <RequestTagChips tags={req.tags} />
+                                {!isEditingReq && (
+                                  <button
+                                    type="button"
+                                    onClick={(e) => {
+                                      e.stopPropagation();
+                                      setEditingRequestId(req.id);
+                                      setEditingRequestName(req.name);
+                                    }}
+                                    className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                                    title="Rename request"
+                                  >
+                                    <Pencil className="h-3 w-3" />
+                                  </button>
+                                )}
+                                <button
+                                  type="button"
+                                  onClick={(e) => {
+                                    e.stopPropagation();
+                                    setEditingTagsRequestId(req.id);
+                                  }}
+                                  className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                                  title="Edit tags"
+                                >
+                                  <Tag className="h-3 w-3" />
+                                </button>
+                                <button
+                                  type="button"
+                                  onClick={(e) => {
+                                    e.stopPropagation();
+                                    downloadRequestExport({ type: "request", request: req });
+                                  }}
+                                  className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                                  title="Export request"
+                                >
+                                  <Download className="h-3 w-3" />
+                                </button>
+                                <button
+                                  type="button"
+                                  onClick={(e) => {
+                                    e.stopPropagation();
+                                    duplicateRequestInCollection(col.id, req.id);
+                                  }}
+                                  className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                                  title="Duplicate request"
+                                >
+                                  <Copy className="h-3 w-3" />
+                                </button>
                                 <button
                                   type="button"
                                   onClick={(e) => {
@@ -616,7 +1017,17 @@ function CollectionsView() {
                                   <Trash2 className="h-3 w-3" />
                                 </button>
                               </div>
-                            ))}
+                              {editingTagsRequestId === req.id && (
+                                <RequestTagEditor
+                                  collectionId={col.id}
+                                  request={req}
+                                  className="pl-12"
+                                  onDone={() => setEditingTagsRequestId(null)}
+                                />
+                              )}
+                              </div>
+                              );
+                            })}
                         </div>
                       );
                     })}
@@ -625,8 +1036,8 @@ function CollectionsView() {
                     {col.requests.map((req) => {
                     const isEditingReq = editingRequestId === req.id;
                     return (
+                      <div key={req.id}>
                       <div
-                        key={req.id}
                         className="group flex items-center gap-2 pl-8 pr-2 py-1.5 hover:bg-[hsl(var(--surface-2))] cursor-pointer"
                         onClick={() => !isEditingReq && loadSavedRequest(req)}
                         onKeyDown={(e) => e.key === "Enter" && !isEditingReq && loadSavedRequest(req)}
@@ -669,6 +1080,7 @@ function CollectionsView() {
                             {req.name}
                           </span>
                         )}
+                        <RequestTagChips tags={req.tags} />
                         {!isEditingReq && (
                           <button
                             type="button"
@@ -683,6 +1095,39 @@ function CollectionsView() {
                             <Pencil className="h-3 w-3" />
                           </button>
                         )}
+                        <button
+                          type="button"
+                          onClick={(e) => {
+                            e.stopPropagation();
+                            setEditingTagsRequestId(req.id);
+                          }}
+                          className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                          title="Edit tags"
+                        >
+                          <Tag className="h-3 w-3" />
+                        </button>
+                        <button
+                          type="button"
+                          onClick={(e) => {
+                            e.stopPropagation();
+                            downloadRequestExport({ type: "request", request: req });
+                          }}
+                          className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                          title="Export request"
+                        >
+                          <Download className="h-3 w-3" />
+                        </button>
+                        <button
+                          type="button"
+                          onClick={(e) => {
+                            e.stopPropagation();
+                            duplicateRequestInCollection(col.id, req.id);
+                          }}
+                          className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground transition-opacity"
+                          title="Duplicate request"
+                        >
+                          <Copy className="h-3 w-3" />
+                        </button>
                         <button
                           type="button"
                           onClick={(e) => {
@@ -694,6 +1139,15 @@ function CollectionsView() {
                           <Trash2 className="h-3 w-3" />
                         </button>
                       </div>
+                      {editingTagsRequestId === req.id && (
+                        <RequestTagEditor
+                          collectionId={col.id}
+                          request={req}
+                          className="pl-8"
+                          onDone={() => setEditingTagsRequestId(null)}
+                        />
+                      )}
+                      </div>
                     );
                   })}
                   </>
@@ -704,7 +1158,7 @@ function CollectionsView() {
           {filteredCollections.length === 0 && (
             <div className="px-4 py-8 text-center">
               <p className="text-xs text-muted-foreground">
-                {searchQuery ? "No matching results" : "No collections yet"}
+                {searchQuery || activeTags.length > 0 ? "No matching results" : "No collections yet"}
               </p>
             </div>
           )}
@@ -714,8 +1168,44 @@ function CollectionsView() {
   );
 }
 
+const historyMethodOptions: HistoryFilter["method"][] = [
+  "ALL", "GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS",
+];
+
+const historyStatusOptions: { value: string; label: string; min: number; max: number }[] = [
+  { value: "all", label: "Any status", min: 0, max: 999 },
+  { value: "2xx", label: "2xx", min: 200, max: 299 },
+  { value: "3xx", label: "3xx", min: 300, max: 399 },
+  { value: "4xx", label: "4xx", min: 400, max: 499 },
+  { value: "5xx", label: "5xx", min: 500, max: 599 },
+  { value: "error", label: "Errors", min: 0, max: 0 },
+];
+
 function HistoryView() {
-  const { history } = useGetmanStore();
+  const { history, historyFilter, historyLimit, requestLogging, slowThresholdMs } =
+    useGetmanStore();
+  const [results, setResults] = useState<HistoryItem[] | null>(null);
+  const filterActive = isHistoryFilterActive(historyFilter);
+
+  useEffect(() => {
+    if (!filterActive) {
+      setResults(null);
+      return;
+    }
+    let cancelled = false;
+    void queryHistoryItems().then((items) => {
+      if (!cancelled) setResults(items);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [filterActive, historyFilter, history]);
+
+  const items = results ?? history;
+  const statusValue =
+    historyStatusOptions.find(
+      (o) => o.min === historyFilter.statusMin && o.max === historyFilter.statusMax
+    )?.value ?? "all";
 
   const formatTime = (ts: number) => {
     const d = new Date(ts);
@@ -732,7 +1222,16 @@ function HistoryView() {
         <span className="text-[11px] font-medium text-muted-foreground uppercase tracking-wider">
           History
         </span>
-        {history.length > 0 && (
+        {filterActive && items.length > 0 ? (
+          <button
+            type="button"
+            onClick={() => void deleteFilteredHistory()}
+            className="text-[10px] text-muted-foreground hover:text-destructive transition-colors"
+            title="Delete every entry matching the filters"
+          >
+            Delete matching
+          </button>
+        ) : history.length > 0 ? (
           <button
             type="button"
             onClick={clearHistory}
@@ -740,14 +1239,117 @@ function HistoryView() {
           >
             Clear
           </button>
-        )}
+        ) : null}
+      </div>
+      <div className="flex flex-col gap-1.5 px-2 py-1.5 border-b border-border/40">
+        <div className="flex items-center gap-1.5 bg-[hsl(var(--surface-2))] rounded px-2 py-1">
+          <Search className="h-3 w-3 text-muted-foreground shrink-0" />
+          <input
+            className="flex-1 bg-transparent text-xs text-foreground outline-none placeholder:text-muted-foreground/50"
+            placeholder="Filter by URL..."
+            value={historyFilter.search}
+            onChange={(e) => setHistoryFilter({ search: e.target.value })}
+          />
+          {filterActive && (
+            <button
+              type="button"
+              onClick={resetHistoryFilter}
+              className="text-muted-foreground hover:text-foreground"
+              title="Reset filters"
+            >
+              <X className="h-3 w-3" />
+            </button>
+          )}
+        </div>
+        <div className="flex items-center gap-1.5">
+          <Select
+            value={historyFilter.method}
+            onValueChange={(v) => setHistoryFilter({ method: v as HistoryFilter["method"] })}
+          >
+            <SelectTrigger className="h-7 flex-1 text-[11px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {historyMethodOptions.map((m) => (
+                <SelectItem key={m} value={m} className="text-xs">
+                  {m === "ALL" ? "Any method" : m}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <Select
+            value={statusValue}
+            onValueChange={(v) => {
+              const option = historyStatusOptions.find((o) => o.value === v);
+              if (option) setHistoryFilter({ statusMin: option.min, statusMax: option.max });
+            }}
+          >
+            <SelectTrigger className="h-7 flex-1 text-[11px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {historyStatusOptions.map((o) => (
+                <SelectItem key={o.value} value={o.value} className="text-xs">
+                  {o.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+        <div className="flex items-center gap-1.5 text-[10px] text-muted-foreground">
+          <input
+            type="date"
+            className="h-6 flex-1 rounded bg-[hsl(var(--surface-2))] px-1.5 text-[10px] text-foreground outline-none"
+            value={historyFilter.dateFrom}
+            onChange={(e) => setHistoryFilter({ dateFrom: e.target.value })}
+            title="From date"
+          />
+          <span>–</span>
+          <input
+            type="date"
+            className="h-6 flex-1 rounded bg-[hsl(var(--surface-2))] px-1.5 text-[10px] text-foreground outline-none"
+            value={historyFilter.dateTo}
+            onChange={(e) => setHistoryFilter({ dateTo: e.target.value })}
+            title="To date"
+          />
+        </div>
+        <div className="flex items-center justify-between gap-1.5 text-[10px] text-muted-foreground">
+          <span>Keep</span>
+          <Select
+            value={String(historyLimit)}
+            onValueChange={(v) => setHistoryLimit(Number(v))}
+          >
+            <SelectTrigger className="h-6 flex-1 text-[10px]" title="History retention">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {HISTORY_LIMIT_OPTIONS.map((limit) => (
+                <SelectItem key={limit} value={String(limit)} className="text-xs">
+                  {limit === 0 ? "All entries" : `Last ${limit} entries`}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+        <label
+          className="flex items-center gap-1.5 text-[10px] text-muted-foreground"
+          title="Append every request and response, with secrets redacted, to logs/requests.log in the app data folder"
+        >
+          <input
+            type="checkbox"
+            checked={requestLogging}
+            onChange={(e) => setRequestLoggingEnabled(e.target.checked)}
+            className="h-3 w-3 rounded border-border accent-primary"
+          />
+          Write debug log file
+        </label>
       </div>
       <ScrollArea className="flex-1">
         <div className="py-1">
-          {history.map((item) => (
+          {items.map((item) => (
             <div
               key={item.id}
-              className="flex items-center gap-2 px-3 py-2 hover:bg-[hsl(var(--surface-2))] cursor-pointer transition-colors"
+              className="group flex items-center gap-2 px-3 py-2 hover:bg-[hsl(var(--surface-2))] cursor-pointer transition-colors"
               onClick={() => loadHistoryItem(item)}
               onKeyDown={(e) => e.key === "Enter" && loadHistoryItem(item)}
               role="button"
@@ -760,6 +1362,14 @@ function HistoryView() {
                 </p>
               </div>
               <div className="flex items-center gap-2 shrink-0">
+                {(item.count ?? 1) > 1 && (
+                  <span
+                    className="rounded bg-[hsl(var(--surface-3))] px-1 text-[10px] font-mono text-muted-foreground"
+                    title={`Sent ${item.count} times in a row`}
+                  >
+                    x{item.count}
+                  </span>
+                )}
                 <span
                   className={`text-[10px] font-mono font-bold ${
                     item.status >= 200 && item.status < 300
@@ -769,19 +1379,45 @@ function HistoryView() {
                         : "text-muted-foreground"
                   }`}
                 >
-                  {item.status}
+                  {item.cancelled
+                    ? "CANCELLED"
+                    : item.grpcStatus !== undefined
+                      ? grpcStatusName(item.grpcStatus)
+                      : item.status}
                 </span>
+                {isSlowResponse(item.time, slowThresholdMs) && (
+                  <span
+                    className="flex items-center gap-0.5 text-[10px] font-mono text-amber-500"
+                    title={`Took ${item.time}ms, over the ${slowThresholdMs}ms warning threshold`}
+                  >
+                    <AlertTriangle className="h-2.5 w-2.5" />
+                    {item.time}ms
+                  </span>
+                )}
                 <span className="text-[10px] text-muted-foreground flex items-center gap-0.5">
                   <Clock className="h-2.5 w-2.5" />
-                  {formatTime(item.timestamp)}
+                  {formatTime(item.lastTimestamp ?? item.timestamp)}
                 </span>
+                <button
+                  type="button"
+                  onClick={(e) => {
+                    e.stopPropagation();
+                    void deleteHistoryItem(item.id);
+                  }}
+                  className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-destructive transition-opacity"
+                  title="Delete entry"
+                >
+                  <Trash2 className="h-3 w-3" />
+                </button>
               </div>
             </div>
           ))}
-          {history.length === 0 && (
+          {items.length === 0 && (
             <div className="px-4 py-8 text-center">
               <p className="text-xs text-muted-foreground">
-                No history yet. Send a request to get started.
+                {filterActive
+                  ? "No history matches these filters"
+                  : "No history yet. Send a request to get started."}
               </p>
             </div>
           )}
@@ -1104,6 +1740,25 @@ function EnvironmentsView() {
 
                 {isExpanded && (
                   <div className="pl-8 pr-3 py-2 space-y-1.5">
+                    <input
+                      className="w-full bg-[hsl(var(--surface-2))] border border-border/50 rounded text-[11px] font-mono text-foreground px-2 py-1 outline-none focus:border-primary/50"
+                      placeholder="Base URL (prepended to relative URLs)"
+                      value={env.baseUrl ?? ""}
+                      onChange={(e) => updateEnvironment(env.id, { baseUrl: e.target.value })}
+                    />
+                    <input
+                      type="number"
+                      className="w-full bg-[hsl(var(--surface-2))] border border-border/50 rounded text-[11px] font-mono text-foreground px-2 py-1 outline-none focus:border-primary/50"
+                      placeholder="Default timeout in ms (blank uses Settings)"
+                      title="Timeout for requests that set none while this environment is active"
+                      value={env.timeoutMs || ""}
+                      onChange={(e) => {
+                        const timeoutMs = Math.max(0, Math.floor(Number(e.target.value) || 0));
+                        updateEnvironment(env.id, { timeoutMs: timeoutMs || undefined });
+                      }}
+                      min={0}
+                      step={1000}
+                    />
                     {env.variables.map((v, i) => (
                       <div key={v.id} className="flex items-center gap-2">
                         <input
@@ -1297,6 +1952,404 @@ function CookiesView() {
   );
 }
 
+interface FlowRequestOption {
+  key: string;
+  collectionId: string;
+  request: SavedRequest;
+  label: string;
+}
+
+function flowRequestOptions(collections: Collection[]): FlowRequestOption[] {
+  const options: FlowRequestOption[] = [];
+  const visit = (
+    collectionId: string,
+    prefix: string,
+    requests: SavedRequest[],
+    folders: CollectionFolder[]
+  ) => {
+    for (const request of requests) {
+      options.push({
+        key: `${collectionId}::${request.id}`,
+        collectionId,
+        request,
+        label: `${prefix} / ${request.name}`,
+      });
+    }
+    for (const folder of folders) {
+      visit(collectionId, `${prefix} / ${folder.name}`, folder.requests, folder.folders);
+    }
+  };
+  for (const collection of collections) {
+    visit(collection.id, collection.name, collection.requests, collection.folders);
+  }
+  return options;
+}
+
+const flowStatusClass: Record<WorkflowReport["steps"][number]["status"], string> = {
+  passed: "text-green-500",
+  failed: "text-destructive",
+  skipped: "text-muted-foreground",
+};
+
+interface FolderRunState {
+  running: boolean;
+  current: number;
+  total: number;
+  report?: FolderRunNode;
+  duration?: number;
+  error?: string;
+}
+
+function FolderRunTotalsLabel({ totals }: { totals: FolderRunTotals }) {
+  return (
+    <span className="flex shrink-0 gap-1.5">
+      <span className="text-green-500">{totals.passed} passed</span>
+      {totals.failed > 0 && <span className="text-destructive">{totals.failed} failed</span>}
+      {totals.skipped > 0 && (
+        <span className="text-muted-foreground">{totals.skipped} skipped</span>
+      )}
+    </span>
+  );
+}
+
+function FolderRunTree({ node, depth }: { node: FolderRunNode; depth: number }) {
+  return (
+    <>
+      {depth > 0 && (
+        <div
+          className="flex items-center gap-1.5 text-[10px]"
+          style={{ paddingLeft: (depth - 1) * 10 }}
+        >
+          <FolderOpen className="h-2.5 w-2.5 shrink-0 text-amber-500/70" />
+          <span className="min-w-0 flex-1 truncate text-foreground/80">{node.folderName}</span>
+          <FolderRunTotalsLabel totals={node.totals} />
+        </div>
+      )}
+      {node.results.map((result, index) => {
+        const outcome = requestOutcome(result);
+        const failedAssertions = result.assertionResults.filter((a) => !a.passed);
+        return (
+          <div
+            key={`${result.requestId}-${index}`}
+            className="text-[10px] font-mono"
+            style={{ paddingLeft: depth * 10 }}
+          >
+            <div className="flex items-center gap-1.5">
+              <span className={flowStatusClass[outcome]}>{outcome}</span>
+              <span className="min-w-0 flex-1 truncate text-foreground/80">
+                {result.requestName}
+              </span>
+              {!result.skipped && (
+                <span className="text-muted-foreground">
+                  {result.response.status} · {result.duration} ms
+                </span>
+              )}
+            </div>
+            {(result.skipReason || failedAssertions[0]) && (
+              <div
+                className="truncate text-muted-foreground"
+                title={result.skipReason ?? failedAssertions[0]?.message}
+              >
+                {result.skipReason ?? failedAssertions[0]?.message}
+              </div>
+            )}
+          </div>
+        );
+      })}
+      {node.folders.map((child) => (
+        <FolderRunTree key={child.folderId} node={child} depth={depth + 1} />
+      ))}
+    </>
+  );
+}
+
+/** Inline progress and pass/fail report of a folder run, under the folder row. */
+function FolderRunPanel({ run, onDismiss }: { run: FolderRunState; onDismiss: () => void }) {
+  return (
+    <div className="ml-6 flex flex-col gap-1 border-l border-border/40 px-3 py-1.5">
+      <div className="flex items-center gap-1.5 text-[10px]">
+        {run.running ? (
+          <span className="flex-1 text-muted-foreground">
+            Running {run.current}/{run.total || "…"}
+          </span>
+        ) : run.error ? (
+          <span className="flex-1 truncate text-destructive" title={run.error}>
+            {run.error}
+          </span>
+        ) : run.report ? (
+          <>
+            <FolderRunTotalsLabel totals={run.report.totals} />
+            <span className="flex-1 text-right text-muted-foreground">{run.duration} ms</span>
+          </>
+        ) : (
+          <span className="flex-1" />
+        )}
+        <button
+          type="button"
+          onClick={onDismiss}
+          className="text-muted-foreground hover:text-foreground transition-colors"
+          title={run.running ? "Stop and close report" : "Close report"}
+        >
+          <X className="h-2.5 w-2.5" />
+        </button>
+      </div>
+      {run.report &&
+        (run.report.totals.passed + run.report.totals.failed + run.report.totals.skipped > 0 ? (
+          <FolderRunTree node={run.report} depth={0} />
+        ) : (
+          <span className="text-[10px] text-muted-foreground">No requests in this folder</span>
+        ))}
+    </div>
+  );
+}
+
+function FlowsView() {
+  const { workflows, collections } = useGetmanStore();
+  const [expandedId, setExpandedId] = useState<string | null>(null);
+  const [runningId, setRunningId] = useState<string | null>(null);
+  const [reports, setReports] = useState<Record<string, WorkflowReport>>({});
+  const requestOptions = useMemo(() => flowRequestOptions(collections), [collections]);
+
+  const setSteps = (workflow: Workflow, steps: WorkflowStep[]) =>
+    updateWorkflow(workflow.id, { steps });
+
+  const addStep = (workflow: Workflow, key: string) => {
+    const option = requestOptions.find((o) => o.key === key);
+    if (!option) return;
+    const step: WorkflowStep = {
+      id: uid(),
+      name: `step${workflow.steps.length + 1}`,
+      collectionId: option.collectionId,
+      requestId: option.request.id,
+    };
+    setSteps(workflow, [...workflow.steps, step]);
+  };
+
+  const moveStep = (workflow: Workflow, index: number, offset: number) => {
+    const target = index + offset;
+    if (target < 0 || target >= workflow.steps.length) return;
+    const steps = [...workflow.steps];
+    [steps[index], steps[target]] = [steps[target], steps[index]];
+    setSteps(workflow, steps);
+  };
+
+  const handleRun = async (workflow: Workflow) => {
+    setRunningId(workflow.id);
+    try {
+      const report = await runWorkflow(workflow, collections);
+      setReports((prev) => ({ ...prev, [workflow.id]: report }));
+    } finally {
+      setRunningId(null);
+    }
+  };
+
+  const handleCreate = () => {
+    setExpandedId(addWorkflow(`Flow ${workflows.length + 1}`));
+  };
+
+  return (
+    <div className="flex flex-col h-full">
+      <div className="flex items-center justify-between border-b border-border/60 bg-[hsl(var(--surface-1))] px-3 py-2">
+        <span className="text-[11px] font-medium text-muted-foreground uppercase tracking-wider">
+          Flows
+        </span>
+        <button
+          type="button"
+          onClick={handleCreate}
+          className="text-muted-foreground hover:text-foreground transition-colors"
+          title="New flow"
+        >
+          <Plus className="h-3.5 w-3.5" />
+        </button>
+      </div>
+
+      <ScrollArea className="flex-1">
+        <div className="py-1">
+          {workflows.map((workflow) => {
+            const expanded = expandedId === workflow.id;
+            const report = reports[workflow.id];
+            return (
+              <div key={workflow.id} className="border-b border-border/40">
+                <div className="group flex items-center gap-1.5 px-2 py-1.5 hover:bg-[hsl(var(--surface-2))]">
+                  <button
+                    type="button"
+                    onClick={() => setExpandedId(expanded ? null : workflow.id)}
+                    className="text-muted-foreground"
+                  >
+                    {expanded ? (
+                      <ChevronDown className="h-3 w-3" />
+                    ) : (
+                      <ChevronRight className="h-3 w-3" />
+                    )}
+                  </button>
+                  <input
+                    className="min-w-0 flex-1 bg-transparent text-xs text-foreground outline-none"
+                    value={workflow.name}
+                    onChange={(e) => updateWorkflow(workflow.id, { name: e.target.value })}
+                  />
+                  <span className="text-[10px] text-muted-foreground">
+                    {workflow.steps.length} steps
+                  </span>
+                  <button
+                    type="button"
+                    onClick={() => void handleRun(workflow)}
+                    disabled={runningId !== null || workflow.steps.length === 0}
+                    className="text-muted-foreground hover:text-primary disabled:opacity-40"
+                    title="Run flow"
+                  >
+                    <Play className="h-3 w-3" />
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => deleteWorkflow(workflow.id)}
+                    className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-destructive transition-opacity"
+                    title="Delete flow"
+                  >
+                    <Trash2 className="h-3 w-3" />
+                  </button>
+                </div>
+
+                {expanded && (
+                  <div className="flex flex-col gap-1 px-3 pb-2">
+                    {workflow.steps.map((step, index) => {
+                      const option = requestOptions.find(
+                        (o) => o.key === `${step.collectionId}::${step.requestId}`
+                      );
+                      return (
+                        <div key={step.id} className="group/step flex items-center gap-1.5">
+                          <span className="w-4 text-[10px] text-muted-foreground">{index + 1}</span>
+                          <input
+                            className="w-16 shrink-0 rounded bg-[hsl(var(--surface-2))] px-1 py-0.5 font-mono text-[10px] text-foreground outline-none"
+                            value={step.name}
+                            title="Alias used in {{alias.body.path}} references"
+                            onChange={(e) =>
+                              setSteps(
+                                workflow,
+                                workflow.steps.map((s) =>
+                                  s.id === step.id ? { ...s, name: e.target.value.trim() } : s
+                                )
+                              )
+                            }
+                          />
+                          {option ? (
+                            <>
+                              <MethodBadge method={option.request.method} />
+                              <span className="min-w-0 flex-1 truncate text-[11px]" title={option.label}>
+                                {option.request.name}
+                              </span>
+                            </>
+                          ) : (
+                            <span className="min-w-0 flex-1 truncate text-[11px] text-destructive">
+                              Missing request
+                            </span>
+                          )}
+                          <button
+                            type="button"
+                            onClick={() => moveStep(workflow, index, -1)}
+                            className="opacity-0 group-hover/step:opacity-100 text-muted-foreground hover:text-foreground"
+                            title="Move up"
+                          >
+                            <ArrowUp className="h-3 w-3" />
+                          </button>
+                          <button
+                            type="button"
+                            onClick={() => moveStep(workflow, index, 1)}
+                            className="opacity-0 group-hover/step:opacity-100 text-muted-foreground hover:text-foreground"
+                            title="Move down"
+                          >
+                            <ArrowDown className="h-3 w-3" />
+                          </button>
+                          <button
+                            type="button"
+                            onClick={() =>
+                              setSteps(workflow, workflow.steps.filter((s) => s.id !== step.id))
+                            }
+                            className="opacity-0 group-hover/step:opacity-100 text-muted-foreground hover:text-destructive"
+                            title="Remove step"
+                          >
+                            <X className="h-3 w-3" />
+                          </button>
+                        </div>
+                      );
+                    })}
+
+                    <Select value="" onValueChange={(key) => addStep(workflow, key)}>
+                      <SelectTrigger className="h-7 text-[11px]">
+                        <SelectValue placeholder="Add step from a saved request..." />
+                      </SelectTrigger>
+                      <SelectContent>
+                        {requestOptions.map((option) => (
+                          <SelectItem key={option.key} value={option.key} className="text-xs">
+                            {option.request.method} {option.label}
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                    {requestOptions.length === 0 && (
+                      <p className="text-[10px] text-muted-foreground">
+                        Save requests to a collection to use them as steps.
+                      </p>
+                    )}
+                    <p className="text-[10px] text-muted-foreground">
+                      Reference earlier steps with{" "}
+                      <code className="font-mono">{"{{step1.body.id}}"}</code>,{" "}
+                      <code className="font-mono">{"{{step1.status}}"}</code> or{" "}
+                      <code className="font-mono">{"{{step1.headers.Location}}"}</code>.
+                    </p>
+                  </div>
+                )}
+
+                {report && (
+                  <div className="flex flex-col gap-1 border-t border-border/40 px-3 py-1.5">
+                    <div className="flex items-center justify-between text-[10px]">
+                      <span className={report.passed ? "text-green-500" : "text-destructive"}>
+                        {report.passed ? "Passed" : "Failed"}
+                      </span>
+                      <span className="text-muted-foreground">{report.totalDuration} ms</span>
+                    </div>
+                    {report.steps.map((step) => (
+                      <div key={step.stepId} className="text-[10px] font-mono">
+                        <div className="flex items-center gap-1.5">
+                          <span className={flowStatusClass[step.status]}>{step.status}</span>
+                          <span className="min-w-0 flex-1 truncate text-foreground/80">
+                            {step.stepName}
+                          </span>
+                          {step.result && (
+                            <span className="text-muted-foreground">
+                              {step.result.response.status} · {step.result.duration} ms
+                            </span>
+                          )}
+                        </div>
+                        {step.error && (
+                          <div className="truncate text-muted-foreground" title={step.error}>
+                            {step.error}
+                          </div>
+                        )}
+                        {Object.entries(step.captured).map(([key, value]) => (
+                          <div key={key} className="truncate text-muted-foreground" title={value}>
+                            {key} = {value}
+                          </div>
+                        ))}
+                      </div>
+                    ))}
+                  </div>
+                )}
+              </div>
+            );
+          })}
+          {workflows.length === 0 && (
+            <div className="px-4 py-8 text-center">
+              <p className="text-xs text-muted-foreground">
+                No flows yet. Chain saved requests into an ordered run.
+              </p>
+            </div>
+          )}
+        </div>
+      </ScrollArea>
+    </div>
+  );
+}
+
 export function GetmanSidebar() {
   const { sidebarView } = useGetmanStore();
 
@@ -1310,6 +2363,7 @@ export function GetmanSidebar() {
           {sidebarView === "history" && <HistoryView />}
           {sidebarView === "environments" && <EnvironmentsView />}
           {sidebarView === "cookies" && <CookiesView />}
+          {sidebarView === "flows" && <FlowsView />}
         </div>
       </div>
     </div>
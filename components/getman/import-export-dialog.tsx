@@ -12,7 +12,7 @@ import { exportCliFormat, exportShellScript } from "@/lib/cli-export";
 import { exportCollectionDocsMarkdown } from "@/lib/api-docs";
 import {
   diffOpenApiCollections,
-  importOpenApiCollection,
+  importOpenApi,
   type OpenApiSyncSummary,
 } from "@/lib/openapi";
 import {
@@ -39,6 +39,7 @@ export function ImportExportDialog() {
   const [openApiSyncReport, setOpenApiSyncReport] = useState<OpenApiSyncSummary | null>(null);
   const [importError, setImportError] = useState<string | null>(null);
   const [importSuccess, setImportSuccess] = useState<string | null>(null);
+  const [importWarnings, setImportWarnings] = useState<string[]>([]);
   const [exportCollectionId, setExportCollectionId] = useState<string>("");
   const [exportFormat, setExportFormat] = useState<"postman" | "cli-json" | "shell" | "docs-md">(
     "postman"
@@ -52,6 +53,7 @@ export function ImportExportDialog() {
     setImportError(null);
     setImportSuccess(null);
     setOpenApiSyncReport(null);
+    setImportWarnings([]);
 
     const reader = new FileReader();
     reader.onload = (ev) => {
@@ -62,7 +64,8 @@ export function ImportExportDialog() {
           importCollections([collection]);
           setImportSuccess(`Imported "${collection.name}" with ${collection.requests.length} requests`);
         } else {
-          const collection = importOpenApiCollection(content);
+          const { collection, warnings } = importOpenApi(content);
+          setImportWarnings(warnings);
           const existing = collections.find(
             (item) => item.name.trim().toLowerCase() === collection.name.trim().toLowerCase()
           );
@@ -206,6 +209,16 @@ export function ImportExportDialog() {
             {importSuccess && (
               <p className="text-xs text-green-500">{importSuccess}</p>
             )}
+            {importWarnings.length > 0 && (
+              <div className="rounded border border-border/60 bg-[hsl(var(--surface-2))] p-2 text-[11px] text-amber-500 space-y-0.5 max-h-32 overflow-y-auto">
+                <p className="font-medium">
+                  {importWarnings.length} {importWarnings.length === 1 ? "warning" : "warnings"}
+                </p>
+                {importWarnings.map((warning) => (
+                  <p key={warning}>{warning}</p>
+                ))}
+              </div>
+            )}
             {openApiSyncReport && (
               <div className="rounded border border-border/60 bg-[hsl(var(--surface-2))] p-2 text-[11px] text-muted-foreground space-y-1">
                 <p>
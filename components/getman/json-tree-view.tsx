@@ -0,0 +1,260 @@
+"use client";
+
+import { useCallback, useState } from "react";
+import { ChevronDown, ChevronRight, Crosshair } from "lucide-react";
+import { jsonPathChild } from "@/lib/assertions";
+
+type JsonType = "object" | "array" | "string" | "number" | "boolean" | "null";
+
+/** Containers larger than this start collapsed. */
+const LARGE_COLLECTION_SIZE = 50;
+/** Containers deeper than this start collapsed. */
+const DEFAULT_EXPANDED_DEPTH = 2;
+/** Children rendered per container before a "show more" row. */
+const CHILD_PAGE_SIZE = 200;
+
+function jsonType(value: unknown): JsonType {
+  if (value === null) return "null";
+  if (Array.isArray(value)) return "array";
+  switch (typeof value) {
+    case "object":
+      return "object";
+    case "string":
+      return "string";
+    case "number":
+      return "number";
+    case "boolean":
+      return "boolean";
+    default:
+      return "null";
+  }
+}
+
+function childEntries(value: unknown): [string | number, unknown][] {
+  if (Array.isArray(value)) return value.map((item, index) => [index, item]);
+  if (value && typeof value === "object") return Object.entries(value as Record<string, unknown>);
+  return [];
+}
+
+function collectPaths(
+  value: unknown,
+  path: string,
+  depth: number,
+  out: Set<string>,
+  includeNode: (size: number, depth: number) => boolean,
+): Set<string> {
+  const type = jsonType(value);
+  if (type !== "object" && type !== "array") return out;
+  const entries = childEntries(value);
+  if (includeNode(entries.length, depth)) out.add(path);
+  for (const [key, child] of entries) {
+    collectPaths(child, jsonPathChild(path, key), depth + 1, out, includeNode);
+  }
+  return out;
+}
+
+function defaultExpandedPaths(value: unknown): Set<string> {
+  return collectPaths(
+    value,
+    "$",
+    0,
+    new Set(),
+    (size, depth) => depth < DEFAULT_EXPANDED_DEPTH && size <= LARGE_COLLECTION_SIZE,
+  );
+}
+
+const VALUE_CLASS: Record<JsonType, string> = {
+  string: "text-[hsl(var(--method-get))]",
+  number: "text-[hsl(var(--method-put))]",
+  boolean: "text-[hsl(var(--method-post))]",
+  null: "text-muted-foreground",
+  object: "text-muted-foreground",
+  array: "text-muted-foreground",
+};
+
+interface JsonTreeNodeProps {
+  name: string | number | null;
+  value: unknown;
+  path: string;
+  depth: number;
+  expanded: Set<string>;
+  showAll: Set<string>;
+  onToggle: (path: string) => void;
+  onShowAll: (path: string) => void;
+  onPickPath?: (path: string, value: unknown) => void;
+}
+
+function PickPathButton({ onPick }: { onPick: () => void }) {
+  return (
+    <span
+      role="button"
+      tabIndex={-1}
+      onClick={(e) => {
+        e.stopPropagation();
+        onPick();
+      }}
+      className="ml-1 shrink-0 text-muted-foreground opacity-0 hover:text-primary group-hover:opacity-100"
+      title="Copy JSONPath"
+    >
+      <Crosshair className="h-3 w-3" />
+    </span>
+  );
+}
+
+function JsonTreeNode({
+  name,
+  value,
+  path,
+  depth,
+  expanded,
+  showAll,
+  onToggle,
+  onShowAll,
+  onPickPath,
+}: JsonTreeNodeProps) {
+  const type = jsonType(value);
+  const isContainer = type === "object" || type === "array";
+  const isOpen = expanded.has(path);
+  const indent = { paddingLeft: `${depth * 14}px` };
+  const label =
+    name === null ? null : (
+      <span className={typeof name === "number" ? "text-muted-foreground" : "text-primary"}>
+        {typeof name === "number" ? name : JSON.stringify(name)}
+        <span className="text-muted-foreground">: </span>
+      </span>
+    );
+
+  if (!isContainer) {
+    const text = type === "string" ? JSON.stringify(value) : String(value);
+    return (
+      <div
+        className={`group flex items-start gap-1 py-px ${onPickPath ? "cursor-pointer hover:bg-accent/40" : ""}`}
+        style={indent}
+        onClick={onPickPath && (() => onPickPath(path, value))}
+        title={onPickPath ? `Copy ${path}` : undefined}
+      >
+        <span className="w-3.5 shrink-0" />
+        <span className="min-w-0 break-all">
+          {label}
+          <span className={VALUE_CLASS[type]}>{text}</span>
+        </span>
+        <span className="ml-1 shrink-0 text-[10px] text-muted-foreground/60">{type}</span>
+      </div>
+    );
+  }
+
+  const entries = childEntries(value);
+  const summary = type === "array" ? `[${entries.length} items]` : `{${entries.length} keys}`;
+  const visible = showAll.has(path) ? entries : entries.slice(0, CHILD_PAGE_SIZE);
+
+  return (
+    <div>
+      <button
+        type="button"
+        onClick={() => onToggle(path)}
+        className="group flex w-full items-center gap-1 py-px text-left hover:bg-accent/40"
+        style={indent}
+        title={path}
+      >
+        {isOpen ? (
+          <ChevronDown className="h-3.5 w-3.5 shrink-0 text-muted-foreground" />
+        ) : (
+          <ChevronRight className="h-3.5 w-3.5 shrink-0 text-muted-foreground" />
+        )}
+        <span className="min-w-0 truncate">
+          {label}
+          <span className="text-muted-foreground">{summary}</span>
+        </span>
+        <span className="ml-1 shrink-0 text-[10px] text-muted-foreground/60">{type}</span>
+        {onPickPath && <PickPathButton onPick={() => onPickPath(path, value)} />}
+      </button>
+      {isOpen &&
+        visible.map(([key, child]) => (
+          <JsonTreeNode
+            key={String(key)}
+            name={key}
+            value={child}
+            path={jsonPathChild(path, key)}
+            depth={depth + 1}
+            expanded={expanded}
+            showAll={showAll}
+            onToggle={onToggle}
+            onShowAll={onShowAll}
+            onPickPath={onPickPath}
+          />
+        ))}
+      {isOpen && visible.length < entries.length && (
+        <button
+          type="button"
+          onClick={() => onShowAll(path)}
+          className="py-px text-[11px] text-primary hover:underline"
+          style={{ paddingLeft: `${(depth + 1) * 14 + 18}px` }}
+        >
+          Show {entries.length - visible.length} more…
+        </button>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Collapsible JSON tree. Expansion state is kept per JSON path; large or deep
+ * containers start collapsed. Remount with a new `key` to reset it. With
+ * `onPickPath`, clicking a value (or the crosshair on an object or array)
+ * reports its JSONPath.
+ */
+export function JsonTreeView({
+  value,
+  onPickPath,
+}: {
+  value: unknown;
+  onPickPath?: (path: string, value: unknown) => void;
+}) {
+  const [expanded, setExpanded] = useState<Set<string>>(() => defaultExpandedPaths(value));
+  const [showAll, setShowAll] = useState<Set<string>>(() => new Set());
+
+  const toggle = useCallback((path: string) => {
+    setExpanded((current) => {
+      const next = new Set(current);
+      if (next.has(path)) next.delete(path);
+      else next.add(path);
+      return next;
+    });
+  }, []);
+
+  const revealAll = useCallback((path: string) => {
+    setShowAll((current) => new Set(current).add(path));
+  }, []);
+
+  return (
+    <div className="font-mono text-xs leading-relaxed">
+      <div className="mb-2 flex items-center gap-3 text-[11px]">
+        <button
+          type="button"
+          onClick={() => setExpanded(collectPaths(value, "$", 0, new Set(), () => true))}
+          className="text-muted-foreground hover:text-foreground"
+        >
+          Expand all
+        </button>
+        <button
+          type="button"
+          onClick={() => setExpanded(new Set())}
+          className="text-muted-foreground hover:text-foreground"
+        >
+          Collapse all
+        </button>
+      </div>
+      <JsonTreeNode
+        name={null}
+        value={value}
+        path="$"
+        depth={0}
+        expanded={expanded}
+        showAll={showAll}
+        onToggle={toggle}
+        onShowAll={revealAll}
+        onPickPath={onPickPath}
+      />
+    </div>
+  );
+}
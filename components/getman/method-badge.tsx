@@ -1,8 +1,13 @@
 "use client";
 
-import type { HttpMethod, RequestType } from "@/lib/getman-store";
+import {
+  type HttpMethod,
+  type RequestType,
+  type StandardHttpMethod,
+  isStandardHttpMethod,
+} from "@/lib/getman-store";
 
-const methodColors: Record<HttpMethod, string> = {
+const methodColors: Record<StandardHttpMethod, string> = {
   GET: "text-[hsl(var(--method-get))]",
   POST: "text-[hsl(var(--method-post))]",
   PUT: "text-[hsl(var(--method-put))]",
@@ -12,7 +17,7 @@ const methodColors: Record<HttpMethod, string> = {
   OPTIONS: "text-[hsl(var(--method-options))]",
 };
 
-const methodBg: Record<HttpMethod, string> = {
+const methodBg: Record<StandardHttpMethod, string> = {
   GET: "bg-[hsl(var(--method-get)/.1)]",
   POST: "bg-[hsl(var(--method-post)/.1)]",
   PUT: "bg-[hsl(var(--method-put)/.1)]",
@@ -22,6 +27,15 @@ const methodBg: Record<HttpMethod, string> = {
   OPTIONS: "bg-[hsl(var(--method-options)/.1)]",
 };
 
+/** Text color for `method`; custom verbs use a neutral color. */
+export function methodTextClass(method: HttpMethod): string {
+  return isStandardHttpMethod(method) ? methodColors[method] : "text-muted-foreground";
+}
+
+function methodBgClass(method: HttpMethod): string {
+  return isStandardHttpMethod(method) ? methodBg[method] : "bg-muted";
+}
+
 export function MethodBadge({
   method,
   size = "sm",
@@ -54,7 +68,7 @@ export function MethodBadge({
   }
   return (
     <span
-      className={`font-mono font-bold ${methodColors[method]} ${methodBg[method]} rounded px-1.5 ${
+      className={`font-mono font-bold ${methodTextClass(method)} ${methodBgClass(method)} rounded px-1.5 ${
         size === "sm" ? "text-[10px] py-0" : "text-xs py-0.5"
       }`}
     >
@@ -0,0 +1,95 @@
+"use client";
+
+import { useEffect, useState } from "react";
+import { checkProxyUrl, type ProxySettings } from "@/lib/tauri";
+
+const inputClass =
+  "rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50";
+
+/** Proxy URL, credentials and bypass list, shared by Settings and Request Settings. */
+export function ProxyFields({
+  value,
+  onChange,
+  emptyHint,
+}: {
+  value: ProxySettings;
+  onChange: (partial: Partial<ProxySettings>) => void;
+  /** Placeholder describing what a blank URL does. */
+  emptyHint: string;
+}) {
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let current = true;
+    const timer = setTimeout(() => {
+      void checkProxyUrl(value.url).then((message) => {
+        if (current) setError(message);
+      });
+    }, 300);
+    return () => {
+      current = false;
+      clearTimeout(timer);
+    };
+  }, [value.url]);
+
+  return (
+    <>
+      <div className="flex flex-col gap-1.5">
+        <label className="text-[11px] font-medium text-muted-foreground">
+          Proxy URL (e.g., http://proxy:8080 or socks5://proxy:1080)
+        </label>
+        <input
+          type="text"
+          className={`${inputClass} ${error ? "border-destructive/60" : ""}`}
+          placeholder={emptyHint}
+          value={value.url}
+          onChange={(e) => onChange({ url: e.target.value })}
+        />
+        {error && <span className="text-[10px] text-destructive">{error}</span>}
+      </div>
+
+      {value.url.trim() && (
+        <>
+          <div className="grid grid-cols-2 gap-3">
+            <div className="flex flex-col gap-1.5">
+              <label className="text-[11px] font-medium text-muted-foreground">
+                Proxy Username
+              </label>
+              <input
+                type="text"
+                className={inputClass}
+                placeholder="Optional"
+                value={value.username}
+                onChange={(e) => onChange({ username: e.target.value })}
+              />
+            </div>
+            <div className="flex flex-col gap-1.5">
+              <label className="text-[11px] font-medium text-muted-foreground">
+                Proxy Password
+              </label>
+              <input
+                type="password"
+                className={inputClass}
+                placeholder="Optional"
+                value={value.password}
+                onChange={(e) => onChange({ password: e.target.value })}
+              />
+            </div>
+          </div>
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">
+              Bypass Proxy For (comma-separated hosts, domains or CIDRs)
+            </label>
+            <input
+              type="text"
+              className={inputClass}
+              placeholder="localhost, .internal.example.com (defaults to NO_PROXY)"
+              value={value.noProxy}
+              onChange={(e) => onChange({ noProxy: e.target.value })}
+            />
+          </div>
+        </>
+      )}
+    </>
+  );
+}
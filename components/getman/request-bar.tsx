@@ -4,6 +4,8 @@ import React, { useEffect, useRef } from "react"
 
 import { Send, X, Settings2, Copy, Check, Eye } from "lucide-react";
 import {
+  applyEnvironmentBaseUrl,
+  applyPathParams,
   useActiveTab,
   useGetmanStore,
   updateActiveTab,
@@ -11,9 +13,12 @@ import {
   setResponse,
   setGrpcResponse,
   setIsLoading,
+  setRequestQueued,
   setActiveRequestId,
   setAssertionResults,
+  setLatencySamples,
   addHistoryItem,
+  snapshotRequestTab,
   addWsConnection,
   updateWsConnection,
   addWsMessage,
@@ -24,7 +29,7 @@ import {
   addCookieEntry,
   uid,
   type AssertionResult,
-  type HttpMethod,
+  type ProxyMode,
   type KeyValue,
   type CookieEntry,
   type RequestSettings,
@@ -32,23 +37,45 @@ import {
   type RequestType,
   type ResponseData,
   defaultSettings,
+  parseResolveOverrides,
+  proxyPayloadFields,
+  requestProxyMode,
+  restoreRequestInActiveTab,
+  resolveDefaultHeaders,
+  configuredUserAgent,
+  configuredReplayMode,
+  effectiveTimeoutMs,
+  grpcHttpStatus,
+  STANDARD_HTTP_METHODS,
+  isStandardHttpMethod,
+  isValidHttpMethod,
 } from "@/lib/getman-store";
 import {
   sendHttpRequest,
   cancelHttpRequest,
   sendGrpcRequest,
+  previewRequest,
+  type FormField,
+  type PreviewAuth,
+  type RequestPreview,
   type SendRequestPayload,
+  type TlsVersion,
 } from "@/lib/tauri";
 import { runAssertions } from "@/lib/assertions";
 import { isCurlCommand, parseCurlCommand } from "@/lib/curl-parser";
-import { generateCode } from "@/lib/code-generator";
+import { type CodeLanguage, generateCode } from "@/lib/code-generator";
 import {
   executePreRequestScript,
   executePostResponseScript,
   type ScriptExecutionLog,
 } from "@/lib/request-scripts";
 import { applyAdvancedAuth } from "@/lib/advanced-auth";
+import { isModalOpen } from "@/lib/utils";
+import type { UrlSuggestion } from "@/lib/url-suggestions";
 import { CodeGeneratorDialog } from "./code-generator-dialog";
+import { methodTextClass } from "./method-badge";
+import { ProxyFields } from "./proxy-fields";
+import { UrlSuggestionList, useUrlSuggestions } from "./url-suggestions";
 import {
   Select,
   SelectContent,
@@ -64,25 +91,11 @@ import {
   DialogTrigger,
 } from "@/components/ui/dialog";
 
-const methods: HttpMethod[] = [
-  "GET",
-  "POST",
-  "PUT",
-  "PATCH",
-  "DELETE",
-  "HEAD",
-  "OPTIONS",
-];
-
-const methodTextColors: Record<HttpMethod, string> = {
-  GET: "text-[hsl(var(--method-get))]",
-  POST: "text-[hsl(var(--method-post))]",
-  PUT: "text-[hsl(var(--method-put))]",
-  PATCH: "text-[hsl(var(--method-patch))]",
-  DELETE: "text-[hsl(var(--method-delete))]",
-  HEAD: "text-[hsl(var(--method-head))]",
-  OPTIONS: "text-[hsl(var(--method-options))]",
-};
+/** Select value that switches the method picker to free text. */
+const CUSTOM_METHOD_VALUE = "__custom__";
+
+/** Dispatch on `window` to send the active request as if Send were clicked. */
+export const SEND_REQUEST_EVENT = "getman:send-request";
 
 const activeWebSocketRequests = new Map<string, WebSocket>();
 
@@ -461,6 +474,7 @@ function RequestSettingsDialog() {
   if (!tab) return null;
 
   const settings = tab.settings || defaultSettings();
+  const proxyMode = requestProxyMode(settings);
 
   const updateSettings = (partial: Partial<RequestSettings>) => {
     updateActiveTab({ settings: { ...settings, ...partial } });
@@ -485,7 +499,7 @@ function RequestSettingsDialog() {
           {/* Timeout */}
           <div className="flex flex-col gap-1.5">
             <label className="text-[11px] font-medium text-muted-foreground">
-              Timeout (ms) — 0 = no timeout
+              Timeout (ms) — 0 uses the environment or global default
             </label>
             <input
               type="number"
@@ -527,20 +541,292 @@ function RequestSettingsDialog() {
             </div>
           </div>
 
+          {/* Response size limit */}
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">
+              Max Response Size (MB, 0 = unlimited)
+            </label>
+            <input
+              type="number"
+              className="rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+              value={settings.maxResponseMb || 0}
+              onChange={(e) => updateSettings({ maxResponseMb: Math.max(0, Number(e.target.value) || 0) })}
+              min={0}
+              step={1}
+            />
+          </div>
+
+          {/* Protocol */}
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">HTTP Version</label>
+            <Select
+              value={settings.httpVersion || "auto"}
+              onValueChange={(value) =>
+                updateSettings({ httpVersion: value as RequestSettings["httpVersion"] })
+              }
+            >
+              <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                <SelectItem value="auto" className="text-xs">Auto (negotiate)</SelectItem>
+                <SelectItem value="http1_only" className="text-xs">HTTP/1.1 only</SelectItem>
+                <SelectItem value="http2_prior_knowledge" className="text-xs">
+                  HTTP/2 (prior knowledge)
+                </SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          {/* Request compression */}
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">
+              Compress Request Body
+            </label>
+            <Select
+              value={settings.requestCompression || "none"}
+              onValueChange={(value) =>
+                updateSettings({
+                  requestCompression: value as RequestSettings["requestCompression"],
+                })
+              }
+            >
+              <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                <SelectItem value="none" className="text-xs">None</SelectItem>
+                <SelectItem value="gzip" className="text-xs">gzip</SelectItem>
+                <SelectItem value="brotli" className="text-xs">Brotli</SelectItem>
+              </SelectContent>
+            </Select>
+            <span className="text-[10px] text-muted-foreground">
+              Sets Content-Encoding; the server must accept compressed bodies.
+            </span>
+          </div>
+
           {/* Proxy */}
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">Proxy</label>
+            <Select
+              value={proxyMode}
+              onValueChange={(value) => updateSettings({ proxyMode: value as ProxyMode })}
+            >
+              <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                <SelectItem value="global" className="text-xs">Use proxy from Settings</SelectItem>
+                <SelectItem value="custom" className="text-xs">Custom proxy</SelectItem>
+                <SelectItem value="none" className="text-xs">No proxy (direct)</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          {proxyMode === "custom" && (
+            <ProxyFields
+              value={{
+                url: settings.proxyUrl,
+                username: settings.proxyUsername || "",
+                password: settings.proxyPassword || "",
+                noProxy: settings.noProxy || "",
+              }}
+              onChange={(proxy) =>
+                updateSettings({
+                  ...(proxy.url !== undefined && { proxyUrl: proxy.url }),
+                  ...(proxy.username !== undefined && { proxyUsername: proxy.username }),
+                  ...(proxy.password !== undefined && { proxyPassword: proxy.password }),
+                  ...(proxy.noProxy !== undefined && { noProxy: proxy.noProxy }),
+                })
+              }
+              emptyHint="Leave empty to use HTTP_PROXY / HTTPS_PROXY or a direct connection"
+            />
+          )}
+
+          {/* DNS overrides */}
           <div className="flex flex-col gap-1.5">
             <label className="text-[11px] font-medium text-muted-foreground">
-              Proxy URL (e.g., http://proxy:8080 or socks5://proxy:1080)
+              DNS Overrides (one host=ip[:port] per line)
             </label>
+            <textarea
+              rows={3}
+              className="resize-y rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
+              placeholder={"api.example.com=10.0.0.12\nstaging.example.com=10.0.0.40:8443"}
+              value={settings.resolveOverrides || ""}
+              onChange={(e) => updateSettings({ resolveOverrides: e.target.value })}
+            />
+          </div>
+
+          {/* Conditional requests */}
+          <div className="flex items-center gap-2">
             <input
-              type="text"
-              className="rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50"
-              placeholder="Leave empty for direct connection"
-              value={settings.proxyUrl}
-              onChange={(e) => updateSettings({ proxyUrl: e.target.value })}
+              type="checkbox"
+              id="conditional-requests"
+              checked={settings.conditionalRequests ?? false}
+              onChange={(e) => updateSettings({ conditionalRequests: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
             />
+            <label htmlFor="conditional-requests" className="text-xs text-foreground">
+              Send conditional requests (ETag / Last-Modified)
+            </label>
           </div>
 
+          {/* Pagination */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="follow-pagination"
+              checked={settings.followPagination ?? false}
+              onChange={(e) => updateSettings({ followPagination: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="follow-pagination" className="text-xs text-foreground">
+              Follow pagination (Link rel=&quot;next&quot;), merging JSON array pages
+            </label>
+            {settings.followPagination && (
+              <input
+                type="number"
+                className="ml-auto w-20 rounded border border-border bg-[hsl(var(--surface-2))] px-2 py-1 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+                value={settings.maxPages ?? 10}
+                onChange={(e) => updateSettings({ maxPages: Number(e.target.value) })}
+                min={1}
+                title="Maximum pages to fetch"
+              />
+            )}
+          </div>
+
+          {/* Redirect tracing */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="trace-redirects"
+              checked={settings.traceRedirects ?? false}
+              onChange={(e) => updateSettings({ traceRedirects: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="trace-redirects" className="text-xs text-foreground">
+              Trace redirects, showing every hop with the response
+            </label>
+            {settings.traceRedirects && (
+              <input
+                type="number"
+                className="ml-auto w-20 rounded border border-border bg-[hsl(var(--surface-2))] px-2 py-1 font-mono text-xs text-foreground outline-none focus:border-primary/50"
+                value={settings.maxRedirects ?? 10}
+                onChange={(e) =>
+                  updateSettings({ maxRedirects: Math.max(0, Number(e.target.value) || 0) })
+                }
+                min={0}
+                title="Maximum redirects to follow"
+              />
+            )}
+          </div>
+
+          {/* Connection reuse */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="fresh-connection"
+              checked={settings.freshConnection ?? false}
+              onChange={(e) => updateSettings({ freshConnection: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="fresh-connection" className="text-xs text-foreground">
+              Always use a fresh connection (Connection: close)
+            </label>
+          </div>
+
+          {/* Automatic decompression */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="disable-decompression"
+              checked={settings.disableDecompression ?? false}
+              onChange={(e) => updateSettings({ disableDecompression: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="disable-decompression" className="text-xs text-foreground">
+              Keep compressed responses encoded (no automatic Accept-Encoding or decoding)
+            </label>
+          </div>
+
+          {/* Expect: 100-continue */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="expect-continue"
+              checked={settings.expectContinue ?? false}
+              onChange={(e) => updateSettings({ expectContinue: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="expect-continue" className="text-xs text-foreground">
+              Send Expect: 100-continue and wait up to a second before uploading the body
+            </label>
+          </div>
+
+          {/* Default headers */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="use-default-headers"
+              checked={settings.useDefaultHeaders ?? true}
+              onChange={(e) => updateSettings({ useDefaultHeaders: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="use-default-headers" className="text-xs text-foreground">
+              Send the default headers from Settings (this request&apos;s own headers take precedence)
+            </label>
+          </div>
+
+          {/* Content-Type detection */}
+          <div className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              id="auto-content-type"
+              checked={settings.autoContentType ?? false}
+              onChange={(e) => updateSettings({ autoContentType: e.target.checked })}
+              className="h-3.5 w-3.5 rounded border-border accent-primary"
+            />
+            <label htmlFor="auto-content-type" className="text-xs text-foreground">
+              Detect Content-Type for bodies sent without one
+            </label>
+          </div>
+
+          {/* TLS versions */}
+          <div className="grid grid-cols-2 gap-3">
+            {(
+              [
+                ["minTlsVersion", "Minimum TLS Version"],
+                ["maxTlsVersion", "Maximum TLS Version"],
+              ] as const
+            ).map(([key, label]) => (
+              <div key={key} className="flex flex-col gap-1.5">
+                <label className="text-[11px] font-medium text-muted-foreground">{label}</label>
+                <Select
+                  value={settings[key] ?? "default"}
+                  onValueChange={(value) => {
+                    const partial: Partial<RequestSettings> = {};
+                    partial[key] = value === "default" ? undefined : (value as TlsVersion);
+                    updateSettings(partial);
+                  }}
+                >
+                  <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                    <SelectItem value="default" className="text-xs">Default</SelectItem>
+                    <SelectItem value="1.2" className="text-xs">TLS 1.2</SelectItem>
+                    <SelectItem value="1.3" className="text-xs">TLS 1.3</SelectItem>
+                  </SelectContent>
+                </Select>
+              </div>
+            ))}
+          </div>
+          {settings.minTlsVersion === "1.3" && settings.maxTlsVersion === "1.2" && (
+            <p className="text-[10px] text-destructive">
+              The minimum TLS version is above the maximum, so requests will fail.
+            </p>
+          )}
+
           {/* SSL Verification */}
           <div className="flex items-center gap-2">
             <input
@@ -565,97 +851,177 @@ function RequestSettingsDialog() {
   );
 }
 
-function PreviewResolvedDialog() {
-  const tab = useActiveTab();
-  if (!tab) return null;
+function previewAuthFor(tab: RequestTab): PreviewAuth | undefined {
+  switch (tab.authType) {
+    case "bearer":
+      return tab.authToken ? { type: "bearer", token: tab.authToken } : undefined;
+    case "oauth2":
+      return tab.oauth2AccessToken
+        ? { type: "bearer", token: tab.oauth2AccessToken }
+        : undefined;
+    case "basic":
+      return tab.authUsername
+        ? { type: "basic", username: tab.authUsername, password: tab.authPassword }
+        : undefined;
+    case "api-key":
+      return {
+        type: "api-key",
+        key: tab.authApiKey,
+        value: tab.authApiValue,
+        addTo: tab.authApiAddTo,
+      };
+    case "digest":
+      return { type: "computed", scheme: "Digest" };
+    case "ntlm":
+      return { type: "computed", scheme: "NTLM" };
+    case "awsv4":
+      return { type: "computed", scheme: "AWS4-HMAC-SHA256" };
+    case "wsse":
+      return { type: "computed", scheme: "WSSE" };
+    default:
+      return undefined;
+  }
+}
 
-  const resolvedUrl = resolveEnvVariables(tab.url);
-  const resolvedHeaders: { key: string; value: string }[] = [];
-  for (const h of tab.headers) {
-    if (h.enabled && h.key) {
-      resolvedHeaders.push({
-        key: h.key,
-        value: resolveEnvVariables(h.value),
-      });
-    }
+/** Unresolved body text as the send path would build it, before interpolation. */
+function previewBody(tab: RequestTab, headers: Record<string, string>): string | undefined {
+  if (["GET", "HEAD", "OPTIONS"].includes(tab.method)) return undefined;
+  const enabledFields = tab.bodyFormData.filter((f) => f.enabled && f.key);
+  switch (tab.bodyType) {
+    case "json":
+      headers["Content-Type"] = headers["Content-Type"] || "application/json";
+      return tab.bodyContent;
+    case "raw":
+    case "binary":
+      return tab.bodyContent;
+    case "file":
+      return tab.bodyFilePath.trim() ? `[file: ${tab.bodyFilePath.trim()}]` : undefined;
+    case "x-www-form-urlencoded":
+      headers["Content-Type"] =
+        headers["Content-Type"] || "application/x-www-form-urlencoded";
+      return enabledFields.map((f) => `${f.key}=${f.value}`).join("&");
+    case "form-data":
+      headers["Content-Type"] = headers["Content-Type"] || "application/json";
+      return JSON.stringify(Object.fromEntries(enabledFields.map((f) => [f.key, f.value])));
+    case "graphql":
+      headers["Content-Type"] = headers["Content-Type"] || "application/json";
+      return `{"query":${JSON.stringify(tab.graphqlQuery)},"variables":${
+        tab.graphqlVariables.trim() || "{}"
+      }}`;
+    default:
+      return undefined;
   }
-  const resolvedParams: { key: string; value: string }[] = [];
-  for (const p of tab.params) {
-    if (p.enabled && p.key) {
-      resolvedParams.push({
-        key: p.key,
-        value: resolveEnvVariables(p.value),
-      });
+}
+
+function RequestPreviewPanel() {
+  const store = useGetmanStore();
+  const tab = useActiveTab();
+  const [preview, setPreview] = React.useState<RequestPreview | null>(null);
+  const [error, setError] = React.useState<string | null>(null);
+
+  useEffect(() => {
+    if (!tab?.url.trim()) {
+      setPreview(null);
+      return;
     }
-  }
-  const resolvedBody = tab.bodyContent
-    ? resolveEnvVariables(tab.bodyContent)
-    : undefined;
+    let cancelled = false;
+    const timer = window.setTimeout(async () => {
+      const scope = findSavedRequestScopeByTab(tab);
+      const headers: Record<string, string> = {};
+      for (const h of tab.headers) {
+        if (h.enabled && h.key) headers[h.key] = h.value;
+      }
+      const body = previewBody(tab, headers);
+      try {
+        const result = await previewRequest(
+          {
+            url: tab.url,
+            method: tab.method,
+            headers,
+            body,
+            environmentId: store.activeEnvironmentId,
+            scopedVariables: [
+              ...(scope?.collection.variables ?? []),
+              ...(scope?.folderChain.flatMap((folder) => folder.variables ?? []) ?? []),
+              ...(tab.variables ?? []),
+            ],
+            params: tab.params.filter((p) => p.enabled && p.key),
+            pathParams: (tab.pathParams ?? []).filter((p) => p.enabled && p.key),
+            auth: previewAuthFor(tab),
+            userAgent: store.userAgent.trim() || undefined,
+          },
+          store.globalVariables,
+          store.environments
+        );
+        if (!cancelled) {
+          setPreview(result);
+          setError(null);
+        }
+      } catch (err) {
+        if (!cancelled) setError(err instanceof Error ? err.message : String(err));
+      }
+    }, 250);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [tab, store.activeEnvironmentId, store.globalVariables, store.environments, store.userAgent]);
+
+  if (!tab) return null;
+
+  const sectionLabel =
+    "text-[10px] font-medium text-muted-foreground uppercase tracking-wider";
+  const headerEntries = Object.entries(preview?.headers ?? {}).sort(([a], [b]) =>
+    a.localeCompare(b)
+  );
 
   return (
-    <Dialog>
-      <DialogTrigger asChild>
-        <button
-          type="button"
-          disabled={!tab.url.trim()}
-          className="flex h-11 items-center px-2.5 text-muted-foreground hover:text-foreground transition-colors border-r border-border/80 disabled:cursor-not-allowed disabled:opacity-50"
-          title="Preview Resolved Request"
-        >
-          <Eye className="h-4 w-4" />
-        </button>
-      </DialogTrigger>
-      <DialogContent className="bg-[hsl(var(--surface-1))] border-border sm:max-w-[520px]">
-        <DialogHeader>
-          <DialogTitle className="text-foreground text-sm">Resolved Request Preview</DialogTitle>
-        </DialogHeader>
-        <div className="flex flex-col gap-3 max-h-[400px] overflow-y-auto">
+    <div className="flex flex-col gap-3 rounded-lg border border-border/80 bg-[hsl(var(--surface-1))] p-3 max-h-[320px] overflow-y-auto">
+      {error && <p className="text-[11px] text-red-500">{error}</p>}
+      {preview && (
+        <>
+          {preview.unresolvedVariables.length > 0 && (
+            <p className="rounded-md bg-yellow-500/10 px-3 py-1.5 text-[11px] text-yellow-600">
+              Unresolved variables:{" "}
+              <span className="font-mono">
+                {preview.unresolvedVariables.map((name) => `{{${name}}}`).join(", ")}
+              </span>
+            </p>
+          )}
           <div className="flex flex-col gap-1">
-            <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">Method & URL</span>
+            <span className={sectionLabel}>Method & URL</span>
             <div className="rounded-md bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground break-all">
-              <span className="font-bold">{tab.method}</span>{" "}
-              {resolvedUrl}
+              <span className="font-bold">{preview.method}</span> {preview.url}
             </div>
           </div>
-
-          {resolvedParams.length > 0 && (
-            <div className="flex flex-col gap-1">
-              <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">Query Parameters</span>
-              <div className="rounded-md bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs space-y-0.5">
-                {resolvedParams.map((p, i) => (
-                  <div key={i} className="flex gap-2">
-                    <span className="text-primary font-medium">{p.key}:</span>
-                    <span className="text-foreground break-all">{p.value}</span>
-                  </div>
-                ))}
-              </div>
-            </div>
-          )}
-
-          {resolvedHeaders.length > 0 && (
+          {headerEntries.length > 0 && (
             <div className="flex flex-col gap-1">
-              <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">Headers</span>
+              <span className={sectionLabel}>Headers</span>
               <div className="rounded-md bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs space-y-0.5">
-                {resolvedHeaders.map((h, i) => (
-                  <div key={i} className="flex gap-2">
-                    <span className="text-primary font-medium">{h.key}:</span>
-                    <span className="text-foreground break-all">{h.value}</span>
+                {headerEntries.map(([key, value]) => (
+                  <div key={key} className="flex gap-2">
+                    <span className="text-primary font-medium">{key}:</span>
+                    <span className="text-foreground break-all">{value}</span>
                   </div>
                 ))}
               </div>
             </div>
           )}
-
-          {resolvedBody && (
+          {preview.body && (
             <div className="flex flex-col gap-1">
-              <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">Body</span>
-              <pre className="rounded-md bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground whitespace-pre-wrap break-all max-h-[200px] overflow-y-auto">
-                {resolvedBody}
+              <span className={sectionLabel}>Body</span>
+              <pre className="rounded-md bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground whitespace-pre-wrap break-all max-h-[160px] overflow-y-auto">
+                {preview.body}
               </pre>
             </div>
           )}
-        </div>
-      </DialogContent>
-    </Dialog>
+          <p className="text-[10px] text-muted-foreground">
+            Secret variables, auth values and credential headers are masked. Cookie-jar
+            cookies and pre-request script changes are applied when the request is sent.
+          </p>
+        </>
+      )}
+    </div>
   );
 }
 
@@ -663,15 +1029,42 @@ export function RequestBar() {
   const store = useGetmanStore();
   const tab = useActiveTab();
   const sendRef = useRef<(() => void) | null>(null);
+  const urlInputRef = useRef<HTMLInputElement | null>(null);
   const [curlCopied, setCurlCopied] = React.useState(false);
+  const [showPreview, setShowPreview] = React.useState(false);
+  const [repeatCount, setRepeatCount] = React.useState(1);
+  // Draft verb while the method picker is in free-text mode
+  const [customMethod, setCustomMethod] = React.useState<string | null>(null);
+  // Set by Cancel so a repeated send stops after the run in flight
+  const repeatStopRef = useRef(false);
+  // URL autocomplete opens on typing and closes on blur, Escape, pick or send
+  const [suggestionsOpen, setSuggestionsOpen] = React.useState(false);
+  const [activeSuggestion, setActiveSuggestion] = React.useState(-1);
+  const urlSuggestions = useUrlSuggestions(
+    tab?.url ?? "",
+    tab?.requestType ?? "http",
+    suggestionsOpen
+  );
+
+  useEffect(() => {
+    const handler = () => sendRef.current?.();
+    window.addEventListener(SEND_REQUEST_EVENT, handler);
+    return () => window.removeEventListener(SEND_REQUEST_EVENT, handler);
+  }, []);
 
-  // Global Cmd/Ctrl+Enter shortcut to send request
+  // Global Cmd/Ctrl+Enter to send, Cmd/Ctrl+L to focus the URL bar
   useEffect(() => {
     const handler = (e: KeyboardEvent) => {
-      // Only trigger on Cmd/Ctrl+Enter (modifier required)
-      if (!(e.metaKey || e.ctrlKey) || e.key !== "Enter") return;
-      e.preventDefault();
-      sendRef.current?.();
+      // Modifier required; dialogs keep their own keyboard handling
+      if (!(e.metaKey || e.ctrlKey) || isModalOpen()) return;
+      if (e.key === "Enter") {
+        e.preventDefault();
+        sendRef.current?.();
+      } else if (e.key.toLowerCase() === "l") {
+        e.preventDefault();
+        urlInputRef.current?.focus();
+        urlInputRef.current?.select();
+      }
     };
     window.addEventListener("keydown", handler);
     return () => window.removeEventListener("keydown", handler);
@@ -697,14 +1090,14 @@ export function RequestBar() {
   const isGraphql = (tab.requestType ?? "http") === "graphql";
   const isWebsocket = (tab.requestType ?? "http") === "websocket";
 
-  const handleCopyAsCurl = () => {
-    const curl = generateCode(tab, "curl");
-    navigator.clipboard.writeText(curl);
+  const handleCopyAs = (language: CodeLanguage) => {
+    navigator.clipboard.writeText(generateCode(tab, language));
     setCurlCopied(true);
     setTimeout(() => setCurlCopied(false), 2000);
   };
 
   const handleCancel = async () => {
+    repeatStopRef.current = true;
     if (store.activeRequestId) {
       const ws = activeWebSocketRequests.get(store.activeRequestId);
       if (ws) {
@@ -728,13 +1121,14 @@ export function RequestBar() {
       .map((p) => resolveEnvVariables(p.trim()))
       .filter(Boolean);
     const settings = tab.settings || defaultSettings();
-    const timeoutMs = settings.timeoutMs > 0 ? settings.timeoutMs : 10000;
+    const timeoutMs = effectiveTimeoutMs(settings) ?? 10000;
     const connectionId = addWsConnection(resolvedUrl, protocols.join(","));
 
     setIsLoading(true);
     setActiveRequestId(requestId);
     setResponse(null);
     setAssertionResults([]);
+    setLatencySamples(null);
 
     const startedAt = performance.now();
     const transcript: string[] = [];
@@ -803,6 +1197,7 @@ export function RequestBar() {
           time: elapsed,
           timestamp: Date.now(),
           requestType: "websocket",
+          request: snapshotRequestTab(tab),
         });
 
         setIsLoading(false);
@@ -889,25 +1284,28 @@ export function RequestBar() {
     setActiveRequestId(requestId);
     setResponse(null);
     setGrpcResponse(null);
+    setLatencySamples(null);
 
     try {
+      // Same scoping as HTTP sends: collection, folder and request variables apply too.
+      const { resolve } = buildScopedResolver(tab, {});
       const metadata: Record<string, string> = {};
       for (const m of (tab.grpcMetadata ?? [])) {
         if (m.enabled && m.key) {
-          metadata[m.key] = resolveEnvVariables(m.value);
+          metadata[m.key] = resolve(m.value);
         }
       }
 
       const settings = tab.settings || defaultSettings();
 
       const data = await sendGrpcRequest({
-        endpoint: resolveEnvVariables(tab.url),
+        endpoint: resolve(tab.url),
         protoContent: tab.grpcProtoContent,
         serviceName: tab.grpcServiceName,
         methodName: tab.grpcMethodName,
-        requestJson: resolveEnvVariables(tab.grpcRequestBody || "{}"),
+        requestJson: resolve(tab.grpcRequestBody || "{}"),
         metadata,
-        timeoutMs: settings.timeoutMs > 0 ? settings.timeoutMs : undefined,
+        timeoutMs: effectiveTimeoutMs(settings),
         requestId,
         descriptorBytes: tab.grpcDescriptorBytes || undefined,
       });
@@ -918,10 +1316,12 @@ export function RequestBar() {
         id: uid(),
         method: "POST",
         url: `${tab.url}/${tab.grpcServiceName}/${tab.grpcMethodName}`,
-        status: data.statusCode === 0 ? 200 : 500,
+        status: grpcHttpStatus(data.statusCode),
         time: data.time,
         timestamp: Date.now(),
         requestType: "grpc",
+        request: snapshotRequestTab(tab),
+        grpcStatus: data.statusCode,
       });
     } catch {
       setGrpcResponse({
@@ -940,19 +1340,39 @@ export function RequestBar() {
 
   const sendRequest = async () => {
     if (!tab.url.trim()) return;
+    if (!isValidHttpMethod(tab.method)) {
+      const message = `Invalid HTTP method "${tab.method}"`;
+      setResponse({
+        status: 0,
+        statusText: "Error",
+        headers: {},
+        body: message,
+        time: 0,
+        size: 0,
+        contentType: "text/plain",
+        error: { kind: "invalidRequest", message },
+      });
+      return;
+    }
 
     const requestId = uid();
+    repeatStopRef.current = false;
     setIsLoading(true);
     setActiveRequestId(requestId);
     setResponse(null);
     setAssertionResults([]);
+    setLatencySamples(null);
 
     try {
       const runtimeVariables: Record<string, string> = {};
       const scoped = buildScopedResolver(tab, runtimeVariables);
       const resolve = scoped.resolve;
       const scriptLogs: ScriptExecutionLog[] = [];
-      const resolvedUrl = resolve(tab.url);
+      const resolvedUrl = applyPathParams(
+        applyEnvironmentBaseUrl(resolve(tab.url), resolve),
+        tab.pathParams,
+        resolve
+      );
 
       // Build query params
       const url = new URL(resolvedUrl);
@@ -1009,6 +1429,8 @@ export function RequestBar() {
 
       // Build body
       let body: string | undefined;
+      let bodyFile: string | undefined;
+      let formFields: FormField[] | undefined;
       if (!["GET", "HEAD", "OPTIONS"].includes(tab.method)) {
         if (tab.bodyType === "json") {
           headers["Content-Type"] = headers["Content-Type"] || "application/json";
@@ -1018,12 +1440,12 @@ export function RequestBar() {
         } else if (tab.bodyType === "x-www-form-urlencoded") {
           headers["Content-Type"] =
             headers["Content-Type"] || "application/x-www-form-urlencoded";
-          const params = new URLSearchParams();
-          for (const f of tab.bodyFormData) {
-            if (f.enabled && f.key)
-              params.set(resolve(f.key), resolve(f.value));
-          }
-          body = params.toString();
+          formFields = tab.bodyFormData
+            .filter((f) => f.enabled && (f.key || f.value))
+            .map((f) => ({ key: resolve(f.key), value: resolve(f.value) }));
+          body = new URLSearchParams(
+            formFields.filter((f) => f.key.trim()).map((f) => [f.key, f.value])
+          ).toString();
         } else if (tab.bodyType === "form-data") {
           // form-data sent as JSON key-values for proxy
           const obj: Record<string, string> = {};
@@ -1047,6 +1469,8 @@ export function RequestBar() {
         } else if (tab.bodyType === "binary") {
           headers["Content-Type"] = headers["Content-Type"] || "application/octet-stream";
           body = tab.bodyContent;
+        } else if (tab.bodyType === "file") {
+          bodyFile = resolve(tab.bodyFilePath).trim() || undefined;
         }
       }
 
@@ -1056,13 +1480,35 @@ export function RequestBar() {
         url: url.toString(),
         method: tab.method,
         headers,
+        defaultHeaders: resolveDefaultHeaders(settings, resolve),
         body,
+        bodyFile,
+        formFields,
         requestId,
-        timeoutMs: settings.timeoutMs > 0 ? settings.timeoutMs : undefined,
+        timeoutMs: effectiveTimeoutMs(settings),
         retryCount: settings.retryCount > 0 ? settings.retryCount : undefined,
         retryDelayMs: settings.retryDelayMs,
-        proxyUrl: settings.proxyUrl || undefined,
+        httpVersion: settings.httpVersion !== "auto" ? settings.httpVersion : undefined,
+        resolveOverrides: parseResolveOverrides(settings.resolveOverrides),
+        maxResponseBytes:
+          settings.maxResponseMb > 0 ? Math.round(settings.maxResponseMb * 1024 * 1024) : undefined,
+        disableDecompression: settings.disableDecompression || undefined,
+        requestCompression:
+          settings.requestCompression !== "none" ? settings.requestCompression : undefined,
+        conditional: settings.conditionalRequests || undefined,
+        detectContentType: settings.autoContentType || undefined,
+        freshConnection: settings.freshConnection || undefined,
+        expectContinue: settings.expectContinue || undefined,
+        followPagination: settings.followPagination || undefined,
+        maxPages: settings.followPagination ? settings.maxPages || undefined : undefined,
+        traceRedirects: settings.traceRedirects || undefined,
+        maxRedirects: settings.traceRedirects ? settings.maxRedirects : undefined,
+        userAgent: configuredUserAgent(),
+        replayMode: configuredReplayMode(),
+        ...proxyPayloadFields(settings),
         verifySsl: settings.verifySsl,
+        minTlsVersion: settings.minTlsVersion,
+        maxTlsVersion: settings.maxTlsVersion,
       };
 
       for (const [index, script] of scoped.preScripts.entries()) {
@@ -1073,7 +1519,7 @@ export function RequestBar() {
           scriptName: `pre-request-${index + 1}`,
         });
       }
-      payload = await applyAdvancedAuth(payload, tab);
+      payload = await applyAdvancedAuth(payload, tab, resolve);
 
       let data = buildMockResponse(tab, {
         method: payload.method,
@@ -1086,7 +1532,21 @@ export function RequestBar() {
         await new Promise((resolveDelay) => setTimeout(resolveDelay, delayMs));
       }
       if (!data) {
-        data = await sendHttpRequest(payload);
+        data = await sendHttpRequest(payload, setRequestQueued);
+        if (repeatCount > 1 && data.status !== 0) {
+          // Sample latency with the same payload; the last response is shown
+          const times = [data.time];
+          setLatencySamples({ times: [...times], runs: repeatCount });
+          while (times.length < repeatCount && !repeatStopRef.current) {
+            const runId = uid();
+            setActiveRequestId(runId);
+            const next = await sendHttpRequest({ ...payload, requestId: runId }, setRequestQueued);
+            if (repeatStopRef.current || next.status === 0) break;
+            data = next;
+            times.push(data.time);
+            setLatencySamples({ times: [...times], runs: repeatCount });
+          }
+        }
       }
       setResponse(data);
       persistCookiesFromResponse(data.headers, new URL(payload.url));
@@ -1124,6 +1584,8 @@ export function RequestBar() {
         status: data.status,
         time: data.time,
         timestamp: Date.now(),
+        request: snapshotRequestTab(tab),
+        cancelled: data.error?.kind === "cancelled" || undefined,
       });
     } catch (error) {
       const message =
@@ -1153,13 +1615,18 @@ export function RequestBar() {
     setActiveRequestId(requestId);
     setResponse(null);
     setAssertionResults([]);
+    setLatencySamples(null);
 
     try {
       const runtimeVariables: Record<string, string> = {};
       const scoped = buildScopedResolver(tab, runtimeVariables);
       const resolve = scoped.resolve;
       const scriptLogs: ScriptExecutionLog[] = [];
-      const resolvedUrl = resolve(tab.url);
+      const resolvedUrl = applyPathParams(
+        applyEnvironmentBaseUrl(resolve(tab.url), resolve),
+        tab.pathParams,
+        resolve
+      );
       const url = new URL(resolvedUrl);
 
       // Build headers
@@ -1209,13 +1676,33 @@ export function RequestBar() {
         url: url.toString(),
         method: "POST",
         headers,
+        defaultHeaders: resolveDefaultHeaders(settings, resolve),
         body,
         requestId,
-        timeoutMs: settings.timeoutMs > 0 ? settings.timeoutMs : undefined,
+        timeoutMs: effectiveTimeoutMs(settings),
         retryCount: settings.retryCount > 0 ? settings.retryCount : undefined,
         retryDelayMs: settings.retryDelayMs,
-        proxyUrl: settings.proxyUrl || undefined,
+        httpVersion: settings.httpVersion !== "auto" ? settings.httpVersion : undefined,
+        resolveOverrides: parseResolveOverrides(settings.resolveOverrides),
+        maxResponseBytes:
+          settings.maxResponseMb > 0 ? Math.round(settings.maxResponseMb * 1024 * 1024) : undefined,
+        disableDecompression: settings.disableDecompression || undefined,
+        requestCompression:
+          settings.requestCompression !== "none" ? settings.requestCompression : undefined,
+        conditional: settings.conditionalRequests || undefined,
+        detectContentType: settings.autoContentType || undefined,
+        freshConnection: settings.freshConnection || undefined,
+        expectContinue: settings.expectContinue || undefined,
+        followPagination: settings.followPagination || undefined,
+        maxPages: settings.followPagination ? settings.maxPages || undefined : undefined,
+        traceRedirects: settings.traceRedirects || undefined,
+        maxRedirects: settings.traceRedirects ? settings.maxRedirects : undefined,
+        userAgent: configuredUserAgent(),
+        replayMode: configuredReplayMode(),
+        ...proxyPayloadFields(settings),
         verifySsl: settings.verifySsl,
+        minTlsVersion: settings.minTlsVersion,
+        maxTlsVersion: settings.maxTlsVersion,
       };
 
       for (const [index, script] of scoped.preScripts.entries()) {
@@ -1226,7 +1713,7 @@ export function RequestBar() {
           scriptName: `pre-request-${index + 1}`,
         });
       }
-      payload = await applyAdvancedAuth(payload, tab);
+      payload = await applyAdvancedAuth(payload, tab, resolve);
 
       let data = buildMockResponse(tab, {
         method: payload.method,
@@ -1239,7 +1726,7 @@ export function RequestBar() {
         await new Promise((resolveDelay) => setTimeout(resolveDelay, delayMs));
       }
       if (!data) {
-        data = await sendHttpRequest(payload);
+        data = await sendHttpRequest(payload, setRequestQueued);
       }
       setResponse(data);
       persistCookiesFromResponse(data.headers, new URL(payload.url));
@@ -1275,6 +1762,8 @@ export function RequestBar() {
         time: data.time,
         timestamp: Date.now(),
         requestType: "graphql",
+        request: snapshotRequestTab(tab),
+        cancelled: data.error?.kind === "cancelled" || undefined,
       });
     } catch (error) {
       const message =
@@ -1305,9 +1794,46 @@ export function RequestBar() {
         : sendRequest;
   sendRef.current = handleSend;
 
+  const closeSuggestions = () => {
+    setSuggestionsOpen(false);
+    setActiveSuggestion(-1);
+  };
+
+  const pickSuggestion = (suggestion: UrlSuggestion) => {
+    restoreRequestInActiveTab(suggestion.method, suggestion.url, suggestion.request);
+    closeSuggestions();
+  };
+
   const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === "ArrowDown" && !suggestionsOpen) {
+      e.preventDefault();
+      setSuggestionsOpen(true);
+      return;
+    }
+    if (urlSuggestions.length > 0) {
+      if (e.key === "ArrowDown" || e.key === "ArrowUp") {
+        e.preventDefault();
+        setActiveSuggestion((index) =>
+          e.key === "ArrowDown"
+            ? Math.min(index + 1, urlSuggestions.length - 1)
+            : Math.max(index - 1, -1)
+        );
+        return;
+      }
+      if (e.key === "Enter" && urlSuggestions[activeSuggestion]) {
+        e.preventDefault();
+        pickSuggestion(urlSuggestions[activeSuggestion]);
+        return;
+      }
+    }
+    if (e.key === "Escape" && suggestionsOpen) {
+      e.preventDefault();
+      closeSuggestions();
+      return;
+    }
     if (e.key === "Enter") {
       e.preventDefault();
+      closeSuggestions();
       handleSend();
     }
   };
@@ -1424,107 +1950,217 @@ export function RequestBar() {
       </div>
 
       {/* URL bar */}
-      <div className="panel-inset flex items-center gap-0 overflow-hidden rounded-xl">
-        {!isGrpc && !isGraphql && !isWebsocket && (
-          <Select
-            value={tab.method}
-            onValueChange={(v) => updateActiveTab({ method: v as HttpMethod })}
-          >
-            <SelectTrigger className={`h-11 w-[118px] rounded-none border-0 border-r border-border/80 bg-transparent font-mono text-sm font-bold ${methodTextColors[tab.method]} focus:ring-0 focus:ring-offset-0`}>
-              <SelectValue />
-            </SelectTrigger>
-            <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
-              {methods.map((m) => (
+      <div className="relative">
+        <div className="panel-inset flex items-center gap-0 overflow-hidden rounded-xl">
+          {!isGrpc && !isGraphql && !isWebsocket && customMethod !== null && (
+            <input
+              autoFocus
+              className={`h-11 w-[118px] border-r border-border/80 bg-transparent px-3 font-mono text-sm font-bold outline-none placeholder:font-normal placeholder:text-muted-foreground/50 ${
+                customMethod && !isValidHttpMethod(customMethod)
+                  ? "text-destructive"
+                  : "text-muted-foreground"
+              }`}
+              placeholder="PROPFIND"
+              title="Custom method: letters, digits or !#$%&'*+-.^_`|~"
+              value={customMethod}
+              onChange={(e) => setCustomMethod(e.target.value.toUpperCase().trim())}
+              onBlur={() => {
+                if (isValidHttpMethod(customMethod)) {
+                  updateActiveTab({ method: customMethod });
+                }
+                setCustomMethod(null);
+              }}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") {
+                  e.preventDefault();
+                  e.currentTarget.blur();
+                } else if (e.key === "Escape") {
+                  setCustomMethod(null);
+                }
+              }}
+            />
+          )}
+
+          {!isGrpc && !isGraphql && !isWebsocket && customMethod === null && (
+            <Select
+              value={tab.method}
+              onValueChange={(v) => {
+                if (v === CUSTOM_METHOD_VALUE) {
+                  setCustomMethod(isStandardHttpMethod(tab.method) ? "" : tab.method);
+                } else {
+                  updateActiveTab({ method: v });
+                }
+              }}
+            >
+              <SelectTrigger className={`h-11 w-[118px] rounded-none border-0 border-r border-border/80 bg-transparent font-mono text-sm font-bold ${methodTextClass(tab.method)} focus:ring-0 focus:ring-offset-0`}>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                {[
+                  ...STANDARD_HTTP_METHODS,
+                  ...(isStandardHttpMethod(tab.method) ? [] : [tab.method]),
+                ].map((m) => (
+                  <SelectItem
+                    key={m}
+                    value={m}
+                    className={`font-mono font-bold ${methodTextClass(m)}`}
+                  >
+                    {m}
+                  </SelectItem>
+                ))}
                 <SelectItem
-                  key={m}
-                  value={m}
-                  className={`font-mono font-bold ${methodTextColors[m]}`}
+                  value={CUSTOM_METHOD_VALUE}
+                  className="text-xs text-muted-foreground"
                 >
-                  {m}
+                  Custom…
                 </SelectItem>
-              ))}
-            </SelectContent>
-          </Select>
-        )}
+              </SelectContent>
+            </Select>
+          )}
 
-        {isGrpc && (
-          <div className="flex h-11 items-center px-3 border-r border-border/80">
-            <span className="font-mono text-sm font-bold text-purple-400">gRPC</span>
-          </div>
-        )}
+          {isGrpc && (
+            <div className="flex h-11 items-center px-3 border-r border-border/80">
+              <span className="font-mono text-sm font-bold text-purple-400">gRPC</span>
+            </div>
+          )}
 
-        {isGraphql && (
-          <div className="flex h-11 items-center px-3 border-r border-border/80">
-            <span className="font-mono text-sm font-bold text-pink-400">GQL</span>
-          </div>
-        )}
+          {isGraphql && (
+            <div className="flex h-11 items-center px-3 border-r border-border/80">
+              <span className="font-mono text-sm font-bold text-pink-400">GQL</span>
+            </div>
+          )}
 
-        {isWebsocket && (
-          <div className="flex h-11 items-center px-3 border-r border-border/80">
-            <span className="font-mono text-sm font-bold text-emerald-400">WS</span>
-          </div>
-        )}
+          {isWebsocket && (
+            <div className="flex h-11 items-center px-3 border-r border-border/80">
+              <span className="font-mono text-sm font-bold text-emerald-400">WS</span>
+            </div>
+          )}
 
-        <input
-          className="h-11 flex-1 bg-transparent px-3 font-mono text-sm text-foreground outline-none placeholder:text-muted-foreground/50"
-          placeholder={
-            isGrpc ? "Enter gRPC server address (e.g., http://localhost:50051)"
-            : isGraphql ? "Enter GraphQL endpoint URL (e.g., https://api.example.com/graphql)"
-            : isWebsocket ? "Enter WebSocket URL (e.g., ws://localhost:8080)"
-            : "Enter request URL or paste cURL..."
-          }
-          value={tab.url}
-          onChange={(e) => {
-            if (isGrpc || isGraphql || isWebsocket) {
-              updateActiveTab({ url: e.target.value });
-            } else {
-              updateActiveTabUrl(e.target.value);
+          <input
+            ref={urlInputRef}
+            className="h-11 flex-1 bg-transparent px-3 font-mono text-sm text-foreground outline-none placeholder:text-muted-foreground/50"
+            placeholder={
+              isGrpc ? "Enter gRPC server address (e.g., http://localhost:50051)"
+              : isGraphql ? "Enter GraphQL endpoint URL (e.g., https://api.example.com/graphql)"
+              : isWebsocket ? "Enter WebSocket URL (e.g., ws://localhost:8080)"
+              : "Enter request URL or paste cURL..."
             }
-          }}
-          onKeyDown={handleKeyDown}
-          onPaste={handlePaste}
-        />
-
-        {!isGrpc && !isGraphql && !isWebsocket && <RequestSettingsDialog />}
-        {!isGrpc && !isGraphql && !isWebsocket && <PreviewResolvedDialog />}
-        {!isGrpc && !isGraphql && !isWebsocket && (
-          <button
-            type="button"
-            onClick={handleCopyAsCurl}
-            disabled={!tab.url.trim()}
-            className="flex h-11 items-center px-2.5 text-muted-foreground hover:text-foreground transition-colors border-r border-border/80 disabled:cursor-not-allowed disabled:opacity-50"
-            title="Copy as cURL"
-          >
-            {curlCopied ? (
-              <Check className="h-4 w-4 text-primary" />
-            ) : (
-              <Copy className="h-4 w-4" />
-            )}
-          </button>
-        )}
-        {!isGrpc && !isGraphql && !isWebsocket && <CodeGeneratorDialog />}
-
-        {store.isLoading ? (
-          <button
-            type="button"
-            onClick={handleCancel}
-            className="flex h-11 items-center gap-2 bg-destructive px-5 text-sm font-semibold text-destructive-foreground transition-colors hover:bg-destructive/90"
-          >
-            <X className="h-4 w-4" />
-            Cancel
-          </button>
-        ) : (
-          <button
-            type="button"
-            onClick={handleSend}
-            disabled={!canSend}
-            className="flex h-11 items-center gap-2 bg-primary px-5 text-sm font-semibold text-primary-foreground transition-colors hover:bg-primary/90 disabled:cursor-not-allowed disabled:opacity-50"
-          >
-            <Send className="h-4 w-4" />
-            {isWebsocket ? "Connect" : "Send"}
-          </button>
+            value={tab.url}
+            onChange={(e) => {
+              if (isGrpc || isGraphql || isWebsocket) {
+                updateActiveTab({ url: e.target.value });
+              } else {
+                updateActiveTabUrl(e.target.value);
+              }
+              setSuggestionsOpen(true);
+              setActiveSuggestion(-1);
+            }}
+            onKeyDown={handleKeyDown}
+            onBlur={closeSuggestions}
+            onPaste={handlePaste}
+            role="combobox"
+            aria-expanded={urlSuggestions.length > 0}
+            aria-autocomplete="list"
+          />
+
+          {!isGrpc && !isGraphql && !isWebsocket && <RequestSettingsDialog />}
+          {!isGrpc && !isGraphql && !isWebsocket && (
+            <button
+              type="button"
+              onClick={() => setShowPreview((open) => !open)}
+              disabled={!tab.url.trim()}
+              className={`flex h-11 items-center px-2.5 transition-colors border-r border-border/80 disabled:cursor-not-allowed disabled:opacity-50 ${
+                showPreview ? "text-primary" : "text-muted-foreground hover:text-foreground"
+              }`}
+              title="Preview Resolved Request"
+            >
+              <Eye className="h-4 w-4" />
+            </button>
+          )}
+          {!isGrpc && !isGraphql && !isWebsocket && (
+            <>
+              <button
+                type="button"
+                onClick={() => handleCopyAs("curl")}
+                disabled={!tab.url.trim()}
+                className="flex h-11 items-center pl-2.5 pr-1 text-muted-foreground hover:text-foreground transition-colors disabled:cursor-not-allowed disabled:opacity-50"
+                title="Copy as cURL"
+              >
+                {curlCopied ? (
+                  <Check className="h-4 w-4 text-primary" />
+                ) : (
+                  <Copy className="h-4 w-4" />
+                )}
+              </button>
+              <Select value="" onValueChange={(value) => handleCopyAs(value as CodeLanguage)}>
+                <SelectTrigger
+                  disabled={!tab.url.trim()}
+                  className="h-11 w-6 rounded-none border-0 border-r border-border/80 bg-transparent px-1 text-muted-foreground hover:text-foreground focus:ring-0 focus:ring-offset-0"
+                  title="Copy as..."
+                />
+                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                  <SelectItem value="curl" className="text-xs">Copy as cURL</SelectItem>
+                  <SelectItem value="httpie" className="text-xs">Copy as HTTPie</SelectItem>
+                  <SelectItem value="javascript-fetch" className="text-xs">Copy as fetch</SelectItem>
+                </SelectContent>
+              </Select>
+            </>
+          )}
+          {!isGrpc && !isGraphql && !isWebsocket && <CodeGeneratorDialog />}
+
+          {!isGrpc && !isGraphql && !isWebsocket && (
+            <label
+              className="flex h-11 items-center gap-0.5 border-r border-border/80 px-2 text-xs text-muted-foreground"
+              title="Send the request this many times in a row and summarize the latency"
+            >
+              <span>&times;</span>
+              <input
+                type="number"
+                min={1}
+                max={1000}
+                value={repeatCount}
+                disabled={store.isLoading}
+                onChange={(e) =>
+                  setRepeatCount(Math.min(1000, Math.max(1, Math.floor(Number(e.target.value)) || 1)))
+                }
+                className="w-10 bg-transparent font-mono text-xs text-foreground outline-none disabled:opacity-50"
+                aria-label="Repeat count"
+              />
+            </label>
+          )}
+
+          {store.isLoading ? (
+            <button
+              type="button"
+              onClick={handleCancel}
+              className="flex h-11 items-center gap-2 bg-destructive px-5 text-sm font-semibold text-destructive-foreground transition-colors hover:bg-destructive/90"
+            >
+              <X className="h-4 w-4" />
+              Cancel
+            </button>
+          ) : (
+            <button
+              type="button"
+              onClick={handleSend}
+              disabled={!canSend}
+              className="flex h-11 items-center gap-2 bg-primary px-5 text-sm font-semibold text-primary-foreground transition-colors hover:bg-primary/90 disabled:cursor-not-allowed disabled:opacity-50"
+            >
+              <Send className="h-4 w-4" />
+              {isWebsocket ? "Connect" : "Send"}
+            </button>
+          )}
+        </div>
+        {urlSuggestions.length > 0 && (
+          <UrlSuggestionList
+            suggestions={urlSuggestions}
+            activeIndex={activeSuggestion}
+            onPick={pickSuggestion}
+            onHover={setActiveSuggestion}
+          />
         )}
       </div>
+
+      {showPreview && !isGrpc && !isGraphql && !isWebsocket && <RequestPreviewPanel />}
     </div>
   );
 }
@@ -1,14 +1,33 @@
 "use client";
 
-import { useState } from "react";
+import { useEffect, useRef, useState } from "react";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import {
+  type KeyValue,
+  defaultSettings,
+  effectiveTimeoutMs,
+  resolveEnvVariables,
   uid,
   useActiveTab,
   updateActiveTab,
   updateActiveTabParams,
 } from "@/lib/getman-store";
-import { parseProtoContent, fetchGrpcReflection } from "@/lib/tauri";
+import {
+  parseProtoContent,
+  fetchGrpcReflection,
+  refreshGrpcReflection,
+  buildGrpcRequestTemplate,
+  validateGrpcRequest,
+  startGrpcStream,
+  sendGrpcStreamMessage,
+  closeGrpcStreamSend,
+  pollGrpcStream,
+  cancelGrpcStream,
+  type GrpcStreamMessage,
+  type GrpcStreamSnapshot,
+  type GrpcValidationIssue,
+} from "@/lib/tauri";
+import { Checkbox } from "@/components/ui/checkbox";
 import { KVEditor } from "./kv-editor";
 import { AuthEditor } from "./auth-editor";
 import { BodyEditor } from "./body-editor";
@@ -45,7 +64,7 @@ function GrpcProtoEditor() {
     }
   };
 
-  const handleServerReflection = async () => {
+  const handleServerReflection = async (refresh = false) => {
     if (!tab.url?.trim()) {
       setParseError("Enter a server address first");
       return;
@@ -55,7 +74,9 @@ function GrpcProtoEditor() {
     setParseError(null);
 
     try {
-      const result = await fetchGrpcReflection(tab.url);
+      const result = refresh
+        ? await refreshGrpcReflection(tab.url)
+        : await fetchGrpcReflection(tab.url);
       updateActiveTab({
         grpcServices: result.services,
         grpcServiceName: result.services[0]?.fullName ?? "",
@@ -80,12 +101,23 @@ function GrpcProtoEditor() {
         <div className="flex-1" />
         <button
           type="button"
-          onClick={handleServerReflection}
+          onClick={() => handleServerReflection()}
           disabled={!tab.url?.trim() || reflecting}
           className="text-[11px] font-medium bg-purple-500/10 text-purple-400 hover:bg-purple-500/20 px-3 py-1 rounded transition-colors disabled:opacity-50"
         >
           {reflecting ? "Reflecting..." : "Server Reflection"}
         </button>
+        {tab.grpcDescriptorBytes && (
+          <button
+            type="button"
+            onClick={() => handleServerReflection(true)}
+            disabled={!tab.url?.trim() || reflecting}
+            title="Reflection results are cached per server; fetch them again"
+            className="text-[11px] font-medium text-purple-400 hover:bg-purple-500/10 px-2 py-1 rounded transition-colors disabled:opacity-50"
+          >
+            Refresh
+          </button>
+        )}
         <button
           type="button"
           onClick={handleParseProto}
@@ -120,26 +152,308 @@ function GrpcProtoEditor() {
 
 function GrpcMessageEditor() {
   const tab = useActiveTab();
+  const [templateError, setTemplateError] = useState<string | null>(null);
+  const [generating, setGenerating] = useState(false);
+  const [validating, setValidating] = useState(false);
+  const [issues, setIssues] = useState<GrpcValidationIssue[] | null>(null);
   if (!tab) return null;
 
+  const canGenerate = Boolean(
+    tab.grpcServiceName &&
+      tab.grpcMethodName &&
+      (tab.grpcProtoContent?.trim() || tab.grpcDescriptorBytes)
+  );
+
+  const handleGenerateTemplate = async () => {
+    setGenerating(true);
+    setTemplateError(null);
+    try {
+      const template = await buildGrpcRequestTemplate(
+        tab.grpcProtoContent ?? "",
+        tab.grpcDescriptorBytes || undefined,
+        tab.grpcServiceName,
+        tab.grpcMethodName
+      );
+      updateActiveTab({ grpcRequestBody: JSON.stringify(template, null, 2) });
+      setIssues(null);
+    } catch (error) {
+      setTemplateError(
+        error instanceof Error ? error.message : String(error || "Failed to build template")
+      );
+    } finally {
+      setGenerating(false);
+    }
+  };
+
+  const handleValidate = async () => {
+    setValidating(true);
+    setTemplateError(null);
+    try {
+      setIssues(
+        await validateGrpcRequest(
+          tab.grpcProtoContent ?? "",
+          tab.grpcDescriptorBytes || undefined,
+          tab.grpcServiceName,
+          tab.grpcMethodName,
+          tab.grpcRequestBody || "{}"
+        )
+      );
+    } catch (error) {
+      setIssues(null);
+      setTemplateError(
+        error instanceof Error ? error.message : String(error || "Failed to validate request")
+      );
+    } finally {
+      setValidating(false);
+    }
+  };
+
   return (
     <div className="flex flex-col h-full">
       <div className="flex items-center gap-2 px-3 py-2 border-b border-border/50">
         <span className="text-[11px] font-medium text-muted-foreground">
           Request message (JSON)
         </span>
+        <div className="flex-1" />
+        <button
+          type="button"
+          onClick={handleValidate}
+          disabled={!canGenerate || validating}
+          title="Check the message against the method's input type"
+          className="text-[11px] font-medium bg-primary/10 text-primary hover:bg-primary/20 px-3 py-1 rounded transition-colors disabled:opacity-50"
+        >
+          {validating ? "Validating..." : "Validate"}
+        </button>
+        <button
+          type="button"
+          onClick={handleGenerateTemplate}
+          disabled={!canGenerate || generating}
+          title="Fill the message with every field set to its default value"
+          className="text-[11px] font-medium bg-primary/10 text-primary hover:bg-primary/20 px-3 py-1 rounded transition-colors disabled:opacity-50"
+        >
+          {generating ? "Generating..." : "Generate Template"}
+        </button>
       </div>
+      {templateError && (
+        <div className="px-3 py-1.5 text-[11px] text-red-500 bg-red-500/5 border-b border-red-500/20">
+          {templateError}
+        </div>
+      )}
+      {issues && issues.length === 0 && (
+        <div className="px-3 py-1.5 text-[11px] text-green-500 bg-green-500/5 border-b border-green-500/20">
+          Message is valid for {tab.grpcMethodName}
+        </div>
+      )}
+      {issues && issues.length > 0 && (
+        <div className="flex flex-col gap-0.5 px-3 py-1.5 text-[11px] text-red-500 bg-red-500/5 border-b border-red-500/20">
+          {issues.map((issue, index) => (
+            <div key={`${issue.path}-${index}`} className="font-mono">
+              <span className="font-semibold">{issue.path}</span> {issue.message}
+            </div>
+          ))}
+        </div>
+      )}
       <textarea
         className="flex-1 w-full bg-transparent px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 resize-none"
         placeholder='{ "name": "world" }'
         value={tab.grpcRequestBody ?? "{}"}
-        onChange={(e) => updateActiveTab({ grpcRequestBody: e.target.value })}
+        onChange={(e) => {
+          updateActiveTab({ grpcRequestBody: e.target.value });
+          setIssues(null);
+        }}
         spellCheck={false}
       />
     </div>
   );
 }
 
+const STREAM_POLL_INTERVAL_MS = 500;
+
+function GrpcStreamPanel() {
+  const tab = useActiveTab();
+  const [streamId, setStreamId] = useState<string | null>(null);
+  const [snapshot, setSnapshot] = useState<GrpcStreamSnapshot | null>(null);
+  const [messages, setMessages] = useState<GrpcStreamMessage[]>([]);
+  const [draft, setDraft] = useState("{}");
+  const [error, setError] = useState<string | null>(null);
+  const [starting, setStarting] = useState(false);
+  const lastSeq = useRef(0);
+
+  const isOpen = Boolean(streamId) && snapshot?.status === "open";
+
+  useEffect(() => {
+    if (!streamId || !isOpen) return;
+    const timer = window.setInterval(async () => {
+      try {
+        const next = await pollGrpcStream(streamId, lastSeq.current);
+        if (next.messages.length > 0) {
+          lastSeq.current = next.messages[next.messages.length - 1].seq;
+          setMessages((prev) => [...prev, ...next.messages]);
+        }
+        setSnapshot(next);
+      } catch (err) {
+        setError(err instanceof Error ? err.message : String(err));
+        setStreamId(null);
+      }
+    }, STREAM_POLL_INTERVAL_MS);
+    return () => window.clearInterval(timer);
+  }, [streamId, isOpen]);
+
+  // Leaving the tab or switching requests tears the call down.
+  useEffect(() => {
+    return () => {
+      if (streamId) void cancelGrpcStream(streamId);
+    };
+  }, [streamId]);
+
+  if (!tab) return null;
+
+  const method = (tab.grpcServices ?? [])
+    .find((service) => service.fullName === tab.grpcServiceName)
+    ?.methods.find((m) => m.name === tab.grpcMethodName);
+  if (!method?.clientStreaming) {
+    return (
+      <div className="px-3 py-4 text-xs text-muted-foreground">
+        Select a client-streaming or bidirectional method to send messages interactively.
+      </div>
+    );
+  }
+
+  const handleStart = async () => {
+    if (streamId) await cancelGrpcStream(streamId);
+    setStarting(true);
+    setError(null);
+    setMessages([]);
+    setSnapshot(null);
+    lastSeq.current = 0;
+    try {
+      const metadata: Record<string, string> = {};
+      for (const m of tab.grpcMetadata ?? []) {
+        if (m.enabled && m.key) metadata[m.key] = resolveEnvVariables(m.value);
+      }
+      const settings = tab.settings || defaultSettings();
+      const id = await startGrpcStream({
+        endpoint: resolveEnvVariables(tab.url),
+        protoContent: tab.grpcProtoContent,
+        serviceName: tab.grpcServiceName,
+        methodName: tab.grpcMethodName,
+        requestJson: "{}",
+        metadata,
+        timeoutMs: effectiveTimeoutMs(settings),
+        requestId: uid(),
+        descriptorBytes: tab.grpcDescriptorBytes || undefined,
+      });
+      setStreamId(id);
+      setSnapshot(await pollGrpcStream(id, 0));
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err || "Failed to open stream"));
+    } finally {
+      setStarting(false);
+    }
+  };
+
+  const handleSend = async () => {
+    if (!streamId) return;
+    setError(null);
+    try {
+      await sendGrpcStreamMessage(streamId, resolveEnvVariables(draft || "{}"));
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err));
+    }
+  };
+
+  const handleCloseSend = async () => {
+    if (!streamId) return;
+    await closeGrpcStreamSend(streamId);
+    setSnapshot((prev) => (prev ? { ...prev, sendClosed: true } : prev));
+  };
+
+  const handleCancel = async () => {
+    if (!streamId) return;
+    await cancelGrpcStream(streamId);
+    setStreamId(null);
+    setSnapshot((prev) =>
+      prev ? { ...prev, status: "failed", statusMessage: "Cancelled" } : prev
+    );
+  };
+
+  const buttonClass =
+    "text-[11px] font-medium bg-primary/10 text-primary hover:bg-primary/20 px-3 py-1 rounded transition-colors disabled:opacity-50";
+
+  return (
+    <div className="flex flex-col h-full">
+      <div className="flex items-center gap-2 px-3 py-2 border-b border-border/50">
+        <span className="text-[11px] font-medium text-muted-foreground">
+          {method.serverStreaming ? "Bidirectional stream" : "Client stream"}
+          {snapshot && ` · ${snapshot.status}`}
+          {snapshot?.sendClosed && snapshot.status === "open" && " · send closed"}
+        </span>
+        <div className="flex-1" />
+        <button type="button" onClick={handleStart} disabled={starting} className={buttonClass}>
+          {starting ? "Connecting..." : isOpen ? "Restart" : "Start"}
+        </button>
+        <button
+          type="button"
+          onClick={handleCloseSend}
+          disabled={!isOpen || snapshot?.sendClosed}
+          title="Tell the server no more messages are coming"
+          className={buttonClass}
+        >
+          Close Send
+        </button>
+        <button type="button" onClick={handleCancel} disabled={!isOpen} className={buttonClass}>
+          Cancel
+        </button>
+      </div>
+      {error && (
+        <div className="px-3 py-1.5 text-[11px] text-red-500 bg-red-500/5 border-b border-red-500/20">
+          {error}
+        </div>
+      )}
+      {snapshot && snapshot.status !== "open" && snapshot.statusMessage && (
+        <div className="px-3 py-1.5 text-[11px] text-muted-foreground border-b border-border/50">
+          {snapshot.statusMessage}
+        </div>
+      )}
+      <div className="flex gap-2 px-3 py-2 border-b border-border/50">
+        <textarea
+          className="flex-1 h-20 bg-transparent font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 resize-none"
+          placeholder='{ "name": "world" }'
+          value={draft}
+          onChange={(e) => setDraft(e.target.value)}
+          spellCheck={false}
+        />
+        <button
+          type="button"
+          onClick={handleSend}
+          disabled={!isOpen || snapshot?.sendClosed}
+          className={`${buttonClass} self-end`}
+        >
+          Send
+        </button>
+      </div>
+      <div className="flex-1 min-h-0 overflow-auto px-3 py-2 font-mono text-xs">
+        {messages.length === 0 ? (
+          <span className="text-muted-foreground">No messages yet</span>
+        ) : (
+          messages.map((message) => (
+            <div key={message.seq} className="flex gap-2 py-0.5">
+              <span
+                className={
+                  message.direction === "sent" ? "text-primary shrink-0" : "text-green-500 shrink-0"
+                }
+              >
+                {message.direction === "sent" ? "→" : "←"}
+              </span>
+              <span className="break-all text-foreground">{message.json}</span>
+            </div>
+          ))
+        )}
+      </div>
+    </div>
+  );
+}
+
 function ScriptEditor() {
   const tab = useActiveTab();
   if (!tab) return null;
@@ -178,6 +492,59 @@ function ScriptEditor() {
   );
 }
 
+/** Values for `:name` / `{name}` placeholders; rows follow the URL. */
+function PathParamsSection() {
+  const tab = useActiveTab();
+  if (!tab) return null;
+  const pathParams = tab.pathParams ?? [];
+
+  const update = (id: string, partial: Partial<KeyValue>) => {
+    updateActiveTab({
+      pathParams: pathParams.map((p) => (p.id === id ? { ...p, ...partial } : p)),
+    });
+  };
+
+  return (
+    <div className="flex flex-col border-t border-border/60">
+      <div className="px-3 py-1.5 border-b border-border/40">
+        <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">
+          Path Params
+        </span>
+      </div>
+      {pathParams.length === 0 ? (
+        <p className="px-3 py-2 text-[11px] text-muted-foreground">
+          Use <code className="font-mono">:id</code> or <code className="font-mono">{"{id}"}</code> in
+          the URL path to add a path parameter.
+        </p>
+      ) : (
+        pathParams.map((param) => (
+          <div
+            key={param.id}
+            className="grid grid-cols-[28px_1fr_1fr] items-center gap-0 border-b border-border/45 hover:bg-[hsl(var(--surface-2))]"
+          >
+            <div className="flex items-center justify-center">
+              <Checkbox
+                checked={param.enabled}
+                onCheckedChange={(v) => update(param.id, { enabled: !!v })}
+                className="h-3.5 w-3.5"
+              />
+            </div>
+            <span className="border-r border-border/50 px-2 py-2 font-mono text-xs text-[hsl(var(--chart-2))]">
+              {param.key}
+            </span>
+            <input
+              className="bg-transparent px-2 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40"
+              placeholder="Value"
+              value={param.value}
+              onChange={(e) => update(param.id, { value: e.target.value })}
+            />
+          </div>
+        ))
+      )}
+    </div>
+  );
+}
+
 function VariablesEditor() {
   const tab = useActiveTab();
   if (!tab) return null;
@@ -421,6 +788,27 @@ function FlowEditor() {
   );
 }
 
+function DocsEditor() {
+  const tab = useActiveTab();
+  if (!tab) return null;
+
+  return (
+    <div className="flex flex-col h-full">
+      <div className="px-3 py-1.5 border-b border-border/40">
+        <span className="text-[10px] font-medium text-muted-foreground uppercase tracking-wider">
+          Description
+        </span>
+      </div>
+      <textarea
+        className="flex-1 min-h-[160px] bg-transparent px-3 py-2 text-xs text-foreground outline-none resize-none placeholder:text-muted-foreground/40"
+        placeholder="What this endpoint does, required setup, expected responses..."
+        value={tab.description ?? ""}
+        onChange={(e) => updateActiveTab({ description: e.target.value })}
+      />
+    </div>
+  );
+}
+
 export function RequestEditor() {
   const tab = useActiveTab();
   if (!tab) return null;
@@ -429,7 +817,9 @@ export function RequestEditor() {
   const isGraphql = (tab.requestType ?? "http") === "graphql";
   const isWebsocket = (tab.requestType ?? "http") === "websocket";
 
-  const enabledParams = tab.params.filter((p) => p.enabled && p.key).length;
+  const enabledParams =
+    tab.params.filter((p) => p.enabled && p.key).length +
+    (tab.pathParams ?? []).filter((p) => p.enabled).length;
   const enabledHeaders = tab.headers.filter((h) => h.enabled && h.key).length;
   const enabledCookies = (tab.cookies ?? []).filter((c) => c.enabled && c.key).length;
   const assertionCount = (tab.assertions ?? []).length;
@@ -448,6 +838,7 @@ export function RequestEditor() {
           {[
             { value: "proto", label: "Proto" },
             { value: "message", label: "Message" },
+            { value: "stream", label: "Stream" },
             { value: "metadata", label: "Metadata", count: enabledMetadata },
           ].map((t) => (
             <TabsTrigger
@@ -474,6 +865,10 @@ export function RequestEditor() {
             <GrpcMessageEditor />
           </TabsContent>
 
+          <TabsContent value="stream" className="m-0 h-full">
+            <GrpcStreamPanel />
+          </TabsContent>
+
           <TabsContent value="metadata" className="m-0 h-full">
             <KVEditor
               items={tab.grpcMetadata ?? []}
@@ -663,6 +1058,7 @@ export function RequestEditor() {
           { value: "tests", label: "Tests", count: assertionCount },
           { value: "scripts", label: "Scripts", count: scriptCount },
           { value: "flow", label: "Flow", count: flowCount },
+          { value: "docs", label: "Docs", count: tab.description?.trim() ? 1 : 0 },
         ].map((t) => (
           <TabsTrigger
             key={t.value}
@@ -687,6 +1083,7 @@ export function RequestEditor() {
             keyPlaceholder="Parameter"
             valuePlaceholder="Value"
           />
+          <PathParamsSection />
         </TabsContent>
 
         <TabsContent value="headers" className="m-0 h-full">
@@ -734,6 +1131,10 @@ export function RequestEditor() {
         <TabsContent value="flow" className="m-0 h-full">
           <FlowEditor />
         </TabsContent>
+
+        <TabsContent value="docs" className="m-0 h-full">
+          <DocsEditor />
+        </TabsContent>
       </div>
     </Tabs>
   );
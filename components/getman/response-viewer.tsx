@@ -1,9 +1,53 @@
 "use client";
 
 import { useState, useMemo, useRef, useCallback, useEffect } from "react";
-import { Copy, Check, Search, X, Download, AlertTriangle } from "lucide-react";
+import {
+  Copy,
+  Check,
+  Search,
+  X,
+  Download,
+  AlertTriangle,
+  ListChecks,
+  Pin,
+  PinOff,
+  Filter,
+  ExternalLink,
+} from "lucide-react";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
-import { useGetmanStore, type ResponseData, type GrpcResponseData } from "@/lib/getman-store";
+import {
+  addAssertionsFromResponse,
+  addJsonPathAssertion,
+  grpcStatusName,
+  isSlowResponse,
+  setPrettyPrintLimitKb,
+  useGetmanStore,
+  type ResponseData,
+  type GrpcResponseData,
+  type LatencySamples,
+} from "@/lib/getman-store";
+import { computeDurationMetrics } from "@/lib/runner";
+import { JsonTreeView } from "./json-tree-view";
+import { SEND_REQUEST_EVENT } from "./request-bar";
+import { prettyJson } from "@/lib/json-format";
+import { detectBodyFormat, formatBody } from "@/lib/body-format";
+import {
+  applyJq,
+  clearResponseBaseline,
+  diffResponses,
+  loadResponseBaseline,
+  openHtmlInBrowser,
+  pinResponseBaseline,
+  pollSendProgress,
+  type ResponseBaseline,
+  type ResponseDiff,
+  type GetmanErrorKind,
+  type ResponseDiffEntry,
+  type SendPhase,
+  type SendProgressSnapshot,
+} from "@/lib/tauri";
+
+type BodyViewMode = "pretty" | "raw" | "tree" | "preview";
 
 function StatusBadge({ status }: { status: number }) {
   let color = "text-muted-foreground bg-muted";
@@ -19,18 +63,109 @@ function StatusBadge({ status }: { status: number }) {
   );
 }
 
+const ERROR_KIND_LABELS: Record<GetmanErrorKind, string> = {
+  invalidUrl: "Invalid URL",
+  invalidRequest: "Invalid request",
+  dns: "DNS error",
+  connect: "Connection error",
+  tls: "TLS error",
+  proxy: "Proxy error",
+  timeout: "Timed out",
+  http: "Protocol error",
+  cancelled: "Cancelled",
+  io: "File error",
+  other: "Error",
+};
+
 function formatBytes(bytes: number): string {
   if (bytes < 1024) return `${bytes} B`;
   if (bytes < 1048576) return `${(bytes / 1024).toFixed(1)} KB`;
   return `${(bytes / 1048576).toFixed(1)} MB`;
 }
 
+const ENCODED_BODY_EXTENSIONS: Record<string, string> = {
+  gzip: "gz",
+  "x-gzip": "gz",
+  br: "br",
+  deflate: "zz",
+  zstd: "zst",
+};
+
+/** File extension for a body saved in its last applied `Content-Encoding`. */
+function encodedBodyExtension(encoding: string): string {
+  const last = encoding.split(",").pop()?.trim().toLowerCase() ?? "";
+  return ENCODED_BODY_EXTENSIONS[last] ?? "bin";
+}
+
+const SEND_PHASE_LABELS: Record<SendPhase, string> = {
+  resolving: "Resolving host...",
+  connecting: "Connecting...",
+  waiting: "Waiting for response...",
+  downloading: "Downloading...",
+};
+
+/** Polls the backend for the phase of an in-flight send and how much has arrived. */
+function SendProgressStatus({ requestId, url }: { requestId: string | null; url: string }) {
+  const [progress, setProgress] = useState<SendProgressSnapshot | null>(null);
+
+  useEffect(() => {
+    setProgress(null);
+    if (!requestId) return;
+    let active = true;
+    const poll = async () => {
+      const snapshot = await pollSendProgress(requestId);
+      if (active) setProgress(snapshot);
+    };
+    void poll();
+    const timer = window.setInterval(poll, 150);
+    return () => {
+      active = false;
+      window.clearInterval(timer);
+    };
+  }, [requestId]);
+
+  if (!progress) {
+    return <p className="text-sm text-muted-foreground">Sending request...</p>;
+  }
+
+  const { phase, bytesReceived, totalBytes, elapsedMs } = progress;
+  let label = SEND_PHASE_LABELS[phase];
+  let hint: string | undefined;
+  if (phase === "connecting" && /^https:/i.test(url.trim())) {
+    label = "Connecting (TCP + TLS handshake)...";
+    hint = "The HTTP client opens connections in one step, so the TLS handshake is not timed separately";
+  }
+  const percent =
+    phase === "downloading" && totalBytes
+      ? Math.min(100, Math.round((bytesReceived / totalBytes) * 100))
+      : null;
+  if (phase === "downloading") {
+    label =
+      percent !== null && totalBytes
+        ? `Downloading ${formatBytes(bytesReceived)} of ${formatBytes(totalBytes)} (${percent}%)`
+        : `Downloading ${formatBytes(bytesReceived)}...`;
+  }
+
+  return (
+    <div className="flex flex-col items-center gap-1.5">
+      <p className="text-sm text-muted-foreground" title={hint}>
+        {label}
+      </p>
+      {percent !== null && (
+        <div className="h-1 w-48 overflow-hidden rounded-full bg-muted">
+          <div className="h-full bg-primary transition-[width]" style={{ width: `${percent}%` }} />
+        </div>
+      )}
+      <p className="text-xs font-mono text-muted-foreground">{elapsedMs}ms</p>
+    </div>
+  );
+}
+
+/** Highlights JSON text as given; callers pass it already pretty-printed. */
 function SyntaxHighlightedJSON({ json }: { json: string }) {
   const highlighted = useMemo(() => {
     try {
-      const parsed = JSON.parse(json);
-      const pretty = JSON.stringify(parsed, null, 2);
-      return pretty
+      return json
         .replace(/&/g, "&amp;")
         .replace(/</g, "&lt;")
         .replace(/>/g, "&gt;")
@@ -59,21 +194,35 @@ function SyntaxHighlightedJSON({ json }: { json: string }) {
   );
 }
 
-function CopyButton({ text }: { text: string }) {
+// `text` may be a function so large bodies are only formatted when copied.
+function CopyButton({
+  text,
+  title = "Copy to clipboard",
+  className = "",
+}: {
+  text: string | (() => string);
+  title?: string;
+  className?: string;
+}) {
   const [copied, setCopied] = useState(false);
 
   const copy = () => {
-    navigator.clipboard.writeText(text);
-    setCopied(true);
-    setTimeout(() => setCopied(false), 2000);
+    const value = typeof text === "function" ? text() : text;
+    navigator.clipboard.writeText(value).then(
+      () => {
+        setCopied(true);
+        setTimeout(() => setCopied(false), 2000);
+      },
+      () => setCopied(false)
+    );
   };
 
   return (
     <button
       type="button"
       onClick={copy}
-      className="text-muted-foreground hover:text-foreground transition-colors p-1"
-      title="Copy to clipboard"
+      className={`text-muted-foreground hover:text-foreground transition-colors p-1 ${className}`}
+      title={title}
     >
       {copied ? <Check className="h-3.5 w-3.5 text-primary" /> : <Copy className="h-3.5 w-3.5" />}
     </button>
@@ -257,18 +406,238 @@ function LargeResponseWarning({ size, onShow }: { size: number; onShow: () => vo
   );
 }
 
-function ResponseBody({ response, viewMode, searchQuery }: { response: ResponseData; viewMode: "pretty" | "raw"; searchQuery: string }) {
+/** JSON tree where clicking a value copies its JSONPath, ready to turn into an assertion. */
+function PickableJsonTree({ value }: { value: unknown }) {
+  const [picked, setPicked] = useState<{ path: string; value: unknown } | null>(null);
+  const [added, setAdded] = useState(false);
+
+  return (
+    <div className="flex flex-col gap-2">
+      {picked && (
+        <div className="flex items-center gap-2 rounded border border-border/60 bg-[hsl(var(--surface-2))] px-2 py-1 text-[11px]">
+          <span className="shrink-0 text-muted-foreground">Copied</span>
+          <code className="min-w-0 flex-1 truncate font-mono text-foreground">{picked.path}</code>
+          <button
+            type="button"
+            disabled={added}
+            onClick={() => {
+              addJsonPathAssertion(picked.path, picked.value);
+              setAdded(true);
+            }}
+            className="shrink-0 font-medium text-primary hover:underline disabled:text-muted-foreground disabled:no-underline"
+            title="Add a JSONPath assertion for this value to the Tests tab"
+          >
+            {added ? "Assertion added" : "Add as assertion"}
+          </button>
+        </div>
+      )}
+      <JsonTreeView
+        value={value}
+        onPickPath={(path, pickedValue) => {
+          void navigator.clipboard.writeText(path).catch(() => undefined);
+          setPicked({ path, value: pickedValue });
+          setAdded(false);
+        }}
+      />
+    </div>
+  );
+}
+
+/**
+ * Pretty-printed JSON kept per response object, so re-renders and view
+ * toggles reuse it instead of re-formatting the body. `null` marks a body
+ * that is not JSON.
+ */
+const prettyBodies = new WeakMap<ResponseData, string | null>();
+
+function cachedPrettyJson(response: ResponseData): string | null {
+  let pretty = prettyBodies.get(response);
+  if (pretty === undefined) {
+    try {
+      pretty = prettyJson(response.body);
+    } catch {
+      pretty = null;
+    }
+    prettyBodies.set(response, pretty);
+  }
+  return pretty;
+}
+
+/** Same as `prettyBodies`, for XML, HTML, CSS and JavaScript bodies. */
+const formattedBodies = new WeakMap<ResponseData, string | null>();
+
+function cachedFormattedBody(response: ResponseData): string | null {
+  let formatted = formattedBodies.get(response);
+  if (formatted === undefined) {
+    formatted = formatBody(response.body, response.contentType);
+    formattedBodies.set(response, formatted);
+  }
+  return formatted;
+}
+
+function PrettyPrintSkipped({ size, limitKb, onFormat }: { size: number; limitKb: number; onFormat: () => void }) {
+  return (
+    <div className="flex items-center gap-2 border-b border-amber-500/20 bg-amber-500/5 px-3 py-1.5 text-[11px] text-amber-600 dark:text-amber-400">
+      <AlertTriangle className="h-3.5 w-3.5 shrink-0" />
+      <span className="flex-1">
+        Showing raw: the body ({formatBytes(size)}) is over the {formatBytes(limitKb * 1024)} pretty-print limit.
+      </span>
+      <button type="button" onClick={onFormat} className="font-medium hover:underline">
+        Format anyway
+      </button>
+      <button
+        type="button"
+        onClick={() => setPrettyPrintLimitKb(0)}
+        className="font-medium hover:underline"
+        title="Always pretty-print, whatever the body size"
+      >
+        Remove limit
+      </button>
+    </div>
+  );
+}
+
+/** Renders `text` a chunk at a time, with buttons to reveal the rest. */
+function LoadMoreBody({
+  text,
+  chunkKb,
+  render,
+}: {
+  text: string;
+  chunkKb: number;
+  render: (visible: string) => React.ReactNode;
+}) {
+  const chunk = chunkKb * 1024;
+  const [shownChars, setShownChars] = useState(chunk);
+  if (text.length <= shownChars) return <>{render(text)}</>;
+
+  return (
+    <div className="flex flex-col">
+      {render(text.slice(0, shownChars))}
+      <div className="flex items-center gap-3 border-t border-border/60 px-3 py-2 text-[11px] text-muted-foreground">
+        <span className="flex-1">
+          Showing {formatBytes(shownChars)} of {formatBytes(text.length)}
+        </span>
+        <button
+          type="button"
+          onClick={() => setShownChars((shown) => shown + chunk)}
+          className="font-medium text-primary hover:underline"
+        >
+          Load {formatBytes(Math.min(chunk, text.length - shownChars))} more
+        </button>
+        <button
+          type="button"
+          onClick={() => setShownChars(text.length)}
+          className="font-medium hover:underline"
+        >
+          Show all
+        </button>
+      </div>
+    </div>
+  );
+}
+
+function ResponseJqFilter({
+  active,
+  onApply,
+}: {
+  active: boolean;
+  onApply: (filter: string | null) => void;
+}) {
+  const [open, setOpen] = useState(false);
+  const [filter, setFilter] = useState("");
+
+  if (!open && !active) {
+    return (
+      <button
+        type="button"
+        onClick={() => setOpen(true)}
+        className="text-muted-foreground hover:text-foreground transition-colors p-1"
+        title="Filter the body with a jq query"
+      >
+        <Filter className="h-3.5 w-3.5" />
+      </button>
+    );
+  }
+
+  return (
+    <div className="flex items-center gap-1.5 bg-[hsl(var(--surface-2))] rounded px-2 py-0.5">
+      <Filter className="h-3 w-3 text-muted-foreground shrink-0" />
+      <input
+        className="w-40 bg-transparent font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/50"
+        placeholder=".data.items[].id"
+        value={filter}
+        onChange={(e) => setFilter(e.target.value)}
+        onKeyDown={(e) => {
+          if (e.key === "Enter") onApply(filter.trim() || null);
+        }}
+        title="jq filter; press Enter to apply"
+        autoFocus
+      />
+      <button
+        type="button"
+        onClick={() => {
+          setOpen(false);
+          setFilter("");
+          onApply(null);
+        }}
+        className="text-muted-foreground hover:text-foreground"
+        title="Reset to the full body"
+      >
+        <X className="h-3 w-3" />
+      </button>
+    </div>
+  );
+}
+
+function ResponseBody({ response, viewMode, searchQuery }: { response: ResponseData; viewMode: BodyViewMode; searchQuery: string }) {
+  const { prettyPrintLimitKb, renderChunkKb } = useGetmanStore();
   const [showLarge, setShowLarge] = useState(false);
+  const [formatAnyway, setFormatAnyway] = useState(false);
   const isLarge = response.size > LARGE_RESPONSE_THRESHOLD_BYTES;
+  const overPrettyLimit =
+    prettyPrintLimitKb > 0 && response.body.length > prettyPrintLimitKb * 1024 && !formatAnyway;
+  const treeValue = useMemo(() => {
+    if (viewMode !== "tree") return undefined;
+    try {
+      return { value: JSON.parse(response.body) as unknown };
+    } catch {
+      return null;
+    }
+  }, [response.body, viewMode]);
 
-  const isJSON = response.contentType.includes("json") || (() => {
-    try { JSON.parse(response.body); return true; } catch { return false; }
-  })();
+  const isJSON =
+    response.contentType.includes("json") ||
+    (!overPrettyLimit && cachedPrettyJson(response) !== null);
 
-  const isHTML = response.contentType.includes("html");
-  const isXML = response.contentType.includes("xml") && !isHTML;
+  const bodyFormat = detectBodyFormat(response.contentType);
+  const isHTML = bodyFormat === "html";
   const isImage = response.contentType.includes("image");
 
+  // Search needs the whole body to find matches, so it renders everything
+  const chunked = (text: string, render: (visible: string) => React.ReactNode) =>
+    renderChunkKb > 0 && !searchQuery ? (
+      <LoadMoreBody
+        key={`${response.status}-${response.time}-${response.size}-${viewMode}`}
+        text={text}
+        chunkKb={renderChunkKb}
+        render={render}
+      />
+    ) : (
+      render(text)
+    );
+  const highlighted = (text: string) =>
+    chunked(text, (visible) => <HighlightedText text={visible} search={searchQuery} />);
+
+  if (viewMode === "tree" && treeValue) {
+    return (
+      <PickableJsonTree
+        key={`${response.status}-${response.time}-${response.size}`}
+        value={treeValue.value}
+      />
+    );
+  }
+
   if (viewMode === "raw") {
     if (isLarge && !showLarge) {
       return <LargeResponseWarning size={response.size} onShow={() => setShowLarge(true)} />;
@@ -276,7 +645,7 @@ function ResponseBody({ response, viewMode, searchQuery }: { response: ResponseD
     if (isLarge) {
       return <VirtualizedText text={response.body} />;
     }
-    return <HighlightedText text={response.body} search={searchQuery} />;
+    return highlighted(response.body);
   }
 
   if (isImage) {
@@ -315,26 +684,35 @@ function ResponseBody({ response, viewMode, searchQuery }: { response: ResponseD
     if (isLarge && !showLarge) {
       return <LargeResponseWarning size={response.size} onShow={() => setShowLarge(true)} />;
     }
+    if (overPrettyLimit) {
+      return (
+        <div className="flex flex-col h-full">
+          <PrettyPrintSkipped
+            size={response.body.length}
+            limitKb={prettyPrintLimitKb}
+            onFormat={() => setFormatAnyway(true)}
+          />
+          <div className="flex-1 min-h-0">
+            {isLarge && !searchQuery ? (
+              <VirtualizedText text={response.body} />
+            ) : (
+              highlighted(response.body)
+            )}
+          </div>
+        </div>
+      );
+    }
+    const pretty = cachedPrettyJson(response) ?? response.body;
     if (isLarge && !searchQuery) {
-      try {
-        const pretty = JSON.stringify(JSON.parse(response.body), null, 2);
-        return <VirtualizedText text={pretty} />;
-      } catch {
-        return <VirtualizedText text={response.body} />;
-      }
+      return <VirtualizedText text={pretty} />;
     }
     if (searchQuery) {
-      try {
-        const pretty = JSON.stringify(JSON.parse(response.body), null, 2);
-        return <HighlightedText text={pretty} search={searchQuery} />;
-      } catch {
-        return <HighlightedText text={response.body} search={searchQuery} />;
-      }
+      return <HighlightedText text={pretty} search={searchQuery} />;
     }
-    return <SyntaxHighlightedJSON json={response.body} />;
+    return chunked(pretty, (visible) => <SyntaxHighlightedJSON json={visible} />);
   }
 
-  if (isHTML) {
+  if (isHTML && viewMode === "preview") {
     return (
       <div className="flex flex-col h-full">
         <iframe
@@ -347,11 +725,43 @@ function ResponseBody({ response, viewMode, searchQuery }: { response: ResponseD
     );
   }
 
-  if (isXML) {
-    return <HighlightedText text={response.body} search={searchQuery} />;
+  if (bodyFormat === "raw") {
+    return highlighted(response.body);
+  }
+  if (isLarge && !showLarge) {
+    return <LargeResponseWarning size={response.size} onShow={() => setShowLarge(true)} />;
   }
+  if (overPrettyLimit) {
+    return (
+      <div className="flex flex-col h-full">
+        <PrettyPrintSkipped
+          size={response.body.length}
+          limitKb={prettyPrintLimitKb}
+          onFormat={() => setFormatAnyway(true)}
+        />
+        <div className="flex-1 min-h-0">
+          {isLarge && !searchQuery ? (
+            <VirtualizedText text={response.body} />
+          ) : (
+            highlighted(response.body)
+          )}
+        </div>
+      </div>
+    );
+  }
+  const formatted = cachedFormattedBody(response) ?? response.body;
+  if (isLarge && !searchQuery) {
+    return <VirtualizedText text={formatted} />;
+  }
+  return highlighted(formatted);
+}
 
-  return <HighlightedText text={response.body} search={searchQuery} />;
+function displayedBody(response: ResponseData, viewMode: BodyViewMode): string {
+  if (viewMode === "raw") return response.body;
+  if (detectBodyFormat(response.contentType) === "json") {
+    return cachedPrettyJson(response) ?? response.body;
+  }
+  return cachedFormattedBody(response) ?? cachedPrettyJson(response) ?? response.body;
 }
 
 function ResponseHeaders({ headers }: { headers: Record<string, string> }) {
@@ -365,15 +775,334 @@ function ResponseHeaders({ headers }: { headers: Record<string, string> }) {
   return (
     <div className="flex flex-col">
       {entries.map(([key, value]) => (
-        <div key={key} className="grid grid-cols-[200px_1fr] gap-3 border-b border-border/35 px-4 py-1.5 text-xs font-mono hover:bg-[hsl(var(--surface-2)/.55)]">
+        <div key={key} className="group grid grid-cols-[200px_1fr_auto] items-start gap-3 border-b border-border/35 px-4 py-1.5 text-xs font-mono hover:bg-[hsl(var(--surface-2)/.55)]">
           <span className="truncate text-[hsl(var(--chart-2))]">{key}</span>
           <span className="text-foreground break-all">{value}</span>
+          <CopyButton
+            text={`${key}: ${value}`}
+            title="Copy header"
+            className="opacity-0 group-hover:opacity-100"
+          />
+        </div>
+      ))}
+    </div>
+  );
+}
+
+/** Split a changed value around its common prefix and suffix so only the
+ *  characters that differ are highlighted. */
+function splitChange(before: string, after: string) {
+  let start = 0;
+  while (start < before.length && start < after.length && before[start] === after[start]) {
+    start += 1;
+  }
+  let end = 0;
+  while (
+    end < before.length - start &&
+    end < after.length - start &&
+    before[before.length - 1 - end] === after[after.length - 1 - end]
+  ) {
+    end += 1;
+  }
+  return {
+    prefix: before.slice(0, start),
+    removed: before.slice(start, before.length - end),
+    added: after.slice(start, after.length - end),
+    suffix: before.slice(before.length - end),
+  };
+}
+
+const DIFF_SECTION_LABELS: Record<ResponseDiffEntry["section"], string> = {
+  status: "Status",
+  header: "Headers",
+  body: "Body",
+};
+
+function DiffLine({ sign, children }: { sign: "-" | "+"; children: React.ReactNode }) {
+  return (
+    <div
+      className={`whitespace-pre-wrap break-all px-2 ${
+        sign === "-" ? "bg-red-500/5 text-red-500" : "bg-green-500/5 text-green-500"
+      }`}
+    >
+      {sign} {children}
+    </div>
+  );
+}
+
+function DiffEntryRow({ entry }: { entry: ResponseDiffEntry }) {
+  const change =
+    entry.kind === "changed" && entry.before !== null && entry.after !== null
+      ? splitChange(entry.before, entry.after)
+      : null;
+  return (
+    <div className="border-b border-border/35 px-4 py-1.5 text-xs font-mono">
+      <div className="mb-1 text-[hsl(var(--chart-2))]">{entry.path}</div>
+      {change ? (
+        <>
+          <DiffLine sign="-">
+            {change.prefix}
+            <span className="bg-red-500/25">{change.removed}</span>
+            {change.suffix}
+          </DiffLine>
+          <DiffLine sign="+">
+            {change.prefix}
+            <span className="bg-green-500/25">{change.added}</span>
+            {change.suffix}
+          </DiffLine>
+        </>
+      ) : (
+        <>
+          {entry.before !== null && <DiffLine sign="-">{entry.before}</DiffLine>}
+          {entry.after !== null && <DiffLine sign="+">{entry.after}</DiffLine>}
+        </>
+      )}
+    </div>
+  );
+}
+
+function ResponseBaselineDiff({
+  baseline,
+  diff,
+  onUnpin,
+}: {
+  baseline: ResponseBaseline;
+  diff: ResponseDiff | null;
+  onUnpin: () => void;
+}) {
+  const sections = (["status", "header", "body"] as const)
+    .map((section) => ({
+      section,
+      entries: diff?.entries.filter((entry) => entry.section === section) ?? [],
+    }))
+    .filter(({ entries }) => entries.length > 0);
+
+  return (
+    <div className="flex flex-col">
+      <div className="flex items-center gap-2 border-b border-border/35 px-4 py-2 text-[11px] text-muted-foreground">
+        <span>
+          Baseline pinned {new Date(baseline.savedAt).toLocaleString()} · status {baseline.response.status}
+          {diff?.jsonBody ? " · JSON compared by structure" : ""}
+        </span>
+        <div className="flex-1" />
+        <button
+          type="button"
+          onClick={onUnpin}
+          className="inline-flex items-center gap-1 hover:text-foreground transition-colors"
+        >
+          <PinOff className="h-3 w-3" />
+          Unpin
+        </button>
+      </div>
+      {!diff ? (
+        <p className="text-muted-foreground text-sm p-4">Comparing…</p>
+      ) : sections.length === 0 ? (
+        <p className="text-green-500 text-sm p-4">Matches the baseline</p>
+      ) : (
+        sections.map(({ section, entries }) => (
+          <div key={section}>
+            <div className="bg-[hsl(var(--surface-2))] px-4 py-1 text-[10px] font-medium uppercase tracking-wider text-muted-foreground">
+              {DIFF_SECTION_LABELS[section]} ({entries.length})
+            </div>
+            {entries.map((entry, index) => (
+              <DiffEntryRow key={`${entry.path}-${index}`} entry={entry} />
+            ))}
+          </div>
+        ))
+      )}
+      {diff?.truncated && (
+        <p className="px-4 py-2 text-[11px] text-amber-600">
+          Only the first {diff.entries.length} differences are shown.
+        </p>
+      )}
+    </div>
+  );
+}
+
+function LatencySparkline({ times }: { times: number[] }) {
+  const width = 120;
+  const height = 20;
+  const max = Math.max(...times);
+  const min = Math.min(...times);
+  const range = max - min || 1;
+  const step = times.length > 1 ? width / (times.length - 1) : 0;
+  const points = times
+    .map((time, i) => {
+      const y = height - 1 - ((time - min) / range) * (height - 2);
+      return `${(i * step).toFixed(1)},${y.toFixed(1)}`;
+    })
+    .join(" ");
+  return (
+    <svg width={width} height={height} className="shrink-0 text-primary" aria-hidden="true">
+      <polyline points={points} fill="none" stroke="currentColor" strokeWidth={1.5} />
+    </svg>
+  );
+}
+
+/**
+ * Banner for a rate-limited (429) or unavailable (503) response. Counts down
+ * the Retry-After wait and can send the request again when it is over.
+ */
+function RetryAfterBanner({ response }: { response: ResponseData }) {
+  const [deadline, setDeadline] = useState(() => Date.now() + (response.retryAfterMs ?? 0));
+  const [now, setNow] = useState(() => Date.now());
+  const [armed, setArmed] = useState(false);
+
+  useEffect(() => {
+    const received = Date.now();
+    setDeadline(received + (response.retryAfterMs ?? 0));
+    setNow(received);
+    setArmed(false);
+  }, [response]);
+
+  const remainingMs = Math.max(0, deadline - now);
+
+  useEffect(() => {
+    if (remainingMs === 0) return;
+    const timer = window.setTimeout(() => setNow(Date.now()), Math.min(remainingMs, 1000));
+    return () => window.clearTimeout(timer);
+  }, [remainingMs]);
+
+  useEffect(() => {
+    if (!armed || remainingMs > 0) return;
+    setArmed(false);
+    window.dispatchEvent(new Event(SEND_REQUEST_EVENT));
+  }, [armed, remainingMs]);
+
+  const seconds = Math.ceil(remainingMs / 1000);
+  const detail =
+    response.retryAfterMs == null
+      ? "The server did not say how long to wait."
+      : remainingMs > 0
+        ? `The server asks to wait ${seconds}s before retrying.`
+        : "The requested wait is over.";
+
+  return (
+    <div className="flex shrink-0 items-center gap-3 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+      <AlertTriangle className="h-3.5 w-3.5 shrink-0" />
+      <span className="flex-1">
+        <span className="font-semibold">
+          {response.status === 429 ? "Rate limited" : "Service unavailable"} ({response.status}).
+        </span>{" "}
+        {detail}
+      </span>
+      {armed ? (
+        <button
+          type="button"
+          onClick={() => setArmed(false)}
+          className="rounded border border-amber-500/40 px-2 py-0.5 font-medium hover:bg-amber-500/10"
+        >
+          Cancel retry ({seconds}s)
+        </button>
+      ) : (
+        <button
+          type="button"
+          onClick={() => setArmed(true)}
+          className="rounded border border-amber-500/40 px-2 py-0.5 font-medium hover:bg-amber-500/10"
+          title="Send the request again once the Retry-After wait is over"
+        >
+          {remainingMs > 0 ? `Retry in ${seconds}s` : "Retry now"}
+        </button>
+      )}
+    </div>
+  );
+}
+
+function LatencySummary({ samples }: { samples: LatencySamples }) {
+  const metrics = computeDurationMetrics(samples.times);
+  if (!metrics) return null;
+  return (
+    <div className="flex shrink-0 items-center gap-3 border-b border-border/70 px-4 py-1.5 text-[11px] text-muted-foreground">
+      <span>
+        {samples.times.length}/{samples.runs} runs
+      </span>
+      <span className="font-mono">
+        min {Math.min(...samples.times)}ms · avg {metrics.avg}ms · p95 {metrics.p95}ms · max{" "}
+        {Math.max(...samples.times)}ms
+      </span>
+      <div className="flex-1" />
+      <LatencySparkline times={samples.times} />
+    </div>
+  );
+}
+
+function ResponseWire({ response }: { response: ResponseData }) {
+  if (!response.rawRequest && !response.rawResponseHead) {
+    return (
+      <p className="text-muted-foreground text-sm p-4">
+        The wire view is only captured by the desktop app
+      </p>
+    );
+  }
+
+  const sections = [
+    { label: "Request", text: response.rawRequest ?? "", note: null },
+    {
+      label: "Response head",
+      text: response.rawResponseHead ?? "",
+      note: "Reconstructed: header names lowercased, repeated headers grouped",
+    },
+  ];
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      {sections.map(({ label, text, note }) => (
+        <div key={label} className="flex flex-col gap-1.5">
+          <div className="flex items-center justify-between">
+            <span className="text-[10px] font-medium uppercase tracking-wide text-muted-foreground">
+              {label}
+              {note && (
+                <span className="ml-2 normal-case tracking-normal" title={note}>
+                  (reconstructed)
+                </span>
+              )}
+            </span>
+            <CopyButton text={text} title={`Copy raw ${label.toLowerCase()}`} />
+          </div>
+          <pre className="whitespace-pre-wrap break-all rounded-md border border-border/50 bg-[hsl(var(--surface-2)/.55)] p-3 text-xs font-mono text-foreground">
+            {text.replace(/\r\n/g, "\n")}
+          </pre>
         </div>
       ))}
     </div>
   );
 }
 
+function ResponseRedirects({ response }: { response: ResponseData }) {
+  const chain = response.redirectChain ?? [];
+  return (
+    <ol className="flex flex-col gap-2 p-4">
+      {chain.map((hop, index) => (
+        <li
+          key={index}
+          className="flex flex-col gap-1 rounded-md border border-border/50 bg-[hsl(var(--surface-2)/.55)] px-3 py-2"
+        >
+          <div className="flex min-w-0 items-center gap-2">
+            <span className="w-5 shrink-0 text-right text-[10px] text-muted-foreground">
+              {index + 1}
+            </span>
+            <StatusBadge status={hop.status} />
+            <span className="shrink-0 font-mono text-[11px] font-semibold text-muted-foreground">
+              {hop.method}
+            </span>
+            <span className="truncate font-mono text-xs text-foreground" title={hop.url}>
+              {hop.url}
+            </span>
+          </div>
+          {hop.location && (
+            <div className="flex min-w-0 items-center gap-2 pl-7 text-[11px] text-muted-foreground">
+              <span className="shrink-0">Location →</span>
+              <span className="truncate font-mono" title={hop.location}>
+                {hop.location}
+              </span>
+            </div>
+          )}
+        </li>
+      ))}
+    </ol>
+  );
+}
+
 interface ParsedCookie {
   name: string;
   value: string;
@@ -450,28 +1179,6 @@ function ResponseCookies({ headers }: { headers: Record<string, string> }) {
   );
 }
 
-// ─── gRPC Status Codes ───────────────────────────────────────────────────────
-
-const grpcStatusNames: Record<number, string> = {
-  0: "OK",
-  1: "CANCELLED",
-  2: "UNKNOWN",
-  3: "INVALID_ARGUMENT",
-  4: "DEADLINE_EXCEEDED",
-  5: "NOT_FOUND",
-  6: "ALREADY_EXISTS",
-  7: "PERMISSION_DENIED",
-  8: "RESOURCE_EXHAUSTED",
-  9: "FAILED_PRECONDITION",
-  10: "ABORTED",
-  11: "OUT_OF_RANGE",
-  12: "UNIMPLEMENTED",
-  13: "INTERNAL",
-  14: "UNAVAILABLE",
-  15: "DATA_LOSS",
-  16: "UNAUTHENTICATED",
-};
-
 function GrpcStatusBadge({ code }: { code: number }) {
   const color = code === 0
     ? "text-[hsl(var(--method-get))] bg-[hsl(var(--method-get)/.12)]"
@@ -479,7 +1186,7 @@ function GrpcStatusBadge({ code }: { code: number }) {
 
   return (
     <span className={`text-xs font-mono font-bold px-2 py-0.5 rounded ${color}`}>
-      {grpcStatusNames[code] ?? `CODE_${code}`}
+      {grpcStatusName(code)}
     </span>
   );
 }
@@ -540,9 +1247,134 @@ function GrpcResponseView({ grpcResponse }: { grpcResponse: GrpcResponseData })
 }
 
 export function ResponseViewer() {
-  const { response, grpcResponse, isLoading, assertionResults } = useGetmanStore();
-  const [viewMode, setViewMode] = useState<"pretty" | "raw">("pretty");
+  const {
+    response,
+    grpcResponse,
+    isLoading,
+    requestQueued,
+    activeRequestId,
+    assertionResults,
+    latencySamples,
+    tabs,
+    activeTabId,
+    slowThresholdMs,
+  } = useGetmanStore();
+  const [viewMode, setViewMode] = useState<BodyViewMode>("pretty");
   const [searchQuery, setSearchQuery] = useState("");
+  const savedRequestId = tabs.find((tab) => tab.id === activeTabId)?.sourceRequestId;
+  const [baseline, setBaseline] = useState<ResponseBaseline | null>(null);
+  const [baselineDiff, setBaselineDiff] = useState<ResponseDiff | null>(null);
+  const [baselineNotice, setBaselineNotice] = useState<string | null>(null);
+  const [assertionNotice, setAssertionNotice] = useState<string | null>(null);
+  const [openNotice, setOpenNotice] = useState<string | null>(null);
+  const [jqFilter, setJqFilter] = useState<string | null>(null);
+  const [jqOutput, setJqOutput] = useState<string | null>(null);
+  const [jqError, setJqError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setAssertionNotice(null);
+    setOpenNotice(null);
+  }, [response, activeTabId]);
+
+  // The filter stays applied to later responses until it is reset.
+  useEffect(() => {
+    setJqOutput(null);
+    setJqError(null);
+    if (!response || !jqFilter) return;
+    let cancelled = false;
+    applyJq(response.body, jqFilter).then(
+      (outputs) => {
+        if (cancelled) return;
+        setJqOutput(outputs.map((output) => prettyJson(output)).join("\n"));
+      },
+      (err) => {
+        if (!cancelled) setJqError(err instanceof Error ? err.message : String(err));
+      },
+    );
+    return () => {
+      cancelled = true;
+    };
+  }, [response, jqFilter]);
+
+  useEffect(() => {
+    setBaseline(null);
+    setBaselineNotice(null);
+    if (!savedRequestId) return;
+    let cancelled = false;
+    loadResponseBaseline(savedRequestId).then((loaded) => {
+      if (!cancelled) setBaseline(loaded);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [savedRequestId]);
+
+  useEffect(() => {
+    setBaselineDiff(null);
+    if (!baseline || !response) return;
+    let cancelled = false;
+    const current = { status: response.status, headers: response.headers, body: response.body };
+    diffResponses(baseline.response, current).then((diff) => {
+      if (!cancelled) setBaselineDiff(diff);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [baseline, response]);
+
+  const pinBaseline = async () => {
+    if (!response || !savedRequestId) return;
+    const pinned: ResponseBaseline = {
+      requestId: savedRequestId,
+      savedAt: Date.now(),
+      response: { status: response.status, headers: response.headers, body: response.body },
+    };
+    if (await pinResponseBaseline(pinned)) {
+      setBaseline(pinned);
+      setBaselineNotice(null);
+    } else {
+      setBaselineNotice("Baselines are stored by the desktop app; this response was not pinned.");
+    }
+  };
+
+  const generateAssertions = () => {
+    if (!response) return;
+    const added = addAssertionsFromResponse(response);
+    setAssertionNotice(
+      added === 0
+        ? "Every generated assertion is already in the Tests tab."
+        : `Added ${added} assertion${added === 1 ? "" : "s"} to the Tests tab${
+            savedRequestId ? " and the saved request" : ""
+          }; remove any you don't need there.`
+    );
+  };
+
+  const openInBrowser = async () => {
+    if (!response) return;
+    setOpenNotice(null);
+    try {
+      await openHtmlInBrowser(response.body, response.url ?? "");
+    } catch (err) {
+      setOpenNotice(err instanceof Error ? err.message : String(err));
+    }
+  };
+
+  const saveEncodedBody = () => {
+    if (!response?.bodyEncoding) return;
+    const bytes = Uint8Array.from(atob(response.body), (char) => char.charCodeAt(0));
+    const url = URL.createObjectURL(new Blob([bytes], { type: "application/octet-stream" }));
+    const a = document.createElement("a");
+    a.href = url;
+    a.download = `response.${encodedBodyExtension(response.bodyEncoding)}`;
+    a.click();
+    URL.revokeObjectURL(url);
+  };
+
+  const unpinBaseline = () => {
+    if (!savedRequestId) return;
+    setBaseline(null);
+    void clearResponseBaseline(savedRequestId);
+  };
 
   const exportDiagnosticLog = () => {
     if (!response) return;
@@ -576,7 +1408,23 @@ export function ResponseViewer() {
           <div className="absolute inset-0 rounded-full border-2 border-border" />
           <div className="absolute inset-0 rounded-full border-2 border-primary border-t-transparent animate-spin" />
         </div>
-        <p className="text-sm text-muted-foreground">Sending request...</p>
+        {requestQueued ? (
+          <p className="text-sm text-muted-foreground">Queued — waiting for a free request slot...</p>
+        ) : (
+          <SendProgressStatus
+            requestId={activeRequestId}
+            url={tabs.find((tab) => tab.id === activeTabId)?.url ?? ""}
+          />
+        )}
+        {latencySamples && (
+          <>
+            <p className="text-xs font-mono text-muted-foreground">
+              Run {Math.min(latencySamples.times.length + 1, latencySamples.runs)}/
+              {latencySamples.runs} · last {latencySamples.times[latencySamples.times.length - 1]}ms
+            </p>
+            <LatencySparkline times={latencySamples.times} />
+          </>
+        )}
       </div>
     );
   }
@@ -608,15 +1456,124 @@ export function ResponseViewer() {
       {/* Status bar */}
       <div className="flex shrink-0 items-center gap-3 border-b border-border/70 px-4 py-2.5">
         <StatusBadge status={response.status} />
-        <span className="text-xs text-muted-foreground">{response.statusText}</span>
-        <div className="flex-1" />
-        <span className="text-[11px] text-muted-foreground font-mono">
-          {response.time}ms
-        </span>
-        <span className="text-[11px] text-muted-foreground font-mono">
-          {formatBytes(response.size)}
+        <span className="text-xs text-muted-foreground">
+          {response.error ? ERROR_KIND_LABELS[response.error.kind] : response.statusText}
         </span>
-        <CopyButton text={response.body} />
+        {response.httpVersion && (
+          <span className="rounded border border-border/70 px-1.5 py-0.5 text-[10px] font-mono text-muted-foreground">
+            {response.httpVersion}
+          </span>
+        )}
+        {response.notModified && (
+          <span
+            className="rounded border border-border/70 px-1.5 py-0.5 text-[10px] text-muted-foreground"
+            title="The server answered 304; the body is from the previous response"
+          >
+            Not modified · cached body
+          </span>
+        )}
+        {response.replayed && (
+          <span
+            className="rounded border border-primary/40 px-1.5 py-0.5 text-[10px] text-primary"
+            title="Answered from the responses recorded for offline replay; nothing was sent"
+          >
+            Replayed
+          </span>
+        )}
+        {response.encoding && response.encoding !== "utf-8" && (
+          <span
+            className="rounded border border-border/70 px-1.5 py-0.5 text-[10px] font-mono text-muted-foreground"
+            title="Text encoding the body was decoded with"
+          >
+            {response.encoding}
+          </span>
+        )}
+        {response.pages ? (
+          <span
+            className="rounded border border-border/70 px-1.5 py-0.5 text-[10px] text-muted-foreground"
+            title='JSON array pages merged by following Link rel="next"'
+          >
+            {response.pages} {response.pages === 1 ? "page" : "pages"}
+          </span>
+        ) : null}
+        <div className="flex-1" />
+        {isSlowResponse(response.time, slowThresholdMs) ? (
+          <span
+            className="flex items-center gap-1 rounded border border-amber-500/40 bg-amber-500/10 px-1.5 py-0.5 text-[11px] font-mono text-amber-600 dark:text-amber-400"
+            title={`Slower than the ${slowThresholdMs}ms warning threshold`}
+          >
+            <AlertTriangle className="h-3 w-3" />
+            {response.time}ms
+          </span>
+        ) : (
+          <span className="text-[11px] text-muted-foreground font-mono">
+            {response.time}ms
+          </span>
+        )}
+        {response.bytesOut ? (
+          <span className="text-[11px] text-muted-foreground font-mono" title="Request body sent">
+            ↑ {formatBytes(response.bytesOut)}
+          </span>
+        ) : null}
+        {response.headerSize !== undefined && response.bodySize !== undefined ? (
+          <span
+            className="text-[11px] text-muted-foreground font-mono"
+            title="Response header block / body bytes received"
+          >
+            H {formatBytes(response.headerSize)} · B {formatBytes(response.bodySize)}
+          </span>
+        ) : null}
+        {response.wireSize !== undefined &&
+        response.decodedSize !== undefined &&
+        response.wireSize !== response.decodedSize ? (
+          <span
+            className="text-[11px] text-muted-foreground font-mono"
+            title={`${formatBytes(response.wireSize)} on the wire, ${formatBytes(response.decodedSize)} decoded`}
+          >
+            {formatBytes(response.wireSize)} → {formatBytes(response.decodedSize)}
+            {response.decodedSize > 0 &&
+              ` (${Math.round((response.wireSize / response.decodedSize) * 100)}%)`}
+          </span>
+        ) : (
+          <span className="text-[11px] text-muted-foreground font-mono">
+            {formatBytes(response.size)}
+          </span>
+        )}
+        <CopyButton
+          text={() => displayedBody(response, viewMode)}
+          title={viewMode === "raw" ? "Copy raw body" : "Copy formatted body"}
+        />
+        <button
+          type="button"
+          onClick={pinBaseline}
+          disabled={!savedRequestId}
+          className="text-muted-foreground hover:text-foreground transition-colors p-1 disabled:opacity-40 disabled:hover:text-muted-foreground"
+          title={
+            savedRequestId
+              ? "Pin this response as the baseline for later sends"
+              : "Save the request to pin a baseline"
+          }
+        >
+          <Pin className="h-3.5 w-3.5" />
+        </button>
+        <button
+          type="button"
+          onClick={generateAssertions}
+          className="text-muted-foreground hover:text-foreground transition-colors p-1"
+          title="Add assertions for the status and each top-level JSON field"
+        >
+          <ListChecks className="h-3.5 w-3.5" />
+        </button>
+        {detectBodyFormat(response.contentType) === "html" && (
+          <button
+            type="button"
+            onClick={openInBrowser}
+            className="text-muted-foreground hover:text-foreground transition-colors p-1"
+            title="Open the HTML in the default browser"
+          >
+            <ExternalLink className="h-3.5 w-3.5" />
+          </button>
+        )}
         <button
           type="button"
           onClick={exportDiagnosticLog}
@@ -627,6 +1584,76 @@ export function ResponseViewer() {
         </button>
       </div>
 
+      {latencySamples && <LatencySummary samples={latencySamples} />}
+
+      {(response.status === 429 || response.retryAfterMs != null) && (
+        <RetryAfterBanner response={response} />
+      )}
+
+      {response.bodyWithheld && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          The server answered {response.status} before the body was sent (Expect: 100-continue);
+          nothing was uploaded.
+        </div>
+      )}
+
+      {response.truncated && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          Response truncated at {formatBytes(response.wireSize ?? response.size)}
+          {response.totalSize ? ` of ${formatBytes(response.totalSize)}` : ""}. Raise the limit in
+          request settings to load the full body.
+        </div>
+      )}
+
+      {response.paginationWarning && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          {response.paginationWarning}
+        </div>
+      )}
+
+      {response.redirectWarning && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          {response.redirectWarning}
+        </div>
+      )}
+
+      {response.bodyEncoding && (
+        <div className="shrink-0 flex items-center gap-2 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          <span className="flex-1">
+            Automatic decompression is off; the body is still {response.bodyEncoding}-encoded and
+            shown as base64.
+          </span>
+          <button type="button" onClick={saveEncodedBody} className="underline hover:no-underline">
+            Save raw bytes
+          </button>
+        </div>
+      )}
+
+      {response.unsupportedCharset && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          The response declares charset {response.unsupportedCharset}, which is not supported; the
+          body was decoded as UTF-8 and may show replacement characters.
+        </div>
+      )}
+
+      {baselineNotice && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          {baselineNotice}
+        </div>
+      )}
+
+      {openNotice && (
+        <div className="shrink-0 border-b border-amber-500/30 bg-amber-500/10 px-4 py-1.5 text-[11px] text-amber-600">
+          {openNotice}
+        </div>
+      )}
+
+      {assertionNotice && (
+        <div className="shrink-0 border-b border-border/70 bg-muted/40 px-4 py-1.5 text-[11px] text-muted-foreground">
+          {assertionNotice}
+        </div>
+      )}
+
       {/* Tabs */}
       <Tabs defaultValue="body" className="flex flex-col flex-1 min-h-0">
         <div className="flex items-center border-b border-border/70">
@@ -655,6 +1682,43 @@ export function ResponseViewer() {
                 ({parseCookies(response.headers).length})
               </span>
             </TabsTrigger>
+            <TabsTrigger
+              value="raw"
+              title="Request and response head as sent and received"
+              className="rounded-none border-b-2 border-transparent data-[state=active]:border-primary data-[state=active]:bg-transparent data-[state=active]:text-foreground text-muted-foreground text-xs px-4 py-2 font-medium"
+            >
+              Raw
+            </TabsTrigger>
+            {response.redirectChain && response.redirectChain.length > 0 && (
+              <TabsTrigger
+                value="redirects"
+                title="Each response of the traced redirect chain"
+                className="rounded-none border-b-2 border-transparent data-[state=active]:border-primary data-[state=active]:bg-transparent data-[state=active]:text-foreground text-muted-foreground text-xs px-4 py-2 font-medium"
+              >
+                Redirects
+                <span className="ml-1.5 text-[10px] text-muted-foreground">
+                  ({response.redirectChain.length - 1})
+                </span>
+              </TabsTrigger>
+            )}
+            {baseline && (
+              <TabsTrigger
+                value="diff"
+                title="Differences from the pinned baseline response"
+                className="rounded-none border-b-2 border-transparent data-[state=active]:border-primary data-[state=active]:bg-transparent data-[state=active]:text-foreground text-muted-foreground text-xs px-4 py-2 font-medium"
+              >
+                Diff
+                {baselineDiff && (
+                  <span
+                    className={`ml-1.5 text-[10px] ${
+                      baselineDiff.entries.length === 0 ? "text-green-500" : "text-amber-500"
+                    }`}
+                  >
+                    ({baselineDiff.entries.length})
+                  </span>
+                )}
+              </TabsTrigger>
+            )}
             {assertionResults.length > 0 && (
               <TabsTrigger
                 value="test-results"
@@ -674,6 +1738,7 @@ export function ResponseViewer() {
 
           {/* View mode toggle + search */}
           <div className="flex items-center gap-1.5 pr-3 shrink-0">
+            <ResponseJqFilter active={jqFilter !== null} onApply={setJqFilter} />
             <ResponseBodySearch body={response.body} onSearch={setSearchQuery} />
             <div className="flex items-center rounded-md border border-border/60 overflow-hidden">
               <button
@@ -698,12 +1763,65 @@ export function ResponseViewer() {
               >
                 Raw
               </button>
+              <button
+                type="button"
+                onClick={() => setViewMode("tree")}
+                title="Collapsible tree (JSON bodies only)"
+                className={`px-2 py-1 text-[10px] font-medium transition-colors ${
+                  viewMode === "tree"
+                    ? "bg-primary/10 text-primary"
+                    : "text-muted-foreground hover:text-foreground"
+                }`}
+              >
+                Tree
+              </button>
+              {detectBodyFormat(response.contentType) === "html" && (
+                <button
+                  type="button"
+                  onClick={() => setViewMode("preview")}
+                  title="Render the HTML in a sandboxed frame"
+                  className={`px-2 py-1 text-[10px] font-medium transition-colors ${
+                    viewMode === "preview"
+                      ? "bg-primary/10 text-primary"
+                      : "text-muted-foreground hover:text-foreground"
+                  }`}
+                >
+                  Preview
+                </button>
+              )}
             </div>
           </div>
         </div>
 
         <TabsContent value="body" className="m-0 min-h-0 flex-1 overflow-auto p-4">
-          <ResponseBody response={response} viewMode={viewMode} searchQuery={searchQuery} />
+          {jqError && (
+            <div className="mb-3 rounded border border-red-500/30 bg-red-500/10 px-3 py-1.5 text-[11px] font-mono text-red-500">
+              {jqError}
+            </div>
+          )}
+          {jqFilter && jqOutput !== null ? (
+            <>
+              <div className="mb-3 flex items-center gap-2 text-[11px] text-muted-foreground">
+                <span>
+                  Filtered with <code className="font-mono text-foreground">{jqFilter}</code>
+                </span>
+                <button
+                  type="button"
+                  onClick={() => setJqFilter(null)}
+                  className="text-primary hover:underline"
+                >
+                  Reset
+                </button>
+              </div>
+              {jqOutput ? (
+                <SyntaxHighlightedJSON json={jqOutput} />
+              ) : (
+                <p className="text-muted-foreground text-sm">The filter produced no output</p>
+              )}
+            </>
+          ) : (
+            <ResponseBody response={response} viewMode={viewMode} searchQuery={searchQuery} />
+          )}
         </TabsContent>
 
         <TabsContent value="headers" className="m-0 flex-1 overflow-auto min-h-0">
@@ -714,6 +1832,22 @@ export function ResponseViewer() {
           <ResponseCookies headers={response.headers} />
         </TabsContent>
 
+        <TabsContent value="raw" className="m-0 flex-1 overflow-auto min-h-0">
+          <ResponseWire response={response} />
+        </TabsContent>
+
+        {response.redirectChain && response.redirectChain.length > 0 && (
+          <TabsContent value="redirects" className="m-0 flex-1 overflow-auto min-h-0">
+            <ResponseRedirects response={response} />
+          </TabsContent>
+        )}
+
+        {baseline && (
+          <TabsContent value="diff" className="m-0 flex-1 overflow-auto min-h-0">
+            <ResponseBaselineDiff baseline={baseline} diff={baselineDiff} onUnpin={unpinBaseline} />
+          </TabsContent>
+        )}
+
         {assertionResults.length > 0 && (
           <TabsContent value="test-results" className="m-0 flex-1 overflow-auto min-h-0 p-4">
             <div className="flex flex-col gap-2">
@@ -3,9 +3,13 @@
 import { useCallback, useEffect, useState } from "react";
 import { Save } from "lucide-react";
 import {
+  type RequestNamingStrategy,
+  createDefaultTab,
+  defaultRequestName,
   useGetmanStore,
   useActiveTab,
   saveRequestToCollection,
+  setRequestNaming,
   uid,
 } from "@/lib/getman-store";
 import {
@@ -25,26 +29,42 @@ import {
 
 export const OPEN_SAVE_REQUEST_DIALOG_EVENT = "getman:open-save-request-dialog";
 
+export const NAMING_LABELS: Record<RequestNamingStrategy, string> = {
+  "full-url": "Method + full URL",
+  path: "Method + path",
+  "host-path": "Method + host and path",
+};
+
+const UNNAMED_TAB = createDefaultTab().name;
+
 interface SaveRequestDialogProps {
   showTrigger?: boolean;
 }
 
 export function SaveRequestDialog({ showTrigger = true }: SaveRequestDialogProps) {
-  const { collections } = useGetmanStore();
+  const { collections, requestNaming } = useGetmanStore();
   const tab = useActiveTab();
   const [open, setOpen] = useState(false);
   const [name, setName] = useState("");
   const [collectionId, setCollectionId] = useState(collections[0]?.id || "");
+  const hasCustomTabName = Boolean(tab?.name) && tab?.name !== UNNAMED_TAB;
 
   const openDialog = useCallback(() => {
     if (!collectionId && collections[0]?.id) {
       setCollectionId(collections[0].id);
     }
-    if (!name.trim()) {
-      setName(tab?.name || "");
+    if (!name.trim() && tab) {
+      setName(hasCustomTabName ? tab.name : defaultRequestName(tab, requestNaming));
     }
     setOpen(true);
-  }, [collectionId, collections, name, tab?.name]);
+  }, [collectionId, collections, hasCustomTabName, name, requestNaming, tab]);
+
+  const changeNaming = (strategy: RequestNamingStrategy) => {
+    if (tab && name.trim() === defaultRequestName(tab, requestNaming)) {
+      setName(defaultRequestName(tab, strategy));
+    }
+    setRequestNaming(strategy);
+  };
 
   useEffect(() => {
     const handler = () => openDialog();
@@ -68,7 +88,7 @@ export function SaveRequestDialog({ showTrigger = true }: SaveRequestDialogProps
   if (!tab) return null;
 
   return (
-    <Dialog open={open} onOpenChange={setOpen}>
+    <Dialog open={open} onOpenChange={(next) => (next ? openDialog() : setOpen(false))}>
       {showTrigger && (
         <DialogTrigger asChild>
           <button
@@ -101,6 +121,26 @@ export function SaveRequestDialog({ showTrigger = true }: SaveRequestDialogProps
               autoFocus
             />
           </div>
+          <div className="flex flex-col gap-1.5">
+            <label className="text-[11px] font-medium text-muted-foreground">
+              Default name
+            </label>
+            <Select
+              value={requestNaming}
+              onValueChange={(value) => changeNaming(value as RequestNamingStrategy)}
+            >
+              <SelectTrigger className="h-9 border-border bg-[hsl(var(--surface-1))] text-sm">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                {(Object.keys(NAMING_LABELS) as RequestNamingStrategy[]).map((strategy) => (
+                  <SelectItem key={strategy} value={strategy} className="text-sm">
+                    {NAMING_LABELS[strategy]}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
           <div className="flex flex-col gap-1.5">
             <label className="text-[11px] font-medium text-muted-foreground">
               Collection
@@ -0,0 +1,463 @@
+"use client";
+
+import { useEffect, useState } from "react";
+import { RotateCcw, Settings } from "lucide-react";
+import {
+  useGetmanStore,
+  addTab,
+  createEmptyKV,
+  resetSettings,
+  setHistoryLimit,
+  setPrettyPrintLimitKb,
+  setRenderChunkKb,
+  setSlowThresholdMs,
+  setDefaultTimeoutMs,
+  setRestoreDrafts,
+  setRequestLoggingEnabled,
+  setReplayMode,
+  setRequestNaming,
+  setTheme,
+  setUserAgent,
+  updateActiveTab,
+  updateConnectionPool,
+  updateDefaultHeaders,
+  updateGlobalProxy,
+  updateMaxConcurrentRequests,
+  HISTORY_LIMIT_OPTIONS,
+  type ReplayMode,
+  type RequestNamingStrategy,
+} from "@/lib/getman-store";
+import {
+  clearResponseCache,
+  getDefaultUserAgent,
+  getEchoServerPort,
+  isEchoServerAvailable,
+  startEchoServer,
+  stopEchoServer,
+} from "@/lib/tauri";
+import { KVEditor } from "./kv-editor";
+import { ProxyFields } from "./proxy-fields";
+import { NAMING_LABELS } from "./save-request-dialog";
+import {
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from "@/components/ui/dialog";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+
+const inputClass =
+  "rounded border border-border bg-[hsl(var(--surface-2))] px-3 py-2 font-mono text-xs text-foreground outline-none placeholder:text-muted-foreground/40 focus:border-primary/50";
+
+function Section({ title, children }: { title: string; children: React.ReactNode }) {
+  return (
+    <section className="flex flex-col gap-3">
+      <h3 className="text-[10px] font-semibold uppercase tracking-wider text-muted-foreground">
+        {title}
+      </h3>
+      {children}
+    </section>
+  );
+}
+
+function Field({ label, children }: { label: string; children: React.ReactNode }) {
+  return (
+    <div className="flex flex-col gap-1.5">
+      <label className="text-[11px] font-medium text-muted-foreground">{label}</label>
+      {children}
+    </div>
+  );
+}
+
+/** Start and stop the built-in echo server and open a request against it. */
+function EchoServerControls() {
+  const [port, setPort] = useState<number | null>(null);
+  const [requestedPort, setRequestedPort] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    void getEchoServerPort().then(setPort);
+  }, []);
+
+  const baseUrl = port ? `http://127.0.0.1:${port}` : null;
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div className="flex items-center gap-2">
+        <input
+          type="number"
+          className={`${inputClass} w-28`}
+          value={port ?? requestedPort}
+          onChange={(e) => setRequestedPort(e.target.value)}
+          disabled={port !== null}
+          min={0}
+          max={65535}
+          placeholder="Any port"
+          title="Port to listen on; leave blank for a free one"
+        />
+        <button
+          type="button"
+          onClick={() => {
+            setError(null);
+            if (port !== null) {
+              void stopEchoServer().then(() => setPort(null));
+              return;
+            }
+            startEchoServer(Number(requestedPort) || undefined)
+              .then(setPort)
+              .catch((err) => setError(String(err)));
+          }}
+          className="shrink-0 rounded border border-border px-2 py-1 text-xs text-muted-foreground transition-colors hover:text-foreground"
+        >
+          {port !== null ? "Stop echo server" : "Start echo server"}
+        </button>
+        {baseUrl && (
+          <button
+            type="button"
+            onClick={() => {
+              addTab();
+              updateActiveTab({ name: "Echo", url: `${baseUrl}/echo` });
+            }}
+            className="shrink-0 rounded border border-border px-2 py-1 text-xs text-muted-foreground transition-colors hover:text-foreground"
+          >
+            New request
+          </button>
+        )}
+      </div>
+      <span className={`text-[10px] ${error ? "text-destructive" : "text-muted-foreground"}`}>
+        {error ??
+          (baseUrl
+            ? `Listening on ${baseUrl}: /echo, /delay/{ms}, /status/{code} and /stream/{n}.`
+            : "A local server with echo, delay, status and streaming endpoints for trying requests offline.")}
+      </span>
+    </div>
+  );
+}
+
+/** App-wide preferences; per-request options stay in Request Settings. */
+export function SettingsDialog() {
+  const {
+    theme,
+    historyLimit,
+    requestLogging,
+    requestNaming,
+    connectionPool,
+    maxConcurrentRequests,
+    prettyPrintLimitKb,
+    renderChunkKb,
+    slowThresholdMs,
+    defaultTimeoutMs,
+    restoreDrafts,
+    defaultHeaders,
+    userAgent,
+    proxy,
+    replayMode,
+  } = useGetmanStore();
+  const [open, setOpen] = useState(false);
+  const [confirmReset, setConfirmReset] = useState(false);
+  const [builtInUserAgent, setBuiltInUserAgent] = useState<string | null>(null);
+  const [cacheStatus, setCacheStatus] = useState<string | null>(null);
+  const [echoServerAvailable, setEchoServerAvailable] = useState(false);
+
+  useEffect(() => {
+    void isEchoServerAvailable().then(setEchoServerAvailable);
+  }, []);
+
+  useEffect(() => {
+    if (!open) {
+      setConfirmReset(false);
+      setCacheStatus(null);
+      return;
+    }
+    getDefaultUserAgent()
+      .then(setBuiltInUserAgent)
+      .catch(() => setBuiltInUserAgent(null));
+  }, [open]);
+
+  return (
+    <Dialog open={open} onOpenChange={setOpen}>
+      <DialogTrigger asChild>
+        <button
+          type="button"
+          className="flex h-8 w-8 items-center justify-center rounded-md border border-border bg-[hsl(var(--surface-1))] text-muted-foreground transition-colors hover:text-foreground"
+          title="Settings"
+        >
+          <Settings className="h-4 w-4" />
+        </button>
+      </DialogTrigger>
+      <DialogContent className="bg-[hsl(var(--surface-1))] border-border sm:max-w-[480px] max-h-[85vh] flex flex-col">
+        <DialogHeader>
+          <DialogTitle className="text-foreground text-sm">Settings</DialogTitle>
+        </DialogHeader>
+
+        <div className="flex flex-col gap-6 overflow-y-auto pr-1">
+          <Section title="Appearance">
+            <Field label="Theme">
+              <Select value={theme} onValueChange={(v) => setTheme(v === "dark" ? "dark" : "light")}>
+                <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                  <SelectItem value="light" className="text-xs">Light</SelectItem>
+                  <SelectItem value="dark" className="text-xs">Dark</SelectItem>
+                </SelectContent>
+              </Select>
+            </Field>
+          </Section>
+
+          <Section title="History">
+            <Field label="Entries Kept">
+              <Select value={String(historyLimit)} onValueChange={(v) => setHistoryLimit(Number(v))}>
+                <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                  {HISTORY_LIMIT_OPTIONS.map((limit) => (
+                    <SelectItem key={limit} value={String(limit)} className="text-xs">
+                      {limit === 0 ? "All entries" : `Last ${limit} entries`}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </Field>
+            <label
+              className="flex items-center gap-2 text-xs text-foreground"
+              title="Append every request and response, with secrets redacted, to logs/requests.log in the app data folder"
+            >
+              <input
+                type="checkbox"
+                checked={requestLogging}
+                onChange={(e) => setRequestLoggingEnabled(e.target.checked)}
+                className="h-3.5 w-3.5 rounded border-border accent-primary"
+              />
+              Write debug log file
+            </label>
+            <label
+              className="flex items-center gap-2 text-xs text-foreground"
+              title="Reopen the tabs from the last session, including edits that were never saved to a collection"
+            >
+              <input
+                type="checkbox"
+                checked={restoreDrafts}
+                onChange={(e) => setRestoreDrafts(e.target.checked)}
+                className="h-3.5 w-3.5 rounded border-border accent-primary"
+              />
+              Restore unsaved tabs on launch
+            </label>
+          </Section>
+
+          <Section title="Requests">
+            <Field label="Name for Saved Requests">
+              <Select
+                value={requestNaming}
+                onValueChange={(v) => setRequestNaming(v as RequestNamingStrategy)}
+              >
+                <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                  {(Object.keys(NAMING_LABELS) as RequestNamingStrategy[]).map((strategy) => (
+                    <SelectItem key={strategy} value={strategy} className="text-xs">
+                      {NAMING_LABELS[strategy]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </Field>
+            <Field label="User-Agent">
+              <input
+                type="text"
+                className={inputClass}
+                value={userAgent}
+                onChange={(e) => setUserAgent(e.target.value)}
+                placeholder={builtInUserAgent ?? "App default"}
+                title="Used when a request has no User-Agent header of its own"
+              />
+            </Field>
+            <Field label="Default Timeout (ms)">
+              <input
+                type="number"
+                className={inputClass}
+                value={defaultTimeoutMs}
+                onChange={(e) => setDefaultTimeoutMs(Number(e.target.value))}
+                min={0}
+                step={1000}
+                title="Used when neither the request nor the active environment sets a timeout (0 waits indefinitely)"
+              />
+            </Field>
+            <Field label="Default Headers">
+              <div className="overflow-hidden rounded border border-border">
+                <KVEditor
+                  items={defaultHeaders.length > 0 ? defaultHeaders : [createEmptyKV()]}
+                  onChange={updateDefaultHeaders}
+                  keyPlaceholder="Header"
+                  valuePlaceholder="Value"
+                />
+              </div>
+              <span className="text-[10px] text-muted-foreground">
+                Added to every HTTP request that does not set them; a request can opt out in its
+                Request Settings.
+              </span>
+            </Field>
+          </Section>
+
+          <Section title="Connections">
+            <Field label="Max Concurrent Requests">
+              <input
+                type="number"
+                className={inputClass}
+                value={maxConcurrentRequests}
+                onChange={(e) => updateMaxConcurrentRequests(Number(e.target.value))}
+                min={1}
+                title="Further sends wait in a queue until a request finishes"
+              />
+            </Field>
+            <Field label="Connection Pool">
+              <div className="grid grid-cols-2 gap-3">
+                <input
+                  type="number"
+                  className={inputClass}
+                  value={connectionPool.maxIdlePerHost}
+                  onChange={(e) => updateConnectionPool({ maxIdlePerHost: Number(e.target.value) })}
+                  min={0}
+                  title="Idle connections kept per host (0 disables reuse)"
+                />
+                <input
+                  type="number"
+                  className={inputClass}
+                  value={connectionPool.idleTimeoutMs}
+                  onChange={(e) => updateConnectionPool({ idleTimeoutMs: Number(e.target.value) })}
+                  min={0}
+                  step={1000}
+                  title="Idle timeout in ms (0 keeps connections until the server closes them)"
+                />
+              </div>
+              <span className="text-[10px] text-muted-foreground">
+                Idle connections per host · idle timeout (ms)
+              </span>
+            </Field>
+          </Section>
+
+          <Section title="Proxy">
+            <ProxyFields
+              value={proxy}
+              onChange={updateGlobalProxy}
+              emptyHint="Leave empty to use HTTP_PROXY / HTTPS_PROXY from the environment"
+            />
+            <span className="text-[10px] text-muted-foreground">
+              Used by every request whose Request Settings keep &quot;Use proxy from Settings&quot;.
+            </span>
+          </Section>
+
+          <Section title="Offline Replay">
+            <Field label="Mode">
+              <Select value={replayMode} onValueChange={(v) => setReplayMode(v as ReplayMode)}>
+                <SelectTrigger className="h-8 border-border bg-[hsl(var(--surface-2))] text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent className="border-border bg-[hsl(var(--surface-1))]">
+                  <SelectItem value="off" className="text-xs">Off</SelectItem>
+                  <SelectItem value="record" className="text-xs">Record responses</SelectItem>
+                  <SelectItem value="replay" className="text-xs">Replay recorded responses</SelectItem>
+                </SelectContent>
+              </Select>
+            </Field>
+            <div className="flex items-center justify-between gap-3">
+              <span className="text-[10px] text-muted-foreground">
+                {cacheStatus ??
+                  "Responses are matched on method, URL, headers and body. Replay sends requests that have no recording."}
+              </span>
+              <button
+                type="button"
+                onClick={() => {
+                  clearResponseCache()
+                    .then((count) =>
+                      setCacheStatus(`Cleared ${count} recorded response${count === 1 ? "" : "s"}.`),
+                    )
+                    .catch((error) => setCacheStatus(String(error)));
+                }}
+                className="shrink-0 rounded border border-border px-2 py-1 text-xs text-muted-foreground transition-colors hover:text-foreground"
+              >
+                Clear recordings
+              </button>
+            </div>
+          </Section>
+
+          <Section title="Responses">
+            <div className="grid grid-cols-2 gap-3">
+              <Field label="Pretty-Print Limit (KB)">
+                <input
+                  type="number"
+                  className={inputClass}
+                  value={prettyPrintLimitKb}
+                  onChange={(e) => setPrettyPrintLimitKb(Number(e.target.value))}
+                  min={0}
+                  step={512}
+                  title="Larger JSON bodies are shown raw (0 always pretty-prints)"
+                />
+              </Field>
+              <Field label="Body Render Chunk (KB)">
+                <input
+                  type="number"
+                  className={inputClass}
+                  value={renderChunkKb}
+                  onChange={(e) => setRenderChunkKb(Number(e.target.value))}
+                  min={0}
+                  step={128}
+                  title="Longer bodies show this much at first, with Load more for the rest (0 renders everything)"
+                />
+              </Field>
+            </div>
+            <Field label="Slow Request Warning (ms)">
+              <input
+                type="number"
+                className={inputClass}
+                value={slowThresholdMs}
+                onChange={(e) => setSlowThresholdMs(Number(e.target.value))}
+                min={0}
+                step={500}
+                title="Slower responses get a warning-colored time in the response panel and history (0 turns it off)"
+              />
+            </Field>
+          </Section>
+
+          {echoServerAvailable && (
+            <Section title="Developer">
+              <EchoServerControls />
+            </Section>
+          )}
+        </div>
+
+        <div className="flex items-center justify-between border-t border-border pt-3">
+          <span className="text-[10px] text-muted-foreground">Changes are saved automatically.</span>
+          <button
+            type="button"
+            onClick={() => {
+              if (!confirmReset) {
+                setConfirmReset(true);
+                return;
+              }
+              resetSettings();
+              setConfirmReset(false);
+            }}
+            className={`flex items-center gap-1.5 rounded px-2 py-1 text-xs transition-colors ${
+              confirmReset
+                ? "bg-destructive/10 text-destructive"
+                : "text-muted-foreground hover:text-foreground"
+            }`}
+          >
+            <RotateCcw className="h-3 w-3" />
+            {confirmReset ? "Click again to reset" : "Reset to defaults"}
+          </button>
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,96 @@
+"use client";
+
+import { useEffect, useMemo, useState } from "react";
+import { Clock, FolderOpen } from "lucide-react";
+import { useGetmanStore, type RequestType } from "@/lib/getman-store";
+import {
+  buildUrlSuggestionIndex,
+  matchUrlSuggestions,
+  type UrlSuggestion,
+} from "@/lib/url-suggestions";
+import { MethodBadge } from "./method-badge";
+
+/** Wait this long after the last keystroke before matching, for large histories. */
+const MATCH_DEBOUNCE_MS = 120;
+
+/** URL suggestions for `query`, matched after typing pauses. */
+export function useUrlSuggestions(
+  query: string,
+  requestType: RequestType,
+  enabled: boolean
+): UrlSuggestion[] {
+  const { history, collections } = useGetmanStore();
+  const index = useMemo(
+    () => buildUrlSuggestionIndex(history, collections),
+    [history, collections]
+  );
+  const [suggestions, setSuggestions] = useState<UrlSuggestion[]>([]);
+
+  useEffect(() => {
+    if (!enabled) {
+      setSuggestions([]);
+      return;
+    }
+    const timer = setTimeout(
+      () => setSuggestions(matchUrlSuggestions(index, query, requestType)),
+      MATCH_DEBOUNCE_MS
+    );
+    return () => clearTimeout(timer);
+  }, [index, query, requestType, enabled]);
+
+  return enabled ? suggestions : [];
+}
+
+export function UrlSuggestionList({
+  suggestions,
+  activeIndex,
+  onPick,
+  onHover,
+}: {
+  suggestions: UrlSuggestion[];
+  activeIndex: number;
+  onPick: (suggestion: UrlSuggestion) => void;
+  onHover: (index: number) => void;
+}) {
+  return (
+    <div
+      role="listbox"
+      className="absolute left-0 right-0 top-full z-50 mt-1 overflow-hidden rounded-lg border border-border bg-[hsl(var(--surface-1))] py-1 shadow-lg"
+    >
+      {suggestions.map((suggestion, index) => (
+        <div
+          key={suggestion.key}
+          role="option"
+          aria-selected={index === activeIndex}
+          // Keep focus in the URL input so typing can continue
+          onMouseDown={(e) => {
+            e.preventDefault();
+            onPick(suggestion);
+          }}
+          onMouseEnter={() => onHover(index)}
+          className={`flex cursor-pointer items-center gap-2 px-3 py-1.5 ${
+            index === activeIndex ? "bg-primary/10" : "hover:bg-[hsl(var(--surface-2))]"
+          }`}
+        >
+          <MethodBadge method={suggestion.method} size="sm" requestType={suggestion.requestType} />
+          <span className="min-w-0 flex-1 truncate font-mono text-xs text-foreground">
+            {suggestion.url}
+          </span>
+          {suggestion.label && (
+            <span className="max-w-[40%] truncate text-[10px] text-muted-foreground">
+              {suggestion.label}
+            </span>
+          )}
+          {suggestion.source === "history" ? (
+            <Clock className="h-3 w-3 shrink-0 text-muted-foreground" aria-label="From history" />
+          ) : (
+            <FolderOpen
+              className="h-3 w-3 shrink-0 text-muted-foreground"
+              aria-label="From collections"
+            />
+          )}
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -7,6 +7,7 @@ export interface AdvancedAuthConfig {
   authUsername?: string;
   authPassword?: string;
   ntlmDomain?: string;
+  ntlmWorkstation?: string;
   awsAccessKeyId?: string;
   awsSecretAccessKey?: string;
   awsSessionToken?: string;
@@ -16,6 +17,36 @@ export interface AdvancedAuthConfig {
   wssePassword?: string;
 }
 
+/** Credential fields that may hold `{{variable}}` references. */
+const ADVANCED_AUTH_FIELDS: Exclude<keyof AdvancedAuthConfig, "authType">[] = [
+  "authUsername",
+  "authPassword",
+  "ntlmDomain",
+  "ntlmWorkstation",
+  "awsAccessKeyId",
+  "awsSecretAccessKey",
+  "awsSessionToken",
+  "awsRegion",
+  "awsService",
+  "wsseUsername",
+  "wssePassword",
+];
+
+/** Copy of `auth` with its credential fields run through `resolve`. */
+export function resolveAdvancedAuth(
+  auth: AdvancedAuthConfig,
+  resolve: (value: string) => string
+): AdvancedAuthConfig {
+  const resolved: AdvancedAuthConfig = { authType: auth.authType };
+  for (const field of ADVANCED_AUTH_FIELDS) {
+    const value = auth[field];
+    if (value !== undefined) {
+      resolved[field] = resolve(value);
+    }
+  }
+  return resolved;
+}
+
 const encoder = new TextEncoder();
 
 function toHex(bytes: Uint8Array): string {
@@ -258,13 +289,20 @@ async function applyNtlmAuth(
     ntlmUsername: username,
     ntlmPassword: password,
     ntlmDomain: (auth.ntlmDomain || "").trim(),
+    ntlmWorkstation: (auth.ntlmWorkstation || "").trim() || undefined,
   };
 }
 
+/**
+ * Apply digest, NTLM, AWS SigV4 or WSSE auth to `payload`. Credentials are
+ * resolved with `resolve` first, so they can reference environment variables.
+ */
 export async function applyAdvancedAuth(
   payload: SendRequestPayload,
-  auth: AdvancedAuthConfig
+  config: AdvancedAuthConfig,
+  resolve: (value: string) => string = (value) => value
 ): Promise<SendRequestPayload> {
+  const auth = resolveAdvancedAuth(config, resolve);
   if (auth.authType === "ntlm") {
     return applyNtlmAuth(payload, auth);
   }
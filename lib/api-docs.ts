@@ -50,6 +50,10 @@ function renderRequestDocs(path: string[], savedRequest: SavedRequest): string {
 
   lines.push(`## ${title}`);
   lines.push("");
+  if (tab.description?.trim()) {
+    lines.push(tab.description.trim());
+    lines.push("");
+  }
   lines.push(`- **Method:** \`${tab.method}\``);
   lines.push(`- **URL:** \`${tab.url}\``);
   lines.push("");
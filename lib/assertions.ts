@@ -3,15 +3,22 @@
 /**
  * Test Assertion Engine
  *
- * Evaluates assertions against HTTP response data (status code, headers, body JSONPath).
+ * Evaluates assertions against HTTP response data (status code, headers, body JSONPath,
+ * response time).
  */
 
 import type { TestAssertion, AssertionResult, ResponseData } from "./getman-store";
+import {
+  compileJsonSchema,
+  describeSchemaViolations,
+  type CompiledSchema,
+} from "./json-schema";
+import { evaluateXPath } from "./xml-format";
 
 // ─── JSONPath (simple implementation) ─────────────────────────────────────────
 
-function evaluateJsonPath(obj: unknown, path: string): unknown {
-  if (!path.startsWith("$.") && !path.startsWith("$[")) {
+export function evaluateJsonPath(obj: unknown, path: string): unknown {
+  if (path !== "$" && !path.startsWith("$.") && !path.startsWith("$[")) {
     path = "$." + path;
   }
 
@@ -22,9 +29,26 @@ function evaluateJsonPath(obj: unknown, path: string): unknown {
   let current = "";
   let inBracket = false;
 
-  for (let i = 2; i < path.length; i++) {
+  for (let i = path[1] === "[" ? 1 : 2; i < path.length; i++) {
     const ch = path[i];
-    if (ch === "[") {
+    const quote = path[i + 1];
+    if (ch === "[" && (quote === '"' || quote === "'")) {
+      // Quoted member name, as produced by jsonPathChild: ["a.b"] or ['a b']
+      let end = i + 2;
+      while (end < path.length && path[end] !== quote) end += path[end] === "\\" ? 2 : 1;
+      const literal = path.slice(i + 1, end + 1);
+      if (current) segments.push(current);
+      try {
+        segments.push(
+          quote === '"' ? (JSON.parse(literal) as string) : literal.slice(1, -1).replace(/\\(.)/g, "$1")
+        );
+      } catch {
+        segments.push(literal.slice(1, -1));
+      }
+      current = "";
+      i = path.indexOf("]", end);
+      if (i === -1) break;
+    } else if (ch === "[") {
       if (current) segments.push(current);
       current = "";
       inBracket = true;
@@ -58,6 +82,72 @@ function evaluateJsonPath(obj: unknown, path: string): unknown {
   return value;
 }
 
+/**
+ * JSONPath of the member `key` of the value at `parent`: `$.a.b` for plain
+ * names, `$["a-b"]` for names needing quotes and `$.items[0]` for indexes.
+ * `evaluateJsonPath` reads every form back.
+ */
+export function jsonPathChild(parent: string, key: string | number): string {
+  if (typeof key === "number") return `${parent}[${key}]`;
+  return /^[A-Za-z_$][\w$]*$/.test(key) ? `${parent}.${key}` : `${parent}[${JSON.stringify(key)}]`;
+}
+
+/**
+ * Assertion pinning the value at `path`: `eq` for scalars and `exists` for
+ * objects and arrays. Strings are compared without their quotes, the way the
+ * assertion runner reads them back.
+ */
+export function jsonPathAssertionDraft(path: string, value: unknown): Omit<TestAssertion, "id"> {
+  const scalar = value === null || typeof value !== "object";
+  return {
+    enabled: true,
+    type: "jsonpath",
+    property: path,
+    comparison: scalar ? "eq" : "exists",
+    expected: !scalar ? "" : typeof value === "string" ? JSON.stringify(value).slice(1, -1) : JSON.stringify(value),
+  };
+}
+
+/**
+ * Starter assertions for a known-good response: its status code, then one
+ * JSONPath assertion per top-level field of a JSON object body. An array
+ * body only gets `$` exists; anything else only the status.
+ */
+export function assertionsFromResponse(response: ResponseData): Omit<TestAssertion, "id">[] {
+  const assertions: Omit<TestAssertion, "id">[] = [
+    { enabled: true, type: "status", property: "", comparison: "eq", expected: String(response.status) },
+  ];
+  let body: unknown;
+  try {
+    body = JSON.parse(response.body);
+  } catch {
+    return assertions;
+  }
+  if (Array.isArray(body)) {
+    assertions.push(jsonPathAssertionDraft("$", body));
+  } else if (body !== null && typeof body === "object") {
+    for (const [key, value] of Object.entries(body)) {
+      assertions.push(jsonPathAssertionDraft(jsonPathChild("$", key), value));
+    }
+  }
+  return assertions;
+}
+
+// ─── JSON Schema ─────────────────────────────────────────────────────────────
+
+/** Compiled schemas per assertion, reused until the schema text changes. */
+const schemaCache = new Map<string, { text: string; schema: CompiledSchema }>();
+
+function getCompiledSchema(assertion: TestAssertion): CompiledSchema {
+  const cached = schemaCache.get(assertion.id);
+  if (cached && cached.text === assertion.expected) {
+    return cached.schema;
+  }
+  const schema = compileJsonSchema(assertion.expected);
+  schemaCache.set(assertion.id, { text: assertion.expected, schema });
+  return schema;
+}
+
 // ─── Comparison ──────────────────────────────────────────────────────────────
 
 function compareValues(
@@ -165,6 +255,29 @@ export function runAssertions(
             };
           }
 
+          case "xpath": {
+            const evaluation = evaluateXPath(response.body, assertion.property);
+            if (!evaluation.ok) {
+              return {
+                assertionId: assertion.id,
+                passed: false,
+                actual: "",
+                message: evaluation.message,
+              };
+            }
+
+            actual = evaluation.value;
+            const passed = compareValues(actual, assertion.expected, assertion.comparison);
+            return {
+              assertionId: assertion.id,
+              passed,
+              actual,
+              message: passed
+                ? `XPath "${assertion.property}" ${assertion.comparison} ${assertion.expected}`
+                : `Expected XPath "${assertion.property}" ${assertion.comparison} "${assertion.expected}", got "${actual}"`,
+            };
+          }
+
           case "body-contains": {
             actual = response.body;
             const passed = actual.includes(assertion.expected);
@@ -178,6 +291,57 @@ export function runAssertions(
             };
           }
 
+          case "response-time": {
+            actual = String(response.time);
+            const passed = compareValues(actual, assertion.expected, assertion.comparison);
+            return {
+              assertionId: assertion.id,
+              passed,
+              actual: `${actual}ms`,
+              message: passed
+                ? `Response time ${assertion.comparison} ${assertion.expected}ms`
+                : `Expected response time ${assertion.comparison} ${assertion.expected}ms, got ${actual}ms`,
+            };
+          }
+
+          case "json-schema": {
+            let schema: CompiledSchema;
+            try {
+              schema = getCompiledSchema(assertion);
+            } catch (error) {
+              const message = error instanceof Error ? error.message : "Invalid schema";
+              return {
+                assertionId: assertion.id,
+                passed: false,
+                actual: "",
+                message: `Invalid JSON Schema: ${message}`,
+              };
+            }
+
+            let parsed: unknown;
+            try {
+              parsed = JSON.parse(response.body);
+            } catch {
+              return {
+                assertionId: assertion.id,
+                passed: false,
+                actual: "",
+                message: "Response body is not valid JSON",
+              };
+            }
+
+            const violations = schema.validate(parsed);
+            const passed = violations.length === 0;
+            return {
+              assertionId: assertion.id,
+              passed,
+              actual: passed ? "valid" : `${violations.length} violation(s)`,
+              message: passed
+                ? "Body matches JSON Schema"
+                : `Body does not match JSON Schema:\n${describeSchemaViolations(violations)}`,
+            };
+          }
+
           default:
             return {
               assertionId: assertion.id,
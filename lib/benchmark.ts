@@ -1,6 +1,12 @@
 'use client';
 
 export type BenchmarkLoadMode = "fixed_iterations" | "fixed_duration";
+export type BenchmarkSaveBodies = "none" | "errors" | "all" | { sampled: { rate: number } };
+/**
+ * "abort" stops immediately and drops in-flight requests; "drain" stops new
+ * iterations and lets in-flight requests finish so no CANCELED errors are recorded.
+ */
+export type BenchmarkCancelMode = "abort" | "drain";
 export type BenchmarkRunStatus = "queued" | "running" | "completed" | "cancelled" | "failed";
 
 export interface BenchmarkRequestSnapshot {
@@ -27,7 +33,13 @@ export interface BenchmarkSpecPayload {
     keepAlive: boolean;
     followRedirects: boolean;
     proxyUrl?: string;
+    proxyUsername?: string;
+    proxyPassword?: string;
+    noProxy?: string[];
+    bypassProxy?: boolean;
     verifySsl: boolean;
+    maxRpsPerHost?: number;
+    userAgent?: string;
   };
   timing: {
     timeoutMs: number;
@@ -36,12 +48,44 @@ export interface BenchmarkSpecPayload {
   };
   logging: {
     sampleErrorsTopK: number;
-    saveBodies: "none" | "errors";
+    saveBodies: BenchmarkSaveBodies;
   };
   env: {
     variablesSnapshot: Record<string, string>;
     randomSeed?: number;
   };
+  sla?: BenchmarkSlaConfig;
+}
+
+/** Thresholds a run must meet to pass; unset fields are not checked. */
+export interface BenchmarkSlaConfig {
+  maxP50Ms?: number;
+  maxP90Ms?: number;
+  maxP95Ms?: number;
+  maxP99Ms?: number;
+  /** Percent of requests that may fail. */
+  maxErrorRate?: number;
+  minRps?: number;
+}
+
+export interface BenchmarkSlaViolation {
+  /** Name of the breached threshold, e.g. `maxP95Ms`. */
+  criterion: string;
+  threshold: number;
+  actual: number;
+}
+
+export interface BenchmarkSlaVerdict {
+  passed: boolean;
+  violations: BenchmarkSlaViolation[];
+}
+
+export interface BenchmarkSpecIssue {
+  /** Spec field the issue is about, e.g. `load.concurrency`. */
+  field: string;
+  /** Errors stop the run; warnings only inform. */
+  severity: "error" | "warning";
+  message: string;
 }
 
 export interface BenchmarkLatencyMetrics {
@@ -55,6 +99,16 @@ export interface BenchmarkLatencyMetrics {
   p99Ms: number;
 }
 
+export interface BenchmarkSizeMetrics {
+  minBytes: number;
+  avgBytes: number;
+  maxBytes: number;
+  p50Bytes: number;
+  p90Bytes: number;
+  p95Bytes: number;
+  p99Bytes: number;
+}
+
 export interface BenchmarkSummaryMetrics {
   totalRequests: number;
   successCount: number;
@@ -67,6 +121,14 @@ export interface BenchmarkSummaryMetrics {
   latency: BenchmarkLatencyMetrics;
   statusCodeCounts: Record<string, number>;
   errorTypeCounts: Record<string, number>;
+  rateLimitedRequests?: number;
+  rateLimitWaitMs?: number;
+  responseBodySize?: BenchmarkSizeMetrics;
+  responseHeaderSize?: BenchmarkSizeMetrics;
+  /** Connections opened while measuring; their setup is included in latency. */
+  newConnections?: number;
+  connectTimeAvgMs?: number;
+  connectTimeMaxMs?: number;
 }
 
 export interface BenchmarkTimeseriesPoint {
@@ -93,11 +155,19 @@ export interface BenchmarkErrorSample {
   sampleBody?: string;
 }
 
+export interface BenchmarkSuccessSample {
+  statusCode: number;
+  body: string;
+  count: number;
+}
+
 export interface BenchmarkAggregatedMetrics {
   summary: BenchmarkSummaryMetrics;
   timeseries: BenchmarkTimeseriesPoint[];
   histogram: BenchmarkHistogramBucket[];
   topErrors: BenchmarkErrorSample[];
+  successSamples?: BenchmarkSuccessSample[];
+  sla?: BenchmarkSlaVerdict;
 }
 
 export interface BenchmarkRunSummary {
@@ -109,6 +179,7 @@ export interface BenchmarkRunSummary {
   startedAt?: number;
   finishedAt?: number;
   specHash?: string;
+  sla?: BenchmarkSlaVerdict;
 }
 
 export interface BenchmarkRunDetail {
@@ -152,6 +223,12 @@ export async function startBenchmark(spec: BenchmarkSpecPayload): Promise<Benchm
   return invokeBenchmark<BenchmarkStartResponse>("start_benchmark", { spec });
 }
 
+/** Issues with a spec before running it; empty outside the desktop app. */
+export async function validateBenchmarkSpec(spec: BenchmarkSpecPayload): Promise<BenchmarkSpecIssue[]> {
+  if (!isTauriRuntime()) return [];
+  return invokeBenchmark<BenchmarkSpecIssue[]>("validate_benchmark_spec", { spec });
+}
+
 export async function listBenchmarkRuns(
   requestId?: string,
   limit = 50,
@@ -166,13 +243,18 @@ export async function getBenchmarkRun(runId: string): Promise<BenchmarkRunDetail
   return invokeBenchmark<BenchmarkRunDetail | null>("get_benchmark_run", { runId });
 }
 
-export async function cancelBenchmarkRun(runId: string): Promise<boolean> {
-  return invokeBenchmark<boolean>("cancel_benchmark_run", { runId });
+export async function cancelBenchmarkRun(
+  runId: string,
+  mode: BenchmarkCancelMode = "abort",
+): Promise<boolean> {
+  return invokeBenchmark<boolean>("cancel_benchmark_run", { runId, mode });
 }
 
+export type BenchmarkExportFormat = "json" | "csv" | "prometheus";
+
 export async function exportBenchmarkRun(
   runId: string,
-  format: "json" | "csv",
+  format: BenchmarkExportFormat,
 ): Promise<BenchmarkExportPayload> {
   return invokeBenchmark<BenchmarkExportPayload>("export_benchmark_run", { runId, format });
 }
@@ -0,0 +1,327 @@
+'use client';
+
+/**
+ * Content-aware body formatting
+ *
+ * Picks a formatter from the response Content-Type: JSON, XML, HTML, CSS and
+ * JavaScript are re-indented, anything else is shown raw. Formatters only
+ * touch whitespace between tokens, so strings, comments and `<pre>` blocks
+ * keep their text.
+ */
+
+import { prettyJson } from "./json-format";
+import { formatXml, isXmlContentType } from "./xml-format";
+
+export type BodyFormat = "json" | "xml" | "html" | "css" | "javascript" | "raw";
+
+const INDENT = "  ";
+
+export function detectBodyFormat(contentType: string): BodyFormat {
+  const mime = contentType.split(";")[0].trim().toLowerCase();
+  if (mime === "application/json" || mime.endsWith("+json") || mime.endsWith("/json")) {
+    return "json";
+  }
+  if (mime === "text/html" || mime === "application/xhtml+xml") return "html";
+  if (isXmlContentType(mime)) return "xml";
+  if (mime === "text/css") return "css";
+  if (
+    mime === "text/javascript" ||
+    mime === "application/javascript" ||
+    mime === "application/x-javascript" ||
+    mime === "application/ecmascript"
+  ) {
+    return "javascript";
+  }
+  return "raw";
+}
+
+/**
+ * Format `body` for its content type, or return `null` when the type has
+ * no formatter or the body does not parse, so callers show it raw.
+ */
+export function formatBody(body: string, contentType: string): string | null {
+  if (!body.trim()) return null;
+  switch (detectBodyFormat(contentType)) {
+    case "json":
+      try {
+        return prettyJson(body);
+      } catch {
+        return null;
+      }
+    case "xml":
+      return formatXml(body);
+    case "html":
+      return formatHtml(body);
+    case "css":
+      return formatCss(body);
+    case "javascript":
+      return formatJavaScript(body);
+    case "raw":
+      return null;
+  }
+}
+
+// ─── HTML ────────────────────────────────────────────────────────────────────
+
+const VOID_ELEMENTS = new Set([
+  "area", "base", "br", "col", "embed", "hr", "img", "input",
+  "link", "meta", "param", "source", "track", "wbr",
+]);
+
+/** Elements whose content is copied as-is. */
+const RAW_TEXT_ELEMENTS = new Set(["script", "style", "pre", "textarea"]);
+
+type HtmlToken =
+  | { kind: "open"; name: string; text: string; selfClosing: boolean }
+  | { kind: "close"; name: string; text: string }
+  | { kind: "other"; text: string }
+  | { kind: "text"; text: string };
+
+/** End of the tag starting at `start`, skipping `>` inside quoted attributes. */
+function tagEnd(html: string, start: number): number {
+  let quote: string | null = null;
+  for (let i = start + 1; i < html.length; i++) {
+    const ch = html[i];
+    if (quote) {
+      if (ch === quote) quote = null;
+    } else if (ch === '"' || ch === "'") {
+      quote = ch;
+    } else if (ch === ">") {
+      return i;
+    }
+  }
+  return html.length - 1;
+}
+
+function tokenizeHtml(html: string): HtmlToken[] {
+  const tokens: HtmlToken[] = [];
+  let i = 0;
+  while (i < html.length) {
+    if (html[i] !== "<" || !/[a-zA-Z/!?]/.test(html[i + 1] ?? "")) {
+      const next = html.indexOf("<", i + 1);
+      const end = next === -1 ? html.length : next;
+      tokens.push({ kind: "text", text: html.slice(i, end) });
+      i = end;
+      continue;
+    }
+    if (html.startsWith("<!--", i)) {
+      const close = html.indexOf("-->", i + 4);
+      const end = close === -1 ? html.length : close + 3;
+      tokens.push({ kind: "other", text: html.slice(i, end) });
+      i = end;
+      continue;
+    }
+    const end = tagEnd(html, i);
+    const text = html.slice(i, end + 1);
+    i = end + 1;
+    if (text[1] === "!" || text[1] === "?") {
+      tokens.push({ kind: "other", text });
+      continue;
+    }
+    const name = (/^<\/?\s*([^\s/>]+)/.exec(text)?.[1] ?? "").toLowerCase();
+    if (text[1] === "/") {
+      tokens.push({ kind: "close", name, text });
+      continue;
+    }
+    tokens.push({ kind: "open", name, text, selfClosing: /\/\s*>$/.test(text) });
+    if (RAW_TEXT_ELEMENTS.has(name) && !/\/\s*>$/.test(text)) {
+      const closeTag = new RegExp(`</${name}\\s*>`, "i");
+      const match = closeTag.exec(html.slice(i));
+      const contentEnd = match ? i + match.index : html.length;
+      if (contentEnd > i) tokens.push({ kind: "text", text: html.slice(i, contentEnd) });
+      i = contentEnd;
+    }
+  }
+  return tokens;
+}
+
+/**
+ * Indent HTML one element per line. Elements holding only text stay on one
+ * line, and the content of `<pre>`, `<script>`, `<style>` and `<textarea>`
+ * is kept verbatim. Returns `null` when the body has no tags.
+ */
+export function formatHtml(html: string): string | null {
+  const tokens = tokenizeHtml(html);
+  if (!tokens.some((token) => token.kind === "open")) return null;
+
+  const out: string[] = [];
+  let depth = 0;
+  let rawText = false;
+  const pad = () => INDENT.repeat(Math.max(depth, 0));
+
+  for (let i = 0; i < tokens.length; i++) {
+    const token = tokens[i];
+    if (token.kind === "text") {
+      if (rawText) {
+        out.push(token.text.replace(/^\n/, "").replace(/\s+$/, ""));
+        continue;
+      }
+      const text = token.text.replace(/\s+/g, " ").trim();
+      if (text) out.push(pad() + text);
+      continue;
+    }
+    if (token.kind === "other") {
+      out.push(pad() + token.text.trim());
+      continue;
+    }
+    if (token.kind === "close") {
+      rawText = false;
+      depth--;
+      out.push(pad() + token.text);
+      continue;
+    }
+
+    const isVoid = VOID_ELEMENTS.has(token.name) || token.selfClosing;
+    const text = tokens[i + 1];
+    const close = tokens[i + 2];
+    if (
+      !isVoid &&
+      !RAW_TEXT_ELEMENTS.has(token.name) &&
+      text?.kind === "text" &&
+      close?.kind === "close" &&
+      close.name === token.name &&
+      !text.text.includes("\n")
+    ) {
+      out.push(pad() + token.text + text.text.trim() + close.text);
+      i += 2;
+      continue;
+    }
+    out.push(pad() + token.text);
+    if (!isVoid) {
+      depth++;
+      rawText = RAW_TEXT_ELEMENTS.has(token.name);
+    }
+  }
+  return out.filter((line) => line.length > 0).join("\n");
+}
+
+// ─── CSS and JavaScript ──────────────────────────────────────────────────────
+
+/** Length of the string, comment or template literal starting at `i`, or 0. */
+function literalLength(source: string, i: number, regexAllowed: boolean): number {
+  const ch = source[i];
+  if (ch === "/" && source[i + 1] === "*") {
+    const end = source.indexOf("*/", i + 2);
+    return (end === -1 ? source.length : end + 2) - i;
+  }
+  if (ch === "/" && source[i + 1] === "/") {
+    const end = source.indexOf("\n", i);
+    return (end === -1 ? source.length : end) - i;
+  }
+  if (ch === '"' || ch === "'" || ch === "`" || (ch === "/" && regexAllowed)) {
+    let j = i + 1;
+    let inClass = false;
+    while (j < source.length) {
+      const c = source[j];
+      if (c === "\\") {
+        j += 2;
+        continue;
+      }
+      if (ch === "/" && c === "[") inClass = true;
+      else if (ch === "/" && c === "]") inClass = false;
+      else if (c === ch && !inClass) break;
+      else if (c === "\n" && ch !== "`") return j - i;
+      j++;
+    }
+    return Math.min(j + 1, source.length) - i;
+  }
+  return 0;
+}
+
+/**
+ * Re-indent brace-delimited source: a line break after `{` and `;` (outside
+ * parentheses) and around `}`. JavaScript also keeps the source's own line
+ * breaks, since statements may end without a semicolon, and one blank line
+ * where the source has several.
+ */
+function formatBraces(source: string, javascript: boolean): string {
+  const lines: string[] = [];
+  let line = "";
+  let depth = 0;
+  let parens = 0;
+  let lastSignificant = "";
+  let breaks = 0;
+
+  const flush = () => {
+    const text = line.trim();
+    if (text) lines.push(INDENT.repeat(Math.max(depth, 0)) + text);
+    line = "";
+  };
+
+  let i = 0;
+  while (i < source.length) {
+    const ch = source[i];
+    const isComment =
+      ch === "/" && (source[i + 1] === "*" || (javascript && source[i + 1] === "/"));
+    const regexAllowed =
+      javascript && (lastSignificant === "" || /[(,=:[!&|?{};+\-*%<>~^]/.test(lastSignificant));
+    const length =
+      isComment || ch === '"' || ch === "'" || (javascript && (ch === "`" || ch === "/"))
+        ? literalLength(source, i, regexAllowed)
+        : 0;
+    if (length > 0) {
+      line += source.slice(i, i + length);
+      if (!isComment) lastSignificant = source[i + length - 1];
+      breaks = 0;
+      i += length;
+      continue;
+    }
+
+    if (ch === "\n" && javascript) {
+      if (line.trim()) flush();
+      breaks++;
+      if (breaks === 2 && lines.length > 0) lines.push("");
+    } else if (/\s/.test(ch)) {
+      if (line && !line.endsWith(" ")) line += " ";
+    } else if (ch === "(" || ch === "[") {
+      parens++;
+      line += ch;
+    } else if (ch === ")" || ch === "]") {
+      parens = Math.max(parens - 1, 0);
+      line += ch;
+    } else if (ch === "{") {
+      line = line.trimEnd();
+      line += /[\w)\]]$/.test(line) ? " {" : "{";
+      flush();
+      depth++;
+    } else if (ch === "}") {
+      flush();
+      depth--;
+      line = "}";
+      // Keep `} else {`, `});`, `},` and `})` together.
+      const rest = source.slice(i + 1, i + 16).trimStart();
+      if (/^[a-z]/.test(rest)) line += " ";
+      if (!/^([;,)\].]|else\b|catch\b|finally\b|while\b)/.test(rest)) flush();
+    } else if (ch === ";" && parens === 0) {
+      line += ";";
+      flush();
+    } else if (ch === "," && line.trim() === "}") {
+      line += ",";
+      flush();
+    } else {
+      line += ch;
+    }
+    if (!/\s/.test(ch)) {
+      lastSignificant = ch;
+      breaks = 0;
+    }
+    i++;
+  }
+  flush();
+  while (lines[lines.length - 1] === "") lines.pop();
+  return lines.join("\n");
+}
+
+/** One declaration per line, with rules indented inside their blocks. */
+export function formatCss(css: string): string {
+  return formatBraces(css, false);
+}
+
+/**
+ * Break minified JavaScript into one statement per line and indent blocks.
+ * Strings, template literals, comments and regular expressions are copied
+ * unchanged.
+ */
+export function formatJavaScript(js: string): string {
+  return formatBraces(js, true);
+}
@@ -1,4 +1,10 @@
-import { type RequestTab, resolveEnvVariables } from "./getman-store";
+import {
+  type RequestTab,
+  applyEnvironmentBaseUrl,
+  applyPathParams,
+  isStandardHttpMethod,
+  resolveEnvVariables,
+} from "./getman-store";
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
@@ -8,7 +14,8 @@ export type CodeLanguage =
   | "python-requests"
   | "go-native"
   | "php-curl"
-  | "node-axios";
+  | "node-axios"
+  | "httpie";
 
 export interface CodeLanguageOption {
   id: CodeLanguage;
@@ -17,6 +24,7 @@ export interface CodeLanguageOption {
 
 export const CODE_LANGUAGES: CodeLanguageOption[] = [
   { id: "curl", label: "cURL" },
+  { id: "httpie", label: "HTTPie" },
   { id: "javascript-fetch", label: "JavaScript (fetch)" },
   { id: "node-axios", label: "Node.js (axios)" },
   { id: "python-requests", label: "Python (requests)" },
@@ -36,7 +44,11 @@ interface ResolvedRequest {
 }
 
 function resolveRequest(tab: RequestTab): ResolvedRequest {
-  const resolvedUrl = resolveEnvVariables(tab.url);
+  const resolvedUrl = applyPathParams(
+    applyEnvironmentBaseUrl(resolveEnvVariables(tab.url)),
+    tab.pathParams,
+    (value) => resolveEnvVariables(value)
+  );
   let url: string;
   try {
     const urlObj = new URL(resolvedUrl);
@@ -153,34 +165,47 @@ function generateCurl(req: ResolvedRequest): string {
   return parts.join(" \\\n  ");
 }
 
+function jsString(s: string): string {
+  const escaped = s
+    .replace(/\\/g, "\\\\")
+    .replace(/'/g, "\\'")
+    .replace(/\n/g, "\\n")
+    .replace(/\r/g, "\\r")
+    .replace(/\t/g, "\\t")
+    .replace(/\u2028/g, "\\u2028")
+    .replace(/\u2029/g, "\\u2029");
+  return `'${escaped}'`;
+}
+
 function generateJavaScriptFetch(req: ResolvedRequest): string {
   const lines: string[] = [];
   const hasHeaders = Object.keys(req.headers).length > 0;
-  const hasBody = req.body || req.formFields.length > 0;
 
-  lines.push(`const response = await fetch('${req.url}', {`);
-  lines.push(`  method: '${req.method}',`);
+  if (req.formFields.length > 0) {
+    lines.push(`const formData = new FormData();`);
+    for (const f of req.formFields) {
+      lines.push(`formData.append(${jsString(f.key)}, ${jsString(f.value)});`);
+    }
+    lines.push(``);
+  }
+
+  lines.push(`const response = await fetch(${jsString(req.url)}, {`);
+  lines.push(`  method: ${jsString(req.method)},`);
 
   if (hasHeaders) {
     lines.push(`  headers: {`);
     const entries = Object.entries(req.headers);
     entries.forEach(([key, value], i) => {
       const comma = i < entries.length - 1 ? "," : "";
-      lines.push(`    '${key}': '${value}'${comma}`);
+      lines.push(`    ${jsString(key)}: ${jsString(value)}${comma}`);
     });
     lines.push(`  },`);
   }
 
   if (req.formFields.length > 0) {
-    lines.push(`  body: new URLSearchParams({`);
-    req.formFields.forEach((f, i) => {
-      const comma = i < req.formFields.length - 1 ? "," : "";
-      lines.push(`    '${f.key}': '${f.value}'${comma}`);
-    });
-    lines.push(`  })`);
+    lines.push(`  body: formData`);
   } else if (req.body) {
-    const escaped = req.body.replace(/\\/g, "\\\\").replace(/'/g, "\\'").replace(/\n/g, "\\n").replace(/\r/g, "\\r").replace(/\t/g, "\\t");
-    lines.push(`  body: '${escaped}'`);
+    lines.push(`  body: ${jsString(req.body)}`);
   }
 
   lines.push(`});`);
@@ -191,6 +216,36 @@ function generateJavaScriptFetch(req: ResolvedRequest): string {
   return lines.join("\n");
 }
 
+function generateHttpie(req: ResolvedRequest): string {
+  const command = /^https:\/\//i.test(req.url) ? "https" : "http";
+  const parts: string[] = [];
+
+  if (req.formFields.length > 0) {
+    parts.push(`${command} --multipart ${req.method}`);
+  } else {
+    parts.push(`${command} ${req.method}`);
+  }
+  parts.push(`'${escapeShellSingle(req.url)}'`);
+
+  // "Name:Value" request items; HTTPie sends an empty header for "Name;".
+  for (const [key, value] of Object.entries(req.headers)) {
+    const item = value ? `${key}:${value}` : `${key};`;
+    parts.push(`'${escapeShellSingle(item)}'`);
+  }
+
+  if (req.formFields.length > 0) {
+    for (const f of req.formFields) {
+      // Escape separators so keys containing "=" or ":" stay a single field.
+      const key = f.key.replace(/([=:@\\])/g, "\\$1");
+      parts.push(`'${escapeShellSingle(`${key}=${f.value}`)}'`);
+    }
+  } else if (req.body) {
+    parts.push(`--raw='${escapeShellSingle(req.body)}'`);
+  }
+
+  return parts.join(" \\\n  ");
+}
+
 function generateNodeAxios(req: ResolvedRequest): string {
   const lines: string[] = [];
   const hasHeaders = Object.keys(req.headers).length > 0;
@@ -237,8 +292,6 @@ function generateNodeAxios(req: ResolvedRequest): string {
 function generatePythonRequests(req: ResolvedRequest): string {
   const lines: string[] = [];
   const hasHeaders = Object.keys(req.headers).length > 0;
-  const method = req.method.toLowerCase();
-
   lines.push(`import requests`);
   lines.push(``);
   lines.push(`url = '${req.url}'`);
@@ -265,7 +318,9 @@ function generatePythonRequests(req: ResolvedRequest): string {
     lines.push(`data = '${escaped}'`);
   }
 
-  const args: string[] = ["url"];
+  // requests only has helpers for the standard methods.
+  const standard = isStandardHttpMethod(req.method);
+  const args: string[] = standard ? ["url"] : [`'${req.method}'`, "url"];
   if (hasHeaders) args.push("headers=headers");
   if (req.formFields.length > 0) {
     args.push("data=data");
@@ -274,7 +329,8 @@ function generatePythonRequests(req: ResolvedRequest): string {
   }
 
   lines.push(``);
-  lines.push(`response = requests.${method}(${args.join(", ")})`);
+  const call = standard ? req.method.toLowerCase() : "request";
+  lines.push(`response = requests.${call}(${args.join(", ")})`);
   lines.push(`print(response.text)`);
 
   return lines.join("\n");
@@ -392,6 +448,8 @@ export function generateCode(tab: RequestTab, language: CodeLanguage): string {
   switch (language) {
     case "curl":
       return generateCurl(req);
+    case "httpie":
+      return generateHttpie(req);
     case "javascript-fetch":
       return generateJavaScriptFetch(req);
     case "node-axios":
@@ -4,6 +4,8 @@ import {
   type RequestTab,
   createDefaultTab,
   createEmptyKV,
+  isValidHttpMethod,
+  syncPathParams,
   uid,
 } from "./getman-store";
 
@@ -110,8 +112,6 @@ interface ParsedCurl {
   compressed: boolean;               // --compressed
 }
 
-const VALID_METHODS = new Set<string>(["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"]);
-
 function parseCurlTokens(tokens: string[]): ParsedCurl {
   let method: string | undefined;
   let url: string | undefined;
@@ -230,8 +230,8 @@ function parseCurlTokens(tokens: string[]): ParsedCurl {
 
   // Default method inference
   let resolvedMethod: HttpMethod = "GET";
-  if (method && VALID_METHODS.has(method)) {
-    resolvedMethod = method as HttpMethod;
+  if (method && isValidHttpMethod(method)) {
+    resolvedMethod = method;
   } else if (!method && (body !== undefined || formFields.length > 0)) {
     resolvedMethod = "POST";
   }
@@ -295,6 +295,7 @@ export function parseCurlCommand(curlString: string): RequestTab {
   const { baseUrl, params } = parseQueryParams(parsed.url);
   tab.url = baseUrl;
   tab.params = params.length > 0 ? [...params, createEmptyKV()] : [createEmptyKV()];
+  tab.pathParams = syncPathParams(baseUrl);
 
   // Method
   tab.method = parsed.method;
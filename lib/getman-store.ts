@@ -1,11 +1,37 @@
 'use client';
 
 import { useSyncExternalStore } from "react";
-import { loadPersistedState, savePersistedState } from "./tauri";
+import {
+  clearHistoryEntries,
+  deleteHistoryEntry,
+  deleteMatchingHistory,
+  joinBaseUrl,
+  loadPersistedState,
+  loadSettings,
+  queryHistory,
+  recordHistoryEntry,
+  savePersistedState,
+  saveSettings,
+  setConnectionPoolSettings,
+  setMaxConcurrentRequests,
+  setRequestLogging,
+  DEFAULT_MAX_CONCURRENT_REQUESTS,
+  type AppSettings,
+  type ConnectionPoolSettings,
+  type GetmanError,
+  type HistoryQuery,
+  type HistoryStatusClass,
+  type HttpVersionPreference,
+  type ProxySettings,
+  type RedirectHop,
+  type RequestCompression,
+  type TlsVersion,
+} from "./tauri";
+import { assertionsFromResponse, jsonPathAssertionDraft } from "./assertions";
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
-export type HttpMethod =
+export type StandardHttpMethod =
   | "GET"
   | "POST"
   | "PUT"
@@ -14,6 +40,19 @@ export type HttpMethod =
   | "HEAD"
   | "OPTIONS";
 
+/** A standard method or a custom verb such as `PROPFIND`. */
+export type HttpMethod = StandardHttpMethod | (string & {});
+
+export const STANDARD_HTTP_METHODS: StandardHttpMethod[] = [
+  "GET",
+  "POST",
+  "PUT",
+  "PATCH",
+  "DELETE",
+  "HEAD",
+  "OPTIONS",
+];
+
 export type RequestType = "http" | "grpc" | "graphql" | "websocket";
 
 export interface KeyValue {
@@ -25,7 +64,14 @@ export interface KeyValue {
 
 // ─── Test Assertions ──────────────────────────────────────────────────────────
 
-export type AssertionType = "status" | "header" | "jsonpath" | "body-contains";
+export type AssertionType =
+  | "status"
+  | "header"
+  | "jsonpath"
+  | "xpath"
+  | "body-contains"
+  | "response-time"
+  | "json-schema";
 
 export interface TestAssertion {
   id: string;
@@ -45,12 +91,51 @@ export interface AssertionResult {
 
 // ─── Request Settings ─────────────────────────────────────────────────────────
 
+/** Use the proxy from Settings, this request's own proxy, or no proxy at all. */
+export type ProxyMode = "global" | "custom" | "none";
+
 export interface RequestSettings {
   timeoutMs: number;
   retryCount: number;
   retryDelayMs: number;
+  httpVersion: HttpVersionPreference;
+  /** Unset on requests saved before the global proxy; see `requestProxyMode`. */
+  proxyMode?: ProxyMode;
   proxyUrl: string;
+  proxyUsername: string;
+  proxyPassword: string;
+  /** Comma-separated hosts/domains that bypass the proxy. */
+  noProxy: string;
+  /** One `host=ip[:port]` pin per line, applied instead of DNS. */
+  resolveOverrides: string;
+  /** Cap on downloaded response body size in MB; 0 reads everything. */
+  maxResponseMb: number;
+  /** Leave compressed response bodies as received: no automatic Accept-Encoding, no decoding. */
+  disableDecompression?: boolean;
+  /** Content coding applied to the request body before sending. */
+  requestCompression: RequestCompression;
+  /** Send `Expect: 100-continue` and hold the body back so the server can reject it first. */
+  expectContinue?: boolean;
+  /** Send If-None-Match/If-Modified-Since from the previous response to the same URL. */
+  conditionalRequests: boolean;
+  /** Set a sniffed Content-Type on raw bodies sent without one. */
+  autoContentType: boolean;
+  /** Open a new connection for this request instead of reusing a pooled one. */
+  freshConnection: boolean;
+  /** Follow Link rel="next" headers and combine JSON array pages into one response. */
+  followPagination: boolean;
+  /** Most pages fetched when following pagination. */
+  maxPages: number;
+  /** Follow redirects hop by hop and show every hop with the response. */
+  traceRedirects?: boolean;
+  /** Most redirects followed when tracing them. */
+  maxRedirects?: number;
+  /** Send the app-wide default headers; the request's own headers still win. */
+  useDefaultHeaders: boolean;
   verifySsl: boolean;
+  /** TLS version bounds; unset leaves negotiation to the defaults. */
+  minTlsVersion?: TlsVersion;
+  maxTlsVersion?: TlsVersion;
 }
 
 export interface RequestExample {
@@ -109,13 +194,27 @@ export interface ProtoServiceInfo {
 export interface RequestTab {
   id: string;
   name: string;
+  /** Free-text notes about the endpoint, shown in the Docs tab. */
+  description: string;
   requestType: RequestType;
   method: HttpMethod;
   url: string;
   params: KeyValue[];
+  /** Values for `:name` and `{name}` placeholders in the URL path. */
+  pathParams: KeyValue[];
   headers: KeyValue[];
-  bodyType: "none" | "json" | "form-data" | "x-www-form-urlencoded" | "raw" | "graphql" | "binary";
+  bodyType:
+    | "none"
+    | "json"
+    | "form-data"
+    | "x-www-form-urlencoded"
+    | "raw"
+    | "graphql"
+    | "binary"
+    | "file";
   bodyContent: string;
+  /** Local file streamed as the body when `bodyType` is "file" (desktop only). */
+  bodyFilePath: string;
   bodyFormData: KeyValue[];
   graphqlQuery: string;
   graphqlVariables: string;
@@ -147,6 +246,7 @@ export interface RequestTab {
   oauth2AccessToken: string;
   // NTLM fields
   ntlmDomain: string;
+  ntlmWorkstation: string;
   // AWS SigV4 fields
   awsAccessKeyId: string;
   awsSecretAccessKey: string;
@@ -195,6 +295,37 @@ export interface ResponseData {
   time: number;
   size: number;
   contentType: string;
+  httpVersion?: string;
+  truncated?: boolean;
+  totalSize?: number;
+  wireSize?: number;
+  decodedSize?: number;
+  headerSize?: number;
+  bodySize?: number;
+  bytesOut?: number;
+  bodyWithheld?: boolean;
+  notModified?: boolean;
+  replayed?: boolean;
+  url?: string;
+  rawRequest?: string;
+  rawResponseHead?: string;
+  pages?: number;
+  paginationWarning?: string | null;
+  redirectChain?: RedirectHop[];
+  redirectWarning?: string | null;
+  retryAfterMs?: number | null;
+  encoding?: string;
+  bodyEncoding?: string | null;
+  unsupportedCharset?: string | null;
+  error?: GetmanError | null;
+}
+
+/** Latencies from sending the same request repeatedly. */
+export interface LatencySamples {
+  /** Response times in ms, in send order. */
+  times: number[];
+  /** Sends requested; `times` is shorter while running or after a stop. */
+  runs: number;
 }
 
 export interface GrpcResponseData {
@@ -206,6 +337,52 @@ export interface GrpcResponseData {
   size: number;
 }
 
+const GRPC_STATUS_NAMES = [
+  "OK",
+  "CANCELLED",
+  "UNKNOWN",
+  "INVALID_ARGUMENT",
+  "DEADLINE_EXCEEDED",
+  "NOT_FOUND",
+  "ALREADY_EXISTS",
+  "PERMISSION_DENIED",
+  "RESOURCE_EXHAUSTED",
+  "FAILED_PRECONDITION",
+  "ABORTED",
+  "OUT_OF_RANGE",
+  "UNIMPLEMENTED",
+  "INTERNAL",
+  "UNAVAILABLE",
+  "DATA_LOSS",
+  "UNAUTHENTICATED",
+];
+
+export function grpcStatusName(code: number): string {
+  return GRPC_STATUS_NAMES[code] ?? `CODE_${code}`;
+}
+
+/** HTTP status a gRPC code maps to, as gRPC gateways translate them. */
+const GRPC_HTTP_STATUS: Record<number, number> = {
+  0: 200,
+  1: 499,
+  3: 400,
+  4: 504,
+  5: 404,
+  6: 409,
+  7: 403,
+  8: 429,
+  9: 400,
+  10: 409,
+  11: 400,
+  12: 501,
+  14: 503,
+  16: 401,
+};
+
+export function grpcHttpStatus(code: number): number {
+  return GRPC_HTTP_STATUS[code] ?? 500;
+}
+
 export interface HistoryItem {
   id: string;
   method: HttpMethod;
@@ -214,6 +391,23 @@ export interface HistoryItem {
   time: number;
   timestamp: number;
   requestType?: RequestType;
+  /** Number of consecutive identical sends folded into this entry. */
+  count?: number;
+  lastTimestamp?: number;
+  /** Full request as sent, so the entry can be reopened exactly. */
+  request?: HistoryRequestSnapshot;
+  /** Cancelled before a response arrived; `status` is 0. */
+  cancelled?: boolean;
+  /** gRPC status code of a gRPC call; `status` is its HTTP equivalent. */
+  grpcStatus?: number;
+}
+
+export type HistoryRequestSnapshot = Omit<RequestTab, "id">;
+
+export function snapshotRequestTab(tab: RequestTab): HistoryRequestSnapshot {
+  const snapshot: Partial<RequestTab> = JSON.parse(JSON.stringify(tab));
+  delete snapshot.id;
+  return snapshot as HistoryRequestSnapshot;
 }
 
 export interface Collection {
@@ -235,6 +429,8 @@ export interface SavedRequest {
   method: HttpMethod;
   url: string;
   tab: RequestTab;
+  /** Freeform labels for filtering across folders. */
+  tags?: string[];
 }
 
 export interface EnvVariable {
@@ -248,6 +444,10 @@ export interface Environment {
   id: string;
   name: string;
   variables: EnvVariable[];
+  /** Prepended to relative request URLs while this environment is active. */
+  baseUrl?: string;
+  /** Timeout for requests that set none while this environment is active; 0 or unset defers to Settings. */
+  timeoutMs?: number;
 }
 
 export interface VariableScopeContext {
@@ -394,22 +594,78 @@ export interface MockServer {
   running: boolean;
 }
 
+// ─── Workflows ────────────────────────────────────────────────────────────────
+export interface WorkflowStep {
+  id: string;
+  /** Alias later steps use in references, e.g. `{{login.body.token}}`. */
+  name: string;
+  collectionId: string;
+  requestId: string;
+}
+
+export interface Workflow {
+  id: string;
+  name: string;
+  steps: WorkflowStep[];
+}
+
 export interface GetmanState {
   tabs: RequestTab[];
   activeTabId: string;
   response: ResponseData | null;
   grpcResponse: GrpcResponseData | null;
   isLoading: boolean;
+  /** The active request is waiting for a free slot under `maxConcurrentRequests`. */
+  requestQueued: boolean;
   activeRequestId: string | null;
   history: HistoryItem[];
+  /** Max history entries kept; 0 keeps everything. */
+  historyLimit: number;
+  /** Write every request/response to the rotating debug log in the data dir. */
+  requestLogging: boolean;
+  /** Keep-alive limits shared by every HTTP request. */
+  connectionPool: ConnectionPoolSettings;
+  /** HTTP requests allowed in flight at once; further sends are queued. */
+  maxConcurrentRequests: number;
+  /** How the save dialog names a request that still has the default name. */
+  requestNaming: RequestNamingStrategy;
+  /** JSON bodies larger than this are shown raw instead of pretty-printed; 0 has no limit. */
+  prettyPrintLimitKb: number;
+  /** Response text rendered at first and per "Load more"; 0 renders everything. */
+  renderChunkKb: number;
+  /** Responses slower than this are flagged in the response panel and history; 0 disables. */
+  slowThresholdMs: number;
+  /** Timeout for requests when neither they nor the active environment set one; 0 has none. */
+  defaultTimeoutMs: number;
+  /** Keep open tabs, unsaved edits included, across restarts. */
+  restoreDrafts: boolean;
+  /** Headers added to every HTTP request that does not set them itself. */
+  defaultHeaders: KeyValue[];
+  /** User-Agent for requests that do not set one; blank uses the app default. */
+  userAgent: string;
+  /** Proxy for requests set to use the global proxy. */
+  proxy: ProxySettings;
+  /** Record responses for offline replay, or answer from the recorded ones. */
+  replayMode: ReplayMode;
   collections: Collection[];
   environments: Environment[];
   activeEnvironmentId: string | null;
   globalVariables: EnvVariable[];
   vaultSecrets: VaultSecret[];
-  sidebarView: "requests" | "collections" | "history" | "environments" | "websocket" | "sse" | "cookies" | "plugins";
+  sidebarView:
+    | "requests"
+    | "collections"
+    | "history"
+    | "environments"
+    | "websocket"
+    | "sse"
+    | "cookies"
+    | "plugins"
+    | "flows";
   sidebarOpen: boolean;
+  theme: "light" | "dark";
   assertionResults: AssertionResult[];
+  latencySamples: LatencySamples | null;
   cookieJar: CookieEntry[];
   presets: Preset[];
   historyFilter: HistoryFilter;
@@ -422,11 +678,13 @@ export interface GetmanState {
   wsConnections: WsConnection[];
   sseConnections: SseConnection[];
   mockServers: MockServer[];
+  workflows: Workflow[];
   commandPaletteOpen: boolean;
   previousResponse: ResponseData | null;
 }
 
-interface PersistedState {
+/** Settings are saved on their own; older versions kept them in this state. */
+interface PersistedState extends Partial<AppSettings> {
   version: number;
   tabs: RequestTab[];
   activeTabId: string;
@@ -446,6 +704,7 @@ interface PersistedState {
   plugins: Plugin[];
   responseSnapshots: ResponseSnapshot[];
   mockServers: MockServer[];
+  workflows?: Workflow[];
 }
 
 // ─── Helpers ──────────────────────────────────────────────────────────────────
@@ -456,6 +715,15 @@ export function uid(): string {
   return `${Date.now()}-${globalId}-${Math.random().toString(36).slice(2, 7)}`;
 }
 
+export function isStandardHttpMethod(method: string): method is StandardHttpMethod {
+  return (STANDARD_HTTP_METHODS as string[]).includes(method);
+}
+
+/** Method names are RFC 9110 tokens: visible ASCII without delimiters. */
+export function isValidHttpMethod(method: string): boolean {
+  return /^[!#$%&'*+\-.^_`|~0-9A-Za-z]+$/.test(method);
+}
+
 export function createEmptyKV(): KeyValue {
   return { id: uid(), key: "", value: "", enabled: true };
 }
@@ -465,11 +733,77 @@ export function defaultSettings(): RequestSettings {
     timeoutMs: 0,
     retryCount: 0,
     retryDelayMs: 1000,
+    httpVersion: "auto",
     proxyUrl: "",
+    proxyUsername: "",
+    proxyPassword: "",
+    noProxy: "",
+    resolveOverrides: "",
+    maxResponseMb: 0,
+    requestCompression: "none",
+    conditionalRequests: false,
+    autoContentType: false,
+    freshConnection: false,
+    followPagination: false,
+    maxPages: 10,
+    useDefaultHeaders: true,
     verifySsl: true,
   };
 }
 
+/** Parse `host=ip[:port]` lines; blank lines and `#` comments are ignored. */
+export function parseResolveOverrides(text: string): Record<string, string> | undefined {
+  const overrides: Record<string, string> = {};
+  for (const rawLine of (text || "").split("\n")) {
+    const line = rawLine.trim();
+    if (!line || line.startsWith("#")) continue;
+    const separator = line.indexOf("=");
+    if (separator <= 0) continue;
+    const host = line.slice(0, separator).trim();
+    const address = line.slice(separator + 1).trim();
+    if (host && address) overrides[host] = address;
+  }
+  return Object.keys(overrides).length > 0 ? overrides : undefined;
+}
+
+/** Proxy fields of a send payload; empty values fall back to the environment. */
+/** Requests saved before the global proxy keep their own proxy when they set one. */
+export function requestProxyMode(settings: RequestSettings): ProxyMode {
+  return settings.proxyMode ?? ((settings.proxyUrl || "").trim() ? "custom" : "global");
+}
+
+export function proxyPayloadFields(settings: RequestSettings): {
+  proxyUrl?: string;
+  proxyUsername?: string;
+  proxyPassword?: string;
+  noProxy?: string[];
+  bypassProxy?: boolean;
+} {
+  const mode = requestProxyMode(settings);
+  if (mode === "none") return { bypassProxy: true };
+  const proxy: ProxySettings =
+    mode === "custom"
+      ? {
+          url: settings.proxyUrl,
+          username: settings.proxyUsername,
+          password: settings.proxyPassword,
+          noProxy: settings.noProxy,
+        }
+      : state.proxy;
+  const proxyUrl = (proxy.url || "").trim();
+  if (!proxyUrl) return {};
+  const noProxy = (proxy.noProxy || "")
+    .split(",")
+    .map((entry) => entry.trim())
+    .filter(Boolean);
+  return {
+    proxyUrl,
+    proxyUsername: (proxy.username || "").trim() || undefined,
+    proxyPassword: proxy.password || undefined,
+    noProxy: noProxy.length > 0 ? noProxy : undefined,
+  };
+}
+
 // ─── URL ↔ Params Sync ───────────────────────────────────────────────────────
 
 /**
@@ -532,23 +866,103 @@ export function updateActiveTabUrl(url: string) {
   const tab = getActiveTab();
   if (!tab) return;
   const newParams = extractParamsFromUrl(url);
+  const pathParams = syncPathParams(url, tab.pathParams);
   const tabs = state.tabs.map((t) =>
-    t.id === state.activeTabId ? { ...t, url, params: newParams } : t
+    t.id === state.activeTabId ? { ...t, url, params: newParams, pathParams } : t
   );
   setState({ tabs });
 }
 
+// ─── Path Params ─────────────────────────────────────────────────────────────
+
+/** `/:name` segments and single-brace `{name}` placeholders; `{{var}}` is left alone. */
+const PATH_PARAM_PATTERN = /(?<=\/):([A-Za-z_]\w*)|(?<!\{)\{([A-Za-z_]\w*)\}(?!\})/g;
+
+/** Start and end of the path portion, skipping scheme, authority, query and fragment. */
+function pathRange(url: string): [number, number] {
+  const scheme = url.match(/^[A-Za-z][A-Za-z0-9+.-]*:\/\//);
+  let start = 0;
+  if (scheme) {
+    const authorityEnd = url.slice(scheme[0].length).search(/[/?#]/);
+    start = authorityEnd === -1 ? url.length : scheme[0].length + authorityEnd;
+  }
+  const tail = url.slice(start).search(/[?#]/);
+  return [start, tail === -1 ? url.length : start + tail];
+}
+
+/** Placeholder names in the URL path, in order of first appearance. */
+export function extractPathParamNames(url: string): string[] {
+  const [start, end] = pathRange(url);
+  const names: string[] = [];
+  for (const match of url.slice(start, end).matchAll(PATH_PARAM_PATTERN)) {
+    const name = match[1] ?? match[2];
+    if (!names.includes(name)) names.push(name);
+  }
+  return names;
+}
+
+/**
+ * One row per placeholder in the URL, keeping values already entered for
+ * names that are still present.
+ */
+export function syncPathParams(url: string, current: KeyValue[] = []): KeyValue[] {
+  return extractPathParamNames(url).map(
+    (name) =>
+      current.find((p) => p.key === name) ?? { id: uid(), key: name, value: "", enabled: true }
+  );
+}
+
+/**
+ * Percent-encode everything but RFC 3986 unreserved characters, so `!'()*`
+ * are escaped too, unlike with `encodeURIComponent`. Matches the preview's
+ * encoder in `engine/env.rs`.
+ */
+export function encodePathSegment(value: string): string {
+  return encodeURIComponent(value).replace(
+    /[!'()*]/g,
+    (char) => `%${char.charCodeAt(0).toString(16).toUpperCase()}`
+  );
+}
+
+/**
+ * Substitute path placeholders with their URL-encoded values. Placeholders
+ * without an enabled value are left as written.
+ */
+export function applyPathParams(
+  url: string,
+  params: KeyValue[] | undefined,
+  resolve: (value: string) => string = (value) => value,
+): string {
+  const values = new Map<string, string>();
+  for (const p of params ?? []) {
+    if (p.enabled && p.key && !values.has(p.key)) values.set(p.key, resolve(p.value));
+  }
+  if (values.size === 0) return url;
+  const [start, end] = pathRange(url);
+  const path = url.slice(start, end).replace(
+    PATH_PARAM_PATTERN,
+    (placeholder, colonName?: string, braceName?: string) => {
+      const value = values.get(colonName ?? braceName ?? "");
+      return value === undefined ? placeholder : encodePathSegment(value);
+    }
+  );
+  return url.slice(0, start) + path + url.slice(end);
+}
+
 export function createDefaultTab(): RequestTab {
   return {
     id: uid(),
     name: "New Request",
+    description: "",
     requestType: "http",
     method: "GET",
     url: "",
     params: [createEmptyKV()],
+    pathParams: [],
     headers: [createEmptyKV()],
     bodyType: "none",
     bodyContent: "",
+    bodyFilePath: "",
     bodyFormData: [createEmptyKV()],
     graphqlQuery: "",
     graphqlVariables: "{}",
@@ -569,6 +983,7 @@ export function createDefaultTab(): RequestTab {
     oauth2CallbackUrl: "http://localhost/callback",
     oauth2AccessToken: "",
     ntlmDomain: "",
+    ntlmWorkstation: "",
     awsAccessKeyId: "",
     awsSecretAccessKey: "",
     awsSessionToken: "",
@@ -606,6 +1021,127 @@ export function createDefaultTab(): RequestTab {
 type Listener = () => void;
 
 const PERSISTED_STATE_VERSION = 1;
+
+export const DEFAULT_HISTORY_LIMIT = 100;
+
+export type RequestNamingStrategy = "full-url" | "path" | "host-path";
+
+const REQUEST_NAMING_STRATEGIES: RequestNamingStrategy[] = ["full-url", "path", "host-path"];
+
+/** Offline record/replay: `record` stores responses, `replay` answers from them. */
+export type ReplayMode = "off" | "record" | "replay";
+
+const REPLAY_MODES: ReplayMode[] = ["off", "record", "replay"];
+
+/** Mirrors `ConnectionPoolSettings::default` in the Rust engine. */
+export function defaultConnectionPool(): ConnectionPoolSettings {
+  return { maxIdlePerHost: 16, idleTimeoutMs: 90_000 };
+}
+
+function normalizeConnectionPool(value: unknown): ConnectionPoolSettings {
+  const defaults = defaultConnectionPool();
+  const input = (value ?? {}) as Partial<ConnectionPoolSettings>;
+  const whole = (n: unknown, fallback: number) =>
+    typeof n === "number" && Number.isFinite(n) && n >= 0 ? Math.floor(n) : fallback;
+  return {
+    maxIdlePerHost: whole(input.maxIdlePerHost, defaults.maxIdlePerHost),
+    idleTimeoutMs: whole(input.idleTimeoutMs, defaults.idleTimeoutMs),
+  };
+}
+
+function normalizeMaxConcurrentRequests(value: unknown): number {
+  return typeof value === "number" && Number.isFinite(value) && value >= 1
+    ? Math.floor(value)
+    : DEFAULT_MAX_CONCURRENT_REQUESTS;
+}
+
+export const DEFAULT_PRETTY_PRINT_LIMIT_KB = 2048;
+
+export const DEFAULT_RENDER_CHUNK_KB = 256;
+
+export const DEFAULT_SLOW_THRESHOLD_MS = 2000;
+
+export const HISTORY_LIMIT_OPTIONS = [50, 100, 250, 500, 1000, 5000, 0];
+
+function normalizeHistoryLimit(value: unknown): number {
+  return typeof value === "number" && Number.isInteger(value) && value >= 0
+    ? value
+    : DEFAULT_HISTORY_LIMIT;
+}
+
+function capHistory(history: HistoryItem[], limit: number): HistoryItem[] {
+  return limit > 0 ? history.slice(0, limit) : history;
+}
+
+export function defaultAppSettings(): AppSettings {
+  return normalizeSettings({});
+}
+
+function normalizeSettings(data: unknown): AppSettings {
+  const parsed = (data && typeof data === "object" ? data : {}) as Partial<AppSettings>;
+  const size = (value: unknown, fallback: number) =>
+    typeof value === "number" && value >= 0 ? Math.floor(value) : fallback;
+  return {
+    theme: parsed.theme === "dark" ? "dark" : "light",
+    historyLimit: normalizeHistoryLimit(parsed.historyLimit),
+    requestLogging: parsed.requestLogging === true,
+    connectionPool: normalizeConnectionPool(parsed.connectionPool),
+    maxConcurrentRequests: normalizeMaxConcurrentRequests(parsed.maxConcurrentRequests),
+    requestNaming: REQUEST_NAMING_STRATEGIES.includes(parsed.requestNaming as RequestNamingStrategy)
+      ? (parsed.requestNaming as RequestNamingStrategy)
+      : "full-url",
+    prettyPrintLimitKb: size(parsed.prettyPrintLimitKb, DEFAULT_PRETTY_PRINT_LIMIT_KB),
+    renderChunkKb: size(parsed.renderChunkKb, DEFAULT_RENDER_CHUNK_KB),
+    slowThresholdMs: size(parsed.slowThresholdMs, DEFAULT_SLOW_THRESHOLD_MS),
+    defaultTimeoutMs: size(parsed.defaultTimeoutMs, 0),
+    restoreDrafts: parsed.restoreDrafts !== false,
+    defaultHeaders: Array.isArray(parsed.defaultHeaders)
+      ? parsed.defaultHeaders.filter(Boolean).map((header) => ({
+          id: header.id || uid(),
+          key: header.key || "",
+          value: header.value || "",
+          enabled: header.enabled ?? true,
+        }))
+      : [],
+    userAgent: typeof parsed.userAgent === "string" ? parsed.userAgent : "",
+    proxy: normalizeProxySettings(parsed.proxy),
+    replayMode: REPLAY_MODES.includes(parsed.replayMode as ReplayMode)
+      ? (parsed.replayMode as ReplayMode)
+      : "off",
+  };
+}
+
+function normalizeProxySettings(value: unknown): ProxySettings {
+  const input = (value && typeof value === "object" ? value : {}) as Partial<ProxySettings>;
+  const text = (field: unknown) => (typeof field === "string" ? field : "");
+  return {
+    url: text(input.url),
+    username: text(input.username),
+    password: text(input.password),
+    noProxy: text(input.noProxy),
+  };
+}
+
+function settingsOf(current: GetmanState): AppSettings {
+  return {
+    theme: current.theme,
+    historyLimit: current.historyLimit,
+    requestLogging: current.requestLogging,
+    connectionPool: current.connectionPool,
+    maxConcurrentRequests: current.maxConcurrentRequests,
+    requestNaming: current.requestNaming,
+    prettyPrintLimitKb: current.prettyPrintLimitKb,
+    renderChunkKb: current.renderChunkKb,
+    slowThresholdMs: current.slowThresholdMs,
+    defaultTimeoutMs: current.defaultTimeoutMs,
+    restoreDrafts: current.restoreDrafts,
+    defaultHeaders: current.defaultHeaders,
+    userAgent: current.userAgent,
+    proxy: current.proxy,
+    replayMode: current.replayMode,
+  };
+}
+
 const MAX_RESPONSE_SNAPSHOTS = 50;
 const MAX_WS_MESSAGES = 500;
 const MAX_SSE_EVENTS = 500;
@@ -650,6 +1186,7 @@ export function normalizeRequestTab(input: RequestTab): RequestTab {
     method: input.method || base.method,
     requestType: input.requestType || base.requestType,
     params: Array.isArray(input.params) && input.params.length > 0 ? input.params : [createEmptyKV()],
+    pathParams: Array.isArray(input.pathParams) ? input.pathParams : syncPathParams(tab.url),
     headers: Array.isArray(input.headers) && input.headers.length > 0 ? input.headers : [createEmptyKV()],
     bodyFormData:
       Array.isArray(input.bodyFormData) && input.bodyFormData.length > 0
@@ -687,9 +1224,97 @@ function normalizeSavedRequest(request: SavedRequest): SavedRequest {
     method: request.method || tab.method,
     url: request.url || tab.url,
     tab,
+    tags: normalizeTags(request.tags),
   };
 }
 
+/** Trimmed, non-empty tags with case-insensitive duplicates removed. */
+export function normalizeTags(tags: unknown): string[] {
+  if (!Array.isArray(tags)) return [];
+  const seen = new Set<string>();
+  const result: string[] = [];
+  for (const tag of tags) {
+    if (typeof tag !== "string") continue;
+    const trimmed = tag.trim();
+    if (!trimmed || seen.has(trimmed.toLowerCase())) continue;
+    seen.add(trimmed.toLowerCase());
+    result.push(trimmed);
+  }
+  return result;
+}
+
+function collectFolderTags(folders: CollectionFolder[], out: string[]) {
+  for (const folder of folders) {
+    out.push(...folder.requests.flatMap((request) => request.tags ?? []));
+    collectFolderTags(folder.folders, out);
+  }
+}
+
+/** Every tag used by a saved request, sorted, for filters and autocomplete. */
+export function collectRequestTags(collections: Collection[]): string[] {
+  const tags: string[] = [];
+  for (const collection of collections) {
+    tags.push(...collection.requests.flatMap((request) => request.tags ?? []));
+    collectFolderTags(collection.folders, tags);
+  }
+  return normalizeTags(tags).sort((a, b) => a.localeCompare(b));
+}
+
+/** Whether `request` carries all of `tags`, ignoring case. */
+export function requestHasTags(request: SavedRequest, tags: string[]): boolean {
+  const own = new Set((request.tags ?? []).map((tag) => tag.toLowerCase()));
+  return tags.every((tag) => own.has(tag.toLowerCase()));
+}
+
+function filterFolderRequests(
+  folders: CollectionFolder[],
+  matches: (request: SavedRequest) => boolean
+): CollectionFolder[] {
+  return folders
+    .map((folder) => ({
+      ...folder,
+      requests: folder.requests.filter(matches),
+      folders: filterFolderRequests(folder.folders, matches),
+    }))
+    .filter((folder) => folder.requests.length > 0 || folder.folders.length > 0);
+}
+
+/**
+ * Narrow collections to saved requests matching the search text (name or
+ * URL) and carrying all selected tags. Without tags, a collection whose name
+ * matches the search keeps all of its requests. Empty folders are dropped.
+ */
+export function filterCollections(
+  collections: Collection[],
+  search: string,
+  tags: string[]
+): Collection[] {
+  const query = search.trim().toLowerCase();
+  if (!query && tags.length === 0) return collections;
+  const matches = (request: SavedRequest) =>
+    (!query ||
+      request.name.toLowerCase().includes(query) ||
+      request.url.toLowerCase().includes(query)) &&
+    requestHasTags(request, tags);
+  return collections
+    .map((collection) => {
+      const nameMatches =
+        tags.length === 0 && query !== "" && collection.name.toLowerCase().includes(query);
+      if (nameMatches) return collection;
+      return {
+        ...collection,
+        requests: collection.requests.filter(matches),
+        folders: filterFolderRequests(collection.folders, matches),
+      };
+    })
+    .filter(
+      (collection) =>
+        (tags.length === 0 && collection.name.toLowerCase().includes(query)) ||
+        collection.requests.length > 0 ||
+        collection.folders.length > 0
+    );
+}
+
 function normalizeCollectionFolder(folder: CollectionFolder): CollectionFolder {
   return {
     id: folder.id || uid(),
@@ -741,8 +1366,23 @@ function createInitialState(): GetmanState {
     response: null,
     grpcResponse: null,
     isLoading: false,
+    requestQueued: false,
     activeRequestId: null,
     history: [],
+    historyLimit: DEFAULT_HISTORY_LIMIT,
+    requestLogging: false,
+    connectionPool: defaultConnectionPool(),
+    maxConcurrentRequests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+    requestNaming: "full-url",
+    prettyPrintLimitKb: DEFAULT_PRETTY_PRINT_LIMIT_KB,
+    renderChunkKb: DEFAULT_RENDER_CHUNK_KB,
+    slowThresholdMs: DEFAULT_SLOW_THRESHOLD_MS,
+    defaultTimeoutMs: 0,
+    restoreDrafts: true,
+    defaultHeaders: [],
+    userAgent: "",
+    proxy: normalizeProxySettings({}),
+    replayMode: "off",
     collections: [],
     environments: [],
     activeEnvironmentId: null,
@@ -750,7 +1390,9 @@ function createInitialState(): GetmanState {
     vaultSecrets: [],
     sidebarView: "requests",
     sidebarOpen: true,
+    theme: "light",
     assertionResults: [],
+    latencySamples: null,
     cookieJar: [],
     presets: [],
     historyFilter: { method: "ALL", statusMin: 0, statusMax: 999, search: "", dateFrom: "", dateTo: "" },
@@ -763,6 +1405,7 @@ function createInitialState(): GetmanState {
     wsConnections: [],
     sseConnections: [],
     mockServers: [],
+    workflows: [],
     commandPaletteOpen: false,
     previousResponse: null,
   };
@@ -793,14 +1436,17 @@ function normalizeState(data: unknown): Partial<GetmanState> | null {
     parsed.sidebarView === "websocket" ||
     parsed.sidebarView === "sse" ||
     parsed.sidebarView === "cookies" ||
-    parsed.sidebarView === "plugins"
+    parsed.sidebarView === "plugins" ||
+    parsed.sidebarView === "flows"
       ? parsed.sidebarView
       : "requests";
 
   return {
+    // Settings saved on their own replace these once loaded.
+    ...normalizeSettings(parsed),
     tabs,
     activeTabId,
-    history: Array.isArray(parsed.history) ? parsed.history.slice(0, 100) : [],
+    history: Array.isArray(parsed.history) ? parsed.history : [],
     collections: Array.isArray(parsed.collections)
       ? parsed.collections.map((collection) => normalizeCollection(collection as Collection))
       : [],
@@ -816,8 +1462,10 @@ function normalizeState(data: unknown): Partial<GetmanState> | null {
     response: null,
     grpcResponse: null,
     isLoading: false,
+    requestQueued: false,
     activeRequestId: null,
     assertionResults: [],
+    latencySamples: null,
     cookieJar: Array.isArray(parsed.cookieJar) ? parsed.cookieJar : [],
     presets: Array.isArray(parsed.presets) ? parsed.presets : [],
     historyFilter: { method: "ALL", statusMin: 0, statusMax: 999, search: "", dateFrom: "", dateTo: "" },
@@ -835,6 +1483,7 @@ function normalizeState(data: unknown): Partial<GetmanState> | null {
     wsConnections: [],
     sseConnections: [],
     mockServers: Array.isArray(parsed.mockServers) ? parsed.mockServers : [],
+    workflows: Array.isArray(parsed.workflows) ? parsed.workflows : [],
     commandPaletteOpen: false,
     previousResponse: null,
   };
@@ -843,8 +1492,9 @@ function normalizeState(data: unknown): Partial<GetmanState> | null {
 function serializeState(current: GetmanState): string {
   const payload: PersistedState = {
     version: PERSISTED_STATE_VERSION,
-    tabs: current.tabs,
-    activeTabId: current.activeTabId,
+    // Without drafts the next launch opens a single blank tab
+    tabs: current.restoreDrafts ? current.tabs : [],
+    activeTabId: current.restoreDrafts ? current.activeTabId : "",
     history: current.history,
     collections: current.collections,
     environments: current.environments,
@@ -861,6 +1511,7 @@ function serializeState(current: GetmanState): string {
     plugins: current.plugins,
     responseSnapshots: current.responseSnapshots,
     mockServers: current.mockServers,
+    workflows: current.workflows,
   };
 
   return JSON.stringify(payload);
@@ -869,6 +1520,8 @@ function serializeState(current: GetmanState): string {
 let state: GetmanState = createInitialState();
 let hydrateStarted = false;
 let persistTimer: ReturnType<typeof setTimeout> | null = null;
+/** Last settings written, so unrelated state changes do not rewrite them. */
+let savedSettingsJson: string | null = null;
 
 const listeners = new Set<Listener>();
 
@@ -891,10 +1544,38 @@ function schedulePersist() {
   }
 
   persistTimer = setTimeout(() => {
+    persistTimer = null;
     void savePersistedState(serializeState(state));
+    persistSettings();
   }, 180);
 }
 
+/** Write a pending debounced save now, e.g. while the window is closing. */
+export async function flushPersistedState(): Promise<void> {
+  if (!persistTimer) return;
+  clearTimeout(persistTimer);
+  persistTimer = null;
+  persistSettings();
+  await savePersistedState(serializeState(state));
+}
+
+function persistSettings() {
+  const settings = settingsOf(state);
+  const json = JSON.stringify(settings);
+  if (json === savedSettingsJson) {
+    return;
+  }
+  savedSettingsJson = json;
+  void saveSettings(settings);
+}
+
+/** Push the settings the backend keeps in memory. */
+function applyBackendSettings() {
+  void setRequestLogging(state.requestLogging);
+  void setConnectionPoolSettings(state.connectionPool);
+  setMaxConcurrentRequests(state.maxConcurrentRequests);
+}
+
 function setState(partial: Partial<GetmanState>, options?: { persist?: boolean }) {
   state = { ...state, ...partial };
   emit();
@@ -910,21 +1591,28 @@ export async function hydrateStore(): Promise<void> {
   }
 
   hydrateStarted = true;
+  let restored: Partial<GetmanState> | null = null;
   try {
     const raw = await loadPersistedState();
-    if (!raw) {
-      return;
-    }
-
-    const restored = normalizeState(JSON.parse(raw));
-    if (!restored) {
-      return;
-    }
-
-    setState(restored, { persist: false });
+    restored = raw ? normalizeState(JSON.parse(raw)) : null;
   } catch {
     // Ignore invalid persisted payloads and continue with default state.
   }
+
+  const saved = await loadSettings();
+  if (!restored && !saved) {
+    return;
+  }
+
+  setState({ ...restored, ...(saved && normalizeSettings(saved)) }, { persist: false });
+  setState({ history: capHistory(state.history, state.historyLimit) }, { persist: false });
+  applyBackendSettings();
+  if (saved) {
+    savedSettingsJson = JSON.stringify(settingsOf(state));
+  } else {
+    // First launch with separate settings: move them out of the old state.
+    persistSettings();
+  }
 }
 
 // ─── Actions ──────────────────────────────────────────────────────────────────
@@ -940,6 +1628,65 @@ export function updateActiveTab(partial: Partial<RequestTab>) {
   setState({ tabs });
 }
 
+/**
+ * Fill the active tab from an earlier request: method, URL, headers, body
+ * and the rest. The tab keeps its name, notes and collection link.
+ */
+export function restoreRequestInActiveTab(
+  method: HttpMethod,
+  url: string,
+  request?: HistoryRequestSnapshot
+) {
+  const active = getActiveTab();
+  if (!active) return;
+  if (!request) {
+    updateActiveTabUrl(url);
+    updateActiveTab({ method });
+    return;
+  }
+  const restored = normalizeRequestTab({ ...request, id: active.id } as RequestTab);
+  updateActiveTab({
+    ...restored,
+    name: active.name,
+    description: active.description,
+    sourceCollectionId: active.sourceCollectionId,
+    sourceFolderPath: active.sourceFolderPath,
+    sourceRequestId: active.sourceRequestId,
+  });
+}
+
+/**
+ * Assert that the value at `path` in the response stays as it is now:
+ * `equals` for scalars, `exists` for objects and arrays.
+ */
+export function addJsonPathAssertion(path: string, value: unknown) {
+  const tab = getActiveTab();
+  if (!tab) return;
+  const assertion: TestAssertion = { id: uid(), ...jsonPathAssertionDraft(path, value) };
+  updateActiveTab({ assertions: [...(tab.assertions || []), assertion] });
+}
+
+/**
+ * Append starter assertions generated from `response` to the active tab,
+ * skipping any whose check already exists, and write them through to the
+ * saved request the tab came from. Returns how many were added.
+ */
+export function addAssertionsFromResponse(response: ResponseData): number {
+  const tab = getActiveTab();
+  if (!tab) return 0;
+  const existing = tab.assertions || [];
+  const added: TestAssertion[] = assertionsFromResponse(response)
+    .filter((draft) => !existing.some((a) => a.type === draft.type && a.property === draft.property))
+    .map((draft) => ({ id: uid(), ...draft }));
+  if (added.length === 0) return 0;
+  const assertions = [...existing, ...added];
+  updateActiveTab({ assertions });
+  if (tab.sourceCollectionId && tab.sourceRequestId) {
+    setSavedRequestAssertions(tab.sourceCollectionId, tab.sourceRequestId, assertions);
+  }
+  return added.length;
+}
+
 export function setActiveTabId(id: string) {
   setState({ activeTabId: id, response: null, grpcResponse: null });
 }
@@ -996,12 +1743,164 @@ export function setIsLoading(isLoading: boolean) {
   setState({ isLoading }, { persist: false });
 }
 
+export function setRequestQueued(requestQueued: boolean) {
+  setState({ requestQueued }, { persist: false });
+}
+
 export function addHistoryItem(item: HistoryItem) {
-  setState({ history: [item, ...state.history].slice(0, 100) });
+  const [latest, ...rest] = state.history;
+  if (
+    latest &&
+    latest.method === item.method &&
+    latest.url === item.url &&
+    (latest.requestType ?? "http") === (item.requestType ?? "http")
+  ) {
+    const merged: HistoryItem = {
+      ...latest,
+      status: item.status,
+      time: item.time,
+      count: (latest.count ?? 1) + 1,
+      lastTimestamp: item.timestamp,
+      request: item.request ?? latest.request,
+      cancelled: item.cancelled,
+      grpcStatus: item.grpcStatus,
+    };
+    setState({ history: [merged, ...rest] });
+    void recordHistoryEntry(merged, state.historyLimit);
+    return;
+  }
+
+  setState({ history: capHistory([item, ...state.history], state.historyLimit) });
+  void recordHistoryEntry(item, state.historyLimit);
+}
+
+/** Lowering the limit drops the oldest entries; SQLite is pruned on the next record. */
+export function setHistoryLimit(limit: number) {
+  const historyLimit = normalizeHistoryLimit(limit);
+  setState({ historyLimit, history: capHistory(state.history, historyLimit) });
+}
+
+export function updateConnectionPool(partial: Partial<ConnectionPoolSettings>) {
+  const connectionPool = normalizeConnectionPool({ ...state.connectionPool, ...partial });
+  setState({ connectionPool });
+  void setConnectionPoolSettings(connectionPool);
+}
+
+export function updateMaxConcurrentRequests(limit: number) {
+  const maxConcurrentRequests = normalizeMaxConcurrentRequests(limit);
+  setState({ maxConcurrentRequests });
+  setMaxConcurrentRequests(maxConcurrentRequests);
+}
+
+export function setRequestLoggingEnabled(enabled: boolean) {
+  setState({ requestLogging: enabled });
+  void setRequestLogging(enabled);
+}
+
+export function setRequestNaming(requestNaming: RequestNamingStrategy) {
+  setState({ requestNaming });
+}
+
+export function setPrettyPrintLimitKb(limitKb: number) {
+  const prettyPrintLimitKb = Number.isFinite(limitKb) && limitKb >= 0 ? Math.floor(limitKb) : 0;
+  setState({ prettyPrintLimitKb });
+}
+
+export function setRenderChunkKb(chunkKb: number) {
+  const renderChunkKb = Number.isFinite(chunkKb) && chunkKb >= 0 ? Math.floor(chunkKb) : 0;
+  setState({ renderChunkKb });
+}
+
+export function setSlowThresholdMs(thresholdMs: number) {
+  const slowThresholdMs =
+    Number.isFinite(thresholdMs) && thresholdMs >= 0 ? Math.floor(thresholdMs) : 0;
+  setState({ slowThresholdMs });
+}
+
+export function setRestoreDrafts(restoreDrafts: boolean) {
+  setState({ restoreDrafts });
+}
+
+export function setDefaultTimeoutMs(timeoutMs: number) {
+  const defaultTimeoutMs = Number.isFinite(timeoutMs) && timeoutMs >= 0 ? Math.floor(timeoutMs) : 0;
+  setState({ defaultTimeoutMs });
+}
+
+/**
+ * The first positive timeout of the request's own setting, the environment
+ * default and the global default, or `undefined` for no timeout. A 0 or
+ * missing value at one level defers to the next.
+ */
+export function resolveTimeoutMs(
+  requestMs: number | undefined,
+  environmentMs: number | undefined,
+  globalMs: number | undefined,
+): number | undefined {
+  for (const ms of [requestMs, environmentMs, globalMs]) {
+    if (ms !== undefined && Number.isFinite(ms) && ms > 0) return ms;
+  }
+  return undefined;
+}
+
+/** Timeout for a request sent now, under the active environment. */
+export function effectiveTimeoutMs(settings?: RequestSettings): number | undefined {
+  const env = state.environments.find((e) => e.id === state.activeEnvironmentId);
+  return resolveTimeoutMs(settings?.timeoutMs, env?.timeoutMs, state.defaultTimeoutMs);
+}
+
+/** Whether a response taking `timeMs` crosses the slow-request threshold. */
+export function isSlowResponse(timeMs: number, thresholdMs: number): boolean {
+  return thresholdMs > 0 && timeMs > thresholdMs;
+}
+
+export function updateDefaultHeaders(defaultHeaders: KeyValue[]) {
+  setState({ defaultHeaders });
+}
+
+export function setUserAgent(userAgent: string) {
+  setState({ userAgent });
+}
+
+export function updateGlobalProxy(partial: Partial<ProxySettings>) {
+  setState({ proxy: { ...state.proxy, ...partial } });
+}
+
+/** The configured User-Agent, or `undefined` to let the backend use its default. */
+export function configuredUserAgent(): string | undefined {
+  return state.userAgent.trim() || undefined;
+}
+
+export function setReplayMode(replayMode: ReplayMode) {
+  setState({ replayMode });
+}
+
+/** The record/replay mode for outgoing requests, or `undefined` when it is off. */
+export function configuredReplayMode(): Exclude<ReplayMode, "off"> | undefined {
+  return state.replayMode === "off" ? undefined : state.replayMode;
+}
+
+/**
+ * Default name offered when saving a request: `METHOD` followed by the full
+ * URL, its path, or host and path. Path-based names drop the query string.
+ */
+export function defaultRequestName(tab: RequestTab, strategy: RequestNamingStrategy): string {
+  const url = tab.url.trim();
+  if (strategy === "full-url" || !url) return `${tab.method} ${url}`.trim();
+
+  const withoutQuery = url.split(/[?#]/)[0];
+  const scheme = withoutQuery.match(/^[A-Za-z][A-Za-z0-9+.-]*:\/\//);
+  const rest = scheme ? withoutQuery.slice(scheme[0].length) : withoutQuery;
+  // A leading `{{baseUrl}}` stands in for scheme and host.
+  const hasHost = Boolean(scheme) || rest.startsWith("{{");
+  const slash = rest.indexOf("/", rest.startsWith("{{") ? rest.indexOf("}}") : 0);
+  const host = hasHost ? (slash === -1 ? rest : rest.slice(0, slash)) : "";
+  const path = hasHost ? (slash === -1 ? "/" : rest.slice(slash)) : rest || "/";
+  return `${tab.method} ${strategy === "host-path" ? host + path : path}`;
 }
 
 export function clearHistory() {
   setState({ history: [] });
+  void clearHistoryEntries();
 }
 
 export function setSidebarView(view: GetmanState["sidebarView"]) {
@@ -1012,6 +1911,17 @@ export function setSidebarOpen(open: boolean) {
   setState({ sidebarOpen: open });
 }
 
+export function setTheme(theme: GetmanState["theme"]) {
+  setState({ theme });
+}
+
+/** Restore every setting to its default; tabs, collections and history stay. */
+export function resetSettings() {
+  const settings = defaultAppSettings();
+  setState({ ...settings, history: capHistory(state.history, settings.historyLimit) });
+  applyBackendSettings();
+}
+
 export function setActiveEnvironment(id: string | null) {
   setState({ activeEnvironmentId: id });
 }
@@ -1147,10 +2057,126 @@ export function deleteRequestFromCollection(collectionId: string, requestId: str
   setState({ collections });
 }
 
+function insertRequestCopy(requests: SavedRequest[], requestId: string): SavedRequest[] | null {
+  const index = requests.findIndex((r) => r.id === requestId);
+  if (index === -1) return null;
+  const source = requests[index];
+  const id = uid();
+  const copy: SavedRequest = {
+    ...source,
+    id,
+    name: `${source.name} (copy)`,
+    tab: normalizeRequestTab({
+      ...(JSON.parse(JSON.stringify(source.tab)) as RequestTab),
+      id: uid(),
+      sourceRequestId: id,
+    }),
+  };
+  const next = [...requests];
+  next.splice(index + 1, 0, copy);
+  return next;
+}
+
+function duplicateRequestInFolders(
+  folders: CollectionFolder[],
+  requestId: string
+): CollectionFolder[] | null {
+  for (let i = 0; i < folders.length; i++) {
+    const folder = folders[i];
+    const requests = insertRequestCopy(folder.requests, requestId);
+    const nested = requests ? null : duplicateRequestInFolders(folder.folders, requestId);
+    if (requests || nested) {
+      const next = [...folders];
+      next[i] = { ...folder, requests: requests ?? folder.requests, folders: nested ?? folder.folders };
+      return next;
+    }
+  }
+  return null;
+}
+
+/** Insert a copy of a saved request right after the original, in the same folder. */
+export function duplicateRequestInCollection(collectionId: string, requestId: string) {
+  const collections = state.collections.map((c) => {
+    if (c.id !== collectionId) return c;
+    const requests = insertRequestCopy(c.requests, requestId);
+    if (requests) return { ...c, requests };
+    const folders = duplicateRequestInFolders(c.folders, requestId);
+    return folders ? { ...c, folders } : c;
+  });
+  setState({ collections });
+}
+
+function renameRequestInFolders(
+  folders: CollectionFolder[],
+  requestId: string,
+  name: string
+): CollectionFolder[] {
+  return folders.map((f) => ({
+    ...f,
+    requests: f.requests.map((r) => (r.id === requestId ? { ...r, name } : r)),
+    folders: renameRequestInFolders(f.folders, requestId, name),
+  }));
+}
+
+function setTagsInFolders(
+  folders: CollectionFolder[],
+  requestId: string,
+  tags: string[]
+): CollectionFolder[] {
+  return folders.map((f) => ({
+    ...f,
+    requests: f.requests.map((r) => (r.id === requestId ? { ...r, tags } : r)),
+    folders: setTagsInFolders(f.folders, requestId, tags),
+  }));
+}
+
+export function setSavedRequestTags(collectionId: string, requestId: string, tags: string[]) {
+  const normalized = normalizeTags(tags);
+  const collections = state.collections.map((c) =>
+    c.id === collectionId
+      ? {
+          ...c,
+          requests: c.requests.map((r) => (r.id === requestId ? { ...r, tags: normalized } : r)),
+          folders: setTagsInFolders(c.folders, requestId, normalized),
+        }
+      : c
+  );
+  setState({ collections });
+}
+
+function setAssertionsInFolders(
+  folders: CollectionFolder[],
+  requestId: string,
+  assertions: TestAssertion[]
+): CollectionFolder[] {
+  return folders.map((f) => ({
+    ...f,
+    requests: f.requests.map((r) => (r.id === requestId ? { ...r, tab: { ...r.tab, assertions } } : r)),
+    folders: setAssertionsInFolders(f.folders, requestId, assertions),
+  }));
+}
+
+export function setSavedRequestAssertions(collectionId: string, requestId: string, assertions: TestAssertion[]) {
+  const collections = state.collections.map((c) =>
+    c.id === collectionId
+      ? {
+          ...c,
+          requests: c.requests.map((r) => (r.id === requestId ? { ...r, tab: { ...r.tab, assertions } } : r)),
+          folders: setAssertionsInFolders(c.folders, requestId, assertions),
+        }
+      : c
+  );
+  setState({ collections });
+}
+
 export function renameRequestInCollection(collectionId: string, requestId: string, name: string) {
   const collections = state.collections.map((c) =>
     c.id === collectionId
-      ? { ...c, requests: c.requests.map((r) => (r.id === requestId ? { ...r, name } : r)) }
+      ? {
+          ...c,
+          requests: c.requests.map((r) => (r.id === requestId ? { ...r, name } : r)),
+          folders: renameRequestInFolders(c.folders, requestId, name),
+        }
       : c
   );
   setState({ collections });
@@ -1180,12 +2206,17 @@ export function loadSavedRequest(
 }
 
 export function loadHistoryItem(item: HistoryItem) {
-  const tab = createDefaultTab();
-  tab.method = item.method;
-  tab.url = item.url;
-  tab.name = item.url.split("/").pop() || "Request";
-  if (item.requestType) {
-    tab.requestType = item.requestType;
+  let tab: RequestTab;
+  if (item.request) {
+    tab = normalizeRequestTab({ ...item.request, id: uid() } as RequestTab);
+  } else {
+    tab = createDefaultTab();
+    tab.method = item.method;
+    tab.url = item.url;
+    tab.name = item.url.split("/").pop() || "Request";
+    if (item.requestType) {
+      tab.requestType = item.requestType;
+    }
   }
   setState({
     tabs: [...state.tabs, tab],
@@ -1237,6 +2268,24 @@ function applyVariableMap(input: string, map: Record<string, string>): string {
   return result;
 }
 
+/**
+ * Enabled app-wide default headers for a request, keys and values passed
+ * through `resolve`; `undefined` when the request opts out or none are set.
+ */
+export function resolveDefaultHeaders(
+  settings: RequestSettings | undefined,
+  resolve: (value: string) => string
+): Record<string, string> | undefined {
+  if (settings?.useDefaultHeaders === false) return undefined;
+  const resolved: Record<string, string> = {};
+  for (const header of state.defaultHeaders) {
+    if (header.enabled && header.key.trim()) {
+      resolved[resolve(header.key.trim())] = resolve(header.value);
+    }
+  }
+  return Object.keys(resolved).length > 0 ? resolved : undefined;
+}
+
 export function getVariableScopeSnapshot(): {
   globalVariables: Record<string, string>;
   environmentVariables: Record<string, string>;
@@ -1266,6 +2315,16 @@ export function getVariableScopeSnapshot(): {
   return { globalVariables, environmentVariables };
 }
 
+/** Prefix a resolved relative URL with the active environment's base URL. */
+export function applyEnvironmentBaseUrl(
+  url: string,
+  resolve: (value: string) => string = (value) => resolveEnvVariables(value),
+): string {
+  const env = state.environments.find((e) => e.id === state.activeEnvironmentId);
+  if (!env?.baseUrl?.trim()) return url;
+  return joinBaseUrl(resolve(env.baseUrl), url);
+}
+
 export function resolveEnvVariables(
   input: string,
   scope?: VariableScopeContext
@@ -1385,6 +2444,10 @@ export function setAssertionResults(results: AssertionResult[]) {
   setState({ assertionResults: results }, { persist: false });
 }
 
+export function setLatencySamples(latencySamples: LatencySamples | null) {
+  setState({ latencySamples }, { persist: false });
+}
+
 // ─── Collection Folder Actions ────────────────────────────────────────────────
 
 export function addFolderToCollection(collectionId: string, folderName: string) {
@@ -1513,6 +2576,23 @@ export function saveRequestToFolder(collectionId: string, folderId: string, requ
 
 // ─── Import/Export ────────────────────────────────────────────────────────────
 
+/** Append requests and folders shared from elsewhere to a collection's root. */
+export function importIntoCollection(
+  collectionId: string,
+  items: { requests?: SavedRequest[]; folders?: CollectionFolder[] }
+) {
+  const collections = state.collections.map((c) =>
+    c.id === collectionId
+      ? {
+          ...c,
+          requests: [...c.requests, ...(items.requests ?? []).map(normalizeSavedRequest)],
+          folders: [...c.folders, ...(items.folders ?? []).map(normalizeCollectionFolder)],
+        }
+      : c
+  );
+  setState({ collections });
+}
+
 export function importCollections(collections: Collection[]) {
   setState({ collections: [...state.collections, ...collections.map(normalizeCollection)] });
 }
@@ -1586,14 +2666,100 @@ export function resetHistoryFilter() {
   setState({ historyFilter: { method: "ALL", statusMin: 0, statusMax: 999, search: "", dateFrom: "", dateTo: "" } });
 }
 
+export function isHistoryFilterActive(f: HistoryFilter): boolean {
+  return (
+    f.method !== "ALL" ||
+    f.statusMin !== 0 ||
+    f.statusMax !== 999 ||
+    f.search.trim() !== "" ||
+    f.dateFrom !== "" ||
+    f.dateTo !== ""
+  );
+}
+
+/**
+ * Local midnight starting the `YYYY-MM-DD` day `days` after `date`.
+ * `new Date("YYYY-MM-DD")` would be UTC midnight instead.
+ */
+function localDayStart(date: string, days = 0): number {
+  const [y, m, d] = date.split("-").map(Number);
+  return new Date(y, m - 1, d + days).getTime();
+}
+
+/** Inclusive bounds of the filter's From/To days in local time. */
+function historyDateBounds(f: HistoryFilter): { fromMs?: number; toMs?: number } {
+  return {
+    fromMs: f.dateFrom ? localDayStart(f.dateFrom) : undefined,
+    toMs: f.dateTo ? localDayStart(f.dateTo, 1) - 1 : undefined,
+  };
+}
+
+function historyQueryFromFilter(f: HistoryFilter): HistoryQuery {
+  return {
+    search: f.search.trim() || undefined,
+    method: f.method === "ALL" ? undefined : f.method,
+    statusClass: statusClassFromFilter(f),
+    ...historyDateBounds(f),
+  };
+}
+
+/**
+ * Remove one entry from the persisted history and the in-memory list. The
+ * row is deleted first so filtered views re-query without it.
+ */
+export async function deleteHistoryItem(id: string) {
+  await deleteHistoryEntry(id);
+  setState({ history: state.history.filter((item) => item.id !== id) });
+}
+
+/**
+ * Remove every entry matching the current filters, keeping the rest in
+ * order. Does nothing when no filter is set; use `clearHistory` for that.
+ */
+export async function deleteFilteredHistory() {
+  const f = state.historyFilter;
+  if (!isHistoryFilterActive(f)) return;
+  await deleteMatchingHistory(historyQueryFromFilter(f));
+  const matching = new Set(getFilteredHistory().map((item) => item.id));
+  setState({ history: state.history.filter((item) => !matching.has(item.id)) });
+}
+
+function statusClassFromFilter(f: HistoryFilter): HistoryStatusClass | undefined {
+  if (f.statusMin === 0 && f.statusMax === 0) return "error";
+  if (f.statusMin >= 200 && f.statusMin <= 500 && f.statusMin % 100 === 0 && f.statusMax === f.statusMin + 99) {
+    return `${f.statusMin / 100}xx` as HistoryStatusClass;
+  }
+  return undefined;
+}
+
+/**
+ * Query the full persisted history (beyond the in-memory 100 entries) in the
+ * desktop app, falling back to filtering the in-memory list in the browser.
+ */
+export async function queryHistoryItems(limit = 500): Promise<HistoryItem[]> {
+  const results = await queryHistory({ ...historyQueryFromFilter(state.historyFilter), limit });
+  if (!results) {
+    return getFilteredHistory();
+  }
+  return results.map((entry) => ({
+    ...entry,
+    method: entry.method as HttpMethod,
+    requestType: entry.requestType as RequestType | undefined,
+    request: entry.request as HistoryRequestSnapshot | undefined,
+    grpcStatus: entry.grpcStatus ?? undefined,
+  }));
+}
+
 export function getFilteredHistory(): HistoryItem[] {
   const f = state.historyFilter;
+  const { fromMs, toMs } = historyDateBounds(f);
   return state.history.filter((item) => {
     if (f.method !== "ALL" && item.method !== f.method) return false;
     if (item.status < f.statusMin || item.status > f.statusMax) return false;
     if (f.search && !item.url.toLowerCase().includes(f.search.toLowerCase())) return false;
-    if (f.dateFrom && item.timestamp < new Date(f.dateFrom).getTime()) return false;
-    if (f.dateTo && item.timestamp > new Date(f.dateTo).getTime() + 86400000) return false;
+    const sentAt = item.lastTimestamp ?? item.timestamp;
+    if (fromMs !== undefined && sentAt < fromMs) return false;
+    if (toMs !== undefined && sentAt > toMs) return false;
     return true;
   });
 }
@@ -1739,6 +2905,22 @@ export function updateMockServer(id: string, partial: Partial<MockServer>) {
   setState({ mockServers: state.mockServers.map((s) => s.id === id ? { ...s, ...partial } : s) });
 }
 
+// ─── Workflow Actions ─────────────────────────────────────────────────────────
+
+export function addWorkflow(name: string) {
+  const workflow: Workflow = { id: uid(), name, steps: [] };
+  setState({ workflows: [...state.workflows, workflow] });
+  return workflow.id;
+}
+
+export function deleteWorkflow(id: string) {
+  setState({ workflows: state.workflows.filter((w) => w.id !== id) });
+}
+
+export function updateWorkflow(id: string, partial: Partial<Workflow>) {
+  setState({ workflows: state.workflows.map((w) => w.id === id ? { ...w, ...partial } : w) });
+}
+
 export function addMockRoute(serverId: string, route: MockRoute) {
   setState({
     mockServers: state.mockServers.map((s) =>
@@ -1760,7 +2942,7 @@ export function createMockFromResponse(name: string, port: number) {
   const response = state.response;
   if (!tab || !response) return;
 
-  const resolvedUrl = resolveEnvVariables(tab.url);
+  const resolvedUrl = applyEnvironmentBaseUrl(resolveEnvVariables(tab.url));
   let pathname = "/";
   try { pathname = new URL(resolvedUrl).pathname; } catch { /* use default */ }
 
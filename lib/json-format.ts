@@ -0,0 +1,128 @@
+'use client';
+
+/**
+ * JSON body formatting
+ *
+ * Validates request and response bodies as JSON and pretty-prints or
+ * minifies them, reporting parse errors with a line/column position.
+ */
+
+export type JsonFormatResult =
+  | { ok: true; formatted: string }
+  | { ok: false; message: string; line?: number; column?: number };
+
+function positionToLineColumn(text: string, position: number): { line: number; column: number } {
+  const before = text.slice(0, Math.max(0, Math.min(position, text.length)));
+  const lines = before.split("\n");
+  return { line: lines.length, column: lines[lines.length - 1].length + 1 };
+}
+
+function locateParseError(text: string, message: string): { line?: number; column?: number } {
+  const lineColumn = /line (\d+) column (\d+)/i.exec(message);
+  if (lineColumn) {
+    return { line: Number(lineColumn[1]), column: Number(lineColumn[2]) };
+  }
+  const position = /position (\d+)/i.exec(message);
+  if (position) {
+    return positionToLineColumn(text, Number(position[1]));
+  }
+  return {};
+}
+
+/**
+ * Pretty-print JSON by re-indenting its tokens instead of round-tripping
+ * through `JSON.parse`, so numbers keep their exact text: 64-bit IDs and
+ * long decimals would otherwise be rounded to the nearest double. Strings
+ * keep their escapes too. Throws like `JSON.parse` on invalid input.
+ */
+export function prettyJson(text: string, indent = 2): string {
+  JSON.parse(text);
+  const unit = " ".repeat(indent);
+  let out = "";
+  let depth = 0;
+  let i = 0;
+  while (i < text.length) {
+    const ch = text[i];
+    if (ch === '"') {
+      let end = i + 1;
+      while (text[end] !== '"') end += text[end] === "\\" ? 2 : 1;
+      out += text.slice(i, end + 1);
+      i = end + 1;
+      continue;
+    }
+    if (ch === "{" || ch === "[") {
+      let next = i + 1;
+      while (/\s/.test(text[next])) next++;
+      const close = ch === "{" ? "}" : "]";
+      if (text[next] === close) {
+        out += ch + close;
+        i = next + 1;
+        continue;
+      }
+      depth++;
+      out += ch + "\n" + unit.repeat(depth);
+    } else if (ch === "}" || ch === "]") {
+      depth--;
+      out += "\n" + unit.repeat(depth) + ch;
+    } else if (ch === ",") {
+      out += ",\n" + unit.repeat(depth);
+    } else if (ch === ":") {
+      out += ": ";
+    } else if (!/\s/.test(ch)) {
+      out += ch;
+    }
+    i++;
+  }
+  return out;
+}
+
+/**
+ * Drop the whitespace between JSON tokens. Like `prettyJson`, strings and
+ * numbers are copied as written. Throws like `JSON.parse` on invalid input.
+ */
+export function minifyJson(text: string): string {
+  JSON.parse(text);
+  let out = "";
+  let i = 0;
+  while (i < text.length) {
+    const ch = text[i];
+    if (ch === '"') {
+      let end = i + 1;
+      while (text[end] !== '"') end += text[end] === "\\" ? 2 : 1;
+      out += text.slice(i, end + 1);
+      i = end + 1;
+      continue;
+    }
+    if (!/\s/.test(ch)) out += ch;
+    i++;
+  }
+  return out;
+}
+
+function transformJson(text: string, transform: (text: string) => string): JsonFormatResult {
+  if (!text.trim()) {
+    return { ok: false, message: "Body is empty" };
+  }
+
+  try {
+    return { ok: true, formatted: transform(text) };
+  } catch (error) {
+    const message = error instanceof Error ? error.message : "Invalid JSON";
+    return { ok: false, message, ...locateParseError(text, message) };
+  }
+}
+
+export function formatJson(text: string, indent = 2): JsonFormatResult {
+  return transformJson(text, (json) => prettyJson(json, indent));
+}
+
+export function compactJson(text: string): JsonFormatResult {
+  return transformJson(text, minifyJson);
+}
+
+export function describeJsonFormatError(result: Extract<JsonFormatResult, { ok: false }>): string {
+  if (result.line !== undefined && result.column !== undefined) {
+    return `Line ${result.line}, column ${result.column}: ${result.message}`;
+  }
+  return result.message;
+}
@@ -0,0 +1,305 @@
+'use client';
+
+/**
+ * JSON Schema validation
+ *
+ * Dependency-free validator for the commonly used subset of JSON Schema
+ * (draft-07 / 2020-12 keywords): types, enums, objects, arrays, strings,
+ * numbers, combinators and local `$ref`s. Violations are reported with a
+ * JSONPath-style location so they line up with JSONPath assertions.
+ */
+
+export interface SchemaViolation {
+  path: string;
+  message: string;
+}
+
+export interface CompiledSchema {
+  validate(value: unknown): SchemaViolation[];
+}
+
+type Schema = boolean | Record<string, unknown>;
+
+const MAX_REF_DEPTH = 64;
+
+function typeOf(value: unknown): string {
+  if (value === null) return "null";
+  if (Array.isArray(value)) return "array";
+  if (typeof value === "number") return Number.isInteger(value) ? "integer" : "number";
+  return typeof value;
+}
+
+function matchesType(value: unknown, type: string): boolean {
+  const actual = typeOf(value);
+  return actual === type || (type === "number" && actual === "integer");
+}
+
+function deepEqual(a: unknown, b: unknown): boolean {
+  if (a === b) return true;
+  if (typeof a !== "object" || typeof b !== "object" || a === null || b === null) return false;
+  if (Array.isArray(a) !== Array.isArray(b)) return false;
+  const aKeys = Object.keys(a);
+  const bKeys = Object.keys(b);
+  if (aKeys.length !== bKeys.length) return false;
+  return aKeys.every((key) =>
+    deepEqual((a as Record<string, unknown>)[key], (b as Record<string, unknown>)[key])
+  );
+}
+
+function childPath(path: string, key: string | number): string {
+  if (typeof key === "number") return `${path}[${key}]`;
+  return /^[A-Za-z_$][\w$]*$/.test(key) ? `${path}.${key}` : `${path}[${JSON.stringify(key)}]`;
+}
+
+function resolvePointer(root: Schema, ref: string): Schema {
+  if (!ref.startsWith("#")) {
+    throw new Error(`Only local $ref values are supported, got "${ref}"`);
+  }
+  let node: unknown = root;
+  for (const raw of ref.slice(1).split("/").filter(Boolean)) {
+    const segment = decodeURIComponent(raw).replace(/~1/g, "/").replace(/~0/g, "~");
+    if (!node || typeof node !== "object" || !(segment in node)) {
+      throw new Error(`Unresolved $ref "${ref}"`);
+    }
+    node = (node as Record<string, unknown>)[segment];
+  }
+  if (typeof node !== "boolean" && (!node || typeof node !== "object")) {
+    throw new Error(`$ref "${ref}" does not point to a schema`);
+  }
+  return node as Schema;
+}
+
+class Validator {
+  private readonly patterns = new Map<string, RegExp>();
+
+  constructor(private readonly root: Schema) {}
+
+  validate(value: unknown): SchemaViolation[] {
+    const violations: SchemaViolation[] = [];
+    this.check(this.root, value, "$", violations, 0);
+    return violations;
+  }
+
+  private regex(pattern: string): RegExp {
+    let compiled = this.patterns.get(pattern);
+    if (!compiled) {
+      compiled = new RegExp(pattern, "u");
+      this.patterns.set(pattern, compiled);
+    }
+    return compiled;
+  }
+
+  private isValid(schema: Schema, value: unknown, depth: number): boolean {
+    const violations: SchemaViolation[] = [];
+    this.check(schema, value, "$", violations, depth);
+    return violations.length === 0;
+  }
+
+  private check(
+    schema: Schema,
+    value: unknown,
+    path: string,
+    out: SchemaViolation[],
+    depth: number
+  ): void {
+    if (schema === true) return;
+    if (schema === false) {
+      out.push({ path, message: "no value is allowed here" });
+      return;
+    }
+
+    const fail = (message: string) => out.push({ path, message });
+    const s = schema;
+
+    if (typeof s.$ref === "string") {
+      if (depth >= MAX_REF_DEPTH) {
+        fail(`$ref "${s.$ref}" nests too deeply`);
+        return;
+      }
+      this.check(resolvePointer(this.root, s.$ref), value, path, out, depth + 1);
+    }
+
+    if (s.type !== undefined) {
+      const types = Array.isArray(s.type) ? (s.type as string[]) : [s.type as string];
+      if (!types.some((type) => matchesType(value, type))) {
+        fail(`expected ${types.join(" or ")}, got ${typeOf(value)}`);
+        return;
+      }
+    }
+    if (Array.isArray(s.enum) && !s.enum.some((option) => deepEqual(option, value))) {
+      fail(`must be one of ${JSON.stringify(s.enum)}`);
+    }
+    if ("const" in s && !deepEqual(s.const, value)) {
+      fail(`must equal ${JSON.stringify(s.const)}`);
+    }
+
+    if (typeof value === "string") {
+      const length = Array.from(value).length;
+      if (typeof s.minLength === "number" && length < s.minLength) {
+        fail(`must be at least ${s.minLength} characters`);
+      }
+      if (typeof s.maxLength === "number" && length > s.maxLength) {
+        fail(`must be at most ${s.maxLength} characters`);
+      }
+      if (typeof s.pattern === "string" && !this.regex(s.pattern).test(value)) {
+        fail(`must match pattern ${s.pattern}`);
+      }
+    }
+
+    if (typeof value === "number") {
+      if (typeof s.minimum === "number" && value < s.minimum) fail(`must be >= ${s.minimum}`);
+      if (typeof s.maximum === "number" && value > s.maximum) fail(`must be <= ${s.maximum}`);
+      if (typeof s.exclusiveMinimum === "number" && value <= s.exclusiveMinimum) {
+        fail(`must be > ${s.exclusiveMinimum}`);
+      }
+      if (typeof s.exclusiveMaximum === "number" && value >= s.exclusiveMaximum) {
+        fail(`must be < ${s.exclusiveMaximum}`);
+      }
+      if (typeof s.multipleOf === "number" && s.multipleOf > 0) {
+        const quotient = value / s.multipleOf;
+        if (Math.abs(quotient - Math.round(quotient)) > 1e-9) {
+          fail(`must be a multiple of ${s.multipleOf}`);
+        }
+      }
+    }
+
+    if (Array.isArray(value)) {
+      this.checkArray(s, value, path, out, depth);
+    } else if (value && typeof value === "object") {
+      this.checkObject(s, value as Record<string, unknown>, path, out, depth);
+    }
+
+    if (Array.isArray(s.allOf)) {
+      for (const sub of s.allOf as Schema[]) this.check(sub, value, path, out, depth);
+    }
+    if (Array.isArray(s.anyOf)) {
+      const options = s.anyOf as Schema[];
+      if (!options.some((sub) => this.isValid(sub, value, depth))) {
+        fail("must match at least one schema in anyOf");
+      }
+    }
+    if (Array.isArray(s.oneOf)) {
+      const matches = (s.oneOf as Schema[]).filter((sub) => this.isValid(sub, value, depth));
+      if (matches.length !== 1) {
+        fail(`must match exactly one schema in oneOf (matched ${matches.length})`);
+      }
+    }
+    if (s.not !== undefined && this.isValid(s.not as Schema, value, depth)) {
+      fail("must not match the schema in not");
+    }
+    if (s.if !== undefined) {
+      const branch = this.isValid(s.if as Schema, value, depth) ? s.then : s.else;
+      if (branch !== undefined) this.check(branch as Schema, value, path, out, depth);
+    }
+  }
+
+  private checkArray(
+    s: Record<string, unknown>,
+    value: unknown[],
+    path: string,
+    out: SchemaViolation[],
+    depth: number
+  ): void {
+    if (typeof s.minItems === "number" && value.length < s.minItems) {
+      out.push({ path, message: `must have at least ${s.minItems} items` });
+    }
+    if (typeof s.maxItems === "number" && value.length > s.maxItems) {
+      out.push({ path, message: `must have at most ${s.maxItems} items` });
+    }
+    if (s.uniqueItems === true) {
+      const duplicate = value.findIndex((item, i) =>
+        value.slice(0, i).some((other) => deepEqual(other, item))
+      );
+      if (duplicate >= 0) {
+        out.push({ path, message: `items must be unique (duplicate at index ${duplicate})` });
+      }
+    }
+
+    // `prefixItems` (2020-12) and array-form `items` (draft-07) validate tuples.
+    const tuple = Array.isArray(s.prefixItems)
+      ? (s.prefixItems as Schema[])
+      : Array.isArray(s.items)
+        ? (s.items as Schema[])
+        : [];
+    const rest = Array.isArray(s.items) ? s.additionalItems : s.items;
+    value.forEach((item, index) => {
+      const itemSchema = index < tuple.length ? tuple[index] : (rest as Schema | undefined);
+      if (itemSchema !== undefined) {
+        this.check(itemSchema, item, childPath(path, index), out, depth);
+      }
+    });
+
+    if (s.contains !== undefined) {
+      if (!value.some((item) => this.isValid(s.contains as Schema, item, depth))) {
+        out.push({ path, message: "must contain at least one matching item" });
+      }
+    }
+  }
+
+  private checkObject(
+    s: Record<string, unknown>,
+    value: Record<string, unknown>,
+    path: string,
+    out: SchemaViolation[],
+    depth: number
+  ): void {
+    const keys = Object.keys(value);
+    if (Array.isArray(s.required)) {
+      for (const key of s.required as string[]) {
+        if (!(key in value)) {
+          out.push({ path: childPath(path, key), message: "is required" });
+        }
+      }
+    }
+    if (typeof s.minProperties === "number" && keys.length < s.minProperties) {
+      out.push({ path, message: `must have at least ${s.minProperties} properties` });
+    }
+    if (typeof s.maxProperties === "number" && keys.length > s.maxProperties) {
+      out.push({ path, message: `must have at most ${s.maxProperties} properties` });
+    }
+
+    const properties = (s.properties ?? {}) as Record<string, Schema>;
+    const patternProperties = (s.patternProperties ?? {}) as Record<string, Schema>;
+    for (const key of keys) {
+      const keyPath = childPath(path, key);
+      let matched = false;
+      if (Object.prototype.hasOwnProperty.call(properties, key)) {
+        matched = true;
+        this.check(properties[key], value[key], keyPath, out, depth);
+      }
+      for (const [pattern, sub] of Object.entries(patternProperties)) {
+        if (this.regex(pattern).test(key)) {
+          matched = true;
+          this.check(sub, value[key], keyPath, out, depth);
+        }
+      }
+      if (!matched && s.additionalProperties !== undefined) {
+        if (s.additionalProperties === false) {
+          out.push({ path: keyPath, message: "is not an allowed property" });
+        } else {
+          this.check(s.additionalProperties as Schema, value[key], keyPath, out, depth);
+        }
+      }
+      if (s.propertyNames !== undefined && !this.isValid(s.propertyNames as Schema, key, depth)) {
+        out.push({ path: keyPath, message: "property name does not match propertyNames" });
+      }
+    }
+  }
+}
+
+/** Parse schema text into a reusable validator. Throws on invalid JSON. */
+export function compileJsonSchema(text: string): CompiledSchema {
+  const schema: unknown = JSON.parse(text);
+  if (typeof schema !== "boolean" && (!schema || typeof schema !== "object")) {
+    throw new Error("Schema must be a JSON object or boolean");
+  }
+  return new Validator(schema as Schema);
+}
+
+export function describeSchemaViolations(violations: SchemaViolation[], limit = 10): string {
+  const lines = violations.slice(0, limit).map((v) => `${v.path} ${v.message}`);
+  if (violations.length > limit) {
+    lines.push(`…and ${violations.length - limit} more`);
+  }
+  return lines.join("\n");
+}
@@ -10,6 +10,7 @@ import {
   type SavedRequest,
   createDefaultTab,
   createEmptyKV,
+  syncPathParams,
   uid,
 } from "./getman-store";
 
@@ -23,14 +24,37 @@ const HTTP_METHODS = [
   "OPTIONS",
 ] as const satisfies readonly HttpMethod[];
 
+/** Path item keys that are not operations. */
+const PATH_ITEM_FIELDS = new Set(["$ref", "summary", "description", "servers", "parameters"]);
+
 type JsonLike = Record<string, unknown>;
 
 interface OpenApiDocument {
   openapi?: string;
   swagger?: string;
   info?: { title?: string };
-  servers?: Array<{ url?: string }>;
-  paths?: Record<string, Record<string, OpenApiOperation>>;
+  servers?: OpenApiServer[];
+  /** Swagger 2.0 server fields. */
+  host?: string;
+  basePath?: string;
+  schemes?: string[];
+  paths?: Record<string, OpenApiPathItem>;
+  components?: { securitySchemes?: Record<string, OpenApiSecurityScheme> };
+  securityDefinitions?: Record<string, OpenApiSecurityScheme>;
+  security?: OpenApiSecurityRequirement[];
+  webhooks?: Record<string, unknown>;
+}
+
+interface OpenApiServer {
+  url?: string;
+  variables?: Record<string, { default?: string }>;
+}
+
+interface OpenApiPathItem {
+  $ref?: string;
+  servers?: OpenApiServer[];
+  parameters?: OpenApiParameter[];
+  [method: string]: unknown;
 }
 
 interface OpenApiOperation {
@@ -41,22 +65,28 @@ interface OpenApiOperation {
   parameters?: OpenApiParameter[];
   requestBody?: OpenApiRequestBody;
   responses?: Record<string, OpenApiResponse>;
+  security?: OpenApiSecurityRequirement[];
+  servers?: OpenApiServer[];
+  callbacks?: Record<string, unknown>;
 }
 
 interface OpenApiParameter {
+  $ref?: string;
   name?: string;
-  in?: "query" | "header" | "path" | "cookie";
+  in?: "query" | "header" | "path" | "cookie" | "body" | "formData";
   required?: boolean;
   schema?: OpenApiSchema;
   example?: unknown;
 }
 
 interface OpenApiRequestBody {
+  $ref?: string;
   required?: boolean;
   content?: Record<string, OpenApiMediaType>;
 }
 
 interface OpenApiResponse {
+  $ref?: string;
   description?: string;
   headers?: Record<string, { schema?: OpenApiSchema; example?: unknown }>;
   content?: Record<string, OpenApiMediaType>;
@@ -69,6 +99,7 @@ interface OpenApiMediaType {
 }
 
 interface OpenApiSchema {
+  $ref?: string;
   type?: string;
   format?: string;
   example?: unknown;
@@ -76,6 +107,41 @@ interface OpenApiSchema {
   enum?: unknown[];
   properties?: Record<string, OpenApiSchema>;
   items?: OpenApiSchema;
+  allOf?: OpenApiSchema[];
+  oneOf?: OpenApiSchema[];
+  anyOf?: OpenApiSchema[];
+}
+
+/** Scheme names mapped to scopes; an operation accepts any one requirement. */
+type OpenApiSecurityRequirement = Record<string, string[]>;
+
+interface OpenApiOAuthFlow {
+  authorizationUrl?: string;
+  tokenUrl?: string;
+  scopes?: Record<string, string>;
+}
+
+interface OpenApiSecurityScheme extends OpenApiOAuthFlow {
+  $ref?: string;
+  type?: string;
+  scheme?: string;
+  name?: string;
+  in?: string;
+  flows?: Record<string, OpenApiOAuthFlow>;
+  /** Swagger 2.0 OAuth2 flow name. */
+  flow?: string;
+}
+
+/** State shared while importing one document. */
+interface ImportContext {
+  doc: OpenApiDocument;
+  warnings: Set<string>;
+}
+
+export interface OpenApiImportResult {
+  collection: Collection;
+  /** Constructs that were skipped or only approximated. */
+  warnings: string[];
 }
 
 interface ContractSignature {
@@ -119,20 +185,94 @@ function buildUrl(serverUrl: string, path: string): string {
   return `${trimmedServer}${normalizedPath}`;
 }
 
-function sampleFromSchema(schema?: OpenApiSchema): unknown {
+/** Follow local `#/...` references. External ones are reported and dropped. */
+function resolveRef<T extends { $ref?: string }>(
+  value: T | undefined,
+  ctx: ImportContext
+): T | undefined {
+  const seen = new Set<string>();
+  let current: T | undefined = value;
+  let ref: string | undefined = current?.$ref;
+  while (typeof ref === "string") {
+    if (!ref.startsWith("#/")) {
+      ctx.warnings.add(`External reference ${ref} was not resolved`);
+      return undefined;
+    }
+    if (seen.has(ref)) {
+      ctx.warnings.add(`Reference ${ref} is circular`);
+      return undefined;
+    }
+    seen.add(ref);
+    const target = ref
+      .slice(2)
+      .split("/")
+      .map((segment) => decodeURIComponent(segment).replace(/~1/g, "/").replace(/~0/g, "~"))
+      .reduce<unknown>(
+        (node, segment) => (node && typeof node === "object" ? (node as JsonLike)[segment] : undefined),
+        ctx.doc
+      );
+    if (target === undefined) {
+      ctx.warnings.add(`Reference ${ref} does not exist`);
+    }
+    current = target as T | undefined;
+    ref = current?.$ref;
+  }
+  return current;
+}
+
+/** Base URL of the first server, with server variables set to their defaults. */
+function serverBaseUrl(ctx: ImportContext): string {
+  const { doc } = ctx;
+  if (doc.swagger) {
+    const basePath = asString(doc.basePath);
+    if (!doc.host) return basePath;
+    return `${doc.schemes?.[0] ?? "https"}://${doc.host}${basePath}`;
+  }
+  const servers = doc.servers || [];
+  const server = servers[0];
+  const url = asString(server?.url);
+  if (servers.length > 1) {
+    ctx.warnings.add(`The document lists ${servers.length} servers; requests use the first (${url})`);
+  }
+  return url.replace(/\{([^}]+)\}/g, (_, name: string) => {
+    const fallback = server?.variables?.[name]?.default;
+    if (fallback !== undefined) return fallback;
+    ctx.warnings.add(`Server variable {${name}} has no default; it was kept as {{${name}}}`);
+    return `{{${name}}}`;
+  });
+}
+
+/** Example value for `schema`. `refs` holds the references being expanded, so
+ *  recursive schemas stop at an empty object. */
+function sampleFromSchema(
+  schema: OpenApiSchema | undefined,
+  ctx: ImportContext,
+  refs: ReadonlySet<string> = new Set()
+): unknown {
+  const ref = schema?.$ref;
+  if (ref) {
+    if (refs.has(ref)) return {};
+    return sampleFromSchema(resolveRef(schema, ctx), ctx, new Set([...refs, ref]));
+  }
   if (!schema) return "";
   if (schema.example !== undefined) return schema.example;
   if (schema.default !== undefined) return schema.default;
   if (Array.isArray(schema.enum) && schema.enum.length > 0) return schema.enum[0];
-  if (schema.type === "object") {
+  if (schema.allOf?.length) {
+    const parts = schema.allOf.map((part) => sampleFromSchema(part, ctx, refs));
+    return Object.assign({}, ...parts.filter((part) => part && typeof part === "object"));
+  }
+  const variant = schema.oneOf?.[0] ?? schema.anyOf?.[0];
+  if (variant) return sampleFromSchema(variant, ctx, refs);
+  if (schema.type === "object" || (!schema.type && schema.properties)) {
     const output: Record<string, unknown> = {};
     for (const [key, nestedSchema] of Object.entries(schema.properties || {})) {
-      output[key] = sampleFromSchema(nestedSchema);
+      output[key] = sampleFromSchema(nestedSchema, ctx, refs);
     }
     return output;
   }
   if (schema.type === "array") {
-    return [sampleFromSchema(schema.items)];
+    return [sampleFromSchema(schema.items, ctx, refs)];
   }
   if (schema.type === "integer" || schema.type === "number") return 0;
   if (schema.type === "boolean") return false;
@@ -148,12 +288,17 @@ function stringifyExample(value: unknown): string {
   }
 }
 
-function buildExamples(responses: Record<string, OpenApiResponse> | undefined): RequestExample[] {
+function buildExamples(
+  responses: Record<string, OpenApiResponse> | undefined,
+  ctx: ImportContext
+): RequestExample[] {
   if (!responses) return [];
   const entries = Object.entries(responses);
   const examples: RequestExample[] = [];
 
-  for (const [statusCode, response] of entries) {
+  for (const [statusCode, responseOrRef] of entries) {
+    const response = resolveRef(responseOrRef, ctx);
+    if (!response) continue;
     const contentEntries = Object.entries(response.content || {});
     if (contentEntries.length === 0) {
       examples.push({
@@ -174,11 +319,11 @@ function buildExamples(responses: Record<string, OpenApiResponse> | undefined):
     const exampleBody =
       content.example ??
       Object.values(content.examples || {}).find((item) => item?.value !== undefined)?.value ??
-      sampleFromSchema(content.schema);
+      sampleFromSchema(content.schema, ctx);
 
     const headers: Record<string, string> = {};
     for (const [name, header] of Object.entries(response.headers || {})) {
-      headers[name] = stringifyExample(header.example ?? sampleFromSchema(header.schema));
+      headers[name] = stringifyExample(header.example ?? sampleFromSchema(header.schema, ctx));
     }
 
     examples.push({
@@ -197,19 +342,49 @@ function buildExamples(responses: Record<string, OpenApiResponse> | undefined):
   return examples;
 }
 
+interface ParsedParameters {
+  params: KeyValue[];
+  headers: KeyValue[];
+  cookies: KeyValue[];
+  requiredPathParams: string[];
+  requiredQueryParams: string[];
+  /** Swagger 2.0 `in: body` parameter, converted to an OpenAPI 3 request body. */
+  bodyParameter?: OpenApiRequestBody;
+}
+
+/** Path-level parameters overridden by operation-level ones with the same name and location. */
 function parseParameters(
-  parameters: OpenApiParameter[] | undefined
-): { params: KeyValue[]; headers: KeyValue[]; requiredPathParams: string[]; requiredQueryParams: string[] } {
+  pathParameters: OpenApiParameter[] | undefined,
+  operationParameters: OpenApiParameter[] | undefined,
+  operationKey: string,
+  ctx: ImportContext
+): ParsedParameters {
   const params: KeyValue[] = [];
   const headers: KeyValue[] = [];
+  const cookies: KeyValue[] = [];
   const requiredPathParams: string[] = [];
   const requiredQueryParams: string[] = [];
+  let bodyParameter: OpenApiRequestBody | undefined;
 
-  for (const parameter of parameters || []) {
+  const merged = new Map<string, OpenApiParameter>();
+  for (const parameterOrRef of [...(pathParameters || []), ...(operationParameters || [])]) {
+    const parameter = resolveRef(parameterOrRef, ctx);
+    if (parameter) merged.set(`${parameter.in}:${parameter.name}`, parameter);
+  }
+
+  for (const parameter of merged.values()) {
     const name = asString(parameter.name);
     const location = parameter.in;
     if (!name || !location) continue;
-    const fallback = sampleFromSchema(parameter.schema);
+    if (location === "body") {
+      bodyParameter = { content: { "application/json": { schema: parameter.schema } } };
+      continue;
+    }
+    if (location === "formData") {
+      ctx.warnings.add(`${operationKey}: Swagger 2.0 formData parameter "${name}" was not imported`);
+      continue;
+    }
+    const fallback = sampleFromSchema(parameter.schema, ctx);
     const value = stringifyExample(parameter.example ?? fallback);
     const item: KeyValue = {
       id: uid(),
@@ -223,6 +398,8 @@ function parseParameters(
       if (parameter.required) requiredQueryParams.push(name);
     } else if (location === "header") {
       headers.push(item);
+    } else if (location === "cookie") {
+      cookies.push(item);
     } else if (location === "path" && parameter.required) {
       requiredPathParams.push(name);
     }
@@ -230,15 +407,18 @@ function parseParameters(
 
   if (params.length === 0) params.push(createEmptyKV());
   if (headers.length === 0) headers.push(createEmptyKV());
-  return { params, headers, requiredPathParams, requiredQueryParams };
+  return { params, headers, cookies, requiredPathParams, requiredQueryParams, bodyParameter };
 }
 
 function buildRequestBody(
-  requestBody: OpenApiRequestBody | undefined
+  requestBodyOrRef: OpenApiRequestBody | undefined,
+  operationKey: string,
+  ctx: ImportContext
 ): Pick<RequestTab, "bodyType" | "bodyContent" | "bodyFormData"> & {
   requestContentTypes: string[];
 } {
-  if (!requestBody?.content) {
+  const requestBody = resolveRef(requestBodyOrRef, ctx);
+  if (!requestBody?.content || Object.keys(requestBody.content).length === 0) {
     return {
       bodyType: "none",
       bodyContent: "",
@@ -253,9 +433,9 @@ function buildRequestBody(
   const sample =
     media.example ??
     Object.values(media.examples || {}).find((item) => item?.value !== undefined)?.value ??
-    sampleFromSchema(media.schema);
+    sampleFromSchema(media.schema, ctx);
 
-  if (contentType.includes("application/json")) {
+  if (contentType.includes("json")) {
     return {
       bodyType: "json",
       bodyContent: stringifyExample(sample),
@@ -264,7 +444,9 @@ function buildRequestBody(
     };
   }
 
-  if (contentType.includes("x-www-form-urlencoded") && sample && typeof sample === "object") {
+  const isForm =
+    contentType.includes("x-www-form-urlencoded") || contentType.includes("multipart/form-data");
+  if (isForm && sample && typeof sample === "object") {
     const bodyFormData = Object.entries(sample as JsonLike).map(([key, value]) => ({
       id: uid(),
       key,
@@ -272,13 +454,16 @@ function buildRequestBody(
       enabled: true,
     }));
     return {
-      bodyType: "x-www-form-urlencoded",
+      bodyType: contentType.includes("multipart") ? "form-data" : "x-www-form-urlencoded",
       bodyContent: "",
       bodyFormData: bodyFormData.length > 0 ? bodyFormData : [createEmptyKV()],
       requestContentTypes,
     };
   }
 
+  if (!contentType.startsWith("text/")) {
+    ctx.warnings.add(`${operationKey}: ${contentType} body was imported as raw text`);
+  }
   return {
     bodyType: "raw",
     bodyContent: stringifyExample(sample),
@@ -287,6 +472,86 @@ function buildRequestBody(
   };
 }
 
+/** Auth fields for the first security requirement an operation accepts, with
+ *  `{{...}}` placeholders named after the scheme in place of credentials. */
+function authFromSecurity(
+  requirements: OpenApiSecurityRequirement[] | undefined,
+  operationKey: string,
+  ctx: ImportContext
+): Partial<RequestTab> {
+  const requirement = requirements?.find((item) => Object.keys(item).length > 0);
+  if (!requirement) return {};
+  const [name, ...others] = Object.keys(requirement);
+  if (others.length > 0) {
+    ctx.warnings.add(
+      `${operationKey}: requires ${[name, ...others].join(" + ")} together; only ${name} was applied`
+    );
+  }
+  const schemes = ctx.doc.components?.securitySchemes ?? ctx.doc.securityDefinitions;
+  const scheme = resolveRef(schemes?.[name], ctx);
+  if (!scheme) {
+    ctx.warnings.add(`Security scheme "${name}" is not defined`);
+    return {};
+  }
+
+  const variable = name.replace(/[^A-Za-z0-9_]/g, "_");
+  const httpScheme = asString(scheme.scheme).toLowerCase();
+  const bearer = (): Partial<RequestTab> => ({
+    authType: "bearer",
+    authToken: `{{${variable}Token}}`,
+  });
+  if (scheme.type === "http" && httpScheme === "bearer") return bearer();
+  const isHttpBasic = scheme.type === "http" && (httpScheme === "basic" || httpScheme === "digest");
+  if (isHttpBasic || scheme.type === "basic") {
+    return {
+      authType: httpScheme === "digest" ? "digest" : "basic",
+      authUsername: `{{${variable}Username}}`,
+      authPassword: `{{${variable}Password}}`,
+    };
+  }
+  if (scheme.type === "apiKey" && (scheme.in === "header" || scheme.in === "query")) {
+    return {
+      authType: "api-key",
+      authApiKey: asString(scheme.name),
+      authApiValue: `{{${variable}}}`,
+      authApiAddTo: scheme.in,
+    };
+  }
+  if (scheme.type === "apiKey" && scheme.in === "cookie" && scheme.name) {
+    return {
+      cookies: [{ id: uid(), key: scheme.name, value: `{{${variable}}}`, enabled: true }],
+    };
+  }
+  if (scheme.type === "oauth2") {
+    const flows = scheme.flows ?? {};
+    const code = flows.authorizationCode ?? (scheme.flow === "accessCode" ? scheme : undefined);
+    const client = flows.clientCredentials ?? (scheme.flow === "application" ? scheme : undefined);
+    const flow = code ?? client;
+    if (flow) {
+      return {
+        authType: "oauth2",
+        oauth2GrantType: code ? "authorization_code" : "client_credentials",
+        oauth2AuthUrl: asString(flow.authorizationUrl),
+        oauth2TokenUrl: asString(flow.tokenUrl),
+        oauth2ClientId: `{{${variable}ClientId}}`,
+        oauth2ClientSecret: `{{${variable}ClientSecret}}`,
+        oauth2Scope: Object.keys(flow.scopes || {}).join(" "),
+      };
+    }
+    ctx.warnings.add(
+      `OAuth2 scheme "${name}" only offers implicit or password flows; it was imported as a bearer token`
+    );
+    return bearer();
+  }
+  if (scheme.type === "openIdConnect") {
+    ctx.warnings.add(`OpenID Connect scheme "${name}" was imported as a bearer token`);
+    return bearer();
+  }
+  const kind = [scheme.type, scheme.scheme ?? scheme.in].filter(Boolean).join(" ");
+  ctx.warnings.add(`Security scheme "${name}" (${kind}) is not supported; requests have no auth`);
+  return {};
+}
+
 function makeOperationKey(method: HttpMethod, path: string): string {
   return `${method} ${path}`;
 }
@@ -330,24 +595,44 @@ function isBreakingContractChange(prev: ContractSignature | null, next: Contract
   return false;
 }
 
-export function importOpenApiCollection(raw: string): Collection {
-  const doc = JSON.parse(raw) as OpenApiDocument;
-  if (!doc.paths || (!doc.openapi && !doc.swagger)) {
+/** Build a collection from a JSON OpenAPI 3.x (or Swagger 2.0) document, one
+ *  request per operation and one folder per tag, reporting what was skipped. */
+export function importOpenApi(raw: string): OpenApiImportResult {
+  let doc: OpenApiDocument;
+  try {
+    doc = JSON.parse(raw) as OpenApiDocument;
+  } catch {
+    throw new Error("Invalid OpenAPI document: only JSON is supported, convert YAML specs to JSON first");
+  }
+  if (!doc?.paths || (!doc.openapi && !doc.swagger)) {
     throw new Error("Invalid OpenAPI document (JSON only)");
   }
 
+  const ctx: ImportContext = { doc, warnings: new Set() };
   const name = asString(doc.info?.title, "OpenAPI Imported Collection");
-  const baseUrl = asString(doc.servers?.[0]?.url, "");
+  const baseUrl = serverBaseUrl(ctx);
+  if (doc.swagger) {
+    ctx.warnings.add(`Swagger ${doc.swagger} is only partly supported; OpenAPI 3 imports are more complete`);
+  }
+  if (doc.webhooks && Object.keys(doc.webhooks).length > 0) {
+    ctx.warnings.add("Webhooks were not imported");
+  }
 
   const foldersByTag = new Map<string, SavedRequest[]>();
   const rootRequests: SavedRequest[] = [];
   const contractFingerprint: Record<string, string> = {};
 
-  for (const [path, pathOperations] of Object.entries(doc.paths)) {
-    for (const methodKey of Object.keys(pathOperations)) {
+  for (const [path, pathItemOrRef] of Object.entries(doc.paths)) {
+    const pathItem = resolveRef(pathItemOrRef, ctx);
+    if (!pathItem) continue;
+    for (const methodKey of Object.keys(pathItem)) {
+      if (PATH_ITEM_FIELDS.has(methodKey) || methodKey.startsWith("x-")) continue;
       const method = methodKey.toUpperCase() as HttpMethod;
-      if (!HTTP_METHODS.includes(method)) continue;
-      const operation = pathOperations[methodKey];
+      if (!HTTP_METHODS.includes(method)) {
+        ctx.warnings.add(`${method} ${path}: this method is not supported`);
+        continue;
+      }
+      const operation = pathItem[methodKey] as OpenApiOperation | undefined;
       if (!operation) continue;
 
       const operationKey = makeOperationKey(method, path);
@@ -355,19 +640,28 @@ export function importOpenApiCollection(raw: string): Collection {
         asString(operation.summary).trim() ||
         asString(operation.operationId).trim() ||
         `${method} ${path}`;
-      const { params, headers, requiredPathParams, requiredQueryParams } = parseParameters(
-        operation.parameters
-      );
-      const requestBody = buildRequestBody(operation.requestBody);
-      const examples = buildExamples(operation.responses);
+      const { params, headers, cookies, requiredPathParams, requiredQueryParams, bodyParameter } =
+        parseParameters(pathItem.parameters, operation.parameters, operationKey, ctx);
+      const requestBody = buildRequestBody(operation.requestBody ?? bodyParameter, operationKey, ctx);
+      const examples = buildExamples(operation.responses, ctx);
+      const auth = authFromSecurity(operation.security ?? doc.security, operationKey, ctx);
       const url = buildUrl(baseUrl, path);
+      const tabCookies = [...cookies, ...(auth.cookies ?? [])];
+      if (operation.servers?.length || pathItem.servers?.length) {
+        ctx.warnings.add(`${operationKey}: its own servers were ignored; the document server was used`);
+      }
+      if (operation.callbacks && Object.keys(operation.callbacks).length > 0) {
+        ctx.warnings.add(`${operationKey}: callbacks were not imported`);
+      }
 
       const tab: RequestTab = {
         ...createDefaultTab(),
         name: requestName,
+        description: asString(operation.description).trim(),
         method,
         url,
         params,
+        pathParams: syncPathParams(url),
         headers,
         bodyType: requestBody.bodyType,
         bodyContent: requestBody.bodyContent,
@@ -376,6 +670,8 @@ export function importOpenApiCollection(raw: string): Collection {
         selectedExampleId: examples.find((item) => item.isDefault)?.id || null,
         useMockExamples: false,
         sourceRequestId: operationKey,
+        ...auth,
+        cookies: tabCookies.length > 0 ? tabCookies : [createEmptyKV()],
       };
 
       const savedRequest: SavedRequest = {
@@ -418,7 +714,7 @@ export function importOpenApiCollection(raw: string): Collection {
       testScript: "",
     }));
 
-  return {
+  const collection: Collection = {
     id: uid(),
     name,
     requests: rootRequests,
@@ -430,6 +726,7 @@ export function importOpenApiCollection(raw: string): Collection {
     sourceHash: hashString(raw),
     contractFingerprint,
   };
+  return { collection, warnings: [...ctx.warnings] };
 }
 
 export function diffOpenApiCollections(existing: Collection, incoming: Collection): OpenApiSyncSummary {
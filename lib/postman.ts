@@ -18,6 +18,7 @@ import {
   uid,
   createEmptyKV,
   createDefaultTab,
+  syncPathParams,
 } from "./getman-store";
 
 // ─── Postman v2.1 Types ──────────────────────────────────────────────────────
@@ -49,7 +50,14 @@ interface PostmanRequest {
   url: PostmanUrl | string;
   body?: PostmanBody;
   auth?: PostmanAuth;
-  description?: string;
+  description?: PostmanDescription;
+}
+
+type PostmanDescription = string | { content?: string; type?: string };
+
+function parsePostmanDescription(description?: PostmanDescription): string {
+  if (!description) return "";
+  return typeof description === "string" ? description : description.content ?? "";
 }
 
 interface PostmanHeader {
@@ -64,6 +72,7 @@ interface PostmanUrl {
   host?: string[];
   path?: string[];
   query?: PostmanQuery[];
+  variable?: PostmanVariable[];
 }
 
 interface PostmanQuery {
@@ -122,9 +131,11 @@ interface PostmanResponse {
 
 // ─── Import ──────────────────────────────────────────────────────────────────
 
-function parsePostmanUrl(url: PostmanUrl | string): { raw: string; query: KeyValue[] } {
+function parsePostmanUrl(
+  url: PostmanUrl | string
+): { raw: string; query: KeyValue[]; pathParams: KeyValue[] } {
   if (typeof url === "string") {
-    return { raw: url, query: [createEmptyKV()] };
+    return { raw: url, query: [createEmptyKV()], pathParams: syncPathParams(url) };
   }
 
   const raw = url.raw || "";
@@ -136,7 +147,13 @@ function parsePostmanUrl(url: PostmanUrl | string): { raw: string; query: KeyVal
   }));
 
   if (query.length === 0) query.push(createEmptyKV());
-  return { raw, query };
+  const pathParams = syncPathParams(raw).map((param) => {
+    const variable = (url.variable || []).find((v) => v.key === param.key);
+    return variable
+      ? { ...param, value: variable.value || "", enabled: !variable.disabled }
+      : param;
+  });
+  return { raw, query, pathParams };
 }
 
 function parsePostmanHeaders(headers?: PostmanHeader[]): KeyValue[] {
@@ -298,7 +315,7 @@ function postmanItemToRequest(item: PostmanItem): SavedRequest | null {
 
   const req = item.request;
   const method = (req.method || "GET").toUpperCase() as HttpMethod;
-  const { raw: url, query } = parsePostmanUrl(req.url);
+  const { raw: url, query, pathParams } = parsePostmanUrl(req.url);
   const headers = parsePostmanHeaders(req.header);
   const bodyParts = parsePostmanBody(req.body);
   const authParts = parsePostmanAuth(req.auth);
@@ -308,9 +325,11 @@ function postmanItemToRequest(item: PostmanItem): SavedRequest | null {
   const tab: RequestTab = {
     ...createDefaultTab(),
     name: item.name,
+    description: parsePostmanDescription(req.description),
     method,
     url,
     params: query,
+    pathParams,
     headers,
     ...bodyParts,
     ...authParts,
@@ -390,6 +409,10 @@ function tabToPostmanRequest(tab: RequestTab): PostmanRequest {
     .filter((p) => p.key)
     .map((p) => ({ key: p.key, value: p.value, disabled: !p.enabled }));
 
+  const variable: PostmanVariable[] = (tab.pathParams ?? [])
+    .filter((p) => p.key)
+    .map((p) => ({ key: p.key, value: p.value, disabled: !p.enabled }));
+
   let body: PostmanBody | undefined;
   if (tab.bodyType === "json") {
     body = {
@@ -444,10 +467,12 @@ function tabToPostmanRequest(tab: RequestTab): PostmanRequest {
 
   return {
     method: tab.method,
+    description: tab.description || undefined,
     header: headers,
     url: {
       raw: tab.url,
       query: query.length > 0 ? query : undefined,
+      variable: variable.length > 0 ? variable : undefined,
     },
     body,
     auth,
@@ -0,0 +1,138 @@
+'use client';
+
+/**
+ * Single request / folder export
+ *
+ * Shares one saved request or a folder subtree as a small Getman JSON file
+ * that can be imported into any collection. IDs are replaced on import so
+ * the same file can be imported repeatedly without collisions.
+ */
+
+import {
+  type CollectionFolder,
+  type RequestTab,
+  type SavedRequest,
+  normalizeRequestTab,
+  uid,
+} from "./getman-store";
+
+export const REQUEST_EXPORT_FORMAT = "getman.request-export";
+
+export type RequestExportItem =
+  | { type: "request"; request: SavedRequest }
+  | { type: "folder"; folder: CollectionFolder };
+
+interface RequestExportFile {
+  format: typeof REQUEST_EXPORT_FORMAT;
+  version: 1;
+  item: RequestExportItem;
+}
+
+/** Drop links to the collection the request was saved in. */
+function detachRequest(request: SavedRequest): SavedRequest {
+  const tab: RequestTab = {
+    ...request.tab,
+    sourceCollectionId: undefined,
+    sourceFolderPath: [],
+    sourceRequestId: undefined,
+  };
+  return { ...request, tab };
+}
+
+function detachFolder(folder: CollectionFolder): CollectionFolder {
+  return {
+    ...folder,
+    requests: folder.requests.map(detachRequest),
+    folders: folder.folders.map(detachFolder),
+  };
+}
+
+export function exportRequestItem(item: RequestExportItem): string {
+  const file: RequestExportFile = {
+    format: REQUEST_EXPORT_FORMAT,
+    version: 1,
+    item:
+      item.type === "request"
+        ? { type: "request", request: detachRequest(item.request) }
+        : { type: "folder", folder: detachFolder(item.folder) },
+  };
+  return JSON.stringify(file, null, 2);
+}
+
+export function requestExportFilename(item: RequestExportItem): string {
+  const name = item.type === "request" ? item.request.name : item.folder.name;
+  const safe = name.replace(/[\\/:*?"<>|]+/g, "_").trim() || item.type;
+  return `${safe}.${item.type}.getman.json`;
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+function isSavedRequest(value: unknown): value is SavedRequest {
+  return isRecord(value) && isRecord(value.tab) && typeof value.name === "string";
+}
+
+function isFolder(value: unknown): value is CollectionFolder {
+  return (
+    isRecord(value) &&
+    typeof value.name === "string" &&
+    Array.isArray(value.requests) &&
+    value.requests.every(isSavedRequest) &&
+    (value.folders === undefined ||
+      (Array.isArray(value.folders) && value.folders.every(isFolder)))
+  );
+}
+
+/** Parse an exported file, throwing a readable error for anything else. */
+export function parseRequestExport(raw: string): RequestExportItem {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(raw);
+  } catch {
+    throw new Error("The file is not valid JSON");
+  }
+  if (!isRecord(parsed) || parsed.format !== REQUEST_EXPORT_FORMAT) {
+    throw new Error("The file is not a Getman request or folder export");
+  }
+  if (parsed.version !== 1) {
+    throw new Error(`Unsupported export version: ${String(parsed.version)}`);
+  }
+  const item = parsed.item;
+  if (isRecord(item) && item.type === "request" && isSavedRequest(item.request)) {
+    return { type: "request", request: item.request };
+  }
+  if (isRecord(item) && item.type === "folder" && isFolder(item.folder)) {
+    return { type: "folder", folder: { ...item.folder, folders: item.folder.folders ?? [] } };
+  }
+  throw new Error("The export does not contain a request or folder");
+}
+
+function remapRequest(request: SavedRequest): SavedRequest {
+  const id = uid();
+  return {
+    ...request,
+    id,
+    tab: normalizeRequestTab({
+      ...(JSON.parse(JSON.stringify(request.tab)) as RequestTab),
+      id: uid(),
+      sourceRequestId: id,
+    }),
+  };
+}
+
+function remapFolder(folder: CollectionFolder): CollectionFolder {
+  return {
+    ...folder,
+    id: uid(),
+    requests: folder.requests.map(remapRequest),
+    folders: (folder.folders ?? []).map(remapFolder),
+  };
+}
+
+/** Give every request, tab and folder in `item` a fresh ID. */
+export function remapRequestExportIds(item: RequestExportItem): RequestExportItem {
+  return item.type === "request"
+    ? { type: "request", request: remapRequest(item.request) }
+    : { type: "folder", folder: remapFolder(item.folder) };
+}
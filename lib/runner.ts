@@ -3,7 +3,7 @@
 /**
  * Collection Runner
  *
- * Runs all requests in a collection sequentially or in parallel,
+ * Runs all requests in a collection, or in one of its folders, sequentially or in parallel,
  * with optional CSV/JSON data-driven parameterization.
  */
 
@@ -15,15 +15,27 @@ import type {
   ResponseData,
   RequestTab,
   HttpMethod,
+  Workflow,
+  WorkflowStep,
 } from "./getman-store";
 import {
+  applyEnvironmentBaseUrl,
+  applyPathParams,
   resolveEnvVariables,
   getVariableScopeSnapshot,
   uid,
   createDefaultTab,
+  defaultSettings,
+  parseResolveOverrides,
+  proxyPayloadFields,
+  resolveDefaultHeaders,
+  configuredUserAgent,
+  configuredReplayMode,
+  effectiveTimeoutMs,
+  isValidHttpMethod,
 } from "./getman-store";
 import { sendHttpRequest, type SendRequestPayload } from "./tauri";
-import { runAssertions } from "./assertions";
+import { evaluateJsonPath, runAssertions } from "./assertions";
 import type { AssertionResult } from "./getman-store";
 import {
   executePreRequestScript,
@@ -43,6 +55,11 @@ export interface RunnerOptions {
   trafficRecorder?: TrafficRecorderOptions;
   chaos?: ChaosOptions;
   performanceLab?: PerformanceLabOptions;
+  /**
+   * In parallel mode, hold back requests that read a variable another
+   * request's scripts capture until that request has run. On by default.
+   */
+  orderCaptures?: boolean;
 }
 
 export interface DataSource {
@@ -115,6 +132,8 @@ export interface RunnerResult {
   results: RunnerRequestResult[];
   effectiveMode: "serial" | "parallel";
   flowOrchestratorUsed: boolean;
+  /** Batches a parallel run was split into by capture ordering; 1 when nothing waited. */
+  captureWaves: number;
   contractGuardUsed: boolean;
   contractDrifts: ContractDriftIssue[];
   contractGateFailed: boolean;
@@ -134,11 +153,29 @@ export interface RunnerResult {
 
 export type RunnerProgressCallback = (current: number, total: number, result: RunnerRequestResult) => void;
 
-interface RequestExecutionTarget {
+export interface RequestExecutionTarget {
   request: SavedRequest;
+  /** Folders from the collection root down to the request's own folder. */
   folderChain: CollectionFolder[];
 }
 
+/** Per-request outcome counts for one folder and everything below it. */
+export interface FolderRunTotals {
+  passed: number;
+  failed: number;
+  skipped: number;
+}
+
+/** Results of a folder run, shaped like the folder tree. */
+export interface FolderRunNode {
+  folderId: string;
+  folderName: string;
+  /** Results of the requests directly in this folder, in document order. */
+  results: RunnerRequestResult[];
+  folders: FolderRunNode[];
+  totals: FolderRunTotals;
+}
+
 // ─── Data Parsing ────────────────────────────────────────────────────────────
 
 function parseCsvData(csv: string): Record<string, string>[] {
@@ -184,15 +221,6 @@ function parseDataSource(source?: DataSource): Record<string, string>[] {
 
 const CONTRACT_BASELINE_PREFIX = "getman.contract.baseline.v1.";
 const PERFORMANCE_BASELINE_PREFIX = "getman.performance.baseline.v1.";
-const HTTP_METHODS: HttpMethod[] = [
-  "GET",
-  "POST",
-  "PUT",
-  "PATCH",
-  "DELETE",
-  "HEAD",
-  "OPTIONS",
-];
 type ChaosCase = "strip-body" | "drop-auth" | "invalid-json" | "random-method";
 
 function getChaosCases(options?: ChaosOptions): ChaosCase[] {
@@ -203,7 +231,7 @@ function getChaosCases(options?: ChaosOptions): ChaosCase[] {
 }
 
 function normalizeMethod(method: string): HttpMethod {
-  return HTTP_METHODS.includes(method as HttpMethod) ? (method as HttpMethod) : "GET";
+  return isValidHttpMethod(method) ? method : "GET";
 }
 
 function loadLocalStorageJson<T>(key: string): T | undefined {
@@ -270,7 +298,7 @@ function percentile(sortedValues: number[], pct: number): number {
   return sortedValues[index];
 }
 
-function computeDurationMetrics(values: number[]): DurationMetrics | undefined {
+export function computeDurationMetrics(values: number[]): DurationMetrics | undefined {
   if (values.length === 0) return undefined;
   const sorted = [...values].sort((a, b) => a - b);
   const sum = sorted.reduce((acc, v) => acc + v, 0);
@@ -364,17 +392,18 @@ function variablesToMap(variables?: EnvVariable[]): Record<string, string> {
 
 // ─── Build Request Payload ──────────────────────────────────────────────────
 
-function buildPayloadFromTab(
-  tab: RequestTab,
+interface ResolveScope {
+  collectionVariables?: EnvVariable[];
+  folderVariables?: EnvVariable[][];
+  requestVariables?: EnvVariable[];
+  runtimeVariables?: Record<string, string>;
+}
+
+function scopeResolver(
   dataRow: Record<string, string>,
-  resolveScope?: {
-    collectionVariables?: EnvVariable[];
-    folderVariables?: EnvVariable[][];
-    requestVariables?: EnvVariable[];
-    runtimeVariables?: Record<string, string>;
-  }
-): SendRequestPayload {
-  const resolveAll = (s: string) =>
+  resolveScope?: ResolveScope
+): (value: string) => string {
+  return (s: string) =>
     substituteDataVariables(
       resolveEnvVariables(s, {
         collectionVariables: resolveScope?.collectionVariables,
@@ -384,6 +413,14 @@ function buildPayloadFromTab(
       }),
       dataRow
     );
+}
+
+function buildPayloadFromTab(
+  tab: RequestTab,
+  dataRow: Record<string, string>,
+  resolveScope?: ResolveScope
+): SendRequestPayload {
+  const resolveAll = scopeResolver(dataRow, resolveScope);
 
   const headers: Record<string, string> = {};
   for (const h of tab.headers) {
@@ -429,24 +466,128 @@ function buildPayloadFromTab(
       body = params.toString();
     }
   }
+  const bodyFile =
+    tab.bodyType === "file" && !["GET", "HEAD", "OPTIONS"].includes(tab.method)
+      ? resolveAll(tab.bodyFilePath).trim() || undefined
+      : undefined;
 
-  const url = resolveAll(tab.url);
+  const url = applyPathParams(
+    applyEnvironmentBaseUrl(resolveAll(tab.url), resolveAll),
+    tab.pathParams,
+    resolveAll
+  );
   const requestId = uid();
 
   return {
     url,
     method: tab.method,
     headers,
+    defaultHeaders: resolveDefaultHeaders(tab.settings, resolveAll),
     body,
+    bodyFile,
     requestId,
-    timeoutMs: tab.settings?.timeoutMs || undefined,
+    timeoutMs: effectiveTimeoutMs(tab.settings),
     retryCount: tab.settings?.retryCount || undefined,
     retryDelayMs: tab.settings?.retryDelayMs || undefined,
-    proxyUrl: tab.settings?.proxyUrl || undefined,
+    httpVersion:
+      tab.settings?.httpVersion && tab.settings.httpVersion !== "auto"
+        ? tab.settings.httpVersion
+        : undefined,
+    resolveOverrides: parseResolveOverrides(tab.settings?.resolveOverrides || ""),
+    maxResponseBytes:
+      (tab.settings?.maxResponseMb || 0) > 0
+        ? Math.round((tab.settings?.maxResponseMb || 0) * 1024 * 1024)
+        : undefined,
+    disableDecompression: tab.settings?.disableDecompression || undefined,
+    requestCompression:
+      tab.settings?.requestCompression && tab.settings.requestCompression !== "none"
+        ? tab.settings.requestCompression
+        : undefined,
+    detectContentType: tab.settings?.autoContentType || undefined,
+    freshConnection: tab.settings?.freshConnection || undefined,
+    expectContinue: tab.settings?.expectContinue || undefined,
+    followPagination: tab.settings?.followPagination || undefined,
+    maxPages: tab.settings?.followPagination ? tab.settings.maxPages || undefined : undefined,
+    traceRedirects: tab.settings?.traceRedirects || undefined,
+    maxRedirects: tab.settings?.traceRedirects ? tab.settings.maxRedirects : undefined,
+    userAgent: configuredUserAgent(),
+    replayMode: configuredReplayMode(),
+    ...proxyPayloadFields(tab.settings || defaultSettings()),
     verifySsl: tab.settings?.verifySsl ?? true,
+    minTlsVersion: tab.settings?.minTlsVersion,
+    maxTlsVersion: tab.settings?.maxTlsVersion,
   };
 }
 
+// ─── Capture Ordering ────────────────────────────────────────────────────────
+
+const CAPTURE_SET_PATTERN = /\bpm\.(?:environment|globals|collectionVariables)\.set\(\s*(["'`])([^"'`]+)\1/g;
+const VARIABLE_GET_PATTERN =
+  /\bpm\.(?:environment|globals|collectionVariables|variables)\.get\(\s*(["'`])([^"'`]+)\1/g;
+const TEMPLATE_PATTERN = /\{\{\s*([^{}\s]+)\s*\}\}/g;
+
+function matchedNames(text: string, pattern: RegExp, group: number): string[] {
+  return Array.from(text.matchAll(pattern), (match) => match[group]);
+}
+
+function requestScripts(tab: RequestTab): string {
+  return `${tab.preRequestScript || ""}\n${tab.testScript || ""}`;
+}
+
+/**
+ * Variables a request's own scripts write to a scope the whole run shares:
+ * the environment, globals or collection variables. `pm.variables.set` only
+ * lasts for the request itself, so it is not a capture.
+ */
+export function capturedVariables(tab: RequestTab): Set<string> {
+  return new Set(matchedNames(requestScripts(tab), CAPTURE_SET_PATTERN, 2));
+}
+
+/** Variables a request reads: `{{name}}` anywhere in it, or `pm.*.get("name")` in its scripts. */
+export function referencedVariables(tab: RequestTab): Set<string> {
+  return new Set([
+    ...matchedNames(JSON.stringify(tab), TEMPLATE_PATTERN, 1),
+    ...matchedNames(requestScripts(tab), VARIABLE_GET_PATTERN, 2),
+  ]);
+}
+
+/**
+ * Split `targets` into waves that can each run in parallel. A request that
+ * reads a captured variable waits for every request capturing it, and
+ * requests capturing the same variable someone reads keep their collection
+ * order so the value read is always the same. Independent requests all
+ * land in the first wave. A capture cycle is broken in collection order.
+ */
+export function planCaptureWaves<T extends { request: SavedRequest }>(targets: T[]): T[][] {
+  const captures = targets.map((target) => capturedVariables(target.request.tab));
+  const waitsFor = targets.map(() => new Set<number>());
+
+  targets.forEach((target, consumer) => {
+    for (const name of referencedVariables(target.request.tab)) {
+      const producers = captures.flatMap((names, index) => (names.has(name) ? [index] : []));
+      for (const producer of producers) {
+        if (producer !== consumer) waitsFor[consumer].add(producer);
+      }
+      for (let i = 1; i < producers.length; i++) {
+        waitsFor[producers[i]].add(producers[i - 1]);
+      }
+    }
+  });
+
+  const waves: T[][] = [];
+  const done = new Set<number>();
+  while (done.size < targets.length) {
+    const pending = targets.map((_, index) => index).filter((index) => !done.has(index));
+    let ready = pending.filter((index) => [...waitsFor[index]].every((dep) => done.has(dep)));
+    if (ready.length === 0) {
+      ready = [pending[0]];
+    }
+    for (const index of ready) done.add(index);
+    waves.push(ready.map((index) => targets[index]));
+  }
+  return waves;
+}
+
 // ─── Runner ──────────────────────────────────────────────────────────────────
 
 function collectFolderRequests(
@@ -467,6 +608,32 @@ function collectFolderRequests(
   return targets;
 }
 
+/**
+ * Every request in `folder` and its subfolders in document order: the
+ * folder's own requests first, then each subfolder depth-first.
+ * `ancestors` are the folders above `folder`, so their variables and
+ * scripts still apply when it runs alone.
+ */
+export function flattenFolderRequests(
+  folder: CollectionFolder,
+  ancestors: CollectionFolder[] = []
+): RequestExecutionTarget[] {
+  return collectFolderRequests([folder], ancestors);
+}
+
+/** Folders from the collection root down to and including `folderId`. */
+export function findFolderChain(
+  folders: CollectionFolder[],
+  folderId: string
+): CollectionFolder[] | null {
+  for (const folder of folders) {
+    if (folder.id === folderId) return [folder];
+    const chain = findFolderChain(folder.folders, folderId);
+    if (chain) return [folder, ...chain];
+  }
+  return null;
+}
+
 function getAllRequests(collection: Collection): RequestExecutionTarget[] {
   const rootTargets: RequestExecutionTarget[] = collection.requests.map((request) => ({
     request,
@@ -627,12 +794,13 @@ async function runSingleRequest(
     { name: `${req.name}::test`, script: req.tab.testScript || "" },
   ].filter((entry) => entry.script.trim());
 
-  let payload = buildPayloadFromTab(req.tab, dataRow, {
+  const resolveScope: ResolveScope = {
     collectionVariables: collection.variables,
     folderVariables: target.folderChain.map((folder) => folder.variables || []),
     requestVariables: req.tab.variables,
     runtimeVariables,
-  });
+  };
+  let payload = buildPayloadFromTab(req.tab, dataRow, resolveScope);
   const start = performance.now();
 
   try {
@@ -650,7 +818,7 @@ async function runSingleRequest(
       });
     }
 
-    payload = await applyAdvancedAuth(payload, req.tab);
+    payload = await applyAdvancedAuth(payload, req.tab, scopeResolver(dataRow, resolveScope));
     if (chaosCase) {
       payload = applyChaosCase(payload, chaosCase);
     }
@@ -739,12 +907,82 @@ export async function runCollection(
   onProgress?: RunnerProgressCallback,
   signal?: AbortSignal,
 ): Promise<RunnerResult> {
-  const requests = getAllRequests(collection);
+  return runTargets(collection, getAllRequests(collection), options, onProgress, signal);
+}
+
+/**
+ * Run every request in one folder of `collection`, subfolders included,
+ * serially and in document order.
+ */
+export async function runFolder(
+  collection: Collection,
+  folderId: string,
+  onProgress?: RunnerProgressCallback,
+  signal?: AbortSignal,
+): Promise<RunnerResult> {
+  const chain = findFolderChain(collection.folders, folderId);
+  if (!chain) throw new Error("Folder not found");
+  const folder = chain[chain.length - 1];
+  const requests = flattenFolderRequests(folder, chain.slice(0, -1));
+  const result = await runTargets(
+    collection,
+    requests,
+    { mode: "serial", delayMs: 0, iterations: 1 },
+    onProgress,
+    signal
+  );
+  return { ...result, collectionName: `${collection.name} / ${folder.name}` };
+}
+
+/** Passed means a 2xx/3xx status with every assertion passing. */
+export function requestOutcome(result: RunnerRequestResult): keyof FolderRunTotals {
+  if (result.skipped) return "skipped";
+  return isFlowSuccess(result) ? "passed" : "failed";
+}
+
+/** Arrange the results of a folder run into the tree of `folder`. */
+export function buildFolderRunReport(
+  folder: CollectionFolder,
+  results: RunnerRequestResult[]
+): FolderRunNode {
+  const byRequest = new Map<string, RunnerRequestResult[]>();
+  for (const result of results) {
+    const list = byRequest.get(result.requestId) ?? [];
+    list.push(result);
+    byRequest.set(result.requestId, list);
+  }
+
+  const build = (current: CollectionFolder): FolderRunNode => {
+    const own = current.requests.flatMap((request) => byRequest.get(request.id) ?? []);
+    const folders = current.folders.map(build);
+    const totals: FolderRunTotals = { passed: 0, failed: 0, skipped: 0 };
+    for (const result of own) totals[requestOutcome(result)]++;
+    for (const child of folders) {
+      totals.passed += child.totals.passed;
+      totals.failed += child.totals.failed;
+      totals.skipped += child.totals.skipped;
+    }
+    return { folderId: current.id, folderName: current.name, results: own, folders, totals };
+  };
+  return build(folder);
+}
+
+async function runTargets(
+  collection: Collection,
+  requests: RequestExecutionTarget[],
+  options: RunnerOptions,
+  onProgress?: RunnerProgressCallback,
+  signal?: AbortSignal,
+): Promise<RunnerResult> {
   const flowOrchestratorUsed = requests.some((target) => hasFlowRules(target.request.tab));
   const effectiveMode =
     flowOrchestratorUsed && options.mode === "parallel"
       ? "serial"
       : options.mode;
+  const captureWaves =
+    effectiveMode === "parallel" && options.orderCaptures !== false
+      ? planCaptureWaves(requests)
+      : [requests];
   const variableScopes = getVariableScopeSnapshot();
   const dataRows = parseDataSource(options.dataSource);
   const iterations = Math.max(1, options.iterations || dataRows.length);
@@ -770,29 +1008,32 @@ export async function runCollection(
     const resultByName = new Map<string, RunnerRequestResult>();
 
     if (effectiveMode === "parallel") {
-      const jobs = requests.flatMap((target) => [
-        { target, chaosCase: undefined as ChaosCase | undefined },
-        ...chaosCases.map((chaosCase) => ({ target, chaosCase })),
-      ]);
-      const promises = jobs.map(async ({ target, chaosCase }) => {
-        if (signal?.aborted) return null;
-        const result = await runSingleRequest(
-          target,
-          collection,
-          iter,
-          dataRow,
-          variableScopes,
-          chaosCase
-        );
-        if (!result.skipped && !result.chaosCase && contractGuardUsed) {
-          currentContractSignatures[target.request.id] = buildContractSignature(result.response);
-        }
-        completed++;
-        onProgress?.(completed, totalRequests, result);
-        return result;
-      });
-      const batchResults = await Promise.all(promises);
-      results.push(...batchResults.filter((r): r is RunnerRequestResult => r !== null));
+      for (const wave of captureWaves) {
+        if (signal?.aborted) break;
+        const jobs = wave.flatMap((target) => [
+          { target, chaosCase: undefined as ChaosCase | undefined },
+          ...chaosCases.map((chaosCase) => ({ target, chaosCase })),
+        ]);
+        const promises = jobs.map(async ({ target, chaosCase }) => {
+          if (signal?.aborted) return null;
+          const result = await runSingleRequest(
+            target,
+            collection,
+            iter,
+            dataRow,
+            variableScopes,
+            chaosCase
+          );
+          if (!result.skipped && !result.chaosCase && contractGuardUsed) {
+            currentContractSignatures[target.request.id] = buildContractSignature(result.response);
+          }
+          completed++;
+          onProgress?.(completed, totalRequests, result);
+          return result;
+        });
+        const batchResults = await Promise.all(promises);
+        results.push(...batchResults.filter((r): r is RunnerRequestResult => r !== null));
+      }
     } else {
       for (const target of requests) {
         const req = target.request;
@@ -945,6 +1186,7 @@ export async function runCollection(
     results,
     effectiveMode,
     flowOrchestratorUsed,
+    captureWaves: captureWaves.length,
     contractGuardUsed,
     contractDrifts,
     contractGateFailed,
@@ -979,6 +1221,9 @@ export function generateTextReport(result: RunnerResult): string {
   lines.push(`  Skipped:           ${result.skippedRequests}`);
   lines.push(`  Mode:              ${result.effectiveMode}`);
   lines.push(`  Flow Orchestrator: ${result.flowOrchestratorUsed ? "on" : "off"}`);
+  if (result.captureWaves > 1) {
+    lines.push(`  Capture Waves:     ${result.captureWaves}`);
+  }
   lines.push(`  Contract Guard:    ${result.contractGuardUsed ? "on" : "off"}`);
   lines.push(`  Contract Drifts:   ${result.contractDrifts.length}`);
   lines.push(`  Chaos Engine:      ${result.chaosUsed ? result.chaosLevel : "off"}`);
@@ -1057,3 +1302,198 @@ export function generateJsonReport(result: RunnerResult): string {
     exitCode,
   }, null, 2);
 }
+
+// ─── Workflows ───────────────────────────────────────────────────────────────
+
+export interface WorkflowStepReport {
+  stepId: string;
+  stepName: string;
+  status: "passed" | "failed" | "skipped";
+  result?: RunnerRequestResult;
+  /** Values taken from earlier steps, keyed by the reference that used them. */
+  captured: Record<string, string>;
+  error?: string;
+}
+
+export interface WorkflowReport {
+  workflowName: string;
+  passed: boolean;
+  steps: WorkflowStepReport[];
+  totalDuration: number;
+}
+
+export type WorkflowProgressCallback = (index: number, report: WorkflowStepReport) => void;
+
+const STEP_REFERENCE_PATTERN = /\{\{([^{}]+)\}\}/g;
+
+function findWorkflowTarget(
+  step: WorkflowStep,
+  collections: Collection[]
+): { target: RequestExecutionTarget; collection: Collection } | null {
+  const collection = collections.find((c) => c.id === step.collectionId);
+  const target = collection
+    ? getAllRequests(collection).find((t) => t.request.id === step.requestId)
+    : undefined;
+  return collection && target ? { target, collection } : null;
+}
+
+function referencedTabStrings(tab: RequestTab): string[] {
+  return [
+    tab.url,
+    tab.bodyContent,
+    tab.authToken,
+    tab.authUsername,
+    tab.authPassword,
+    tab.authApiKey,
+    tab.authApiValue,
+    ...tab.headers.flatMap((h) => (h.enabled ? [h.key, h.value] : [])),
+    ...tab.bodyFormData.flatMap((f) => (f.enabled ? [f.value] : [])),
+    ...(tab.cookies ?? []).flatMap((c) => (c.enabled ? [c.key, c.value] : [])),
+  ].filter((value): value is string => Boolean(value));
+}
+
+/**
+ * Resolve `status`, `statusText`, `headers.<name>`, `body` or a JSON path
+ * under `body` (e.g. `body.items[0].id`) against a step's response.
+ */
+export function resolveStepReference(response: ResponseData, path: string): string {
+  if (path === "status") return String(response.status);
+  if (path === "statusText") return response.statusText;
+  if (path === "body") return response.body;
+
+  if (path.startsWith("headers.")) {
+    const name = path.slice("headers.".length).toLowerCase();
+    const header = Object.entries(response.headers).find(([key]) => key.toLowerCase() === name);
+    if (!header) throw new Error(`response has no "${name}" header`);
+    return header[1];
+  }
+
+  if (path.startsWith("body.") || path.startsWith("body[")) {
+    let json: unknown;
+    try {
+      json = JSON.parse(response.body);
+    } catch {
+      throw new Error("response body is not JSON");
+    }
+    const value = evaluateJsonPath(json, `$${path.slice("body".length)}`);
+    if (value === undefined) throw new Error(`"${path}" not found in response body`);
+    return typeof value === "object" && value !== null ? JSON.stringify(value) : String(value);
+  }
+
+  throw new Error(`unsupported reference "${path}"`);
+}
+
+function captureStepReferences(
+  tab: RequestTab,
+  aliases: Set<string>,
+  responses: Map<string, ResponseData>
+): Record<string, string> {
+  const captured: Record<string, string> = {};
+  for (const text of referencedTabStrings(tab)) {
+    for (const match of text.matchAll(STEP_REFERENCE_PATTERN)) {
+      const placeholder = match[1];
+      const reference = placeholder.trim();
+      const dot = reference.indexOf(".");
+      const alias = dot > 0 ? reference.slice(0, dot) : "";
+      if (!aliases.has(alias) || placeholder in captured) continue;
+
+      const response = responses.get(alias);
+      if (!response) {
+        throw new Error(`{{${reference}}}: step "${alias}" has not run yet`);
+      }
+      try {
+        captured[placeholder] = resolveStepReference(response, reference.slice(dot + 1));
+      } catch (error) {
+        const message = error instanceof Error ? error.message : "unresolved reference";
+        throw new Error(`{{${reference}}}: ${message}`);
+      }
+    }
+  }
+  return captured;
+}
+
+function describeStepFailure(result: RunnerRequestResult): string {
+  const failed = result.assertionResults.filter((assertion) => !assertion.passed).length;
+  if (result.response.status < 200 || result.response.status >= 400) {
+    return result.response.status === 0
+      ? result.response.body || result.response.statusText
+      : `HTTP ${result.response.status} ${result.response.statusText}`.trim();
+  }
+  return `${failed} assertion${failed === 1 ? "" : "s"} failed`;
+}
+
+/**
+ * Run workflow steps in order against the active environment. Each step can
+ * reference earlier responses as `{{<step>.status}}`, `{{<step>.headers.X}}`
+ * or `{{<step>.body.<path>}}`; the first failing step stops the run.
+ */
+export async function runWorkflow(
+  workflow: Workflow,
+  collections: Collection[],
+  onProgress?: WorkflowProgressCallback,
+  signal?: AbortSignal
+): Promise<WorkflowReport> {
+  const variableScopes = getVariableScopeSnapshot();
+  const aliases = new Set(workflow.steps.map((step) => step.name.trim()).filter(Boolean));
+  const responses = new Map<string, ResponseData>();
+  const steps: WorkflowStepReport[] = [];
+  const startTime = performance.now();
+  let stopReason: string | null = null;
+
+  for (const [index, step] of workflow.steps.entries()) {
+    let report: WorkflowStepReport = {
+      stepId: step.id,
+      stepName: step.name,
+      status: "skipped",
+      captured: {},
+    };
+
+    if (!stopReason && signal?.aborted) {
+      stopReason = "Run cancelled";
+    }
+    const found = findWorkflowTarget(step, collections);
+    if (stopReason) {
+      report.error = stopReason;
+    } else if (!found) {
+      report = { ...report, status: "failed", error: "Saved request not found" };
+    } else {
+      try {
+        const captured = captureStepReferences(found.target.request.tab, aliases, responses);
+        const result = await runSingleRequest(
+          found.target,
+          found.collection,
+          0,
+          captured,
+          variableScopes
+        );
+        const passed = isFlowSuccess(result);
+        report = {
+          ...report,
+          status: passed ? "passed" : "failed",
+          result,
+          captured,
+          error: passed ? undefined : describeStepFailure(result),
+        };
+        if (passed && step.name.trim()) {
+          responses.set(step.name.trim(), result.response);
+        }
+      } catch (error) {
+        const message = error instanceof Error ? error.message : "Step failed";
+        report = { ...report, status: "failed", error: message };
+      }
+    }
+
+    if (report.status === "failed") {
+      stopReason = `Stopped after "${step.name || `step ${index + 1}`}" failed`;
+    }
+    steps.push(report);
+    onProgress?.(index, report);
+  }
+
+  return {
+    workflowName: workflow.name,
+    passed: steps.length > 0 && steps.every((step) => step.status === "passed"),
+    steps,
+    totalDuration: Math.round(performance.now() - startTime),
+  };
+}
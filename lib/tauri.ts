@@ -1,21 +1,76 @@
 'use client';
 
+export type HttpVersionPreference = "auto" | "http1_only" | "http2_prior_knowledge";
+
+export type RequestCompression = "none" | "gzip" | "brotli";
+
+/** TLS versions a request can be pinned to; older ones are never negotiated. */
+export type TlsVersion = "1.2" | "1.3";
+
 export interface SendRequestPayload {
   url: string;
   method: string;
   headers: Record<string, string>;
+  /** App-wide headers, sent unless `headers` has the same name (any case). */
+  defaultHeaders?: Record<string, string>;
   body?: string;
   requestId?: string;
   timeoutMs?: number;
   retryCount?: number;
   retryDelayMs?: number;
+  httpVersion?: HttpVersionPreference;
+  resolveOverrides?: Record<string, string>;
+  maxResponseBytes?: number;
+  /** Skip the automatic Accept-Encoding and keep compressed bodies encoded. */
+  disableDecompression?: boolean;
+  requestCompression?: RequestCompression;
+  /** Send `Expect: 100-continue` and hold the body back until the server had a chance to reject it. */
+  expectContinue?: boolean;
+  /** How long the body is held back for `expectContinue`; 1000 ms by default. */
+  expectContinueTimeoutMs?: number;
+  /** Revalidate with the ETag/Last-Modified of the previous response (desktop only). */
+  conditional?: boolean;
+  /** Sniff a Content-Type (JSON, XML or plain text) when the body has none. */
+  detectContentType?: boolean;
+  /** Skip the shared connection pool and send `Connection: close`. */
+  freshConnection?: boolean;
+  /** Follow `Link: rel="next"` and merge JSON array pages (desktop only). */
+  followPagination?: boolean;
+  /** Page limit when following pagination, the first page included. */
+  maxPages?: number;
+  /** Follow redirects one hop at a time and return the chain (desktop only). */
+  traceRedirects?: boolean;
+  /** Redirect limit when tracing redirects; defaults to 10. */
+  maxRedirects?: number;
+  /** User-Agent sent when `headers` has none; blank uses the app default (desktop only). */
+  userAgent?: string;
+  /** Record the response, or answer from a recorded one (desktop only). */
+  replayMode?: "record" | "replay";
   proxyUrl?: string;
+  proxyUsername?: string;
+  proxyPassword?: string;
+  noProxy?: string[];
+  /** Connect directly, ignoring HTTP_PROXY / HTTPS_PROXY too. */
+  bypassProxy?: boolean;
   verifySsl?: boolean;
+  /** Lowest TLS version accepted; unset keeps the default. */
+  minTlsVersion?: TlsVersion;
+  /** Highest TLS version offered; unset keeps the default. */
+  maxTlsVersion?: TlsVersion;
   digestUsername?: string;
   digestPassword?: string;
   ntlmUsername?: string;
   ntlmPassword?: string;
   ntlmDomain?: string;
+  ntlmWorkstation?: string;
+  formFields?: FormField[];
+  /** Path of a local file streamed as the body in place of `body` (desktop only). */
+  bodyFile?: string;
+}
+
+export interface FormField {
+  key: string;
+  value: string;
 }
 
 export interface HttpResponseData {
@@ -26,6 +81,82 @@ export interface HttpResponseData {
   time: number;
   size: number;
   contentType: string;
+  /** Negotiated protocol, e.g. "HTTP/1.1" or "HTTP/2" (desktop only). */
+  httpVersion?: string;
+  /** Body was cut off at `maxResponseBytes`; `size` is the bytes kept. */
+  truncated?: boolean;
+  /** Full size from Content-Length when the server reported it. */
+  totalSize?: number;
+  /** Body bytes received before content decoding (desktop only). */
+  wireSize?: number;
+  /** Body bytes after content decoding; equals `size`. */
+  decodedSize?: number;
+  /** Response header block bytes (desktop only). */
+  headerSize?: number;
+  /** Body bytes as received; equals `wireSize` (desktop only). */
+  bodySize?: number;
+  /** Request body bytes sent, after request compression (desktop only). */
+  bytesOut?: number;
+  /** Answered before the `Expect: 100-continue` body was sent; nothing was uploaded. */
+  bodyWithheld?: boolean;
+  /** 304 response whose body was replayed from the previous response. */
+  notModified?: boolean;
+  /** Answered from the record/replay cache; nothing was sent. */
+  replayed?: boolean;
+  /** URL the response came from, after redirects (desktop only). */
+  url?: string;
+  /** Request line, headers and body as written to the connection (desktop only). */
+  rawRequest?: string;
+  /**
+   * Status line and header block rebuilt from the parsed response: names are
+   * lowercased and repeated headers grouped (desktop only).
+   */
+  rawResponseHead?: string;
+  /** Pages merged into the body when following pagination; 0 otherwise. */
+  pages?: number;
+  /** Why pagination stopped early, if it did. */
+  paginationWarning?: string | null;
+  /** Every response of a traced redirect chain, the final one last. */
+  redirectChain?: RedirectHop[];
+  /** Why redirect tracing stopped before a non-redirect answer, if it did. */
+  redirectWarning?: string | null;
+  /** Wait a 429 or 503 response asked for with Retry-After (desktop only). */
+  retryAfterMs?: number | null;
+  /** Encoding the body text was decoded with, e.g. "utf-8" or "shift_jis" (desktop only). */
+  encoding?: string;
+  /** Content-Encoding of a body left compressed by `disableDecompression`; `body` is then base64. */
+  bodyEncoding?: string | null;
+  /** Declared charset that is not supported; the body was decoded as UTF-8 instead. */
+  unsupportedCharset?: string | null;
+  /** Why the request failed, for `status: 0` responses (desktop only). */
+  error?: GetmanError | null;
+}
+
+/** One response in a traced redirect chain. */
+export interface RedirectHop {
+  method: string;
+  url: string;
+  status: number;
+  /** The `Location` followed from this response, resolved to an absolute URL. */
+  location?: string | null;
+}
+
+export type GetmanErrorKind =
+  | "invalidUrl"
+  | "invalidRequest"
+  | "dns"
+  | "connect"
+  | "tls"
+  | "proxy"
+  | "timeout"
+  | "http"
+  | "cancelled"
+  | "io"
+  | "other";
+
+export interface GetmanError {
+  kind: GetmanErrorKind;
+  message: string;
 }
 
 // ─── gRPC Types ──────────────────────────────────────────────────────────────
@@ -40,6 +171,8 @@ export interface GrpcRequestPayload {
   timeoutMs?: number;
   requestId?: string;
   descriptorBytes?: string;
+  /** Values for `{{name}}` placeholders left in the endpoint, metadata and request JSON. */
+  variables?: Record<string, string>;
 }
 
 export interface GrpcResponseData {
@@ -80,11 +213,23 @@ export interface ProtoFieldInfo {
 }
 
 const LOCAL_STATE_KEY = "getman.state.v1";
+const LOCAL_SETTINGS_KEY = "getman.settings.v1";
 
 function isBodyAllowed(method: string): boolean {
   return !["GET", "HEAD", "OPTIONS"].includes(method.toUpperCase());
 }
 
+export function encodeFormFields(fields: FormField[]): string {
+  const params = new URLSearchParams();
+  fields.forEach((field, index) => {
+    if (!field.key.trim()) {
+      throw new Error(`Form field #${index + 1} has an empty key`);
+    }
+    params.append(field.key, field.value);
+  });
+  return params.toString();
+}
+
 function isTauriRuntime(): boolean {
   return (
     typeof window !== "undefined" &&
@@ -102,13 +247,21 @@ async function fetchResponse(payload: SendRequestPayload): Promise<HttpResponseD
     abortControllers.set(payload.requestId, controller);
   }
 
+  const headers = new Headers(payload.defaultHeaders);
+  for (const [key, value] of Object.entries(payload.headers)) {
+    headers.set(key, value);
+  }
   const requestInit: RequestInit = {
     method: payload.method,
-    headers: payload.headers,
+    headers,
     signal: controller.signal,
   };
 
-  if (payload.body && isBodyAllowed(payload.method)) {
+  if (payload.bodyFile && isBodyAllowed(payload.method)) {
+    throw new Error("File bodies can only be sent from the desktop app");
+  } else if (payload.formFields && isBodyAllowed(payload.method)) {
+    requestInit.body = encodeFormFields(payload.formFields);
+  } else if (payload.body && isBodyAllowed(payload.method)) {
     requestInit.body = payload.body;
   }
 
@@ -168,9 +321,80 @@ function toErrorResponse(error: unknown): HttpResponseData {
   };
 }
 
+/** Requests allowed in flight at once unless configured otherwise. */
+export const DEFAULT_MAX_CONCURRENT_REQUESTS = 16;
+
+let maxConcurrentRequests = DEFAULT_MAX_CONCURRENT_REQUESTS;
+let inFlightRequests = 0;
+const queuedRequests: Array<{ requestId?: string; settle: (start: boolean) => void }> = [];
+
+function startQueuedRequests() {
+  while (inFlightRequests < maxConcurrentRequests && queuedRequests.length > 0) {
+    inFlightRequests += 1;
+    queuedRequests.shift()?.settle(true);
+  }
+}
+
+/** Cap concurrent HTTP sends; queued requests start at once if the limit grew. */
+export function setMaxConcurrentRequests(limit: number) {
+  maxConcurrentRequests =
+    Number.isFinite(limit) && limit >= 1 ? Math.floor(limit) : DEFAULT_MAX_CONCURRENT_REQUESTS;
+  startQueuedRequests();
+}
+
+/**
+ * Wait for a free request slot. Resolves `false` when the request is
+ * cancelled while still queued. `onQueueChange` hears `true` when the request
+ * has to wait and `false` once it leaves the queue.
+ */
+function acquireRequestSlot(
+  requestId: string | undefined,
+  onQueueChange?: (queued: boolean) => void,
+): Promise<boolean> {
+  if (inFlightRequests < maxConcurrentRequests && queuedRequests.length === 0) {
+    inFlightRequests += 1;
+    return Promise.resolve(true);
+  }
+  onQueueChange?.(true);
+  return new Promise((resolve) => {
+    queuedRequests.push({
+      requestId,
+      settle: (start) => {
+        onQueueChange?.(false);
+        resolve(start);
+      },
+    });
+  });
+}
+
+function releaseRequestSlot() {
+  inFlightRequests -= 1;
+  startQueuedRequests();
+}
+
+function cancelQueuedRequest(requestId: string): boolean {
+  const index = queuedRequests.findIndex((item) => item.requestId === requestId);
+  if (index < 0) return false;
+  queuedRequests.splice(index, 1)[0].settle(false);
+  return true;
+}
+
 export async function sendHttpRequest(
-  payload: SendRequestPayload
+  payload: SendRequestPayload,
+  onQueueChange?: (queued: boolean) => void,
 ): Promise<HttpResponseData> {
+  if (!(await acquireRequestSlot(payload.requestId, onQueueChange))) {
+    const message = "Request cancelled";
+    return { ...toErrorResponse(new Error(message)), error: { kind: "cancelled", message } };
+  }
+  try {
+    return await sendHttpRequestNow(payload);
+  } finally {
+    releaseRequestSlot();
+  }
+}
+
+async function sendHttpRequestNow(payload: SendRequestPayload): Promise<HttpResponseData> {
   if (isTauriRuntime()) {
     try {
       const { invoke } = await import("@tauri-apps/api/core");
@@ -195,7 +419,118 @@ export async function sendHttpRequest(
   }
 }
 
+export interface BodyFileInfo {
+  name: string;
+  size: number;
+  /** Sent when the request sets no Content-Type of its own. */
+  contentType: string;
+}
+
+/**
+ * Name and size of a body file. Rejects with the backend's message when the
+ * file is missing; resolves to `null` outside the desktop app.
+ */
+export async function inspectBodyFile(path: string): Promise<BodyFileInfo | null> {
+  if (!isTauriRuntime()) return null;
+  const { invoke } = await import("@tauri-apps/api/core");
+  return invoke<BodyFileInfo>("inspect_body_file", { path });
+}
+
+/**
+ * Why `url` cannot be used as a proxy, or `null` when it can. Only the
+ * desktop app checks; the browser build always resolves to `null`.
+ */
+export async function checkProxyUrl(url: string): Promise<string | null> {
+  if (!isTauriRuntime() || !url.trim()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke<string>("check_proxy_url", { url });
+    return null;
+  } catch (error) {
+    return String(error);
+  }
+}
+
+/** Forget every recorded response; resolves to how many there were. */
+export async function clearResponseCache(): Promise<number> {
+  if (!isTauriRuntime()) return 0;
+  const { invoke } = await import("@tauri-apps/api/core");
+  return invoke<number>("clear_response_cache");
+}
+
+/** The app's built-in User-Agent, e.g. `Getman/1.2.0`; `null` outside the desktop app. */
+export async function getDefaultUserAgent(): Promise<string | null> {
+  if (!isTauriRuntime()) return null;
+  const { invoke } = await import("@tauri-apps/api/core");
+  return invoke<string>("default_user_agent");
+}
+
+/** Whether this desktop build includes the echo server; false in the browser. */
+export async function isEchoServerAvailable(): Promise<boolean> {
+  if (!isTauriRuntime()) return false;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<boolean>("echo_server_available");
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Start the built-in echo server, on a free port unless `port` is given;
+ * resolves to the port it listens on. Rejects outside the desktop app and in
+ * builds without the `echo-server` feature.
+ */
+export async function startEchoServer(port?: number): Promise<number> {
+  if (!isTauriRuntime()) throw new Error("The echo server needs the desktop app");
+  const { invoke } = await import("@tauri-apps/api/core");
+  return invoke<number>("start_echo_server", { port: port || null });
+}
+
+export async function stopEchoServer(): Promise<boolean> {
+  if (!isTauriRuntime()) return false;
+  const { invoke } = await import("@tauri-apps/api/core");
+  return invoke<boolean>("stop_echo_server");
+}
+
+/** Port of the running echo server; `null` when it is stopped or unavailable. */
+export async function getEchoServerPort(): Promise<number | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<number | null>("echo_server_port");
+  } catch {
+    return null;
+  }
+}
+
+export type SendPhase = "resolving" | "connecting" | "waiting" | "downloading";
+
+/** Where a desktop HTTP send is, and how much of its body has arrived. */
+export interface SendProgressSnapshot {
+  phase: SendPhase;
+  bytesReceived: number;
+  /** From `Content-Length`; `null` when the server did not send one. */
+  totalBytes: number | null;
+  elapsedMs: number;
+}
+
+/** Progress of the send with `requestId`; `null` once it has finished or outside the desktop app. */
+export async function pollSendProgress(requestId: string): Promise<SendProgressSnapshot | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<SendProgressSnapshot | null>("poll_send_progress", { requestId });
+  } catch {
+    return null;
+  }
+}
+
 export async function cancelHttpRequest(requestId: string): Promise<boolean> {
+  if (cancelQueuedRequest(requestId)) {
+    return true;
+  }
+
   // Try Tauri first
   if (isTauriRuntime()) {
     try {
@@ -254,6 +589,96 @@ export async function savePersistedState(stateJson: string): Promise<void> {
   window.localStorage.setItem(LOCAL_STATE_KEY, stateJson);
 }
 
+/**
+ * Run `handler` before the window closes. The desktop app waits for it to
+ * finish; a browser tab gets it on `pagehide` without waiting. Returns the
+ * function that removes it.
+ */
+export async function onWindowClose(handler: () => Promise<void>): Promise<() => void> {
+  if (isTauriRuntime()) {
+    try {
+      const { getCurrentWindow } = await import("@tauri-apps/api/window");
+      return await getCurrentWindow().onCloseRequested(handler);
+    } catch {
+      // Fall back to the page lifecycle below.
+    }
+  }
+
+  const listener = () => void handler();
+  window.addEventListener("pagehide", listener);
+  return () => window.removeEventListener("pagehide", listener);
+}
+
+/** Proxy used by requests that do not choose their own. */
+export interface ProxySettings {
+  /** Blank falls back to HTTP_PROXY / HTTPS_PROXY from the environment. */
+  url: string;
+  username: string;
+  password: string;
+  /** Comma-separated hosts, domains or CIDRs that connect directly. */
+  noProxy: string;
+}
+
+/** App-wide preferences, stored apart from the workspace state. */
+export interface AppSettings {
+  theme: "light" | "dark";
+  historyLimit: number;
+  requestLogging: boolean;
+  connectionPool: ConnectionPoolSettings;
+  maxConcurrentRequests: number;
+  requestNaming: "full-url" | "path" | "host-path";
+  prettyPrintLimitKb: number;
+  renderChunkKb: number;
+  slowThresholdMs: number;
+  defaultTimeoutMs: number;
+  restoreDrafts: boolean;
+  defaultHeaders: { id: string; key: string; value: string; enabled: boolean }[];
+  userAgent: string;
+  proxy: ProxySettings;
+  replayMode: "off" | "record" | "replay";
+}
+
+/** Saved settings, or `null` before they are first saved. */
+export async function loadSettings(): Promise<AppSettings | null> {
+  if (typeof window === "undefined") {
+    return null;
+  }
+
+  if (isTauriRuntime()) {
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      return await invoke<AppSettings | null>("load_settings");
+    } catch {
+      // Local storage fallback is used when Tauri invocation fails.
+    }
+  }
+
+  try {
+    const raw = window.localStorage.getItem(LOCAL_SETTINGS_KEY);
+    return raw ? (JSON.parse(raw) as AppSettings) : null;
+  } catch {
+    return null;
+  }
+}
+
+export async function saveSettings(settings: AppSettings): Promise<void> {
+  if (typeof window === "undefined") {
+    return;
+  }
+
+  if (isTauriRuntime()) {
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      await invoke("save_settings", { settings });
+      return;
+    } catch {
+      // Local storage fallback is used when Tauri invocation fails.
+    }
+  }
+
+  window.localStorage.setItem(LOCAL_SETTINGS_KEY, JSON.stringify(settings));
+}
+
 // ─── Environment / Resolve ────────────────────────────────────────────────────
 
 export interface ResolveRequestPayload {
@@ -262,6 +687,8 @@ export interface ResolveRequestPayload {
   headers: Record<string, string>;
   body?: string;
   environmentId?: string | null;
+  /** Collection, folder and request variables in increasing priority. */
+  scopedVariables?: EnvVariablePayload[];
 }
 
 export interface ResolvedRequest {
@@ -271,6 +698,27 @@ export interface ResolvedRequest {
   body?: string;
 }
 
+export type PreviewAuth =
+  | { type: "bearer"; token: string }
+  | { type: "basic"; username: string; password: string }
+  | { type: "api-key"; key: string; value: string; addTo: "header" | "query" }
+  | { type: "computed"; scheme: string };
+
+export interface PreviewRequestPayload extends ResolveRequestPayload {
+  params: { key: string; value: string }[];
+  pathParams?: { key: string; value: string }[];
+  auth?: PreviewAuth;
+  userAgent?: string;
+}
+
+export interface RequestPreview {
+  url: string;
+  method: string;
+  headers: Record<string, string>;
+  body?: string | null;
+  unresolvedVariables: string[];
+}
+
 export interface EnvVariablePayload {
   id: string;
   key: string;
@@ -282,6 +730,22 @@ export interface EnvironmentPayload {
   id: string;
   name: string;
   variables: EnvVariablePayload[];
+  baseUrl?: string;
+}
+
+/**
+ * Prepend `base` to a relative `url` with exactly one slash between them.
+ * Absolute URLs and an empty base pass through. Mirrors `engine/env.rs`.
+ */
+export function joinBaseUrl(base: string, url: string): string {
+  const trimmedBase = base.trim();
+  const trimmedUrl = url.trim();
+  if (!trimmedBase || /^[a-z][a-z0-9+.-]*:\/\//i.test(trimmedUrl)) return trimmedUrl;
+
+  const root = trimmedBase.replace(/\/+$/, "");
+  if (!trimmedUrl) return root;
+  if (trimmedUrl.startsWith("?") || trimmedUrl.startsWith("#")) return root + trimmedUrl;
+  return `${root}/${trimmedUrl.replace(/^\/+/, "")}`;
 }
 
 export async function resolveRequest(
@@ -313,14 +777,19 @@ export async function resolveRequest(
   }
 
   // Environment variables (override globals)
-  if (payload.environmentId) {
-    const env = environments.find((e) => e.id === payload.environmentId);
-    if (env) {
-      for (const v of env.variables) {
-        if (v.enabled && v.key) {
-          variables[v.key] = v.value;
-        }
-      }
+  const env = payload.environmentId
+    ? environments.find((e) => e.id === payload.environmentId)
+    : undefined;
+  for (const v of env?.variables ?? []) {
+    if (v.enabled && v.key) {
+      variables[v.key] = v.value;
+    }
+  }
+
+  // Scoped variables (override the environment)
+  for (const v of payload.scopedVariables ?? []) {
+    if (v.enabled && v.key) {
+      variables[v.key] = v.value;
     }
   }
 
@@ -337,14 +806,33 @@ export async function resolveRequest(
     headers[interpolate(k)] = interpolate(v);
   }
 
+  const url = interpolate(payload.url);
   return {
-    url: interpolate(payload.url),
+    url: env?.baseUrl ? joinBaseUrl(interpolate(env.baseUrl), url) : url,
     method: payload.method,
     headers,
     body: payload.body ? interpolate(payload.body) : undefined,
   };
 }
 
+/** Resolve a request without sending it, with secret values masked. */
+export async function previewRequest(
+  payload: PreviewRequestPayload,
+  globalVariables: EnvVariablePayload[],
+  environments: EnvironmentPayload[]
+): Promise<RequestPreview> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<RequestPreview>("preview_request", {
+      payload,
+      globalVariables,
+      environments,
+    });
+  }
+
+  throw new Error("Request preview is only available in the desktop app");
+}
+
 // ─── gRPC Functions ──────────────────────────────────────────────────────────
 
 export async function parseProtoContent(
@@ -364,6 +852,52 @@ export async function parseProtoContent(
   throw new Error("gRPC is only supported in the desktop app");
 }
 
+export async function buildGrpcRequestTemplate(
+  protoContent: string,
+  descriptorBytes: string | undefined,
+  service: string,
+  method: string
+): Promise<unknown> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<unknown>("build_grpc_request_template", {
+      protoContent,
+      descriptorBytes,
+      service,
+      method,
+    });
+  }
+
+  throw new Error("gRPC is only supported in the desktop app");
+}
+
+export interface GrpcValidationIssue {
+  path: string;
+  kind: "invalid_json" | "unknown_field" | "type_mismatch" | "missing_required" | "invalid";
+  message: string;
+}
+
+export async function validateGrpcRequest(
+  protoContent: string,
+  descriptorBytes: string | undefined,
+  service: string,
+  method: string,
+  requestJson: string
+): Promise<GrpcValidationIssue[]> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<GrpcValidationIssue[]>("validate_grpc_request", {
+      protoContent,
+      descriptorBytes,
+      service,
+      method,
+      requestJson,
+    });
+  }
+
+  throw new Error("gRPC is only supported in the desktop app");
+}
+
 export async function sendGrpcRequest(
   payload: GrpcRequestPayload
 ): Promise<GrpcResponseData> {
@@ -410,3 +944,304 @@ export async function fetchGrpcReflection(
 
   throw new Error("gRPC reflection is only supported in the desktop app");
 }
+
+/** Re-run server reflection for an endpoint, bypassing the cached result. */
+export async function refreshGrpcReflection(
+  endpoint: string
+): Promise<GrpcReflectionResponse> {
+  if (isTauriRuntime()) {
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      return await invoke<GrpcReflectionResponse>("refresh_grpc_reflection", {
+        endpoint,
+      });
+    } catch (error) {
+      throw error instanceof Error ? error : new Error("Failed to refresh gRPC reflection");
+    }
+  }
+
+  throw new Error("gRPC reflection is only supported in the desktop app");
+}
+
+// ─── gRPC Streaming ──────────────────────────────────────────────────────────
+
+export type GrpcStreamStatus = "open" | "completed" | "failed";
+
+export interface GrpcStreamMessage {
+  seq: number;
+  direction: "sent" | "received";
+  json: string;
+  timestamp: number;
+}
+
+export interface GrpcStreamSnapshot {
+  streamId: string;
+  status: GrpcStreamStatus;
+  sendClosed: boolean;
+  statusCode?: number | null;
+  statusMessage?: string | null;
+  responseMetadata: Record<string, string>;
+  messages: GrpcStreamMessage[];
+}
+
+/** Open a client-streaming or bidirectional call; messages are sent separately. */
+export async function startGrpcStream(payload: GrpcRequestPayload): Promise<string> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    const result = await invoke<{ streamId: string }>("start_grpc_stream", { payload });
+    return result.streamId;
+  }
+
+  throw new Error("gRPC streaming is only supported in the desktop app");
+}
+
+export async function sendGrpcStreamMessage(
+  streamId: string,
+  requestJson: string
+): Promise<void> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("send_grpc_stream_message", { streamId, requestJson });
+    return;
+  }
+
+  throw new Error("gRPC streaming is only supported in the desktop app");
+}
+
+export async function closeGrpcStreamSend(streamId: string): Promise<void> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("close_grpc_stream_send", { streamId });
+  }
+}
+
+/** Stream state plus messages with a sequence number above `afterSeq`. */
+export async function pollGrpcStream(
+  streamId: string,
+  afterSeq: number
+): Promise<GrpcStreamSnapshot> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<GrpcStreamSnapshot>("poll_grpc_stream", { streamId, afterSeq });
+  }
+
+  throw new Error("gRPC streaming is only supported in the desktop app");
+}
+
+export async function cancelGrpcStream(streamId: string): Promise<boolean> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<boolean>("cancel_grpc_stream", { streamId });
+  }
+  return false;
+}
+
+// ─── History ─────────────────────────────────────────────────────────────────
+
+export interface HistoryEntryPayload {
+  id: string;
+  method: string;
+  url: string;
+  status: number;
+  time: number;
+  timestamp: number;
+  requestType?: string;
+  count?: number;
+  lastTimestamp?: number;
+  request?: unknown;
+  cancelled?: boolean;
+  grpcStatus?: number | null;
+}
+
+export type HistoryStatusClass = "2xx" | "3xx" | "4xx" | "5xx" | "error";
+
+export interface HistoryQuery {
+  search?: string;
+  method?: string;
+  statusClass?: HistoryStatusClass;
+  fromMs?: number;
+  toMs?: number;
+  limit?: number;
+}
+
+/** Records an entry and prunes SQLite history to `maxEntries` (0 keeps everything). */
+export async function recordHistoryEntry(
+  entry: HistoryEntryPayload,
+  maxEntries?: number,
+): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("record_history_entry", { entry, maxEntries });
+  } catch {
+    // The in-memory history still has the entry; SQLite is a secondary index.
+  }
+}
+
+export interface ConnectionPoolSettings {
+  /** Idle keep-alive connections kept per host; 0 disables reuse. */
+  maxIdlePerHost: number;
+  /** How long an idle connection is kept; 0 keeps it until the server closes it. */
+  idleTimeoutMs: number;
+}
+
+/** Apply keep-alive limits to the HTTP clients shared across sends (desktop only). */
+export async function setConnectionPoolSettings(settings: ConnectionPoolSettings): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("set_connection_pool_settings", { settings });
+  } catch {
+    // Keep the previous limits; the next change retries.
+  }
+}
+
+/** Turn the on-disk request/response debug log on or off (desktop only). */
+export async function setRequestLogging(enabled: boolean): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("set_request_logging", { enabled });
+  } catch {
+    // Logging is a debugging aid; a failure here must not affect the UI.
+  }
+}
+
+/** Returns `null` outside the desktop app so callers can filter locally. */
+export async function queryHistory(query: HistoryQuery): Promise<HistoryEntryPayload[] | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<HistoryEntryPayload[]>("query_history", { query });
+  } catch {
+    return null;
+  }
+}
+
+export async function deleteHistoryEntry(id: string): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("delete_history_entry", { id });
+  } catch {
+    // The in-memory list is already updated; a stale row only shows in filtered queries.
+  }
+}
+
+/** Deletes persisted entries matching the query's filters (its limit is ignored). */
+export async function deleteMatchingHistory(query: HistoryQuery): Promise<number | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<number>("delete_matching_history", { query });
+  } catch {
+    return null;
+  }
+}
+
+export async function clearHistoryEntries(): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("clear_history");
+  } catch {
+    // Ignore; the next query simply returns stale rows.
+  }
+}
+
+export interface ResponseSnapshot {
+  status: number;
+  headers: Record<string, string>;
+  body: string;
+}
+
+/** A response pinned for a saved request; later sends are diffed against it. */
+export interface ResponseBaseline {
+  requestId: string;
+  savedAt: number;
+  response: ResponseSnapshot;
+}
+
+export interface ResponseDiffEntry {
+  section: "status" | "header" | "body";
+  kind: "added" | "removed" | "changed";
+  /** Header name, JSON path such as `$.items[0].id`, or `line N` for text bodies. */
+  path: string;
+  before: string | null;
+  after: string | null;
+}
+
+export interface ResponseDiff {
+  entries: ResponseDiffEntry[];
+  /** Both bodies were JSON and compared by structure, so key order is ignored. */
+  jsonBody: boolean;
+  truncated: boolean;
+}
+
+/** Store `baseline` in SQLite. Returns `false` outside the desktop app or on failure. */
+export async function pinResponseBaseline(baseline: ResponseBaseline): Promise<boolean> {
+  if (!isTauriRuntime()) return false;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("pin_response_baseline", { baseline });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+export async function loadResponseBaseline(requestId: string): Promise<ResponseBaseline | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<ResponseBaseline | null>("load_response_baseline", { requestId });
+  } catch {
+    return null;
+  }
+}
+
+export async function clearResponseBaseline(requestId: string): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("clear_response_baseline", { requestId });
+  } catch {
+    // The baseline stays pinned and shows up again on the next load.
+  }
+}
+
+/**
+ * Outputs of the jq `filter` over a JSON response `body`, each as compact
+ * JSON. Rejects with the backend's message for invalid filters or bodies.
+ */
+export async function applyJq(body: string, filter: string): Promise<string[]> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<string[]>("apply_jq", { body, filter });
+  }
+
+  throw new Error("jq filters are only supported in the desktop app");
+}
+
+/** Open an HTML body in the default browser, resolving relative links against `url`. */
+export async function openHtmlInBrowser(body: string, url: string): Promise<void> {
+  if (isTauriRuntime()) {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<void>("open_html_in_browser", { body, url });
+  }
+
+  throw new Error("Opening responses in the browser is only supported in the desktop app");
+}
+
+export async function diffResponses(
+  baseline: ResponseSnapshot,
+  current: ResponseSnapshot,
+): Promise<ResponseDiff | null> {
+  if (!isTauriRuntime()) return null;
+  try {
+    const { invoke } = await import("@tauri-apps/api/core");
+    return await invoke<ResponseDiff>("diff_responses", { baseline, current });
+  } catch {
+    return null;
+  }
+}
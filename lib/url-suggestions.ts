@@ -0,0 +1,129 @@
+'use client';
+
+/**
+ * URL bar autocomplete
+ *
+ * Offers URLs from history and saved requests while typing in the URL bar.
+ * Prefix matches rank above substring matches; within each group recent
+ * history comes first, then saved requests in collection order. Each URL and
+ * method pair is suggested once, with the most recent request it came from.
+ */
+
+import type {
+  Collection,
+  CollectionFolder,
+  HistoryItem,
+  HistoryRequestSnapshot,
+  HttpMethod,
+  RequestType,
+} from "./getman-store";
+
+export interface UrlSuggestion {
+  key: string;
+  method: HttpMethod;
+  url: string;
+  requestType: RequestType;
+  source: "history" | "collection";
+  /** Saved request name, or collection path for context. */
+  label?: string;
+  /** Full request restored when the suggestion is picked. */
+  request?: HistoryRequestSnapshot;
+}
+
+export const MAX_URL_SUGGESTIONS = 8;
+
+function collectFolderSuggestions(
+  folders: CollectionFolder[],
+  path: string,
+  out: UrlSuggestion[],
+): void {
+  for (const folder of folders) {
+    const folderPath = `${path} / ${folder.name}`;
+    for (const request of folder.requests) {
+      out.push(savedSuggestion(request.tab, request.name, folderPath));
+    }
+    collectFolderSuggestions(folder.folders ?? [], folderPath, out);
+  }
+}
+
+function savedSuggestion(
+  tab: HistoryRequestSnapshot,
+  name: string,
+  path: string,
+): UrlSuggestion {
+  const requestType = tab.requestType ?? "http";
+  return {
+    key: `${requestType} ${tab.method} ${tab.url}`,
+    method: tab.method,
+    url: tab.url,
+    requestType,
+    source: "collection",
+    label: `${path} / ${name}`,
+    request: tab,
+  };
+}
+
+/** Every distinct request in history and collections, most relevant first. */
+export function buildUrlSuggestionIndex(
+  history: HistoryItem[],
+  collections: Collection[],
+): UrlSuggestion[] {
+  const all: UrlSuggestion[] = history.map((item) => {
+    const requestType = item.requestType ?? "http";
+    return {
+      key: `${requestType} ${item.method} ${item.url}`,
+      method: item.method,
+      url: item.url,
+      requestType,
+      source: "history",
+      request: item.request,
+    };
+  });
+  for (const collection of collections) {
+    for (const request of collection.requests) {
+      all.push(savedSuggestion(request.tab, request.name, collection.name));
+    }
+    collectFolderSuggestions(collection.folders, collection.name, all);
+  }
+
+  const seen = new Set<string>();
+  return all.filter((suggestion) => {
+    if (!suggestion.url.trim() || seen.has(suggestion.key)) return false;
+    seen.add(suggestion.key);
+    return true;
+  });
+}
+
+function withoutScheme(url: string): string {
+  return url.replace(/^[a-z][a-z0-9+.-]*:\/\//i, "");
+}
+
+/**
+ * Suggestions for `query` of the given request type. A query without a
+ * scheme also prefix-matches URLs that have one, so `api.test/u` finds
+ * `https://api.test/users`. The URL already typed in full is left out.
+ */
+export function matchUrlSuggestions(
+  index: UrlSuggestion[],
+  query: string,
+  requestType: RequestType,
+  limit = MAX_URL_SUGGESTIONS,
+): UrlSuggestion[] {
+  const needle = query.trim().toLowerCase();
+  if (!needle) return [];
+
+  const prefix: UrlSuggestion[] = [];
+  const substring: UrlSuggestion[] = [];
+  for (const suggestion of index) {
+    if (suggestion.requestType !== requestType) continue;
+    const url = suggestion.url.toLowerCase();
+    if (url === needle) continue;
+    if (url.startsWith(needle) || withoutScheme(url).startsWith(needle)) {
+      prefix.push(suggestion);
+      if (prefix.length >= limit) break;
+    } else if (substring.length < limit && url.includes(needle)) {
+      substring.push(suggestion);
+    }
+  }
+  return [...prefix, ...substring].slice(0, limit);
+}
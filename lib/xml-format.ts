@@ -0,0 +1,123 @@
+'use client';
+
+/**
+ * XML formatting and XPath
+ *
+ * Pretty-prints XML bodies and evaluates XPath expressions against them using
+ * the browser's DOMParser and XPath engine. Malformed XML is reported as
+ * `null`/an error result so callers can fall back to the raw text.
+ */
+
+export type XPathEvaluation =
+  | { ok: true; found: boolean; value: string }
+  | { ok: false; message: string };
+
+export function isXmlContentType(contentType: string): boolean {
+  const mime = contentType.split(";")[0].trim().toLowerCase();
+  return mime === "application/xml" || mime === "text/xml" || mime.endsWith("+xml");
+}
+
+function parseXml(text: string): Document | null {
+  if (!text.trim() || typeof DOMParser === "undefined") return null;
+  const doc = new DOMParser().parseFromString(text, "application/xml");
+  return doc.getElementsByTagName("parsererror").length > 0 ? null : doc;
+}
+
+function escapeText(value: string): string {
+  return value.replace(/&/g, "&amp;").replace(/</g, "&lt;").replace(/>/g, "&gt;");
+}
+
+function escapeAttribute(value: string): string {
+  return escapeText(value).replace(/"/g, "&quot;");
+}
+
+function serializeNode(node: Node, depth: number, indent: string, out: string[]): void {
+  const pad = indent.repeat(depth);
+  switch (node.nodeType) {
+    case Node.ELEMENT_NODE: {
+      const element = node as Element;
+      const attributes = Array.from(element.attributes)
+        .map((attr) => ` ${attr.name}="${escapeAttribute(attr.value)}"`)
+        .join("");
+      const children = Array.from(element.childNodes).filter(
+        (child) => child.nodeType !== Node.TEXT_NODE || child.textContent?.trim()
+      );
+      if (children.length === 0) {
+        out.push(`${pad}<${element.tagName}${attributes}/>`);
+      } else if (children.length === 1 && children[0].nodeType === Node.TEXT_NODE) {
+        const text = escapeText(children[0].textContent?.trim() ?? "");
+        out.push(`${pad}<${element.tagName}${attributes}>${text}</${element.tagName}>`);
+      } else {
+        out.push(`${pad}<${element.tagName}${attributes}>`);
+        for (const child of children) serializeNode(child, depth + 1, indent, out);
+        out.push(`${pad}</${element.tagName}>`);
+      }
+      break;
+    }
+    case Node.TEXT_NODE:
+      out.push(`${pad}${escapeText(node.textContent?.trim() ?? "")}`);
+      break;
+    case Node.CDATA_SECTION_NODE:
+      out.push(`${pad}<![CDATA[${node.textContent ?? ""}]]>`);
+      break;
+    case Node.COMMENT_NODE:
+      out.push(`${pad}<!--${node.textContent ?? ""}-->`);
+      break;
+    case Node.PROCESSING_INSTRUCTION_NODE: {
+      const instruction = node as ProcessingInstruction;
+      out.push(`${pad}<?${instruction.target} ${instruction.data}?>`);
+      break;
+    }
+    case Node.DOCUMENT_TYPE_NODE:
+      out.push(new XMLSerializer().serializeToString(node));
+      break;
+  }
+}
+
+/** Pretty-print an XML document, or return `null` when it does not parse. */
+export function formatXml(text: string, indent = 2): string | null {
+  const doc = parseXml(text);
+  if (!doc) return null;
+
+  const out: string[] = [];
+  // DOMParser drops the XML declaration, so carry it over from the source.
+  const declaration = /^\s*(<\?xml[^?]*\?>)/.exec(text);
+  if (declaration) out.push(declaration[1]);
+  for (const child of Array.from(doc.childNodes)) {
+    serializeNode(child, 0, " ".repeat(indent), out);
+  }
+  return out.join("\n");
+}
+
+/**
+ * Evaluate an XPath expression. Node-set results yield the text content of
+ * the first matching node; number, string and boolean results are stringified.
+ */
+export function evaluateXPath(text: string, expression: string): XPathEvaluation {
+  const doc = parseXml(text);
+  if (!doc) return { ok: false, message: "Response body is not valid XML" };
+
+  try {
+    const resolver = doc.documentElement
+      ? doc.createNSResolver(doc.documentElement)
+      : null;
+    const result = doc.evaluate(expression, doc, resolver, XPathResult.ANY_TYPE, null);
+    switch (result.resultType) {
+      case XPathResult.NUMBER_TYPE:
+        return { ok: true, found: true, value: String(result.numberValue) };
+      case XPathResult.STRING_TYPE:
+        return { ok: true, found: true, value: result.stringValue };
+      case XPathResult.BOOLEAN_TYPE:
+        return { ok: true, found: true, value: String(result.booleanValue) };
+      default: {
+        const node = result.iterateNext();
+        return node
+          ? { ok: true, found: true, value: node.textContent ?? "" }
+          : { ok: true, found: false, value: "" };
+      }
+    }
+  } catch (error) {
+    const message = error instanceof Error ? error.message : "Invalid XPath expression";
+    return { ok: false, message: `Invalid XPath expression: ${message}` };
+  }
+}
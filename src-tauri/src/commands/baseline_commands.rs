@@ -0,0 +1,30 @@
+use crate::domain::{ResponseBaseline, ResponseDiff, ResponseSnapshot};
+use crate::engine::response_diff;
+use crate::store::sqlite;
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn pin_response_baseline(app: AppHandle, baseline: ResponseBaseline) -> Result<(), String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::upsert_response_baseline(&conn, &baseline)
+}
+
+#[tauri::command]
+pub fn load_response_baseline(
+    app: AppHandle,
+    request_id: String,
+) -> Result<Option<ResponseBaseline>, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::load_response_baseline(&conn, &request_id)
+}
+
+#[tauri::command]
+pub fn clear_response_baseline(app: AppHandle, request_id: String) -> Result<bool, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::delete_response_baseline(&conn, &request_id)
+}
+
+#[tauri::command]
+pub fn diff_responses(baseline: ResponseSnapshot, current: ResponseSnapshot) -> ResponseDiff {
+    response_diff::diff_responses(&baseline, &current)
+}
@@ -1,12 +1,14 @@
 use crate::domain::{
-    BenchmarkAggregatedMetrics, BenchmarkExportPayload, BenchmarkRunDetail, BenchmarkRunStatus,
-    BenchmarkRunSummary, BenchmarkSpecPayload, BenchmarkStartResponse,
+    BenchmarkAggregatedMetrics, BenchmarkCancelMode, BenchmarkExportPayload, BenchmarkRunDetail,
+    BenchmarkRunStatus, BenchmarkRunSummary, BenchmarkSlaVerdict, BenchmarkSpecIssue,
+    BenchmarkSpecPayload, BenchmarkStartResponse,
 };
 use crate::engine::benchmark::{
-    collect_environment_fingerprint, execute_benchmark, BenchmarkRegistry,
+    collect_environment_fingerprint, execute_benchmark, prometheus_exposition, spec_issues,
+    BenchmarkRegistry,
 };
 use crate::store::sqlite::{self, StoredBenchmarkRunRow};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, State};
@@ -46,6 +48,18 @@ fn status_from_str(value: &str) -> BenchmarkRunStatus {
     }
 }
 
+/// The SLA verdict stored with a run's aggregated metrics.
+fn sla_verdict(aggregated_json: &str) -> Option<BenchmarkSlaVerdict> {
+    #[derive(Deserialize)]
+    struct StoredVerdict {
+        #[serde(default)]
+        sla: Option<BenchmarkSlaVerdict>,
+    }
+    serde_json::from_str::<StoredVerdict>(aggregated_json)
+        .ok()?
+        .sla
+}
+
 fn run_summary_from_row(row: &StoredBenchmarkRunRow) -> BenchmarkRunSummary {
     BenchmarkRunSummary {
         run_id: row.run_id.clone(),
@@ -56,6 +70,7 @@ fn run_summary_from_row(row: &StoredBenchmarkRunRow) -> BenchmarkRunSummary {
         started_at: row.started_at,
         finished_at: row.finished_at,
         spec_hash: Some(row.spec_hash.clone()),
+        sla: row.aggregated_json.as_deref().and_then(sla_verdict),
     }
 }
 
@@ -73,6 +88,7 @@ fn run_detail_from_row(
         metrics.timeseries = sqlite::load_benchmark_timeseries(conn, &row.run_id)?;
         metrics.histogram = sqlite::load_benchmark_histogram(conn, &row.run_id)?;
         metrics.top_errors = sqlite::load_benchmark_error_samples(conn, &row.run_id)?;
+        metrics.success_samples = sqlite::load_benchmark_success_samples(conn, &row.run_id)?;
         Some(metrics)
     } else {
         None
@@ -86,6 +102,12 @@ fn run_detail_from_row(
     })
 }
 
+/// Errors and warnings for a spec, so the form can flag them before a run.
+#[tauri::command]
+pub fn validate_benchmark_spec(spec: BenchmarkSpecPayload) -> Vec<BenchmarkSpecIssue> {
+    spec_issues(&spec)
+}
+
 #[tauri::command]
 pub async fn start_benchmark(
     spec: BenchmarkSpecPayload,
@@ -118,7 +140,7 @@ pub async fn start_benchmark(
         )?;
     }
 
-    let mut cancel_rx = registry.register(&run_id);
+    let mut signals = registry.register(&run_id);
     let run_id_for_task = run_id.clone();
     let spec_for_task = spec.clone();
     let app_for_task = app.clone();
@@ -137,7 +159,7 @@ pub async fn start_benchmark(
             );
         }
 
-        let outcome = execute_benchmark(spec_for_task, &mut cancel_rx).await;
+        let outcome = execute_benchmark(spec_for_task, &mut signals).await;
         let finished_at = now_ms();
         let update_result = match outcome {
             Ok(result) => {
@@ -172,6 +194,11 @@ pub async fn start_benchmark(
                         &run_id_for_task,
                         &result.metrics.top_errors,
                     );
+                    let _ = sqlite::replace_benchmark_success_samples(
+                        &conn,
+                        &run_id_for_task,
+                        &result.metrics.success_samples,
+                    );
                 }
                 Ok(())
             }
@@ -229,8 +256,12 @@ pub fn get_benchmark_run(
 }
 
 #[tauri::command]
-pub fn cancel_benchmark_run(run_id: String, registry: State<'_, BenchmarkRegistry>) -> bool {
-    registry.cancel(&run_id)
+pub fn cancel_benchmark_run(
+    run_id: String,
+    mode: Option<BenchmarkCancelMode>,
+    registry: State<'_, BenchmarkRegistry>,
+) -> bool {
+    registry.cancel(&run_id, mode.unwrap_or_default())
 }
 
 #[derive(Serialize)]
@@ -284,6 +315,18 @@ pub fn export_benchmark_run(
                 content: csv,
             })
         }
+        "prometheus" => {
+            let metrics = detail
+                .metrics
+                .as_ref()
+                .ok_or_else(|| "Benchmark run has no metrics yet".to_string())?;
+            let target = &detail.spec.target.request_snapshot.url;
+            Ok(BenchmarkExportPayload {
+                file_name: format!("benchmark-{run_id}.prom"),
+                mime_type: "text/plain; version=0.0.4".to_string(),
+                content: prometheus_exposition(&run_id, target, metrics),
+            })
+        }
         _ => {
             let json = serde_json::to_string_pretty(&BenchmarkJsonExport { run: detail })
                 .map_err(|err| format!("Failed to serialize benchmark export: {err}"))?;
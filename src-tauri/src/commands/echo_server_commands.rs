@@ -0,0 +1,34 @@
+#[cfg(feature = "echo-server")]
+use crate::engine::echo_server::EchoServer;
+#[cfg(feature = "echo-server")]
+use tauri::State;
+
+/// Whether this build has the echo server; the commands below exist only
+/// with the `echo-server` feature.
+#[tauri::command]
+pub fn echo_server_available() -> bool {
+    cfg!(feature = "echo-server")
+}
+
+/// Start the echo server on `port`, or a free port when none is given, and
+/// return the port it listens on.
+#[cfg(feature = "echo-server")]
+#[tauri::command]
+pub async fn start_echo_server(
+    port: Option<u16>,
+    server: State<'_, EchoServer>,
+) -> Result<u16, String> {
+    server.start(port.unwrap_or(0)).await
+}
+
+#[cfg(feature = "echo-server")]
+#[tauri::command]
+pub fn stop_echo_server(server: State<'_, EchoServer>) -> bool {
+    server.stop()
+}
+
+#[cfg(feature = "echo-server")]
+#[tauri::command]
+pub fn echo_server_port(server: State<'_, EchoServer>) -> Option<u16> {
+    server.port()
+}
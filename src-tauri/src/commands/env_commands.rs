@@ -1,5 +1,8 @@
-use crate::domain::{EnvVariable, Environment, ResolvedRequest, ResolveRequestPayload};
-use crate::engine::env;
+use crate::domain::{
+    EnvVariable, Environment, PreviewRequestPayload, RequestPreview, ResolvedRequest,
+    ResolveRequestPayload,
+};
+use crate::engine::{env, preview};
 
 #[tauri::command]
 pub fn resolve_request(
@@ -13,3 +16,16 @@ pub fn resolve_request(
         &environments,
     ))
 }
+
+#[tauri::command]
+pub fn preview_request(
+    payload: PreviewRequestPayload,
+    global_variables: Vec<EnvVariable>,
+    environments: Vec<Environment>,
+) -> Result<RequestPreview, String> {
+    Ok(preview::preview_request(
+        &payload,
+        &global_variables,
+        &environments,
+    ))
+}
@@ -1,6 +1,17 @@
-use crate::domain::{GrpcReflectionResponse, GrpcRequestPayload, GrpcResponsePayload, ProtoServiceInfo};
+use crate::domain::{
+    GrpcReflectionResponse, GrpcRequestPayload, GrpcResponsePayload, GrpcStreamSnapshot,
+    GrpcStreamStartResponse, GrpcValidationIssue, ProtoServiceInfo,
+};
 use crate::engine::cancel::CancelRegistry;
-use crate::engine::grpc::{grpc_error_response, fetch_grpc_reflection_impl, parse_proto_content_impl, send_grpc_request_impl};
+use crate::engine::grpc_stream::{start_grpc_stream_impl, GrpcStreamRegistry};
+use crate::engine::reflection_cache::ReflectionCache;
+use crate::engine::request_log::{RequestLog, RequestLogEntry};
+use std::sync::atomic::{AtomicU64, Ordering};
+use crate::engine::grpc::{
+    build_grpc_request_template_impl, fetch_grpc_reflection_impl, grpc_error_response,
+    interpolate_grpc_payload, parse_proto_content_impl, send_grpc_request_impl,
+    validate_grpc_request_impl,
+};
 use tauri::State;
 
 #[tauri::command]
@@ -10,27 +21,149 @@ pub fn parse_proto_content(
     parse_proto_content_impl(&proto_content)
 }
 
+#[tauri::command]
+pub fn build_grpc_request_template(
+    proto_content: String,
+    descriptor_bytes: Option<String>,
+    service: String,
+    method: String,
+) -> Result<serde_json::Value, String> {
+    build_grpc_request_template_impl(
+        &proto_content,
+        descriptor_bytes.as_deref(),
+        &service,
+        &method,
+    )
+}
+
+#[tauri::command]
+pub fn validate_grpc_request(
+    proto_content: String,
+    descriptor_bytes: Option<String>,
+    service: String,
+    method: String,
+    request_json: String,
+) -> Result<Vec<GrpcValidationIssue>, String> {
+    validate_grpc_request_impl(
+        &proto_content,
+        descriptor_bytes.as_deref(),
+        &service,
+        &method,
+        &request_json,
+    )
+}
+
 #[tauri::command]
 pub async fn fetch_grpc_reflection(
     endpoint: String,
+    cache: State<'_, ReflectionCache>,
+) -> Result<GrpcReflectionResponse, String> {
+    cache
+        .get_or_fetch(&endpoint, false, || fetch_grpc_reflection_impl(&endpoint))
+        .await
+}
+
+#[tauri::command]
+pub async fn refresh_grpc_reflection(
+    endpoint: String,
+    cache: State<'_, ReflectionCache>,
 ) -> Result<GrpcReflectionResponse, String> {
-    fetch_grpc_reflection_impl(&endpoint).await
+    cache
+        .get_or_fetch(&endpoint, true, || fetch_grpc_reflection_impl(&endpoint))
+        .await
 }
 
 #[tauri::command]
 pub async fn send_grpc_request(
-    payload: GrpcRequestPayload,
+    mut payload: GrpcRequestPayload,
     registry: State<'_, CancelRegistry>,
+    reflection: State<'_, ReflectionCache>,
+    request_log: State<'_, RequestLog>,
 ) -> Result<GrpcResponsePayload, String> {
+    interpolate_grpc_payload(&mut payload);
+    if payload.descriptor_bytes.is_none() && payload.proto_content.trim().is_empty() {
+        payload.descriptor_bytes = reflection.cached_descriptor(&payload.endpoint);
+    }
+
     let request_id = payload.request_id.clone().unwrap_or_default();
     let mut cancel_rx = registry.register(&request_id);
 
+    let log_entry = request_log.is_enabled().then(|| {
+        RequestLogEntry::new(
+            "grpc",
+            &format!("{}/{}", payload.service_name, payload.method_name),
+            &payload.endpoint,
+            &payload.metadata,
+            Some(&payload.request_json),
+        )
+    });
+
     let result = send_grpc_request_impl(payload, &mut cancel_rx).await;
 
     registry.remove(&request_id);
 
-    match result {
-        Ok(response) => Ok(response),
-        Err(message) => Ok(grpc_error_response(message)),
+    let response = result.unwrap_or_else(grpc_error_response);
+    if let Some(entry) = log_entry {
+        request_log.record(&entry.with_response(
+            response.status_code.into(),
+            &response.status_message,
+            response.time,
+            &response.response_metadata,
+            &response.response_json,
+        ));
     }
+    Ok(response)
+}
+
+static STREAM_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+#[tauri::command]
+pub async fn start_grpc_stream(
+    payload: GrpcRequestPayload,
+    registry: State<'_, GrpcStreamRegistry>,
+    reflection: State<'_, ReflectionCache>,
+) -> Result<GrpcStreamStartResponse, String> {
+    let mut payload = payload;
+    interpolate_grpc_payload(&mut payload);
+    if payload.descriptor_bytes.is_none() && payload.proto_content.trim().is_empty() {
+        payload.descriptor_bytes = reflection.cached_descriptor(&payload.endpoint);
+    }
+    let stream_id = payload.request_id.clone().unwrap_or_else(|| {
+        format!("stream-{}", STREAM_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
+    });
+    // A restarted stream with the same id replaces the previous one.
+    registry.cancel(&stream_id);
+    start_grpc_stream_impl(payload, &stream_id, registry.inner().clone()).await?;
+    Ok(GrpcStreamStartResponse { stream_id })
+}
+
+#[tauri::command]
+pub fn send_grpc_stream_message(
+    stream_id: String,
+    request_json: String,
+    registry: State<'_, GrpcStreamRegistry>,
+) -> Result<(), String> {
+    registry.send_message(&stream_id, &request_json)
+}
+
+#[tauri::command]
+pub fn close_grpc_stream_send(
+    stream_id: String,
+    registry: State<'_, GrpcStreamRegistry>,
+) -> Result<(), String> {
+    registry.close_send(&stream_id)
+}
+
+#[tauri::command]
+pub fn poll_grpc_stream(
+    stream_id: String,
+    after_seq: Option<u64>,
+    registry: State<'_, GrpcStreamRegistry>,
+) -> Result<GrpcStreamSnapshot, String> {
+    registry.snapshot(&stream_id, after_seq.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn cancel_grpc_stream(stream_id: String, registry: State<'_, GrpcStreamRegistry>) -> bool {
+    registry.cancel(&stream_id)
 }
@@ -0,0 +1,46 @@
+use crate::domain::{HistoryEntry, HistoryQuery};
+use crate::engine::request_log::RequestLog;
+use crate::store::sqlite;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+pub fn record_history_entry(
+    app: AppHandle,
+    entry: HistoryEntry,
+    max_entries: Option<u32>,
+) -> Result<(), String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::upsert_history_entry(&conn, &entry)?;
+    sqlite::prune_history(&conn, max_entries.unwrap_or(sqlite::DEFAULT_HISTORY_LIMIT))
+}
+
+#[tauri::command]
+pub fn query_history(app: AppHandle, query: HistoryQuery) -> Result<Vec<HistoryEntry>, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::query_history(&conn, &query)
+}
+
+#[tauri::command]
+pub fn clear_history(app: AppHandle) -> Result<(), String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::clear_history(&conn)
+}
+
+#[tauri::command]
+pub fn delete_history_entry(app: AppHandle, id: String) -> Result<bool, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::delete_history_entry(&conn, &id)
+}
+
+/// Delete every entry matching the filters in `query`; returns how many went.
+#[tauri::command]
+pub fn delete_matching_history(app: AppHandle, query: HistoryQuery) -> Result<usize, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::delete_matching_history(&conn, &query)
+}
+
+/// Toggle the on-disk request log. `GETMAN_LOG` keeps it on regardless.
+#[tauri::command]
+pub fn set_request_logging(enabled: bool, request_log: State<'_, RequestLog>) {
+    request_log.set_enabled(enabled);
+}
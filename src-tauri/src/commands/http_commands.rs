@@ -1,25 +1,126 @@
-use crate::domain::SendRequestPayload;
+use crate::domain::{
+    BodyFileInfo, ConnectionPoolSettings, ReplayMode, SendProgressSnapshot, SendRequestPayload,
+    SendResponsePayload,
+};
 use crate::engine::cancel::CancelRegistry;
-use crate::engine::http::{error_response, send_http_request_impl};
-use crate::domain::SendResponsePayload;
-use tauri::State;
+use crate::engine::client_pool::ClientPool;
+use crate::engine::conditional::ConditionalCache;
+use crate::engine::http::{
+    body_file_info, error_response, send_http_request_impl, validate_proxy_url, DEFAULT_USER_AGENT,
+};
+use crate::engine::pagination::follow_pagination;
+use crate::engine::progress::{with_progress, SendProgressRegistry};
+use crate::engine::redirects::trace_redirects;
+use crate::engine::replay::request_signature;
+use crate::engine::request_log::{RequestLog, RequestLogEntry};
+use crate::store::sqlite;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, State};
+
+/// The recorded response to a request, if replay mode has one. Cache
+/// failures count as a miss so the request is still sent.
+fn replayed_response(app: &AppHandle, signature: &str) -> Option<SendResponsePayload> {
+    let conn = sqlite::open_db(app).ok()?;
+    let mut response = sqlite::load_cached_response(&conn, signature).ok()??;
+    response.replayed = true;
+    Some(response)
+}
+
+fn record_response(
+    app: &AppHandle,
+    signature: &str,
+    response: &SendResponsePayload,
+) -> Result<(), String> {
+    let saved_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+    let conn = sqlite::open_db(app)?;
+    sqlite::upsert_cached_response(&conn, signature, saved_at, response)
+}
 
 #[tauri::command]
 pub async fn send_http_request(
-    payload: SendRequestPayload,
+    app: AppHandle,
+    mut payload: SendRequestPayload,
     registry: State<'_, CancelRegistry>,
+    conditional_cache: State<'_, ConditionalCache>,
+    request_log: State<'_, RequestLog>,
+    clients: State<'_, ClientPool>,
+    send_progress: State<'_, SendProgressRegistry>,
 ) -> Result<SendResponsePayload, String> {
+    let signature =
+        (payload.replay_mode != ReplayMode::Off).then(|| request_signature(&payload));
+    if let (ReplayMode::Replay, Some(signature)) = (payload.replay_mode, &signature) {
+        if let Some(response) = replayed_response(&app, signature) {
+            return Ok(response);
+        }
+    }
+
     let request_id = payload.request_id.clone().unwrap_or_default();
     let mut cancel_rx = registry.register(&request_id);
 
-    let result = send_http_request_impl(payload, &mut cancel_rx).await;
+    let conditional = payload.conditional.then(|| (payload.method.clone(), payload.url.clone()));
+    if let Some((method, url)) = &conditional {
+        conditional_cache.apply_validators(method, url, &mut payload.headers);
+    }
+
+    let log_entry = request_log.is_enabled().then(|| {
+        RequestLogEntry::new(
+            "http",
+            &payload.method,
+            &payload.url,
+            &payload.headers,
+            payload.body.as_deref(),
+        )
+    });
+
+    let record = payload.replay_mode == ReplayMode::Record;
+    let paged = payload.follow_pagination.then(|| payload.clone());
+    let progress = send_progress.register(&request_id);
+    let result = with_progress(progress, async {
+        let mut result = if payload.trace_redirects {
+            trace_redirects(payload, &clients, &mut cancel_rx).await
+        } else {
+            send_http_request_impl(payload, &clients, &mut cancel_rx).await
+        };
+        if let Some(payload) = &paged {
+            result = match result {
+                Ok(first) => follow_pagination(first, payload, &clients, &mut cancel_rx).await,
+                Err(message) => Err(message),
+            };
+        }
+        result
+    })
+    .await;
 
     registry.remove(&request_id);
+    send_progress.remove(&request_id);
 
-    match result {
-        Ok(response) => Ok(response),
-        Err(message) => Ok(error_response(message)),
+    let response = match result {
+        Ok(response) => match &conditional {
+            Some((method, url)) => conditional_cache.resolve(method, url, response),
+            None => response,
+        },
+        Err(message) => error_response(message),
+    };
+    if let (true, Some(signature)) = (record, &signature) {
+        // Only real answers are recorded, and a cache that cannot be written
+        // must not hide the response that did arrive.
+        if response.error.is_none() {
+            let _ = record_response(&app, signature, &response);
+        }
+    }
+    if let Some(entry) = log_entry {
+        request_log.record(&entry.with_response(
+            response.status.into(),
+            &response.status_text,
+            response.time,
+            &response.headers,
+            &response.body,
+        ));
     }
+    Ok(response)
 }
 
 #[tauri::command]
@@ -29,3 +130,44 @@ pub fn cancel_http_request(
 ) -> bool {
     registry.cancel(&request_id)
 }
+
+/// Phase and bytes so far of the send with `request_id`, while it runs.
+#[tauri::command]
+pub fn poll_send_progress(
+    request_id: String,
+    send_progress: State<'_, SendProgressRegistry>,
+) -> Option<SendProgressSnapshot> {
+    send_progress.snapshot(&request_id)
+}
+
+#[tauri::command]
+pub fn set_connection_pool_settings(
+    settings: ConnectionPoolSettings,
+    clients: State<'_, ClientPool>,
+) {
+    clients.configure(settings);
+}
+
+#[tauri::command]
+pub async fn inspect_body_file(path: String) -> Result<BodyFileInfo, String> {
+    body_file_info(&path).await.map_err(String::from)
+}
+
+/// Forget every response recorded for offline replay.
+#[tauri::command]
+pub fn clear_response_cache(app: AppHandle) -> Result<usize, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::clear_response_cache(&conn)
+}
+
+/// The proxy URL as it will be used, or why it cannot be.
+#[tauri::command]
+pub fn check_proxy_url(url: String) -> Result<String, String> {
+    validate_proxy_url(&url)
+}
+
+/// The `User-Agent` sent when neither the request nor the settings set one.
+#[tauri::command]
+pub fn default_user_agent() -> &'static str {
+    DEFAULT_USER_AGENT
+}
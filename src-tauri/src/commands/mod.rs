@@ -1,5 +1,9 @@
+pub mod baseline_commands;
 pub mod benchmark_commands;
+pub mod echo_server_commands;
 pub mod env_commands;
 pub mod grpc_commands;
+pub mod history_commands;
 pub mod http_commands;
+pub mod response_commands;
 pub mod state_commands;
@@ -0,0 +1,17 @@
+use crate::engine::{browser_preview, jq};
+
+/// Outputs of the jq `filter` over a response body, one compact JSON value each.
+/// Filters run on a blocking thread so a slow one cannot stall the app.
+#[tauri::command]
+pub async fn apply_jq(body: String, filter: String) -> Result<Vec<String>, String> {
+    tokio::task::spawn_blocking(move || jq::apply_jq(&body, &filter))
+        .await
+        .map_err(|err| format!("Failed to run filter: {err}"))?
+}
+
+/// Open an HTML response body in the default browser, with relative links
+/// resolved against the `url` it came from.
+#[tauri::command]
+pub fn open_html_in_browser(body: String, url: String) -> Result<(), String> {
+    browser_preview::open_html(&body, &url)
+}
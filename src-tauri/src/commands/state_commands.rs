@@ -1,3 +1,4 @@
+use crate::domain::AppSettings;
 use crate::store::sqlite;
 use std::fs;
 use tauri::AppHandle;
@@ -31,3 +32,17 @@ pub fn save_app_state(app: AppHandle, state_json: String) -> Result<(), String>
     sqlite::upsert_state(&conn, &state_json)?;
     Ok(())
 }
+
+/// `None` until settings are first saved, so the frontend can migrate the
+/// values older versions kept in the app state.
+#[tauri::command]
+pub fn load_settings(app: AppHandle) -> Result<Option<AppSettings>, String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::load_settings(&conn)
+}
+
+#[tauri::command]
+pub fn save_settings(app: AppHandle, settings: AppSettings) -> Result<(), String> {
+    let conn = sqlite::open_db(&app)?;
+    sqlite::save_settings(&conn, &settings)
+}
@@ -43,8 +43,21 @@ pub struct BenchmarkTransportConfig {
     pub follow_redirects: bool,
     #[serde(default)]
     pub proxy_url: Option<String>,
+    #[serde(default)]
+    pub proxy_username: Option<String>,
+    #[serde(default)]
+    pub proxy_password: Option<String>,
+    #[serde(default)]
+    pub no_proxy: Option<Vec<String>>,
+    #[serde(default)]
+    pub bypass_proxy: bool,
     #[serde(default = "default_verify_ssl")]
     pub verify_ssl: bool,
+    #[serde(default)]
+    pub max_rps_per_host: Option<f64>,
+    /// `User-Agent` sent when the request snapshot has none; blank uses the app default.
+    #[serde(default)]
+    pub user_agent: Option<String>,
 }
 
 fn default_verify_ssl() -> bool {
@@ -66,6 +79,12 @@ pub struct BenchmarkTimingConfig {
 pub enum BenchmarkSaveBodies {
     None,
     Errors,
+    /// Error bodies plus every successful response body.
+    All,
+    /// Error bodies plus roughly `rate` (0..=1) of successful response bodies.
+    Sampled {
+        rate: f64,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +116,78 @@ pub struct BenchmarkSpecPayload {
     pub timing: BenchmarkTimingConfig,
     pub logging: BenchmarkLoggingConfig,
     pub env: BenchmarkEnvConfig,
+    #[serde(default)]
+    pub sla: Option<BenchmarkSlaConfig>,
+}
+
+/// Thresholds a run must meet to pass, checked once its metrics are
+/// aggregated. Unset thresholds are not checked.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSlaConfig {
+    #[serde(default)]
+    pub max_p50_ms: Option<f64>,
+    #[serde(default)]
+    pub max_p90_ms: Option<f64>,
+    #[serde(default)]
+    pub max_p95_ms: Option<f64>,
+    #[serde(default)]
+    pub max_p99_ms: Option<f64>,
+    /// Percentage of failed requests, as in `BenchmarkSummaryMetrics::error_rate`.
+    #[serde(default)]
+    pub max_error_rate: Option<f64>,
+    #[serde(default)]
+    pub min_rps: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSlaViolation {
+    /// Name of the breached `BenchmarkSlaConfig` field, e.g. `maxP95Ms`.
+    pub criterion: String,
+    pub threshold: f64,
+    pub actual: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSlaVerdict {
+    pub passed: bool,
+    #[serde(default)]
+    pub violations: Vec<BenchmarkSlaViolation>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchmarkSpecIssueSeverity {
+    /// The run is refused.
+    Error,
+    /// The run starts, but likely not as intended.
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSpecIssue {
+    /// Spec field the issue is about, e.g. `load.concurrency`.
+    pub field: String,
+    pub severity: BenchmarkSpecIssueSeverity,
+    pub message: String,
+}
+
+/// How `cancel_benchmark_run` stops a run.
+///
+/// `Abort` stops immediately; in-flight requests are dropped and left out of
+/// the metrics. `Drain` stops starting new iterations but lets in-flight
+/// requests finish, so no request is cut short and no `CANCELED` errors are
+/// produced; genuine failures such as timeouts or 5xx responses of those
+/// requests are still counted.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchmarkCancelMode {
+    #[default]
+    Abort,
+    Drain,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -135,6 +226,18 @@ pub struct BenchmarkLatencyMetrics {
     pub p99_ms: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSizeMetrics {
+    pub min_bytes: u64,
+    pub avg_bytes: f64,
+    pub max_bytes: u64,
+    pub p50_bytes: u64,
+    pub p90_bytes: u64,
+    pub p95_bytes: u64,
+    pub p99_bytes: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct BenchmarkSummaryMetrics {
@@ -151,6 +254,22 @@ pub struct BenchmarkSummaryMetrics {
     pub status_code_counts: HashMap<String, u64>,
     #[serde(default)]
     pub error_type_counts: HashMap<String, u64>,
+    #[serde(default)]
+    pub rate_limited_requests: u64,
+    #[serde(default)]
+    pub rate_limit_wait_ms: f64,
+    #[serde(default)]
+    pub response_body_size: BenchmarkSizeMetrics,
+    #[serde(default)]
+    pub response_header_size: BenchmarkSizeMetrics,
+    /// Connections opened during the measured phase. The request that opens
+    /// one includes its DNS, TCP and TLS setup in its latency.
+    #[serde(default)]
+    pub new_connections: u64,
+    #[serde(default)]
+    pub connect_time_avg_ms: f64,
+    #[serde(default)]
+    pub connect_time_max_ms: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -185,6 +304,14 @@ pub struct BenchmarkErrorSample {
     pub sample_body: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSuccessSample {
+    pub status_code: u16,
+    pub body: String,
+    pub count: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct BenchmarkAggregatedMetrics {
@@ -195,6 +322,11 @@ pub struct BenchmarkAggregatedMetrics {
     pub histogram: Vec<BenchmarkHistogramBucket>,
     #[serde(default)]
     pub top_errors: Vec<BenchmarkErrorSample>,
+    #[serde(default)]
+    pub success_samples: Vec<BenchmarkSuccessSample>,
+    /// Set when the spec has an SLA.
+    #[serde(default)]
+    pub sla: Option<BenchmarkSlaVerdict>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -221,6 +353,9 @@ pub struct BenchmarkRunSummary {
     pub finished_at: Option<u64>,
     #[serde(default)]
     pub spec_hash: Option<String>,
+    /// SLA outcome of a finished run whose spec has an SLA.
+    #[serde(default)]
+    pub sla: Option<BenchmarkSlaVerdict>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A failure reported to the frontend as `{ "kind": ..., "message": ... }`,
+/// so it can tell a DNS failure from a timeout without reading the message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "message", rename_all = "camelCase")]
+pub enum GetmanError {
+    /// The URL could not be parsed.
+    InvalidUrl(String),
+    /// The request could not be built, e.g. an invalid method or header.
+    InvalidRequest(String),
+    /// The host name did not resolve.
+    Dns(String),
+    /// The TCP connection was refused or dropped.
+    Connect(String),
+    /// The TLS handshake or certificate check failed.
+    Tls(String),
+    /// The proxy refused the connection or the tunnel through it failed.
+    Proxy(String),
+    Timeout(String),
+    /// The server broke the HTTP protocol or the body could not be read.
+    Http(String),
+    Cancelled(String),
+    /// A local file could not be read or written.
+    Io(String),
+    Other(String),
+}
+
+impl GetmanError {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::InvalidUrl(message)
+            | Self::InvalidRequest(message)
+            | Self::Dns(message)
+            | Self::Connect(message)
+            | Self::Tls(message)
+            | Self::Proxy(message)
+            | Self::Timeout(message)
+            | Self::Http(message)
+            | Self::Cancelled(message)
+            | Self::Io(message)
+            | Self::Other(message) => message,
+        }
+    }
+
+    pub fn cancelled() -> Self {
+        Self::Cancelled("Request cancelled".into())
+    }
+}
+
+impl fmt::Display for GetmanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for GetmanError {}
+
+/// Errors from code that still reports plain messages carry no kind.
+impl From<String> for GetmanError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for GetmanError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_string())
+    }
+}
+
+impl From<GetmanError> for String {
+    fn from(error: GetmanError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_with_kind_and_message() {
+        let error = GetmanError::InvalidUrl("Invalid URL: empty host".into());
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({ "kind": "invalidUrl", "message": "Invalid URL: empty host" })
+        );
+        assert_eq!(error.to_string(), "Invalid URL: empty host");
+        assert_eq!(GetmanError::from("boom"), GetmanError::Other("boom".into()));
+    }
+}
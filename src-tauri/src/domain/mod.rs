@@ -1,5 +1,7 @@
 mod benchmark_types;
+mod error;
 mod types;
 
 pub use benchmark_types::*;
+pub use error::*;
 pub use types::*;
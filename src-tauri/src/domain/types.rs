@@ -1,15 +1,22 @@
+use super::GetmanError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // ─── HTTP Types ───────────────────────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendRequestPayload {
     pub url: String,
     pub method: String,
     pub headers: HashMap<String, String>,
+    /// App-wide headers sent unless `headers` sets the same name.
+    #[serde(default)]
+    pub default_headers: HashMap<String, String>,
     pub body: Option<String>,
+    /// Path of a file streamed as the body in place of `body` and `form_fields`.
+    #[serde(default)]
+    pub body_file: Option<String>,
     #[serde(default)]
     pub request_id: Option<String>,
     #[serde(default)]
@@ -20,8 +27,23 @@ pub struct SendRequestPayload {
     pub retry_delay_ms: Option<u64>,
     #[serde(default)]
     pub proxy_url: Option<String>,
+    #[serde(default)]
+    pub proxy_username: Option<String>,
+    #[serde(default)]
+    pub proxy_password: Option<String>,
+    #[serde(default)]
+    pub no_proxy: Option<Vec<String>>,
+    /// Connect directly, ignoring `HTTP_PROXY` and `HTTPS_PROXY` too.
+    #[serde(default)]
+    pub bypass_proxy: bool,
     #[serde(default = "default_verify_ssl")]
     pub verify_ssl: bool,
+    /// Lowest TLS version the handshake may settle on; `None` keeps the library default.
+    #[serde(default)]
+    pub min_tls_version: Option<TlsVersion>,
+    /// Highest TLS version offered; `None` keeps the library default.
+    #[serde(default)]
+    pub max_tls_version: Option<TlsVersion>,
     #[serde(default)]
     pub digest_username: Option<String>,
     #[serde(default)]
@@ -32,13 +54,142 @@ pub struct SendRequestPayload {
     pub ntlm_password: Option<String>,
     #[serde(default)]
     pub ntlm_domain: Option<String>,
+    #[serde(default)]
+    pub ntlm_workstation: Option<String>,
+    #[serde(default)]
+    pub form_fields: Option<Vec<FormField>>,
+    #[serde(default)]
+    pub http_version: HttpVersionPreference,
+    /// Host name to `ip` or `ip:port` pins that bypass DNS for this request.
+    #[serde(default)]
+    pub resolve_overrides: HashMap<String, String>,
+    /// Stop reading the body after this many bytes. `None` or 0 reads it all.
+    #[serde(default)]
+    pub max_response_bytes: Option<u64>,
+    /// Leave compressed bodies as received: no automatic `Accept-Encoding`
+    /// and no decoding.
+    #[serde(default)]
+    pub disable_decompression: bool,
+    #[serde(default)]
+    pub request_compression: RequestCompression,
+    /// Send `Expect: 100-continue` with a body and hold the body back so the
+    /// server can reject the request before it is uploaded.
+    #[serde(default)]
+    pub expect_continue: bool,
+    /// How long to hold the body back for `expect_continue`; 1000 by default.
+    #[serde(default)]
+    pub expect_continue_timeout_ms: Option<u64>,
+    /// Send `If-None-Match`/`If-Modified-Since` from the last response to this URL.
+    #[serde(default)]
+    pub conditional: bool,
+    /// Sniff a `Content-Type` for raw bodies sent without one.
+    #[serde(default)]
+    pub detect_content_type: bool,
+    /// Send on a one-off connection with `Connection: close` instead of the shared pool.
+    #[serde(default)]
+    pub fresh_connection: bool,
+    /// Follow `Link: <...>; rel="next"` and merge the JSON array pages into one body.
+    #[serde(default)]
+    pub follow_pagination: bool,
+    /// Most pages fetched when following pagination, the first included.
+    #[serde(default)]
+    pub max_pages: Option<u32>,
+    /// Follow redirects hop by hop, recording each one in `redirect_chain`.
+    #[serde(default)]
+    pub trace_redirects: bool,
+    /// Most redirects followed when tracing them.
+    #[serde(default)]
+    pub max_redirects: Option<u32>,
+    /// `User-Agent` sent when `headers` has none; blank uses the app default.
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub replay_mode: ReplayMode,
+}
+
+/// A file picked as a request body, as shown in the body editor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BodyFileInfo {
+    pub name: String,
+    pub size: u64,
+    /// Sent when the request sets no `Content-Type` of its own.
+    pub content_type: String,
+}
+
+/// Idle connection limits for the clients shared across sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionPoolSettings {
+    /// Idle keep-alive connections kept per host; 0 disables reuse.
+    pub max_idle_per_host: usize,
+    /// How long an idle connection is kept; 0 keeps it until the server closes it.
+    pub idle_timeout_ms: u64,
+}
+
+impl Default for ConnectionPoolSettings {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: 16,
+            idle_timeout_ms: 90_000,
+        }
+    }
+}
+
+/// Content coding applied to outgoing request bodies.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestCompression {
+    #[default]
+    None,
+    Gzip,
+    Brotli,
+}
+
+/// Protocol selection for the HTTP client. `Auto` negotiates via ALPN.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersionPreference {
+    #[default]
+    Auto,
+    Http1Only,
+    Http2PriorKnowledge,
+}
+
+/// TLS protocol versions the client can be pinned to. Older versions are
+/// not implemented by the TLS backend, so they cannot be negotiated at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum TlsVersion {
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// Offline record/replay of HTTP responses, keyed by request signature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayMode {
+    #[default]
+    Off,
+    /// Send as usual and store each response.
+    Record,
+    /// Answer from the stored responses, sending only on a miss.
+    Replay,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormField {
+    pub key: String,
+    pub value: String,
 }
 
 fn default_verify_ssl() -> bool {
     true
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendResponsePayload {
     pub status: u16,
@@ -48,11 +199,108 @@ pub struct SendResponsePayload {
     pub time: u64,
     pub size: u64,
     pub content_type: String,
+    /// Protocol actually used for the response, e.g. `HTTP/1.1` or `HTTP/2`.
+    pub http_version: String,
+    /// The body was cut off at `max_response_bytes`.
+    pub truncated: bool,
+    /// Full body size from `Content-Length`, when the server sent one.
+    pub total_size: Option<u64>,
+    /// Body bytes received over the wire, before content decoding.
+    pub wire_size: u64,
+    /// Body bytes after content decoding; same as `size`.
+    pub decoded_size: u64,
+    /// Size of the response header block, counted as `name: value\r\n` lines.
+    pub header_size: u64,
+    /// Body bytes as received; same as `wire_size`. With `header_size` it
+    /// tells bloated headers apart from large payloads.
+    pub body_size: u64,
+    /// Request body bytes sent, after any request compression.
+    pub bytes_out: u64,
+    /// The server answered an `Expect: 100-continue` request before its body
+    /// was sent, so the body was never uploaded.
+    #[serde(default)]
+    pub body_withheld: bool,
+    /// A `304` answer whose body was filled from the conditional cache.
+    pub not_modified: bool,
+    /// Served from the record/replay cache without sending the request.
+    #[serde(default)]
+    pub replayed: bool,
+    /// URL the response came from, after any redirects followed on the way.
+    #[serde(default)]
+    pub url: String,
+    /// The request as written to the connection: request line, headers, body.
+    pub raw_request: String,
+    /// The response status line and header block, rebuilt from the parsed
+    /// response: the reason phrase is the server's, but header names are
+    /// lowercased and repeated headers grouped.
+    pub raw_response_head: String,
+    /// Pages merged into the body when following pagination; 0 otherwise.
+    pub pages: u32,
+    /// Why pagination stopped early, if it did.
+    pub pagination_warning: Option<String>,
+    /// Every response of a traced redirect chain, the final one last; empty
+    /// unless redirects were traced.
+    #[serde(default)]
+    pub redirect_chain: Vec<RedirectHop>,
+    /// Why redirect tracing stopped before a non-redirect answer, if it did.
+    #[serde(default)]
+    pub redirect_warning: Option<String>,
+    /// Wait a `429` or `503` response asked for with `Retry-After`.
+    #[serde(default)]
+    pub retry_after_ms: Option<u64>,
+    /// Encoding the body text was decoded with, e.g. `utf-8` or `windows-1252`.
+    pub encoding: String,
+    /// `Content-Encoding` of a body left compressed by `disable_decompression`;
+    /// `body` then holds the received bytes as base64.
+    #[serde(default)]
+    pub body_encoding: Option<String>,
+    /// Charset declared by `Content-Type` that is not supported; the body
+    /// was decoded as UTF-8 instead.
+    pub unsupported_charset: Option<String>,
+    /// Why the request failed, for `status: 0` responses.
+    pub error: Option<GetmanError>,
+}
+
+/// One response in a traced redirect chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedirectHop {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    /// The `Location` this response pointed to, resolved against `url`.
+    pub location: Option<String>,
+}
+
+/// Where an HTTP send is, as reported while it is in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SendPhase {
+    /// Looking up the host name.
+    Resolving,
+    /// Opening the connection, including the TLS handshake for HTTPS; the
+    /// client does not expose the handshake as a step of its own.
+    Connecting,
+    /// The request is out and no response headers have arrived yet.
+    Waiting,
+    /// Reading the response body.
+    Downloading,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendProgressSnapshot {
+    pub phase: SendPhase,
+    /// Body bytes read so far, as sent on the wire.
+    pub bytes_received: u64,
+    /// `Content-Length` of the response, when it sent one.
+    pub total_bytes: Option<u64>,
+    pub elapsed_ms: u64,
 }
 
 // ─── gRPC Types ───────────────────────────────────────────────────────────────
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtoServiceInfo {
     pub name: String,
@@ -60,7 +308,7 @@ pub struct ProtoServiceInfo {
     pub methods: Vec<ProtoMethodInfo>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtoMethodInfo {
     pub name: String,
@@ -72,7 +320,7 @@ pub struct ProtoMethodInfo {
     pub input_fields: Vec<ProtoFieldInfo>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtoFieldInfo {
     pub name: String,
@@ -81,13 +329,33 @@ pub struct ProtoFieldInfo {
     pub is_repeated: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GrpcReflectionResponse {
     pub services: Vec<ProtoServiceInfo>,
     pub descriptor_bytes: String,
 }
 
+/// Category of a problem found while validating gRPC request JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrpcValidationKind {
+    InvalidJson,
+    UnknownField,
+    TypeMismatch,
+    MissingRequired,
+    Invalid,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcValidationIssue {
+    /// JSONPath-style location, e.g. `$.address.city` or `$.tags[1]`.
+    pub path: String,
+    pub kind: GrpcValidationKind,
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GrpcRequestPayload {
@@ -103,6 +371,9 @@ pub struct GrpcRequestPayload {
     pub request_id: Option<String>,
     #[serde(default)]
     pub descriptor_bytes: Option<String>,
+    /// Values for `{{name}}` placeholders in the endpoint, metadata and request JSON.
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -116,6 +387,49 @@ pub struct GrpcResponsePayload {
     pub size: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrpcStreamStatus {
+    Open,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrpcStreamDirection {
+    Sent,
+    Received,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcStreamMessage {
+    pub seq: u64,
+    pub direction: GrpcStreamDirection,
+    pub json: String,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcStreamStartResponse {
+    pub stream_id: String,
+}
+
+/// State of an interactive stream plus messages newer than the polled sequence.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcStreamSnapshot {
+    pub stream_id: String,
+    pub status: GrpcStreamStatus,
+    pub send_closed: bool,
+    pub status_code: Option<i32>,
+    pub status_message: Option<String>,
+    pub response_metadata: HashMap<String, String>,
+    pub messages: Vec<GrpcStreamMessage>,
+}
+
 // ─── Environment Types ────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -133,9 +447,12 @@ pub struct Environment {
     pub id: String,
     pub name: String,
     pub variables: Vec<EnvVariable>,
+    /// Prepended to relative request URLs while this environment is active.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResolveRequestPayload {
     pub url: String,
@@ -143,6 +460,10 @@ pub struct ResolveRequestPayload {
     pub headers: HashMap<String, String>,
     pub body: Option<String>,
     pub environment_id: Option<String>,
+    /// Collection, folder and request variables, in increasing priority.
+    /// They override the environment.
+    #[serde(default)]
+    pub scoped_variables: Vec<EnvVariable>,
 }
 
 #[derive(Debug, Serialize)]
@@ -153,3 +474,297 @@ pub struct ResolvedRequest {
     pub headers: HashMap<String, String>,
     pub body: Option<String>,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyLocation {
+    #[default]
+    Header,
+    Query,
+}
+
+/// Request auth as configured in the editor, applied the same way the send path applies it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "kebab-case",
+    rename_all_fields = "camelCase"
+)]
+pub enum PreviewAuth {
+    Bearer {
+        token: String,
+    },
+    Basic {
+        username: String,
+        password: String,
+    },
+    ApiKey {
+        key: String,
+        value: String,
+        #[serde(default)]
+        add_to: ApiKeyLocation,
+    },
+    /// Digest, NTLM and signed schemes whose header is only known at send time.
+    Computed {
+        scheme: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParam {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewRequestPayload {
+    #[serde(flatten)]
+    pub request: ResolveRequestPayload,
+    #[serde(default)]
+    pub params: Vec<QueryParam>,
+    /// Values for `:name` and `{name}` placeholders in the URL path.
+    #[serde(default)]
+    pub path_params: Vec<QueryParam>,
+    #[serde(default)]
+    pub auth: Option<PreviewAuth>,
+    /// Shown as `User-Agent` when the headers do not set one.
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+/// A fully resolved request as it would be sent, with secret values masked.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestPreview {
+    pub url: String,
+    pub method: String,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    /// `{{name}}` placeholders that no variable resolved, sorted and deduplicated.
+    pub unresolved_variables: Vec<String>,
+}
+
+// ─── Window Types ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowState {
+    pub width: f64,
+    pub height: f64,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+// ─── Settings Types ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// How the save dialog names a request that still has the default name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RequestNaming {
+    #[default]
+    FullUrl,
+    Path,
+    HostPath,
+}
+
+/// A header added to every request that does not set it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultHeader {
+    pub id: String,
+    pub key: String,
+    pub value: String,
+    pub enabled: bool,
+}
+
+/// Proxy used by requests that do not choose their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProxySettings {
+    /// Blank falls back to `HTTP_PROXY` / `HTTPS_PROXY` from the environment.
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    /// Comma-separated hosts, domains or CIDRs that connect directly.
+    pub no_proxy: String,
+}
+
+/// App-wide preferences, stored apart from the workspace state. Fields
+/// missing from a saved copy take their defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AppSettings {
+    pub theme: Theme,
+    /// History entries kept; 0 keeps all of them.
+    pub history_limit: u32,
+    pub request_logging: bool,
+    pub connection_pool: ConnectionPoolSettings,
+    pub max_concurrent_requests: u32,
+    pub request_naming: RequestNaming,
+    /// JSON bodies larger than this are shown raw; 0 has no limit.
+    pub pretty_print_limit_kb: u64,
+    /// Response text rendered at first and per "Load more"; 0 renders everything.
+    pub render_chunk_kb: u64,
+    /// Responses slower than this are flagged; 0 turns the warning off.
+    pub slow_threshold_ms: u64,
+    /// Timeout for requests that set none and whose environment sets none; 0 waits forever.
+    pub default_timeout_ms: u64,
+    /// Open tabs, unsaved edits included, are kept across restarts.
+    pub restore_drafts: bool,
+    pub default_headers: Vec<DefaultHeader>,
+    /// Blank uses the built-in `Getman/<version>`.
+    pub user_agent: String,
+    pub proxy: ProxySettings,
+    pub replay_mode: ReplayMode,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            history_limit: 100,
+            request_logging: false,
+            connection_pool: ConnectionPoolSettings::default(),
+            max_concurrent_requests: 16,
+            request_naming: RequestNaming::default(),
+            pretty_print_limit_kb: 2048,
+            render_chunk_kb: 256,
+            slow_threshold_ms: 2000,
+            default_timeout_ms: 0,
+            restore_drafts: true,
+            default_headers: Vec::new(),
+            user_agent: String::new(),
+            proxy: ProxySettings::default(),
+            replay_mode: ReplayMode::default(),
+        }
+    }
+}
+
+// ─── History Types ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub id: String,
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub time: u64,
+    pub timestamp: u64,
+    #[serde(default)]
+    pub request_type: Option<String>,
+    #[serde(default)]
+    pub count: Option<u32>,
+    #[serde(default)]
+    pub last_timestamp: Option<u64>,
+    /// Full request snapshot as captured by the frontend when it was sent.
+    #[serde(default)]
+    pub request: Option<serde_json::Value>,
+    /// The send was cancelled before a response arrived.
+    #[serde(default)]
+    pub cancelled: bool,
+    /// gRPC status code of a gRPC call; `status` holds its HTTP equivalent
+    /// so the status filters still apply.
+    #[serde(default)]
+    pub grpc_status: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum HistoryStatusClass {
+    #[serde(rename = "2xx")]
+    Success,
+    #[serde(rename = "3xx")]
+    Redirect,
+    #[serde(rename = "4xx")]
+    ClientError,
+    #[serde(rename = "5xx")]
+    ServerError,
+    #[serde(rename = "error")]
+    NetworkError,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryQuery {
+    #[serde(default)]
+    pub search: Option<String>,
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub status_class: Option<HistoryStatusClass>,
+    #[serde(default)]
+    pub from_ms: Option<u64>,
+    #[serde(default)]
+    pub to_ms: Option<u64>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+// ─── Response Diff Types ──────────────────────────────────────────────────────
+
+/// The parts of a response compared against a baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseSnapshot {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// A response pinned for a saved request; the next sends are diffed against it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseBaseline {
+    pub request_id: String,
+    pub saved_at: u64,
+    pub response: ResponseSnapshot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffSection {
+    Status,
+    Header,
+    Body,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntry {
+    pub section: DiffSection,
+    pub kind: DiffKind,
+    /// Header name, JSON path such as `$.items[0].id`, or `line N` for text bodies.
+    pub path: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseDiff {
+    pub entries: Vec<DiffEntry>,
+    /// Both bodies parsed as JSON and were compared by structure, not text.
+    pub json_body: bool,
+    /// Entries past the display limit were dropped.
+    pub truncated: bool,
+}
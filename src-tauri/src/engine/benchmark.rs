@@ -1,52 +1,103 @@
 use crate::domain::{
-    BenchmarkAggregatedMetrics, BenchmarkEnvironmentFingerprint, BenchmarkErrorSample,
-    BenchmarkErrorType, BenchmarkHistogramBucket, BenchmarkLatencyMetrics, BenchmarkLoadMode,
-    BenchmarkSaveBodies, BenchmarkSpecPayload, BenchmarkSummaryMetrics, BenchmarkTimeseriesPoint,
+    BenchmarkAggregatedMetrics, BenchmarkCancelMode, BenchmarkEnvironmentFingerprint,
+    BenchmarkErrorSample, BenchmarkErrorType, BenchmarkHistogramBucket, BenchmarkLatencyMetrics,
+    BenchmarkLoadMode, BenchmarkSaveBodies, BenchmarkSizeMetrics, BenchmarkSlaConfig,
+    BenchmarkSlaVerdict, BenchmarkSlaViolation, BenchmarkSpecIssue, BenchmarkSpecIssueSeverity,
+    BenchmarkSpecPayload, BenchmarkSuccessSample, BenchmarkSummaryMetrics,
+    BenchmarkTimeseriesPoint, GetmanError,
+};
+use crate::engine::charset::decode_text;
+use crate::engine::connect_hook::{ConnectHook, OnConnect};
+use crate::engine::http::{
+    apply_proxy, build_headers, classify_reqwest_error, header_block_size, method_allows_body,
+    user_agent_header, ProxyOptions,
 };
-use crate::engine::http::build_headers;
 use hdrhistogram::Histogram;
-use reqwest::header::{HeaderMap, HeaderValue, CONNECTION};
-use reqwest::{Client, Method, Proxy, Response};
+use reqwest::header::{HeaderMap, HeaderValue, CONNECTION, CONTENT_TYPE};
+use reqwest::{Client, Method, Response};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio::sync::{broadcast, mpsc};
-
-const MAX_ERROR_BODY_BYTES: usize = 8 * 1024;
+use tokio::sync::{broadcast, mpsc, watch};
+
+const MAX_SAMPLE_BODY_BYTES: usize = 8 * 1024;
+const MAX_SUCCESS_SAMPLES: usize = 20;
+/// Success bodies a phase keeps in memory for the sample list, so long `All`
+/// or `Sampled` runs stay bounded (8 MB at most).
+const MAX_CAPTURED_SUCCESS_BODIES: u64 = 1000;
+/// Tokens a per-host bucket can hold; one keeps requests evenly spaced at the
+/// configured rate instead of allowing bursts above it.
+const RATE_LIMIT_BURST: f64 = 1.0;
 const HISTOGRAM_EDGES_MS: [f64; 18] = [
     0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0,
     20000.0, 30000.0, 45000.0, 60000.0,
 ];
 
+struct RunSignalSenders {
+    abort: broadcast::Sender<()>,
+    drain: watch::Sender<bool>,
+}
+
+/// Stop signals handed to a running benchmark: `abort_rx` fires to abort
+/// in-flight requests, `drain` turns true to ask workers to stop picking up
+/// new iterations.
+pub struct BenchmarkStopSignals {
+    pub abort_rx: broadcast::Receiver<()>,
+    pub drain: watch::Receiver<bool>,
+}
+
 #[derive(Clone)]
 pub struct BenchmarkRegistry {
-    senders: Arc<Mutex<HashMap<String, broadcast::Sender<()>>>>,
+    runs: Arc<Mutex<HashMap<String, RunSignalSenders>>>,
 }
 
 impl BenchmarkRegistry {
     pub fn new() -> Self {
         Self {
-            senders: Arc::new(Mutex::new(HashMap::new())),
+            runs: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    pub fn register(&self, id: &str) -> broadcast::Receiver<()> {
+    pub fn register(&self, id: &str) -> BenchmarkStopSignals {
         let (tx, rx) = broadcast::channel(1);
-        self.senders.lock().unwrap().insert(id.to_string(), tx);
-        rx
+        let (drain_tx, drain_rx) = watch::channel(false);
+        self.runs.lock().unwrap().insert(
+            id.to_string(),
+            RunSignalSenders {
+                abort: tx,
+                drain: drain_tx,
+            },
+        );
+        BenchmarkStopSignals {
+            abort_rx: rx,
+            drain: drain_rx,
+        }
     }
 
-    pub fn cancel(&self, id: &str) -> bool {
-        if let Some(tx) = self.senders.lock().unwrap().remove(id) {
-            let _ = tx.send(());
-            return true;
+    pub fn cancel(&self, id: &str, mode: BenchmarkCancelMode) -> bool {
+        let mut runs = self.runs.lock().unwrap();
+        match mode {
+            BenchmarkCancelMode::Abort => {
+                if let Some(signals) = runs.remove(id) {
+                    let _ = signals.abort.send(());
+                    return true;
+                }
+                false
+            }
+            // Keep the entry so a drain can still be escalated to an abort.
+            BenchmarkCancelMode::Drain => {
+                if let Some(signals) = runs.get(id) {
+                    signals.drain.send_replace(true);
+                    return true;
+                }
+                false
+            }
         }
-        false
     }
 
     pub fn remove(&self, id: &str) {
-        self.senders.lock().unwrap().remove(id);
+        self.runs.lock().unwrap().remove(id);
     }
 }
 
@@ -56,14 +107,68 @@ pub struct BenchmarkExecutionResult {
     pub cancelled: bool,
 }
 
+/// Connections a benchmark client has opened and the time spent opening
+/// them, which covers DNS, TCP, TLS and any proxy handshake.
+#[derive(Default)]
+struct ConnectionStats {
+    opened: AtomicU64,
+    connect_us_total: AtomicU64,
+    connect_us_max: AtomicU64,
+}
+
+impl ConnectionStats {
+    fn record(&self, elapsed: Duration) {
+        let micros = elapsed.as_micros() as u64;
+        self.opened.fetch_add(1, Ordering::Relaxed);
+        self.connect_us_total.fetch_add(micros, Ordering::Relaxed);
+        self.connect_us_max.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    /// Forget connections opened so far, e.g. by the warmup phase. Pooled
+    /// connections they left behind are reused without being counted again.
+    fn reset(&self) {
+        self.opened.store(0, Ordering::Relaxed);
+        self.connect_us_total.store(0, Ordering::Relaxed);
+        self.connect_us_max.store(0, Ordering::Relaxed);
+    }
+
+    fn apply(&self, summary: &mut BenchmarkSummaryMetrics) {
+        let opened = self.opened.load(Ordering::Relaxed);
+        let total_ms = self.connect_us_total.load(Ordering::Relaxed) as f64 / 1000.0;
+        summary.new_connections = opened;
+        summary.connect_time_avg_ms = if opened > 0 {
+            total_ms / opened as f64
+        } else {
+            0.0
+        };
+        summary.connect_time_max_ms = self.connect_us_max.load(Ordering::Relaxed) as f64 / 1000.0;
+    }
+}
+
+/// Times each connection the client establishes.
+impl ConnectHook for ConnectionStats {
+    type Token = Instant;
+
+    fn started(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn finished(&self, started_at: Instant, connected: bool) {
+        if connected {
+            self.record(started_at.elapsed());
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct RequestTemplate {
     method: Method,
     url: String,
     headers: HeaderMap,
     body: Option<String>,
-    keep_alive: bool,
     bytes_out: u64,
+    host_key: String,
+    max_rps_per_host: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -78,6 +183,16 @@ struct SampleResult {
     bytes_out: u64,
     sample_body: Option<String>,
     cancelled: bool,
+    throttle_wait_ms: f64,
+    response_header_bytes: Option<u64>,
+    response_body_bytes: Option<u64>,
+}
+
+/// Which response bodies a single request should keep for the report.
+#[derive(Debug, Clone, Copy, Default)]
+struct BodyCapture {
+    errors: bool,
+    success: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -137,6 +252,58 @@ struct SeriesBucket {
     bytes_out: u64,
 }
 
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token buckets shared by every worker of a phase, keyed by target host so
+/// the configured ceiling applies to the host rather than to each worker.
+struct HostRateLimiter {
+    rate_per_sec: f64,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl HostRateLimiter {
+    fn new(rate_per_sec: f64) -> Self {
+        Self {
+            rate_per_sec,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserve a token for `host` and return how long the caller has to wait
+    /// before sending. Reservations may drive the bucket negative so queued
+    /// workers are released one interval apart. Returns `None`, reserving
+    /// nothing, when the wait would end after `deadline`.
+    fn reserve(&self, host: &str, now: Instant, deadline: Option<Instant>) -> Option<Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(host.to_string()).or_insert(TokenBucket {
+            tokens: RATE_LIMIT_BURST,
+            refilled_at: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        if !elapsed.is_zero() {
+            bucket.tokens =
+                (bucket.tokens + elapsed.as_secs_f64() * self.rate_per_sec).min(RATE_LIMIT_BURST);
+            bucket.refilled_at = now;
+        }
+
+        let tokens = bucket.tokens - 1.0;
+        let wait = if tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-tokens / self.rate_per_sec)
+        };
+        if deadline.is_some_and(|deadline| now + wait > deadline) {
+            return None;
+        }
+        bucket.tokens = tokens;
+        Some(wait)
+    }
+}
+
 fn now_ms() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -157,10 +324,11 @@ pub fn collect_environment_fingerprint() -> BenchmarkEnvironmentFingerprint {
 
 pub async fn execute_benchmark(
     spec: BenchmarkSpecPayload,
-    cancel_rx: &mut broadcast::Receiver<()>,
+    signals: &mut BenchmarkStopSignals,
 ) -> Result<BenchmarkExecutionResult, String> {
     validate_spec(&spec)?;
-    let client = Arc::new(build_client(&spec)?);
+    let connections = Arc::new(ConnectionStats::default());
+    let client = Arc::new(build_client(&spec, connections.clone())?);
     let template = Arc::new(build_request_template(&spec)?);
 
     if let Some(workload) = warmup_workload(&spec) {
@@ -171,7 +339,7 @@ pub async fn execute_benchmark(
             spec.load.concurrency,
             false,
             &spec.logging.save_bodies,
-            cancel_rx,
+            signals,
         )
         .await?;
 
@@ -181,6 +349,7 @@ pub async fn execute_benchmark(
                 cancelled: true,
             });
         }
+        connections.reset();
     }
 
     let measurement = run_phase(
@@ -190,16 +359,21 @@ pub async fn execute_benchmark(
         spec.load.concurrency,
         true,
         &spec.logging.save_bodies,
-        cancel_rx,
+        signals,
     )
     .await?;
 
-    let metrics = aggregate_samples(
+    let mut metrics = aggregate_samples(
         measurement.samples,
         measurement.started_at_ms,
         measurement.finished_at_ms,
         spec.logging.sample_errors_top_k.max(1) as usize,
     )?;
+    connections.apply(&mut metrics.summary);
+    metrics.sla = spec
+        .sla
+        .as_ref()
+        .map(|sla| evaluate_sla(sla, &metrics.summary));
 
     Ok(BenchmarkExecutionResult {
         metrics,
@@ -207,15 +381,264 @@ pub async fn execute_benchmark(
     })
 }
 
-fn validate_spec(spec: &BenchmarkSpecPayload) -> Result<(), String> {
-    if spec.load.concurrency == 0 {
-        return Err("Benchmark concurrency must be greater than 0".to_string());
+/// Concurrency above this draws a warning; each worker holds a connection.
+const HIGH_CONCURRENCY: u32 = 1000;
+
+/// Problems with a spec, in field order. Errors stop `execute_benchmark`;
+/// warnings only inform the form.
+pub fn spec_issues(spec: &BenchmarkSpecPayload) -> Vec<BenchmarkSpecIssue> {
+    use BenchmarkSpecIssueSeverity::{Error, Warning};
+
+    let mut issues = Vec::new();
+    let mut issue = |field: &str, severity, message: String| {
+        issues.push(BenchmarkSpecIssue {
+            field: field.to_string(),
+            severity,
+            message,
+        });
+    };
+
+    if spec.target.request_snapshot.url.trim().is_empty() {
+        issue(
+            "target.url",
+            Error,
+            "Benchmark request URL is empty".to_string(),
+        );
+    }
+    let concurrency = spec.load.concurrency;
+    if concurrency == 0 {
+        let message = "Benchmark concurrency must be greater than 0".to_string();
+        issue("load.concurrency", Error, message);
+    } else if concurrency > HIGH_CONCURRENCY {
+        issue(
+            "load.concurrency",
+            Warning,
+            format!("Concurrency {concurrency} is very high and may exhaust local sockets"),
+        );
+    }
+    match measurement_workload(spec) {
+        Err(message) => {
+            let field = match spec.load.mode {
+                BenchmarkLoadMode::FixedIterations => "load.iterations",
+                BenchmarkLoadMode::FixedDuration => "load.durationMs",
+            };
+            issue(field, Error, message);
+        }
+        Ok(PhaseWorkload::Iterations(iterations)) if u64::from(concurrency) > iterations => {
+            issue(
+                "load.concurrency",
+                Warning,
+                format!(
+                    "Concurrency {concurrency} exceeds the {iterations} iterations; \
+                     only {iterations} workers will send requests"
+                ),
+            );
+        }
+        Ok(PhaseWorkload::DurationMs(duration_ms)) => {
+            let warmup_ms = spec.timing.warmup_duration_ms.unwrap_or(0);
+            if spec.timing.warmup_iterations.unwrap_or(0) == 0 && warmup_ms > duration_ms {
+                issue(
+                    "timing.warmupDurationMs",
+                    Warning,
+                    format!(
+                        "Warmup ({warmup_ms} ms) is longer than the measured duration \
+                         ({duration_ms} ms)"
+                    ),
+                );
+            }
+            if spec.timing.timeout_ms > duration_ms {
+                issue(
+                    "timing.timeoutMs",
+                    Warning,
+                    "Request timeout is longer than the measured duration".to_string(),
+                );
+            }
+        }
+        Ok(_) => {}
     }
     if spec.timing.timeout_ms == 0 {
-        return Err("Benchmark timeoutMs must be greater than 0".to_string());
+        issue(
+            "timing.timeoutMs",
+            Error,
+            "Benchmark timeoutMs must be greater than 0".to_string(),
+        );
+    }
+    let warmup = &spec.timing;
+    if warmup.warmup_iterations.unwrap_or(0) > 0 && warmup.warmup_duration_ms.unwrap_or(0) > 0 {
+        issue(
+            "timing.warmupDurationMs",
+            Warning,
+            "Warmup iterations take precedence; the warmup duration is ignored".to_string(),
+        );
+    }
+    if let Some(max_rps) = spec.transport.max_rps_per_host {
+        if !max_rps.is_finite() || max_rps <= 0.0 {
+            issue(
+                "transport.maxRpsPerHost",
+                Error,
+                "Benchmark maxRpsPerHost must be greater than 0".to_string(),
+            );
+        }
+    }
+    if let BenchmarkSaveBodies::Sampled { rate } = spec.logging.save_bodies {
+        if !rate.is_finite() || rate <= 0.0 || rate > 1.0 {
+            issue(
+                "logging.saveBodies",
+                Error,
+                "Benchmark sampled body rate must be between 0 and 1".to_string(),
+            );
+        }
+    }
+    issues
+}
+
+fn validate_spec(spec: &BenchmarkSpecPayload) -> Result<(), String> {
+    match spec_issues(spec)
+        .into_iter()
+        .find(|issue| issue.severity == BenchmarkSpecIssueSeverity::Error)
+    {
+        Some(issue) => Err(issue.message),
+        None => Ok(()),
+    }
+}
+
+/// Check aggregated metrics against the SLA thresholds that are set.
+pub fn evaluate_sla(
+    sla: &BenchmarkSlaConfig,
+    summary: &BenchmarkSummaryMetrics,
+) -> BenchmarkSlaVerdict {
+    let latency = &summary.latency;
+    let ceilings = [
+        ("maxP50Ms", sla.max_p50_ms, latency.p50_ms),
+        ("maxP90Ms", sla.max_p90_ms, latency.p90_ms),
+        ("maxP95Ms", sla.max_p95_ms, latency.p95_ms),
+        ("maxP99Ms", sla.max_p99_ms, latency.p99_ms),
+        ("maxErrorRate", sla.max_error_rate, summary.error_rate),
+    ];
+    let mut violations: Vec<BenchmarkSlaViolation> = ceilings
+        .into_iter()
+        .filter_map(|(criterion, threshold, actual)| {
+            let threshold = threshold?;
+            (actual > threshold).then(|| BenchmarkSlaViolation {
+                criterion: criterion.to_string(),
+                threshold,
+                actual,
+            })
+        })
+        .collect();
+    if let Some(min_rps) = sla.min_rps.filter(|min_rps| summary.rps_avg < *min_rps) {
+        violations.push(BenchmarkSlaViolation {
+            criterion: "minRps".to_string(),
+            threshold: min_rps,
+            actual: summary.rps_avg,
+        });
+    }
+    BenchmarkSlaVerdict {
+        passed: violations.is_empty(),
+        violations,
+    }
+}
+
+/// Escape a label value as the Prometheus text format requires.
+fn prometheus_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn prometheus_number(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
     }
-    measurement_workload(spec)?;
-    Ok(())
+}
+
+/// Render a run summary as Prometheus exposition text, labelled with the run
+/// and its target URL, e.g. for pushing to a Pushgateway.
+pub fn prometheus_exposition(
+    run_id: &str,
+    target: &str,
+    metrics: &BenchmarkAggregatedMetrics,
+) -> String {
+    let summary = &metrics.summary;
+    let latency = &summary.latency;
+    let labels = format!(
+        "run_id=\"{}\",target=\"{}\"",
+        prometheus_label(run_id),
+        prometheus_label(target)
+    );
+    let mut out = String::new();
+    let mut family = |name: &str, kind: &str, help: &str, samples: &[(&str, String, f64)]| {
+        out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n"));
+        for (suffix, extra_labels, value) in samples {
+            out.push_str(&format!(
+                "{name}{suffix}{{{labels}{extra_labels}}} {}\n",
+                prometheus_number(*value)
+            ));
+        }
+    };
+
+    let total = summary.total_requests as f64;
+    family(
+        "getman_benchmark_requests_total",
+        "counter",
+        "Requests sent during the run.",
+        &[("", String::new(), total)],
+    );
+    family(
+        "getman_benchmark_errors_total",
+        "counter",
+        "Requests that failed.",
+        &[("", String::new(), summary.error_count as f64)],
+    );
+    family(
+        "getman_benchmark_error_rate",
+        "gauge",
+        "Share of requests that failed, from 0 to 1.",
+        &[("", String::new(), summary.error_rate / 100.0)],
+    );
+    family(
+        "getman_benchmark_requests_per_second",
+        "gauge",
+        "Average request rate over the run.",
+        &[("", String::new(), summary.rps_avg)],
+    );
+    let quantiles = [
+        ("0.5", latency.p50_ms),
+        ("0.9", latency.p90_ms),
+        ("0.95", latency.p95_ms),
+        ("0.99", latency.p99_ms),
+    ];
+    let mut latency_samples: Vec<(&str, String, f64)> = quantiles
+        .into_iter()
+        .map(|(quantile, value)| ("", format!(",quantile=\"{quantile}\""), value))
+        .collect();
+    latency_samples.push(("_sum", String::new(), latency.avg_ms * total));
+    latency_samples.push(("_count", String::new(), total));
+    family(
+        "getman_benchmark_latency_ms",
+        "summary",
+        "Request latency in milliseconds.",
+        &latency_samples,
+    );
+    family(
+        "getman_benchmark_bytes_total",
+        "counter",
+        "Bytes transferred, by direction.",
+        &[
+            ("", ",direction=\"in\"".to_string(), summary.bytes_in as f64),
+            (
+                "",
+                ",direction=\"out\"".to_string(),
+                summary.bytes_out as f64,
+            ),
+        ],
+    );
+    out
 }
 
 fn warmup_workload(spec: &BenchmarkSpecPayload) -> Option<PhaseWorkload> {
@@ -251,8 +674,16 @@ fn measurement_workload(spec: &BenchmarkSpecPayload) -> Result<PhaseWorkload, St
     }
 }
 
-fn build_client(spec: &BenchmarkSpecPayload) -> Result<Client, String> {
-    let mut builder = Client::builder();
+/// Without keep-alive no connection is pooled, so every request pays for
+/// connection setup; with it, only the first request on each connection
+/// does. Either way `connections` records how often and for how long.
+fn build_client(
+    spec: &BenchmarkSpecPayload,
+    connections: Arc<ConnectionStats>,
+) -> Result<Client, String> {
+    let mut builder = Client::builder()
+        .connector_layer(OnConnect::new(connections))
+        .user_agent(user_agent_header(spec.transport.user_agent.as_deref())?);
     builder = if spec.transport.follow_redirects {
         builder.redirect(reqwest::redirect::Policy::limited(10))
     } else {
@@ -260,17 +691,16 @@ fn build_client(spec: &BenchmarkSpecPayload) -> Result<Client, String> {
     };
     builder = builder.timeout(Duration::from_millis(spec.timing.timeout_ms));
 
-    if let Some(proxy_url) = spec
-        .transport
-        .proxy_url
-        .as_ref()
-        .map(String::as_str)
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        let proxy = Proxy::all(proxy_url).map_err(|err| format!("Invalid proxy URL: {err}"))?;
-        builder = builder.proxy(proxy);
-    }
+    builder = apply_proxy(
+        builder,
+        ProxyOptions {
+            url: spec.transport.proxy_url.as_deref(),
+            username: spec.transport.proxy_username.as_deref(),
+            password: spec.transport.proxy_password.as_deref(),
+            no_proxy: spec.transport.no_proxy.as_deref().unwrap_or_default(),
+            bypass: spec.transport.bypass_proxy,
+        },
+    )?;
 
     if !spec.transport.verify_ssl {
         builder = builder.danger_accept_invalid_certs(true);
@@ -288,69 +718,84 @@ fn build_client(spec: &BenchmarkSpecPayload) -> Result<Client, String> {
 fn build_request_template(spec: &BenchmarkSpecPayload) -> Result<RequestTemplate, String> {
     let method = Method::from_bytes(spec.target.request_snapshot.method.as_bytes())
         .map_err(|err| format!("Invalid benchmark method: {err}"))?;
-    let headers = build_headers(&spec.target.request_snapshot.headers)?;
-    let body = if should_send_body(&method) {
+    let mut headers = build_headers(&spec.target.request_snapshot.headers)?;
+    if !spec.transport.keep_alive {
+        headers.insert(CONNECTION, HeaderValue::from_static("close"));
+    }
+    let body = if method_allows_body(&method) {
         spec.target.request_snapshot.body.clone()
     } else {
         None
     };
 
     let bytes_out = estimate_request_bytes(&headers, body.as_deref());
+    let url = spec.target.request_snapshot.url.clone();
 
     Ok(RequestTemplate {
         method,
-        url: spec.target.request_snapshot.url.clone(),
+        host_key: host_key(&url),
+        url,
         headers,
         body,
-        keep_alive: spec.transport.keep_alive,
         bytes_out,
+        max_rps_per_host: spec.transport.max_rps_per_host,
     })
 }
 
-fn should_send_body(method: &Method) -> bool {
-    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
-}
-
-fn estimate_request_bytes(headers: &HeaderMap, body: Option<&str>) -> u64 {
-    let mut bytes = 0u64;
-    for (name, value) in headers {
-        bytes += name.as_str().len() as u64;
-        bytes += value.as_bytes().len() as u64;
-        bytes += 4;
-    }
-    if let Some(body) = body {
-        bytes += body.as_bytes().len() as u64;
+fn host_key(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port_or_known_default()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            _ => url.to_string(),
+        },
+        Err(_) => url.to_string(),
     }
-    bytes
 }
 
-fn estimate_response_headers_bytes(headers: &HeaderMap) -> u64 {
-    let mut bytes = 0u64;
-    for (name, value) in headers {
-        bytes += name.as_str().len() as u64;
-        bytes += value.as_bytes().len() as u64;
-        bytes += 4;
+/// Decide body capture for the `sequence`-th request of a phase. Sampling is
+/// spread evenly across the run instead of using a random draw so repeated
+/// runs capture comparable requests. `captured` counts the success bodies
+/// already handed out; none are captured once it reaches the cap.
+fn body_capture(
+    save_bodies: &BenchmarkSaveBodies,
+    sequence: u64,
+    captured: &AtomicU64,
+) -> BodyCapture {
+    let mut capture = match save_bodies {
+        BenchmarkSaveBodies::None => BodyCapture::default(),
+        BenchmarkSaveBodies::Errors => BodyCapture {
+            errors: true,
+            success: false,
+        },
+        BenchmarkSaveBodies::All => BodyCapture {
+            errors: true,
+            success: true,
+        },
+        BenchmarkSaveBodies::Sampled { rate } => BodyCapture {
+            errors: true,
+            success: ((sequence + 1) as f64 * rate).floor() > (sequence as f64 * rate).floor(),
+        },
+    };
+    if capture.success {
+        capture.success = captured.fetch_add(1, Ordering::Relaxed) < MAX_CAPTURED_SUCCESS_BODIES;
     }
-    bytes
+    capture
 }
 
-fn classify_reqwest_error(err: &reqwest::Error) -> BenchmarkErrorType {
-    if err.is_timeout() {
-        return BenchmarkErrorType::Timeout;
-    }
+fn estimate_request_bytes(headers: &HeaderMap, body: Option<&str>) -> u64 {
+    header_block_size(headers) + body.map_or(0, |body| body.len() as u64)
+}
 
-    let message = err.to_string().to_ascii_lowercase();
-    if message.contains("dns") || message.contains("failed to lookup address") {
-        return BenchmarkErrorType::DnsError;
-    }
-    if message.contains("tls") || message.contains("ssl") || message.contains("certificate") {
-        return BenchmarkErrorType::TlsError;
+fn benchmark_error_type(error: &GetmanError) -> BenchmarkErrorType {
+    match error {
+        GetmanError::Timeout(_) => BenchmarkErrorType::Timeout,
+        GetmanError::Dns(_) => BenchmarkErrorType::DnsError,
+        GetmanError::Tls(_) => BenchmarkErrorType::TlsError,
+        GetmanError::Connect(_) | GetmanError::Proxy(_) => BenchmarkErrorType::ConnectError,
+        GetmanError::Cancelled(_) => BenchmarkErrorType::Canceled,
+        _ => BenchmarkErrorType::ReadError,
     }
-    if err.is_connect() {
-        return BenchmarkErrorType::ConnectError;
-    }
-
-    BenchmarkErrorType::ReadError
 }
 
 fn error_type_name(value: BenchmarkErrorType) -> &'static str {
@@ -369,7 +814,7 @@ fn error_type_name(value: BenchmarkErrorType) -> &'static str {
 async fn execute_single_request(
     client: &Client,
     template: &RequestTemplate,
-    save_bodies: &BenchmarkSaveBodies,
+    capture: BodyCapture,
     cancel_rx: &mut broadcast::Receiver<()>,
 ) -> SampleResult {
     let started_at = Instant::now();
@@ -377,10 +822,6 @@ async fn execute_single_request(
         .request(template.method.clone(), &template.url)
         .headers(template.headers.clone());
 
-    if !template.keep_alive {
-        request = request.header(CONNECTION, HeaderValue::from_static("close"));
-    }
-
     if let Some(body) = &template.body {
         request = request.body(body.clone());
     }
@@ -405,6 +846,9 @@ async fn execute_single_request(
             bytes_out: template.bytes_out,
             sample_body: None,
             cancelled: true,
+            throttle_wait_ms: 0.0,
+            response_header_bytes: None,
+            response_body_bytes: None,
         };
     };
 
@@ -415,24 +859,27 @@ async fn execute_single_request(
                 elapsed_ms,
                 completed_at,
                 template.bytes_out,
-                save_bodies,
+                capture,
                 cancel_rx,
             )
             .await
         }
         Err(err) => {
-            let error_type = classify_reqwest_error(&err);
+            let error = classify_reqwest_error("Request failed", &err);
             SampleResult {
                 timestamp_ms: completed_at,
                 latency_ms: elapsed_ms,
                 status_code: None,
                 success: false,
-                error_type: Some(error_type_name(error_type).to_string()),
-                error_message: Some(err.to_string()),
+                error_type: Some(error_type_name(benchmark_error_type(&error)).to_string()),
+                error_message: Some(error.to_string()),
                 bytes_in: 0,
                 bytes_out: template.bytes_out,
                 sample_body: None,
                 cancelled: false,
+                throttle_wait_ms: 0.0,
+                response_header_bytes: None,
+                response_body_bytes: None,
             }
         }
     }
@@ -443,7 +890,7 @@ async fn handle_response(
     elapsed_ms: f64,
     completed_at: u64,
     bytes_out: u64,
-    save_bodies: &BenchmarkSaveBodies,
+    capture: BodyCapture,
     cancel_rx: &mut broadcast::Receiver<()>,
 ) -> SampleResult {
     let status = response.status().as_u16();
@@ -455,7 +902,13 @@ async fn handle_response(
         None
     };
 
-    let response_headers_bytes = estimate_response_headers_bytes(response.headers());
+    let response_headers_bytes = header_block_size(response.headers());
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
     let bytes_result = tokio::select! {
         body = response.bytes() => Some(body),
         _ = cancel_rx.recv() => None,
@@ -473,17 +926,23 @@ async fn handle_response(
             bytes_out,
             sample_body: None,
             cancelled: true,
+            throttle_wait_ms: 0.0,
+            response_header_bytes: Some(response_headers_bytes),
+            response_body_bytes: None,
         };
     };
 
     match bytes_result {
         Ok(body_bytes) => {
             let response_body_len = body_bytes.len() as u64;
-            let sample_body = if status_family_error.is_some()
-                && matches!(save_bodies, BenchmarkSaveBodies::Errors)
-            {
-                let capped = &body_bytes[..body_bytes.len().min(MAX_ERROR_BODY_BYTES)];
-                Some(String::from_utf8_lossy(capped).to_string())
+            let keep_body = if status_family_error.is_some() {
+                capture.errors
+            } else {
+                capture.success
+            };
+            let sample_body = if keep_body {
+                let capped = &body_bytes[..body_bytes.len().min(MAX_SAMPLE_BODY_BYTES)];
+                Some(decode_text(capped, &content_type).text)
             } else {
                 None
             };
@@ -499,6 +958,9 @@ async fn handle_response(
                 bytes_out,
                 sample_body,
                 cancelled: false,
+                throttle_wait_ms: 0.0,
+                response_header_bytes: Some(response_headers_bytes),
+                response_body_bytes: Some(response_body_len),
             }
         }
         Err(err) => SampleResult {
@@ -512,6 +974,9 @@ async fn handle_response(
             bytes_out,
             sample_body: None,
             cancelled: false,
+            throttle_wait_ms: 0.0,
+            response_header_bytes: Some(response_headers_bytes),
+            response_body_bytes: None,
         },
     }
 }
@@ -534,12 +999,17 @@ async fn run_phase(
     concurrency: u32,
     collect_samples: bool,
     save_bodies: &BenchmarkSaveBodies,
-    cancel_rx: &mut broadcast::Receiver<()>,
+    signals: &mut BenchmarkStopSignals,
 ) -> Result<PhaseResult, String> {
     let worker_count = concurrency.max(1) as usize;
     let started_at_ms = now_ms();
     let cancelled = Arc::new(AtomicBool::new(false));
     let iteration_counter = Arc::new(AtomicU64::new(0));
+    let request_sequence = Arc::new(AtomicU64::new(0));
+    let captured_bodies = Arc::new(AtomicU64::new(0));
+    let rate_limiter = template
+        .max_rps_per_host
+        .map(|rate| Arc::new(HostRateLimiter::new(rate)));
     let deadline = match workload {
         PhaseWorkload::DurationMs(duration_ms) => {
             Some(Instant::now() + Duration::from_millis(duration_ms))
@@ -559,16 +1029,21 @@ async fn run_phase(
         let client = client.clone();
         let template = template.clone();
         let cancelled = cancelled.clone();
-        let mut worker_cancel_rx = cancel_rx.resubscribe();
-        let workload = workload;
+        let mut worker_cancel_rx = signals.abort_rx.resubscribe();
+        let mut drain = signals.drain.clone();
         let iteration_counter = iteration_counter.clone();
-        let deadline = deadline;
+        let request_sequence = request_sequence.clone();
+        let captured_bodies = captured_bodies.clone();
         let save_bodies = save_bodies.clone();
         let sample_tx = sample_tx.clone();
+        let rate_limiter = rate_limiter.clone();
 
         let handle = tokio::spawn(async move {
             loop {
-                if cancelled.load(Ordering::Relaxed) || cancel_requested(&mut worker_cancel_rx) {
+                if cancelled.load(Ordering::Relaxed)
+                    || *drain.borrow()
+                    || cancel_requested(&mut worker_cancel_rx)
+                {
                     cancelled.store(true, Ordering::Relaxed);
                     break;
                 }
@@ -589,9 +1064,42 @@ async fn run_phase(
                     }
                 }
 
-                let sample =
-                    execute_single_request(&client, &template, &save_bodies, &mut worker_cancel_rx)
+                // Time spent waiting for a token is reported separately and is
+                // not part of the request latency measured below.
+                let mut throttle_wait_ms = 0.0;
+                if let Some(rate_limiter) = &rate_limiter {
+                    // No token before the deadline means the phase is over for
+                    // this worker; sleeping past it would stretch the run.
+                    let Some(wait) =
+                        rate_limiter.reserve(&template.host_key, Instant::now(), deadline)
+                    else {
+                        break;
+                    };
+                    if !wait.is_zero() {
+                        tokio::select! {
+                            _ = tokio::time::sleep(wait) => {}
+                            _ = worker_cancel_rx.recv() => {
+                                cancelled.store(true, Ordering::Relaxed);
+                                break;
+                            }
+                            Ok(_) = drain.wait_for(|drain| *drain) => {
+                                cancelled.store(true, Ordering::Relaxed);
+                                break;
+                            }
+                        }
+                        throttle_wait_ms = wait.as_secs_f64() * 1000.0;
+                    }
+                }
+
+                let capture = body_capture(
+                    &save_bodies,
+                    request_sequence.fetch_add(1, Ordering::Relaxed),
+                    &captured_bodies,
+                );
+                let mut sample =
+                    execute_single_request(&client, &template, capture, &mut worker_cancel_rx)
                         .await;
+                sample.throttle_wait_ms = throttle_wait_ms;
 
                 if sample.cancelled {
                     cancelled.store(true, Ordering::Relaxed);
@@ -651,6 +1159,22 @@ fn histogram_bucket_index(latency_ms: f64) -> usize {
     HISTOGRAM_EDGES_MS.len() - 2
 }
 
+fn size_metrics(histogram: &Histogram<u64>) -> BenchmarkSizeMetrics {
+    if histogram.is_empty() {
+        return BenchmarkSizeMetrics::default();
+    }
+
+    BenchmarkSizeMetrics {
+        min_bytes: histogram.min(),
+        avg_bytes: round_to_3(histogram.mean()),
+        max_bytes: histogram.max(),
+        p50_bytes: histogram.value_at_quantile(0.50),
+        p90_bytes: histogram.value_at_quantile(0.90),
+        p95_bytes: histogram.value_at_quantile(0.95),
+        p99_bytes: histogram.value_at_quantile(0.99),
+    }
+}
+
 fn aggregate_samples(
     samples: Vec<SampleResult>,
     started_at_ms: u64,
@@ -661,9 +1185,14 @@ fn aggregate_samples(
     let mut stats = RunningStats::default();
     let mut latency_histogram = Histogram::<u64>::new_with_bounds(1, 60_000_000, 3)
         .map_err(|err| format!("Failed to initialize latency histogram: {err}"))?;
+    let mut body_size_histogram = Histogram::<u64>::new(3)
+        .map_err(|err| format!("Failed to initialize response size histogram: {err}"))?;
+    let mut header_size_histogram = Histogram::<u64>::new(3)
+        .map_err(|err| format!("Failed to initialize response size histogram: {err}"))?;
     let mut histogram_counts = vec![0u64; HISTOGRAM_EDGES_MS.len() - 1];
     let mut series = BTreeMap::<u64, SeriesBucket>::new();
     let mut top_error_map = HashMap::<String, BenchmarkErrorSample>::new();
+    let mut success_sample_map = HashMap::<(u16, String), u64>::new();
 
     for sample in samples {
         if sample.cancelled {
@@ -673,9 +1202,22 @@ fn aggregate_samples(
         summary.total_requests += 1;
         summary.bytes_in += sample.bytes_in;
         summary.bytes_out += sample.bytes_out;
+        if let Some(header_bytes) = sample.response_header_bytes {
+            let _ = header_size_histogram.record(header_bytes);
+        }
+        if let Some(body_bytes) = sample.response_body_bytes {
+            let _ = body_size_histogram.record(body_bytes);
+        }
+        if sample.throttle_wait_ms > 0.0 {
+            summary.rate_limited_requests += 1;
+            summary.rate_limit_wait_ms += sample.throttle_wait_ms;
+        }
 
         if sample.success {
             summary.success_count += 1;
+            if let (Some(status), Some(body)) = (sample.status_code, sample.sample_body.clone()) {
+                *success_sample_map.entry((status, body)).or_insert(0) += 1;
+            }
         } else {
             summary.error_count += 1;
         }
@@ -733,6 +1275,10 @@ fn aggregate_samples(
         bucket.latencies.push(sample.latency_ms);
     }
 
+    summary.rate_limit_wait_ms = round_to_3(summary.rate_limit_wait_ms);
+    summary.response_body_size = size_metrics(&body_size_histogram);
+    summary.response_header_size = size_metrics(&header_size_histogram);
+
     if summary.total_requests > 0 {
         summary.error_rate =
             round_to_3((summary.error_count as f64 / summary.total_requests as f64) * 100.0);
@@ -801,11 +1347,30 @@ fn aggregate_samples(
     });
     top_errors.truncate(top_k_errors.max(1));
 
+    let mut success_samples: Vec<BenchmarkSuccessSample> = success_sample_map
+        .into_iter()
+        .map(|((status_code, body), count)| BenchmarkSuccessSample {
+            status_code,
+            body,
+            count,
+        })
+        .collect();
+    success_samples.sort_by(|left, right| {
+        right
+            .count
+            .cmp(&left.count)
+            .then_with(|| left.status_code.cmp(&right.status_code))
+            .then_with(|| left.body.cmp(&right.body))
+    });
+    success_samples.truncate(MAX_SUCCESS_SAMPLES);
+
     Ok(BenchmarkAggregatedMetrics {
         summary,
         timeseries,
         histogram,
         top_errors,
+        success_samples,
+        sla: None,
     })
 }
 
@@ -816,6 +1381,7 @@ mod tests {
         BenchmarkEnvConfig, BenchmarkLoadConfig, BenchmarkLoggingConfig, BenchmarkRequestSnapshot,
         BenchmarkTarget, BenchmarkTimingConfig, BenchmarkTransportConfig,
     };
+    use crate::engine::test_server;
 
     fn base_spec() -> BenchmarkSpecPayload {
         BenchmarkSpecPayload {
@@ -840,7 +1406,13 @@ mod tests {
                 keep_alive: true,
                 follow_redirects: true,
                 proxy_url: None,
+                proxy_username: None,
+                proxy_password: None,
+                no_proxy: None,
+                bypass_proxy: false,
                 verify_ssl: true,
+                max_rps_per_host: None,
+                user_agent: None,
             },
             timing: BenchmarkTimingConfig {
                 timeout_ms: 1000,
@@ -855,6 +1427,7 @@ mod tests {
                 variables_snapshot: HashMap::new(),
                 random_seed: None,
             },
+            sla: None,
         }
     }
 
@@ -880,6 +1453,9 @@ mod tests {
             bytes_out: 50,
             sample_body: None,
             cancelled: false,
+            throttle_wait_ms: 0.0,
+            response_header_bytes: None,
+            response_body_bytes: None,
         }
     }
 
@@ -951,4 +1527,499 @@ mod tests {
         spec.load.iterations = Some(0);
         assert!(measurement_workload(&spec).is_err());
     }
+
+    fn issue_fields(
+        spec: &BenchmarkSpecPayload,
+        severity: BenchmarkSpecIssueSeverity,
+    ) -> Vec<String> {
+        spec_issues(spec)
+            .into_iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| issue.field)
+            .collect()
+    }
+
+    #[test]
+    fn spec_issues_report_each_error() {
+        let errors =
+            |spec: &BenchmarkSpecPayload| issue_fields(spec, BenchmarkSpecIssueSeverity::Error);
+        assert!(spec_issues(&base_spec()).is_empty());
+
+        let mut spec = base_spec();
+        spec.target.request_snapshot.url = " ".to_string();
+        assert_eq!(errors(&spec), ["target.url"]);
+
+        let mut spec = base_spec();
+        spec.load.concurrency = 0;
+        assert_eq!(errors(&spec), ["load.concurrency"]);
+        assert_eq!(
+            validate_spec(&spec).unwrap_err(),
+            "Benchmark concurrency must be greater than 0"
+        );
+
+        let mut spec = base_spec();
+        spec.load.duration_ms = None;
+        assert_eq!(errors(&spec), ["load.durationMs"]);
+
+        let mut spec = base_spec();
+        spec.load.mode = BenchmarkLoadMode::FixedIterations;
+        assert_eq!(errors(&spec), ["load.iterations"]);
+
+        let mut spec = base_spec();
+        spec.timing.timeout_ms = 0;
+        assert_eq!(errors(&spec), ["timing.timeoutMs"]);
+    }
+
+    #[test]
+    fn spec_issues_warn_without_blocking_the_run() {
+        let warnings = |spec: &BenchmarkSpecPayload| {
+            assert!(validate_spec(spec).is_ok());
+            issue_fields(spec, BenchmarkSpecIssueSeverity::Warning)
+        };
+
+        let mut spec = base_spec();
+        spec.load.mode = BenchmarkLoadMode::FixedIterations;
+        spec.load.iterations = Some(5);
+        spec.load.concurrency = 8;
+        assert_eq!(warnings(&spec), ["load.concurrency"]);
+
+        let mut spec = base_spec();
+        spec.load.concurrency = 5000;
+        assert_eq!(warnings(&spec), ["load.concurrency"]);
+
+        let mut spec = base_spec();
+        spec.timing.warmup_duration_ms = Some(2000);
+        assert_eq!(warnings(&spec), ["timing.warmupDurationMs"]);
+
+        let mut spec = base_spec();
+        spec.timing.warmup_iterations = Some(10);
+        spec.timing.warmup_duration_ms = Some(500);
+        assert_eq!(warnings(&spec), ["timing.warmupDurationMs"]);
+
+        let mut spec = base_spec();
+        spec.timing.timeout_ms = 5000;
+        assert_eq!(warnings(&spec), ["timing.timeoutMs"]);
+    }
+
+    #[test]
+    fn validate_spec_rejects_non_positive_rate_limit() {
+        let mut spec = base_spec();
+        spec.transport.max_rps_per_host = Some(0.0);
+        assert!(validate_spec(&spec).is_err());
+
+        spec.transport.max_rps_per_host = Some(-5.0);
+        assert!(validate_spec(&spec).is_err());
+
+        spec.transport.max_rps_per_host = Some(f64::NAN);
+        assert!(validate_spec(&spec).is_err());
+
+        spec.transport.max_rps_per_host = Some(20.0);
+        assert!(validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn host_rate_limiter_spaces_requests_per_host() {
+        let limiter = HostRateLimiter::new(10.0);
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter.reserve("example.com:443", now, None),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            limiter.reserve("example.com:443", now, None),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            limiter.reserve("example.com:443", now, None),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            limiter.reserve("other.test:80", now, None),
+            Some(Duration::ZERO)
+        );
+
+        // A wait ending after the deadline is refused without using a token.
+        let deadline = Some(now + Duration::from_millis(250));
+        assert_eq!(limiter.reserve("example.com:443", now, deadline), None);
+        assert_eq!(
+            limiter.reserve("example.com:443", now, None),
+            Some(Duration::from_millis(300))
+        );
+
+        let later = now + Duration::from_millis(800);
+        assert_eq!(
+            limiter.reserve("example.com:443", later, None),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[tokio::test]
+    async fn throttled_workers_stop_at_the_phase_deadline() {
+        let server = test_server::serve(|_, stream| {
+            test_server::send(stream, &test_server::reply("200 OK", &[], "ok"));
+        });
+        let mut spec = base_spec();
+        spec.target.request_snapshot.url = server.url("/");
+        spec.load.duration_ms = Some(1000);
+        spec.load.concurrency = 20;
+        spec.transport.max_rps_per_host = Some(2.0);
+        let registry = BenchmarkRegistry::new();
+        let mut signals = registry.register("run");
+
+        let started = Instant::now();
+        let result = execute_benchmark(spec, &mut signals).await.unwrap();
+        assert!(
+            started.elapsed() < Duration::from_millis(1500),
+            "{:?}",
+            started.elapsed()
+        );
+        assert!(!result.cancelled);
+        assert!(result.metrics.summary.success_count <= 3);
+    }
+
+    #[tokio::test]
+    async fn draining_interrupts_a_throttled_wait() {
+        let server = test_server::serve(|_, stream| {
+            test_server::send(stream, &test_server::reply("200 OK", &[], "ok"));
+        });
+        let mut spec = base_spec();
+        spec.target.request_snapshot.url = server.url("/");
+        spec.load.duration_ms = Some(10_000);
+        spec.load.concurrency = 5;
+        spec.transport.max_rps_per_host = Some(0.5);
+        let registry = BenchmarkRegistry::new();
+        let mut signals = registry.register("run");
+        let drainer = registry.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            drainer.cancel("run", BenchmarkCancelMode::Drain);
+        });
+
+        let started = Instant::now();
+        let result = execute_benchmark(spec, &mut signals).await.unwrap();
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            started.elapsed()
+        );
+        assert!(result.cancelled);
+    }
+
+    fn sla_summary() -> BenchmarkSummaryMetrics {
+        let samples = (1..=100)
+            .map(|latency| sample(latency as f64, 200, latency != 100))
+            .collect();
+        aggregate_samples(samples, 0, 1000, 10)
+            .expect("aggregate metrics")
+            .summary
+    }
+
+    #[tokio::test]
+    async fn new_connections_are_counted_with_and_without_keep_alive() {
+        let server = test_server::serve(|_, stream| {
+            test_server::send(stream, &test_server::reply("200 OK", &[], "ok"));
+        });
+        let url = server.url("/");
+        let registry = BenchmarkRegistry::new();
+        // Warmup opens the single keep-alive connection the measured phase reuses.
+        for (keep_alive, expected) in [(true, 0), (false, 6)] {
+            let mut spec = base_spec();
+            spec.target.request_snapshot.url = url.clone();
+            spec.load.mode = BenchmarkLoadMode::FixedIterations;
+            spec.load.iterations = Some(6);
+            spec.load.duration_ms = None;
+            spec.transport.keep_alive = keep_alive;
+            spec.timing.warmup_iterations = Some(2);
+            let mut signals = registry.register("run");
+
+            let result = execute_benchmark(spec, &mut signals).await.unwrap();
+            let summary = &result.metrics.summary;
+            assert_eq!(summary.success_count, 6);
+            assert_eq!(summary.new_connections, expected, "keep_alive={keep_alive}");
+            assert!(summary.connect_time_max_ms >= summary.connect_time_avg_ms);
+        }
+    }
+
+    type ParsedSample = (String, Vec<(String, String)>, f64);
+
+    /// Parse Prometheus exposition text strictly enough to reject malformed
+    /// names, label escapes, values, or samples without a `# TYPE` line.
+    fn parse_exposition(text: &str) -> Result<Vec<ParsedSample>, String> {
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        };
+        let mut typed = Vec::new();
+        let mut samples = Vec::new();
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = rest.split_once(' ').ok_or("TYPE without a kind")?;
+                if !is_name(name) || !["counter", "gauge", "summary"].contains(&kind) {
+                    return Err(format!("bad TYPE line: {line}"));
+                }
+                typed.push(name.to_string());
+                continue;
+            }
+            if line.starts_with("# HELP ") {
+                continue;
+            }
+            let name_end = line.find(['{', ' ']).ok_or("sample without a value")?;
+            let name = &line[..name_end];
+            if !is_name(name) {
+                return Err(format!("bad metric name: {name}"));
+            }
+            let family = ["_sum", "_count"]
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))
+                .filter(|base| typed.iter().any(|typed| typed == base))
+                .unwrap_or(name);
+            if !typed.iter().any(|typed| typed == family) {
+                return Err(format!("sample before its TYPE: {name}"));
+            }
+            let mut rest = &line[name_end..];
+            let mut labels = Vec::new();
+            if let Some(inner) = rest.strip_prefix('{') {
+                rest = inner;
+                loop {
+                    let (label, after) = rest.split_once("=\"").ok_or("bad label")?;
+                    if !is_name(label) {
+                        return Err(format!("bad label name: {label}"));
+                    }
+                    let mut value = String::new();
+                    let mut chars = after.char_indices();
+                    let end = loop {
+                        match chars.next().ok_or("unterminated label value")? {
+                            (index, '"') => break index,
+                            (_, '\\') => match chars.next().ok_or("dangling escape")?.1 {
+                                '\\' => value.push('\\'),
+                                '"' => value.push('"'),
+                                'n' => value.push('\n'),
+                                other => return Err(format!("bad escape: \\{other}")),
+                            },
+                            (_, '\n') => return Err("raw newline in label".into()),
+                            (_, c) => value.push(c),
+                        }
+                    };
+                    labels.push((label.to_string(), value));
+                    rest = &after[end + 1..];
+                    if let Some(after) = rest.strip_prefix(',') {
+                        rest = after;
+                    } else {
+                        rest = rest.strip_prefix('}').ok_or("unclosed label set")?;
+                        break;
+                    }
+                }
+            }
+            let value = rest.strip_prefix(' ').ok_or("missing value separator")?;
+            let value = match value {
+                "NaN" => f64::NAN,
+                "+Inf" => f64::INFINITY,
+                "-Inf" => f64::NEG_INFINITY,
+                value => value.parse().map_err(|_| format!("bad value: {value}"))?,
+            };
+            samples.push((name.to_string(), labels, value));
+        }
+        Ok(samples)
+    }
+
+    #[test]
+    fn prometheus_export_is_valid_exposition_text() {
+        let metrics = aggregate_samples(
+            (1..=100)
+                .map(|latency| sample(latency as f64, 200, latency != 100))
+                .collect(),
+            0,
+            1000,
+            10,
+        )
+        .expect("aggregate metrics");
+        let target = "https://api.test/items?q=\"a\\b\"\nnext";
+        let text = prometheus_exposition("bench-1", target, &metrics);
+        let samples = parse_exposition(&text).unwrap_or_else(|err| panic!("{err}:\n{text}"));
+
+        let find = |name: &str, quantile: Option<&str>| {
+            samples
+                .iter()
+                .find(|(sample, labels, _)| {
+                    sample == name
+                        && labels
+                            .iter()
+                            .find(|(label, _)| label == "quantile")
+                            .map(|l| &*l.1)
+                            == quantile
+                })
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        let (_, labels, total) = find("getman_benchmark_requests_total", None);
+        assert_eq!(*total, 100.0);
+        assert_eq!(
+            labels,
+            &[
+                ("run_id".to_string(), "bench-1".to_string()),
+                ("target".to_string(), target.to_string()),
+            ]
+        );
+        assert_eq!(find("getman_benchmark_error_rate", None).2, 0.01);
+        let p95 = find("getman_benchmark_latency_ms", Some("0.95")).2;
+        assert!((p95 - metrics.summary.latency.p95_ms).abs() < f64::EPSILON);
+        assert_eq!(find("getman_benchmark_latency_ms_count", None).2, 100.0);
+        assert!(parse_exposition("getman_x 1").is_err());
+    }
+
+    #[test]
+    fn sla_passes_when_every_threshold_is_met() {
+        let sla = BenchmarkSlaConfig {
+            max_p95_ms: Some(200.0),
+            max_error_rate: Some(1.0),
+            min_rps: Some(50.0),
+            ..BenchmarkSlaConfig::default()
+        };
+        let verdict = evaluate_sla(&sla, &sla_summary());
+        assert!(verdict.passed);
+        assert!(verdict.violations.is_empty());
+    }
+
+    #[test]
+    fn sla_fails_with_the_breached_percentile() {
+        let summary = sla_summary();
+        let sla = BenchmarkSlaConfig {
+            max_p50_ms: Some(100.0),
+            max_p95_ms: Some(50.0),
+            max_error_rate: Some(1.0),
+            ..BenchmarkSlaConfig::default()
+        };
+        let verdict = evaluate_sla(&sla, &summary);
+        assert!(!verdict.passed);
+        assert_eq!(verdict.violations.len(), 1);
+        let violation = &verdict.violations[0];
+        assert_eq!(violation.criterion, "maxP95Ms");
+        assert_eq!(violation.threshold, 50.0);
+        assert_eq!(violation.actual, summary.latency.p95_ms);
+    }
+
+    #[test]
+    fn aggregate_samples_reports_rate_limit_wait_separately() {
+        let mut throttled = sample(10.0, 200, true);
+        throttled.throttle_wait_ms = 40.0;
+
+        let result = aggregate_samples(vec![throttled, sample(30.0, 200, true)], 0, 1000, 10)
+            .expect("aggregate metrics");
+
+        assert_eq!(result.summary.rate_limited_requests, 1);
+        assert_eq!(result.summary.rate_limit_wait_ms, 40.0);
+        assert_eq!(result.summary.latency.max_ms, 30.0);
+    }
+
+    #[test]
+    fn body_capture_samples_success_bodies_evenly() {
+        let counter = AtomicU64::new(0);
+        let sampled = BenchmarkSaveBodies::Sampled { rate: 0.25 };
+        let captured = (0..100)
+            .filter(|sequence| body_capture(&sampled, *sequence, &counter).success)
+            .count();
+        assert_eq!(captured, 25);
+        assert!(body_capture(&sampled, 0, &counter).errors);
+
+        assert!(body_capture(&BenchmarkSaveBodies::All, 7, &counter).success);
+        assert!(!body_capture(&BenchmarkSaveBodies::Errors, 7, &counter).success);
+        assert!(!body_capture(&BenchmarkSaveBodies::None, 7, &counter).errors);
+    }
+
+    #[test]
+    fn body_capture_stops_keeping_success_bodies_at_the_cap() {
+        let counter = AtomicU64::new(0);
+        let captured = (0..MAX_CAPTURED_SUCCESS_BODIES + 500)
+            .filter(|sequence| body_capture(&BenchmarkSaveBodies::All, *sequence, &counter).success)
+            .count();
+        assert_eq!(captured as u64, MAX_CAPTURED_SUCCESS_BODIES);
+
+        let capture = body_capture(&BenchmarkSaveBodies::All, 0, &counter);
+        assert!(capture.errors && !capture.success);
+    }
+
+    #[test]
+    fn validate_spec_rejects_out_of_range_sample_rate() {
+        let mut spec = base_spec();
+        spec.logging.save_bodies = BenchmarkSaveBodies::Sampled { rate: 0.0 };
+        assert!(validate_spec(&spec).is_err());
+
+        spec.logging.save_bodies = BenchmarkSaveBodies::Sampled { rate: 1.5 };
+        assert!(validate_spec(&spec).is_err());
+
+        spec.logging.save_bodies = BenchmarkSaveBodies::Sampled { rate: 0.1 };
+        assert!(validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn aggregate_samples_groups_success_bodies() {
+        let mut empty = sample(10.0, 200, true);
+        empty.sample_body = Some(String::new());
+        let mut ok = sample(12.0, 200, true);
+        ok.sample_body = Some("{\"ok\":true}".to_string());
+
+        let result = aggregate_samples(
+            vec![empty.clone(), ok, empty, sample(11.0, 200, true)],
+            0,
+            1000,
+            10,
+        )
+        .expect("aggregate metrics");
+
+        assert_eq!(result.success_samples.len(), 2);
+        assert_eq!(result.success_samples[0].body, "");
+        assert_eq!(result.success_samples[0].count, 2);
+        assert_eq!(result.success_samples[1].count, 1);
+    }
+
+    #[test]
+    fn aggregate_samples_computes_response_size_percentiles() {
+        let samples = [120u64, 80, 1500, 400, 400, 0, 950, 200, 400, 1900]
+            .into_iter()
+            .map(|body_bytes| {
+                let mut item = sample(10.0, 200, true);
+                item.response_header_bytes = Some(64);
+                item.response_body_bytes = Some(body_bytes);
+                item
+            })
+            .collect();
+
+        let result = aggregate_samples(samples, 0, 1000, 10).expect("aggregate metrics");
+        let body = &result.summary.response_body_size;
+
+        assert_eq!(body.min_bytes, 0);
+        assert_eq!(body.max_bytes, 1900);
+        assert_eq!(body.p50_bytes, 400);
+        assert_eq!(body.p90_bytes, 1500);
+        assert_eq!(body.p99_bytes, 1900);
+        assert!((body.avg_bytes - 595.0).abs() < 0.001);
+        assert_eq!(result.summary.response_header_size.p99_bytes, 64);
+    }
+
+    #[test]
+    fn aggregate_samples_skips_sizes_without_response() {
+        let result = aggregate_samples(vec![sample(10.0, 200, true)], 0, 1000, 10)
+            .expect("aggregate metrics");
+        assert_eq!(
+            result.summary.response_body_size,
+            BenchmarkSizeMetrics::default()
+        );
+    }
+
+    #[test]
+    fn registry_drain_keeps_run_abortable() {
+        let registry = BenchmarkRegistry::new();
+        let mut signals = registry.register("run-1");
+
+        assert!(registry.cancel("run-1", BenchmarkCancelMode::Drain));
+        assert!(*signals.drain.borrow());
+        assert!(!cancel_requested(&mut signals.abort_rx));
+
+        assert!(registry.cancel("run-1", BenchmarkCancelMode::Abort));
+        assert!(cancel_requested(&mut signals.abort_rx));
+        assert!(!registry.cancel("run-1", BenchmarkCancelMode::Drain));
+    }
 }
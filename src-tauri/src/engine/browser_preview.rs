@@ -0,0 +1,93 @@
+use std::fs;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Write an HTML response body to a temp file and open it in the default
+/// browser. A `<base href>` for `url` is added so relative links, styles and
+/// images load from the server the page came from.
+pub fn open_html(html: &str, url: &str) -> Result<(), String> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let path = std::env::temp_dir().join(format!(
+        "getman-preview-{}-{}.html",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&path, with_base_href(html, url))
+        .map_err(|err| format!("Failed to write preview file: {err}"))?;
+    open::that_detached(&path).map_err(|err| format!("Failed to open browser: {err}"))
+}
+
+/// `html` with a `<base href="url">` as the first element of its head. Pages
+/// that set their own base, and empty URLs, are left alone.
+pub fn with_base_href(html: &str, url: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    if url.is_empty() || lower.contains("<base ") || lower.contains("<base>") {
+        return html.to_string();
+    }
+    let base = format!(
+        "<base href=\"{}\">",
+        url.replace('&', "&amp;").replace('"', "&quot;")
+    );
+    let at = ["<head", "<html"]
+        .iter()
+        .find_map(|tag| opening_tag_end(&lower, tag))
+        .unwrap_or(0);
+    format!("{}{base}{}", &html[..at], &html[at..])
+}
+
+/// Byte offset just past the first `tag` element's opening tag.
+fn opening_tag_end(lower: &str, tag: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(found) = lower[from..].find(tag) {
+        let start = from + found;
+        let after = start + tag.len();
+        match lower.as_bytes().get(after) {
+            // `<header>` is not `<head>`.
+            Some(b'>' | b' ' | b'\t' | b'\n' | b'\r') => {
+                return lower[after..].find('>').map(|end| after + end + 1);
+            }
+            _ => from = after,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://shop.test/catalog/items?page=2&sort=name";
+
+    #[test]
+    fn base_goes_first_in_the_head() {
+        let html = "<!DOCTYPE html><html lang=\"en\"><head><title>x</title></head></html>";
+        assert_eq!(
+            with_base_href(html, URL),
+            "<!DOCTYPE html><html lang=\"en\"><head>\
+             <base href=\"https://shop.test/catalog/items?page=2&amp;sort=name\">\
+             <title>x</title></head></html>"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_html_tag_then_the_start() {
+        assert_eq!(
+            with_base_href(
+                "<HTML>\n<body><header>hi</header></body></HTML>",
+                "https://a.test/"
+            ),
+            "<HTML><base href=\"https://a.test/\">\n<body><header>hi</header></body></HTML>"
+        );
+        assert_eq!(
+            with_base_href("<p>hi</p>", "https://a.test/"),
+            "<base href=\"https://a.test/\"><p>hi</p>"
+        );
+    }
+
+    #[test]
+    fn keeps_a_base_the_page_sets() {
+        let html = "<html><head><BASE href=\"/static/\"></head></html>";
+        assert_eq!(with_base_href(html, URL), html);
+        assert_eq!(with_base_href("<p>hi</p>", ""), "<p>hi</p>");
+    }
+}
@@ -0,0 +1,104 @@
+use encoding_rs::{Encoding, REPLACEMENT, UTF_8};
+
+/// Body text and how it was decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedText {
+    pub text: String,
+    /// Name of the encoding used, lowercased, e.g. `utf-8` or `shift_jis`.
+    pub encoding: String,
+    /// Charset declared by `Content-Type` that is not supported; the text
+    /// was decoded as UTF-8 instead.
+    pub unsupported_charset: Option<String>,
+}
+
+/// The `charset` parameter of a `Content-Type` value, unquoted.
+fn charset_param(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"'))
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// Decode a response body as text with the WHATWG encoding of the declared
+/// `charset`, so `iso-8859-1` reads as windows-1252 the way browsers do. A
+/// byte order mark wins over the declared charset; without either the body
+/// is read as UTF-8. Invalid sequences and unsupported charsets decode
+/// lossily.
+pub fn decode_text(bytes: &[u8], content_type: &str) -> DecodedText {
+    let declared = charset_param(content_type);
+    // Labels mapped to `replacement` (e.g. ISO-2022-KR) would turn the whole
+    // body into one U+FFFD; reading them as UTF-8 shows more.
+    let encoding = declared
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .filter(|encoding| *encoding != REPLACEMENT);
+    let (text, used, _) = encoding.unwrap_or(UTF_8).decode(bytes);
+    DecodedText {
+        text: text.into_owned(),
+        encoding: used.name().to_ascii_lowercase(),
+        unsupported_charset: declared.filter(|_| encoding.is_none()).map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latin1_body_is_decoded_from_the_declared_charset() {
+        let body = b"caf\xe9 \x80 na\xefve";
+        let decoded = decode_text(body, "text/plain; charset=\"ISO-8859-1\"");
+        assert_eq!(decoded.text, "café € naïve");
+        assert_eq!(decoded.encoding, "windows-1252");
+        assert_eq!(decoded.unsupported_charset, None);
+
+        let lossy = decode_text(body, "text/plain");
+        assert_eq!(lossy.encoding, "utf-8");
+        assert!(lossy.text.contains(char::REPLACEMENT_CHARACTER));
+    }
+
+    #[test]
+    fn byte_order_mark_overrides_declared_charset() {
+        let utf16 = [0xFF, 0xFE, b'h', 0, b'i', 0, 0xAC, 0x20];
+        let decoded = decode_text(&utf16, "text/plain; charset=iso-8859-1");
+        assert_eq!(decoded.text, "hi€");
+        assert_eq!(decoded.encoding, "utf-16le");
+
+        let utf8 = decode_text(b"\xEF\xBB\xBF{\"a\":1}", "application/json");
+        assert_eq!(utf8.text, "{\"a\":1}");
+    }
+
+    #[test]
+    fn multibyte_charsets_are_decoded() {
+        // "日本語" in Shift_JIS, EUC-KR "한국", GBK "中文" and KOI8-R "мир".
+        let cases: [(&[u8], &str, &str, &str); 4] = [
+            (
+                b"\x93\xfa\x96\x7b\x8c\xea",
+                "Shift_JIS",
+                "日本語",
+                "shift_jis",
+            ),
+            (b"\xc7\xd1\xb1\xb9", "euc-kr", "한국", "euc-kr"),
+            (b"\xd6\xd0\xce\xc4", "GBK", "中文", "gbk"),
+            (b"\xcd\xc9\xd2", "koi8-r", "мир", "koi8-r"),
+        ];
+        for (bytes, charset, text, encoding) in cases {
+            let decoded = decode_text(bytes, &format!("text/html; Charset={charset}"));
+            assert_eq!(decoded.text, text, "{charset}");
+            assert_eq!(decoded.encoding, encoding);
+            assert_eq!(decoded.unsupported_charset, None);
+        }
+    }
+
+    #[test]
+    fn unsupported_charset_falls_back_to_utf8() {
+        for charset in ["x-made-up", "iso-2022-kr"] {
+            let decoded = decode_text("ok".as_bytes(), &format!("text/html; charset={charset}"));
+            assert_eq!(decoded.text, "ok");
+            assert_eq!(decoded.encoding, "utf-8");
+            assert_eq!(decoded.unsupported_charset.as_deref(), Some(charset));
+        }
+    }
+}
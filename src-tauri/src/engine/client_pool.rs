@@ -0,0 +1,90 @@
+use crate::domain::ConnectionPoolSettings;
+use reqwest::Client;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Distinct client configurations kept alive at once. Past this the cache is
+/// emptied rather than tracking usage; rebuilding a client is cheap.
+const MAX_CACHED_CLIENTS: usize = 32;
+
+/// Shared `reqwest` clients, one per transport configuration, so repeated
+/// sends to a host reuse pooled keep-alive connections.
+pub struct ClientPool {
+    settings: Mutex<ConnectionPoolSettings>,
+    clients: Mutex<HashMap<String, Client>>,
+}
+
+impl ClientPool {
+    pub fn new() -> Self {
+        Self {
+            settings: Mutex::new(ConnectionPoolSettings::default()),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn settings(&self) -> ConnectionPoolSettings {
+        *self.settings.lock().unwrap()
+    }
+
+    /// Apply new pool limits. Cached clients were built with the old limits,
+    /// so they are dropped and their idle connections closed.
+    pub fn configure(&self, settings: ConnectionPoolSettings) {
+        let mut current = self.settings.lock().unwrap();
+        if *current != settings {
+            *current = settings;
+            self.clients.lock().unwrap().clear();
+        }
+    }
+
+    /// Return the client cached under `key`, building it with the current
+    /// pool settings on first use.
+    pub fn get_or_build(
+        &self,
+        key: &str,
+        build: impl FnOnce(ConnectionPoolSettings) -> Result<Client, String>,
+    ) -> Result<Client, String> {
+        if let Some(client) = self.clients.lock().unwrap().get(key) {
+            return Ok(client.clone());
+        }
+        let client = build(self.settings())?;
+        let mut clients = self.clients.lock().unwrap();
+        if clients.len() >= MAX_CACHED_CLIENTS {
+            clients.clear();
+        }
+        Ok(clients.entry(key.to_string()).or_insert(client).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn clients_are_reused_until_settings_change() {
+        let pool = ClientPool::new();
+        let pool_settings = |max_idle_per_host| ConnectionPoolSettings {
+            max_idle_per_host,
+            idle_timeout_ms: 30_000,
+        };
+        let builds = Cell::new(0);
+        let build = |settings: ConnectionPoolSettings| {
+            builds.set(builds.get() + 1);
+            assert_eq!(settings, pool_settings(4));
+            Ok(Client::new())
+        };
+
+        pool.configure(pool_settings(4));
+        pool.get_or_build("a", build).unwrap();
+        pool.get_or_build("a", build).unwrap();
+        pool.get_or_build("b", build).unwrap();
+        assert_eq!(builds.get(), 2);
+
+        pool.configure(pool_settings(4));
+        pool.get_or_build("a", build).unwrap();
+        assert_eq!(builds.get(), 2);
+
+        pool.configure(pool_settings(8));
+        assert!(pool.get_or_build("a", |_| Err("rebuilt".into())).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::domain::SendResponsePayload;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+const IF_NONE_MATCH: &str = "If-None-Match";
+const IF_MODIFIED_SINCE: &str = "If-Modified-Since";
+
+/// Last successful response for a URL along with its cache validators.
+struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+    content_type: String,
+    encoding: String,
+    body_encoding: Option<String>,
+    size: u64,
+}
+
+/// Remembers `ETag`/`Last-Modified` per method and URL so repeated sends can
+/// be made conditional, and replays the cached body when the server answers
+/// `304 Not Modified`.
+pub struct ConditionalCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+fn cache_key(method: &str, url: &str) -> Option<String> {
+    let method = method.to_ascii_uppercase();
+    // Only safe reads are revalidated; other methods always hit the server.
+    matches!(method.as_str(), "GET" | "HEAD").then(|| format!("{method} {url}"))
+}
+
+fn has_header(headers: &HashMap<String, String>, name: &str) -> bool {
+    headers.keys().any(|key| key.eq_ignore_ascii_case(name))
+}
+
+/// Drop conditional headers, e.g. from follow-up requests they do not
+/// describe.
+pub fn strip_validators(headers: &mut HashMap<String, String>) {
+    headers.retain(|key, _| {
+        !key.eq_ignore_ascii_case(IF_NONE_MATCH) && !key.eq_ignore_ascii_case(IF_MODIFIED_SINCE)
+    });
+}
+
+fn response_header(response: &SendResponsePayload, name: &str) -> Option<String> {
+    response
+        .headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl ConditionalCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Add validators from the cached response, unless the request already
+    /// sets its own conditional headers.
+    pub fn apply_validators(&self, method: &str, url: &str, headers: &mut HashMap<String, String>) {
+        let Some(key) = cache_key(method, url) else {
+            return;
+        };
+        if has_header(headers, IF_NONE_MATCH) || has_header(headers, IF_MODIFIED_SINCE) {
+            return;
+        }
+
+        let entries = self.entries.lock().unwrap();
+        let Some(cached) = entries.get(&key) else {
+            return;
+        };
+        if let Some(etag) = &cached.etag {
+            headers.insert(IF_NONE_MATCH.to_string(), etag.clone());
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            headers.insert(IF_MODIFIED_SINCE.to_string(), last_modified.clone());
+        }
+    }
+
+    /// Fill a `304` response from the cache, or remember a fresh `2xx` response
+    /// that carries validators. Pages merged by pagination are neither cached
+    /// nor revalidated: the validators only describe the first page.
+    pub fn resolve(
+        &self,
+        method: &str,
+        url: &str,
+        mut response: SendResponsePayload,
+    ) -> SendResponsePayload {
+        let Some(key) = cache_key(method, url) else {
+            return response;
+        };
+        let mut entries = self.entries.lock().unwrap();
+        if response.pages > 1 {
+            entries.remove(&key);
+            return response;
+        }
+
+        if response.status == 304 {
+            if let Some(cached) = entries.get(&key) {
+                response.body = cached.body.clone();
+                response.size = cached.size;
+                response.decoded_size = cached.size;
+                response.encoding = cached.encoding.clone();
+                response.body_encoding = cached.body_encoding.clone();
+                response.not_modified = true;
+                // A 304 without `Content-Type` got the `text/plain` fallback.
+                if response_header(&response, "content-type").is_none() {
+                    response.content_type = cached.content_type.clone();
+                }
+            }
+            return response;
+        }
+
+        if !(200..300).contains(&response.status) || response.truncated {
+            return response;
+        }
+        let etag = response_header(&response, "etag");
+        let last_modified = response_header(&response, "last-modified");
+        if etag.is_none() && last_modified.is_none() {
+            entries.remove(&key);
+            return response;
+        }
+        entries.insert(
+            key,
+            CachedResponse {
+                etag,
+                last_modified,
+                body: response.body.clone(),
+                content_type: response.content_type.clone(),
+                encoding: response.encoding.clone(),
+                body_encoding: response.body_encoding.clone(),
+                size: response.size,
+            },
+        );
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::http::error_response;
+
+    const URL: &str = "https://api.test/items";
+
+    /// A response as `response_to_payload` builds it, with `text/plain`
+    /// standing in for a missing `Content-Type`.
+    fn response(status: u16, body: &str, headers: &[(&str, &str)]) -> SendResponsePayload {
+        let mut response = error_response(body);
+        response.status = status;
+        response.size = body.len() as u64;
+        response.headers = headers
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        response.content_type =
+            response_header(&response, "content-type").unwrap_or_else(|| "text/plain".into());
+        response
+    }
+
+    #[test]
+    fn not_modified_replays_cached_body() {
+        let cache = ConditionalCache::new();
+        let fresh = response(
+            200,
+            "{\"v\":1}",
+            &[("etag", "\"abc\""), ("content-type", "application/json")],
+        );
+        cache.resolve("GET", URL, fresh);
+
+        let mut headers = HashMap::new();
+        cache.apply_validators("get", URL, &mut headers);
+        assert_eq!(
+            headers.get(IF_NONE_MATCH).map(String::as_str),
+            Some("\"abc\"")
+        );
+        assert!(!headers.contains_key(IF_MODIFIED_SINCE));
+
+        let replayed = cache.resolve("GET", URL, response(304, "", &[]));
+        assert_eq!(replayed.status, 304);
+        assert!(replayed.not_modified);
+        assert_eq!(replayed.body, "{\"v\":1}");
+        assert_eq!(replayed.size, 7);
+        assert_eq!(replayed.content_type, "application/json");
+
+        let retyped = cache.resolve(
+            "GET",
+            URL,
+            response(304, "", &[("Content-Type", "text/csv")]),
+        );
+        assert_eq!(retyped.content_type, "text/csv");
+    }
+
+    #[test]
+    fn not_modified_keeps_a_still_encoded_body_encoded() {
+        let cache = ConditionalCache::new();
+        let mut fresh = response(200, "H4sIAAAA", &[("etag", "\"gz\"")]);
+        fresh.encoding = "base64".into();
+        fresh.body_encoding = Some("gzip".into());
+        cache.resolve("GET", URL, fresh);
+
+        let replayed = cache.resolve("GET", URL, response(304, "", &[]));
+        assert_eq!(replayed.body, "H4sIAAAA");
+        assert_eq!(replayed.encoding, "base64");
+        assert_eq!(replayed.body_encoding.as_deref(), Some("gzip"));
+    }
+
+    #[test]
+    fn validators_respect_existing_headers_and_unsafe_methods() {
+        let cache = ConditionalCache::new();
+        let fresh = response(
+            200,
+            "ok",
+            &[("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")],
+        );
+        cache.resolve("GET", URL, fresh);
+
+        let mut headers = HashMap::from([("if-none-match".to_string(), "\"mine\"".to_string())]);
+        cache.apply_validators("GET", URL, &mut headers);
+        assert_eq!(headers.len(), 1);
+
+        let mut headers = HashMap::new();
+        cache.apply_validators("POST", URL, &mut headers);
+        assert!(headers.is_empty());
+
+        let passthrough = cache.resolve("POST", URL, response(304, "", &[]));
+        assert!(!passthrough.not_modified);
+    }
+
+    #[test]
+    fn merged_pages_are_not_cached_under_the_first_pages_validators() {
+        let cache = ConditionalCache::new();
+        cache.resolve("GET", URL, response(200, "[1]", &[("etag", "\"p1\"")]));
+
+        let mut merged = response(200, "[1,2,3]", &[("etag", "\"p1\"")]);
+        merged.pages = 3;
+        cache.resolve("GET", URL, merged);
+
+        let mut headers = HashMap::new();
+        cache.apply_validators("GET", URL, &mut headers);
+        assert!(headers.is_empty());
+
+        let mut headers = HashMap::from([
+            ("if-none-match".to_string(), "\"p1\"".to_string()),
+            ("If-Modified-Since".to_string(), "yesterday".to_string()),
+            ("Accept".to_string(), "*/*".to_string()),
+        ]);
+        strip_validators(&mut headers);
+        assert_eq!(headers.keys().collect::<Vec<_>>(), ["Accept"]);
+    }
+
+    #[test]
+    fn responses_without_validators_clear_the_entry() {
+        let cache = ConditionalCache::new();
+        cache.resolve("GET", URL, response(200, "v1", &[("etag", "\"1\"")]));
+        cache.resolve("GET", URL, response(200, "v2", &[]));
+
+        let mut headers = HashMap::new();
+        cache.apply_validators("GET", URL, &mut headers);
+        assert!(headers.is_empty());
+
+        let unmatched = cache.resolve("GET", URL, response(304, "", &[]));
+        assert!(!unmatched.not_modified);
+        assert_eq!(unmatched.body, "");
+    }
+}
@@ -0,0 +1,86 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Callbacks around each new connection a client opens. reqwest connects in
+/// one step, so a connection covers DNS, TCP, any proxy tunnel and the TLS
+/// handshake; the steps cannot be told apart from here.
+pub trait ConnectHook: Send + Sync + 'static {
+    /// State carried from `started` to `finished`.
+    type Token: Send + 'static;
+
+    fn started(&self) -> Self::Token;
+
+    /// The connection is ready (`connected`) or failed to open.
+    fn finished(&self, token: Self::Token, connected: bool);
+}
+
+/// Connector layer running a `ConnectHook` around every new connection.
+pub struct OnConnect<H>(Arc<H>);
+
+impl<H> OnConnect<H> {
+    pub fn new(hook: Arc<H>) -> Self {
+        Self(hook)
+    }
+}
+
+impl<H> Clone for OnConnect<H> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<S, H> Layer<S> for OnConnect<H> {
+    type Service = HookedConnector<S, H>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HookedConnector {
+            inner,
+            hook: self.0.clone(),
+        }
+    }
+}
+
+pub struct HookedConnector<S, H> {
+    inner: S,
+    hook: Arc<H>,
+}
+
+impl<S: Clone, H> Clone for HookedConnector<S, H> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            hook: self.hook.clone(),
+        }
+    }
+}
+
+impl<S, H, R> Service<R> for HookedConnector<S, H>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+    S::Response: Send + 'static,
+    S::Error: Send + 'static,
+    H: ConnectHook,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: R) -> Self::Future {
+        let hook = self.hook.clone();
+        let token = hook.started();
+        let connecting = self.inner.call(target);
+        Box::pin(async move {
+            let connection = connecting.await;
+            hook.finished(token, connection.is_ok());
+            connection
+        })
+    }
+}
@@ -0,0 +1,348 @@
+use bytes::Bytes;
+use http_body_util::channel::Channel;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Empty, Full};
+use hyper::body::Incoming;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+
+/// Longest wait `/delay/{ms}` honors.
+const MAX_DELAY_MS: u64 = 30_000;
+/// Most lines `/stream/{n}` sends.
+const MAX_STREAM_LINES: u64 = 1_000;
+/// Pause between the lines of `/stream/{n}`.
+const STREAM_INTERVAL: Duration = Duration::from_millis(100);
+
+type EchoBody = BoxBody<Bytes, Infallible>;
+
+struct RunningServer {
+    port: u16,
+    shutdown: broadcast::Sender<()>,
+}
+
+/// Local HTTP server with canned endpoints, for trying the client without a
+/// network target. At most one runs at a time, on `127.0.0.1`.
+///
+/// - `/echo/...` answers with the request as JSON
+/// - `/delay/{ms}` echoes after waiting `ms` milliseconds
+/// - `/status/{code}` answers with that status
+/// - `/stream/{n}` sends `n` JSON lines, one every 100 ms
+pub struct EchoServer {
+    running: Mutex<Option<RunningServer>>,
+}
+
+impl EchoServer {
+    pub fn new() -> Self {
+        Self {
+            running: Mutex::new(None),
+        }
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.running
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|server| server.port)
+    }
+
+    /// Listen on `port`, or on a free port for `0`, and return the port in
+    /// use. When a server is already running its port is returned instead.
+    pub async fn start(&self, port: u16) -> Result<u16, String> {
+        if let Some(port) = self.port() {
+            return Ok(port);
+        }
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .await
+            .map_err(|err| format!("Failed to start echo server on port {port}: {err}"))?;
+        let port = listener
+            .local_addr()
+            .map_err(|err| format!("Failed to start echo server: {err}"))?
+            .port();
+
+        let mut running = self.running.lock().unwrap();
+        if let Some(server) = running.as_ref() {
+            // Another start won the race; keep that server.
+            return Ok(server.port);
+        }
+        let (shutdown, shutdown_rx) = broadcast::channel(1);
+        tokio::spawn(serve(listener, shutdown_rx));
+        *running = Some(RunningServer { port, shutdown });
+        Ok(port)
+    }
+
+    /// Stop accepting connections. Returns whether a server was running.
+    pub fn stop(&self) -> bool {
+        match self.running.lock().unwrap().take() {
+            Some(server) => {
+                let _ = server.shutdown.send(());
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+async fn serve(listener: TcpListener, mut shutdown: broadcast::Receiver<()>) {
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(_) => continue,
+            },
+            _ = shutdown.recv() => return,
+        };
+        tokio::spawn(async move {
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service_fn(handle))
+                .await;
+        });
+    }
+}
+
+async fn handle(request: Request<Incoming>) -> Result<Response<EchoBody>, Infallible> {
+    let path = request.uri().path().to_string();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let response = match segments.as_slice() {
+        ["echo", ..] => echo(request).await,
+        ["delay", ms] => match ms.parse::<u64>() {
+            Ok(ms) => {
+                tokio::time::sleep(Duration::from_millis(ms.min(MAX_DELAY_MS))).await;
+                echo(request).await
+            }
+            Err(_) => error(
+                StatusCode::BAD_REQUEST,
+                "Delay must be a number of milliseconds",
+            ),
+        },
+        ["status", code] => status(code),
+        ["stream", lines] => match lines.parse::<u64>() {
+            Ok(lines) => stream(lines.min(MAX_STREAM_LINES)),
+            Err(_) => error(StatusCode::BAD_REQUEST, "Line count must be a number"),
+        },
+        _ => error(
+            StatusCode::NOT_FOUND,
+            "Unknown endpoint; try /echo, /delay/{ms}, /status/{code} or /stream/{n}",
+        ),
+    };
+    Ok(response)
+}
+
+fn json_response(status: StatusCode, value: Value) -> Response<EchoBody> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(value.to_string())).boxed())
+        .unwrap()
+}
+
+fn error(status: StatusCode, message: &str) -> Response<EchoBody> {
+    json_response(status, json!({ "error": message }))
+}
+
+/// The request as JSON: method, path, query, headers and body text.
+async fn echo(request: Request<Incoming>) -> Response<EchoBody> {
+    let (parts, body) = request.into_parts();
+    let body = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(err) => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                &format!("Failed to read request body: {err}"),
+            )
+        }
+    };
+
+    let query: BTreeMap<String, String> = parts
+        .uri
+        .query()
+        .map(|query| {
+            url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut headers: BTreeMap<String, String> = BTreeMap::new();
+    for (name, value) in &parts.headers {
+        let value = String::from_utf8_lossy(value.as_bytes());
+        headers
+            .entry(name.to_string())
+            .and_modify(|existing| {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            })
+            .or_insert_with(|| value.into_owned());
+    }
+
+    json_response(
+        StatusCode::OK,
+        json!({
+            "method": parts.method.as_str(),
+            "path": parts.uri.path(),
+            "query": query,
+            "headers": headers,
+            "body": String::from_utf8_lossy(&body),
+        }),
+    )
+}
+
+fn status(code: &str) -> Response<EchoBody> {
+    let Some(status) = code
+        .parse::<u16>()
+        .ok()
+        .filter(|code| (200..=599).contains(code))
+        .and_then(|code| StatusCode::from_u16(code).ok())
+    else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Status must be between 200 and 599",
+        );
+    };
+    if matches!(status, StatusCode::NO_CONTENT | StatusCode::NOT_MODIFIED) {
+        let mut response = Response::new(Empty::new().boxed());
+        *response.status_mut() = status;
+        return response;
+    }
+    json_response(status, json!({ "status": status.as_u16() }))
+}
+
+/// `lines` newline-delimited JSON objects, sent one at a time.
+fn stream(lines: u64) -> Response<EchoBody> {
+    let (mut sender, body) = Channel::<Bytes, Infallible>::new(1);
+    tokio::spawn(async move {
+        for line in 0..lines {
+            if line > 0 {
+                tokio::time::sleep(STREAM_INTERVAL).await;
+            }
+            let chunk = Bytes::from(format!("{{\"line\":{line}}}\n"));
+            if sender.send_data(chunk).await.is_err() {
+                return;
+            }
+        }
+    });
+    Response::builder()
+        .header(CONTENT_TYPE, "application/x-ndjson")
+        .body(body.boxed())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{GetmanError, SendRequestPayload};
+    use crate::engine::client_pool::ClientPool;
+    use crate::engine::http::send_http_request_impl;
+
+    fn payload(url: String, method: &str, extra: Value) -> SendRequestPayload {
+        let mut value = json!({ "method": method, "url": url, "headers": {} });
+        if let (Value::Object(target), Value::Object(extra)) = (&mut value, extra) {
+            target.extend(extra);
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    async fn send(
+        payload: SendRequestPayload,
+    ) -> Result<crate::domain::SendResponsePayload, GetmanError> {
+        let clients = ClientPool::new();
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        send_http_request_impl(payload, &clients, &mut cancel_rx).await
+    }
+
+    #[tokio::test]
+    async fn echoes_method_query_headers_and_body() {
+        let server = EchoServer::new();
+        let port = server.start(0).await.unwrap();
+        assert_eq!(server.start(0).await.unwrap(), port);
+
+        let response = send(payload(
+            format!("http://127.0.0.1:{port}/echo/orders?page=2&q=a%20b"),
+            "POST",
+            json!({ "headers": { "X-Trace": "t-1" }, "body": "{\"id\":7}" }),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        let echoed: Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(echoed["method"], "POST");
+        assert_eq!(echoed["path"], "/echo/orders");
+        assert_eq!(echoed["query"], json!({ "page": "2", "q": "a b" }));
+        assert_eq!(echoed["headers"]["x-trace"], "t-1");
+        assert_eq!(echoed["body"], "{\"id\":7}");
+        assert!(server.stop());
+        assert!(!server.stop());
+    }
+
+    #[tokio::test]
+    async fn status_endpoint_returns_the_requested_code() {
+        let server = EchoServer::new();
+        let port = server.start(0).await.unwrap();
+        let base = format!("http://127.0.0.1:{port}");
+
+        let failed = send(payload(format!("{base}/status/503"), "GET", json!({})))
+            .await
+            .unwrap();
+        assert_eq!(failed.status, 503);
+        assert_eq!(failed.body, "{\"status\":503}");
+
+        let empty = send(payload(format!("{base}/status/204"), "GET", json!({})))
+            .await
+            .unwrap();
+        assert_eq!((empty.status, empty.body.as_str()), (204, ""));
+
+        let invalid = send(payload(format!("{base}/status/99"), "GET", json!({})))
+            .await
+            .unwrap();
+        assert_eq!(invalid.status, 400);
+        server.stop();
+    }
+
+    #[tokio::test]
+    async fn delay_outlasting_the_request_timeout_times_out() {
+        let server = EchoServer::new();
+        let port = server.start(0).await.unwrap();
+        let base = format!("http://127.0.0.1:{port}");
+
+        let slow = send(payload(
+            format!("{base}/delay/2000"),
+            "GET",
+            json!({ "timeoutMs": 100 }),
+        ))
+        .await;
+        assert!(matches!(slow, Err(GetmanError::Timeout(_))), "{slow:?}");
+
+        let quick = send(payload(format!("{base}/delay/10"), "GET", json!({})))
+            .await
+            .unwrap();
+        assert_eq!(quick.status, 200);
+        server.stop();
+    }
+
+    #[tokio::test]
+    async fn stream_sends_every_line() {
+        let server = EchoServer::new();
+        let port = server.start(0).await.unwrap();
+
+        let response = send(payload(
+            format!("http://127.0.0.1:{port}/stream/3"),
+            "GET",
+            json!({}),
+        ))
+        .await
+        .unwrap();
+        assert_eq!(response.content_type, "application/x-ndjson");
+        assert_eq!(response.body, "{\"line\":0}\n{\"line\":1}\n{\"line\":2}\n");
+        server.stop();
+    }
+}
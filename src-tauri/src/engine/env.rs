@@ -2,7 +2,7 @@ use crate::domain::{EnvVariable, Environment, ResolvedRequest, ResolveRequestPay
 use std::collections::HashMap;
 
 /// Interpolate `{{key}}` placeholders in a string using the provided variable map.
-fn interpolate(input: &str, variables: &HashMap<String, String>) -> String {
+pub(crate) fn interpolate(input: &str, variables: &HashMap<String, String>) -> String {
     let mut result = input.to_string();
     for (key, value) in variables {
         result = result.replace(&format!("{{{{{key}}}}}"), value);
@@ -37,20 +37,170 @@ pub fn build_variable_map(
     variables
 }
 
+/// Whether `url` carries its own scheme (`https://`, `ws://`, ...).
+fn is_absolute_url(url: &str) -> bool {
+    url.split_once("://").is_some_and(|(scheme, _)| {
+        let mut chars = scheme.chars();
+        chars.next().is_some_and(|first| first.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Prepend `base` to a relative `url`, joining with exactly one slash.
+/// Absolute URLs and an empty base are returned unchanged.
+pub fn join_base_url(base: &str, url: &str) -> String {
+    let base = base.trim();
+    let url = url.trim();
+    if base.is_empty() || is_absolute_url(url) {
+        return url.to_string();
+    }
+
+    let base = base.trim_end_matches('/');
+    if url.is_empty() {
+        return base.to_string();
+    }
+    if url.starts_with('?') || url.starts_with('#') {
+        return format!("{base}{url}");
+    }
+    format!("{base}/{}", url.trim_start_matches('/'))
+}
+
+/// Byte range of the path in `url`, after any scheme and authority and
+/// before the query or fragment.
+fn path_range(url: &str) -> (usize, usize) {
+    let start = match url.find("://").filter(|_| is_absolute_url(url)) {
+        Some(scheme_end) => url[scheme_end + 3..]
+            .find(['/', '?', '#'])
+            .map_or(url.len(), |index| scheme_end + 3 + index),
+        None => 0,
+    };
+    let end = url[start..]
+        .find(['?', '#'])
+        .map_or(url.len(), |index| start + index);
+    (start, end)
+}
+
+/// Length of the identifier at the start of `text`, or 0 if there is none.
+fn identifier_len(text: &str) -> usize {
+    let mut chars = text.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_') {
+        return 0;
+    }
+    1 + chars
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .count()
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters, like
+/// `encodeURIComponent` minus its extra `!'()*` allowances. Must match
+/// `encodePathSegment` in `lib/getman-store.ts`, which encodes the URL sent.
+fn encode_path_segment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+/// Substitute `/:name` segments and `{name}` placeholders in the URL path
+/// with URL-encoded values. `{{var}}` placeholders and names without a value
+/// are left untouched.
+pub fn apply_path_params(url: &str, params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return url.to_string();
+    }
+    let lookup = |name: &str| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| encode_path_segment(value))
+    };
+    let (start, end) = path_range(url);
+    let path = &url[start..end];
+    let mut out = String::with_capacity(url.len());
+    out.push_str(&url[..start]);
+
+    let mut index = 0;
+    while let Some(ch) = path[index..].chars().next() {
+        let rest = &path[index..];
+        let after_slash = path[..index].ends_with('/');
+        let replaced = match ch {
+            ':' if after_slash => {
+                let len = identifier_len(&rest[1..]);
+                (len > 0)
+                    .then(|| lookup(&rest[1..=len]))
+                    .flatten()
+                    .map(|value| (value, len + 1))
+            }
+            '{' if !path[..index].ends_with('{') => {
+                let len = identifier_len(&rest[1..]);
+                let closing = &rest[(1 + len).min(rest.len())..];
+                (len > 0 && closing.starts_with('}') && !closing.starts_with("}}"))
+                    .then(|| lookup(&rest[1..=len]))
+                    .flatten()
+                    .map(|value| (value, len + 2))
+            }
+            _ => None,
+        };
+        match replaced {
+            Some((value, consumed)) => {
+                out.push_str(&value);
+                index += consumed;
+            }
+            None => {
+                out.push(ch);
+                index += ch.len_utf8();
+            }
+        }
+    }
+    out.push_str(&url[end..]);
+    out
+}
+
+fn selected_environment<'a>(
+    payload: &ResolveRequestPayload,
+    environments: &'a [Environment],
+) -> Option<&'a Environment> {
+    payload
+        .environment_id
+        .as_ref()
+        .and_then(|id| environments.iter().find(|e| e.id == *id))
+}
+
+/// Variables visible to a request: globals, then the selected environment,
+/// then the payload's scoped variables.
+pub fn request_variables(
+    payload: &ResolveRequestPayload,
+    global_variables: &[EnvVariable],
+    environments: &[Environment],
+) -> HashMap<String, String> {
+    let mut variables =
+        build_variable_map(global_variables, selected_environment(payload, environments));
+    for v in &payload.scoped_variables {
+        if v.enabled && !v.key.is_empty() {
+            variables.insert(v.key.clone(), v.value.clone());
+        }
+    }
+    variables
+}
+
 /// Resolve all `{{var}}` placeholders in a request payload.
 pub fn resolve_request(
     payload: &ResolveRequestPayload,
     global_variables: &[EnvVariable],
     environments: &[Environment],
 ) -> ResolvedRequest {
-    let env = payload
-        .environment_id
-        .as_ref()
-        .and_then(|id| environments.iter().find(|e| e.id == *id));
+    let env = selected_environment(payload, environments);
+    let variables = request_variables(payload, global_variables, environments);
 
-    let variables = build_variable_map(global_variables, env);
-
-    let url = interpolate(&payload.url, &variables);
+    let mut url = interpolate(&payload.url, &variables);
+    if let Some(base_url) = env.and_then(|env| env.base_url.as_deref()) {
+        url = join_base_url(&interpolate(base_url, &variables), &url);
+    }
     let method = payload.method.clone();
 
     let headers: HashMap<String, String> = payload
@@ -130,6 +280,7 @@ mod tests {
             id: "env1".to_string(),
             name: "dev".to_string(),
             variables: vec![make_var("host", "dev.example.com")],
+            base_url: None,
         };
         let map = build_variable_map(&globals, Some(&env));
         assert_eq!(map.get("host").unwrap(), "dev.example.com");
@@ -145,7 +296,96 @@ mod tests {
             enabled: false,
         }];
         let map = build_variable_map(&globals, None);
-        assert!(map.get("host").is_none());
+        assert!(!map.contains_key("host"));
+    }
+
+    #[test]
+    fn test_join_base_url_normalizes_slashes() {
+        let base = "https://api.example.com/v1";
+        assert_eq!(join_base_url(base, "users"), "https://api.example.com/v1/users");
+        assert_eq!(join_base_url(base, "/users"), "https://api.example.com/v1/users");
+        assert_eq!(
+            join_base_url("https://api.example.com/v1//", "//users/1"),
+            "https://api.example.com/v1/users/1"
+        );
+        assert_eq!(join_base_url(base, ""), base);
+        assert_eq!(join_base_url(base, "?page=2"), "https://api.example.com/v1?page=2");
+    }
+
+    #[test]
+    fn test_join_base_url_leaves_absolute_urls_alone() {
+        let base = "https://api.example.com";
+        assert_eq!(join_base_url(base, "http://other.test/a"), "http://other.test/a");
+        assert_eq!(join_base_url(base, "HTTPS://other.test"), "HTTPS://other.test");
+        assert_eq!(join_base_url(base, "wss://other.test/ws"), "wss://other.test/ws");
+        assert_eq!(join_base_url("", "/users"), "/users");
+        assert_eq!(
+            join_base_url(base, "search?next=http://x.test"),
+            "https://api.example.com/search?next=http://x.test"
+        );
+    }
+
+    fn path_params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_path_params_substitutes_colon_and_brace_forms() {
+        let params = path_params(&[("id", "42"), ("org", "acme")]);
+        assert_eq!(
+            apply_path_params("https://api.test:8080/orgs/{org}/users/:id?id=:id#:id", &params),
+            "https://api.test:8080/orgs/acme/users/42?id=:id#:id"
+        );
+        assert_eq!(apply_path_params("/users/:id.json", &params), "/users/42.json");
+        assert_eq!(
+            apply_path_params("/users/:missing/{{id}}/v1:batch/:identity", &params),
+            "/users/:missing/{{id}}/v1:batch/:identity"
+        );
+        assert_eq!(
+            apply_path_params("search?next=http://x.test/:id", &params),
+            "search?next=http://x.test/:id"
+        );
+    }
+
+    #[test]
+    fn test_apply_path_params_encodes_special_characters() {
+        let params = path_params(&[
+            ("name", "a b/c?d#e&f=ü"),
+            ("tag", "v1.0_~-"),
+            ("note", "it's (a)*!"),
+        ]);
+        assert_eq!(
+            apply_path_params("http://x.test/files/:name/{tag}/:note", &params),
+            "http://x.test/files/a%20b%2Fc%3Fd%23e%26f%3D%C3%BC/v1.0_~-/it%27s%20%28a%29%2A%21"
+        );
+    }
+
+    #[test]
+    fn test_resolve_request_prepends_environment_base_url() {
+        let envs = vec![Environment {
+            id: "env1".to_string(),
+            name: "staging".to_string(),
+            variables: vec![make_var("version", "v2")],
+            base_url: Some("https://staging.example.com/{{version}}/".to_string()),
+        }];
+        let mut payload = ResolveRequestPayload {
+            url: "/users".to_string(),
+            method: "GET".to_string(),
+            headers: HashMap::new(),
+            body: None,
+            environment_id: Some("env1".to_string()),
+            scoped_variables: Vec::new(),
+        };
+
+        let resolved = resolve_request(&payload, &[], &envs);
+        assert_eq!(resolved.url, "https://staging.example.com/v2/users");
+
+        payload.url = "https://prod.example.com/users".to_string();
+        let resolved = resolve_request(&payload, &[], &envs);
+        assert_eq!(resolved.url, "https://prod.example.com/users");
     }
 
     #[test]
@@ -158,6 +398,7 @@ mod tests {
             id: "env1".to_string(),
             name: "staging".to_string(),
             variables: vec![make_var("auth_token", "staging-token")],
+            base_url: None,
         }];
 
         let mut headers = HashMap::new();
@@ -172,6 +413,7 @@ mod tests {
             headers,
             body: Some("{\"key\": \"{{auth_token}}\"}".to_string()),
             environment_id: Some("env1".to_string()),
+            scoped_variables: Vec::new(),
         };
 
         let resolved = resolve_request(&payload, &globals, &envs);
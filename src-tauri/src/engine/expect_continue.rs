@@ -0,0 +1,62 @@
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::Sleep;
+
+/// Wait for an `Expect: 100-continue` answer when none is configured, as curl does.
+pub const DEFAULT_WAIT: Duration = Duration::from_millis(1000);
+
+/// Request body that sends nothing until `wait` has passed, so a server
+/// asked for `Expect: 100-continue` can turn the request down first.
+///
+/// reqwest drops the interim `100 Continue`, so an accepting server cannot
+/// end the wait early. A final response that arrives within it ends the
+/// exchange and the body is never sent.
+struct HeldBody {
+    inner: reqwest::Body,
+    hold: Option<Pin<Box<Sleep>>>,
+    sent: Arc<AtomicBool>,
+}
+
+/// Hold `body` back for `wait`. The flag turns true once it starts going out.
+pub fn hold_body(body: reqwest::Body, wait: Duration) -> (reqwest::Body, Arc<AtomicBool>) {
+    let sent = Arc::new(AtomicBool::new(false));
+    let held = HeldBody {
+        inner: body,
+        hold: Some(Box::pin(tokio::time::sleep(wait))),
+        sent: sent.clone(),
+    };
+    (reqwest::Body::wrap(held), sent)
+}
+
+impl Body for HeldBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, reqwest::Error>>> {
+        if let Some(hold) = self.hold.as_mut() {
+            if hold.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            self.hold = None;
+            self.sent.store(true, Ordering::SeqCst);
+        }
+        Pin::new(&mut self.inner).poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
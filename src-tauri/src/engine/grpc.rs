@@ -1,10 +1,11 @@
 use crate::domain::{
-    GrpcReflectionResponse, GrpcRequestPayload, GrpcResponsePayload, ProtoFieldInfo,
-    ProtoMethodInfo, ProtoServiceInfo,
+    GrpcReflectionResponse, GrpcRequestPayload, GrpcResponsePayload, GrpcValidationIssue,
+    GrpcValidationKind, ProtoFieldInfo, ProtoMethodInfo, ProtoServiceInfo,
 };
+use crate::engine::env::interpolate;
 use bytes::{Buf, BufMut, Bytes};
 use prost::Message as ProstMessage;
-use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor};
 use std::collections::HashMap;
 use std::fs;
 use std::time::{Duration, Instant};
@@ -27,7 +28,7 @@ pub fn grpc_error_response(message: impl Into<String>) -> GrpcResponsePayload {
 
 // ─── Raw bytes codec for tonic dynamic gRPC calls ────────────────────────────
 
-struct RawBytesCodec;
+pub(crate) struct RawBytesCodec;
 
 impl Codec for RawBytesCodec {
     type Encode = Bytes;
@@ -44,7 +45,7 @@ impl Codec for RawBytesCodec {
     }
 }
 
-struct RawBytesEncoder;
+pub(crate) struct RawBytesEncoder;
 
 impl Encoder for RawBytesEncoder {
     type Item = Bytes;
@@ -56,7 +57,7 @@ impl Encoder for RawBytesEncoder {
     }
 }
 
-struct RawBytesDecoder;
+pub(crate) struct RawBytesDecoder;
 
 impl Decoder for RawBytesDecoder {
     type Item = Bytes;
@@ -87,7 +88,7 @@ pub fn compile_proto(proto_content: &str) -> Result<DescriptorPool, String> {
     fs::write(&proto_path, proto_content)
         .map_err(|e| format!("Failed to write proto file: {e}"))?;
 
-    let fds = protox::compile(&["service.proto"], &[&temp_dir])
+    let fds = protox::compile(["service.proto"], [&temp_dir])
         .map_err(|e| format!("Failed to compile proto: {e}"))?;
 
     let pool = DescriptorPool::decode(fds.encode_to_vec().as_ref())
@@ -164,30 +165,491 @@ pub fn parse_proto_content_impl(
     Ok(services)
 }
 
-pub async fn send_grpc_request_impl(
-    payload: GrpcRequestPayload,
-    cancel_rx: &mut broadcast::Receiver<()>,
-) -> Result<GrpcResponsePayload, String> {
-    // 1. Build descriptor pool from proto content or reflection descriptor bytes
-    let pool = if let Some(ref desc_b64) = payload.descriptor_bytes {
-        use base64::Engine;
-        let desc_bytes = base64::engine::general_purpose::STANDARD
-            .decode(desc_b64)
-            .map_err(|e| format!("Failed to decode descriptor bytes: {e}"))?;
-        DescriptorPool::decode(desc_bytes.as_slice())
-            .map_err(|e| format!("Failed to create descriptor pool: {e}"))?
-    } else {
-        compile_proto(&payload.proto_content)?
+/// Build a descriptor pool from reflection descriptor bytes (base64) when
+/// present, otherwise by compiling the proto source.
+pub fn load_descriptor_pool(
+    proto_content: &str,
+    descriptor_bytes: Option<&str>,
+) -> Result<DescriptorPool, String> {
+    let Some(desc_b64) = descriptor_bytes.filter(|value| !value.is_empty()) else {
+        return compile_proto(proto_content);
     };
+    use base64::Engine;
+    let desc_bytes = base64::engine::general_purpose::STANDARD
+        .decode(desc_b64)
+        .map_err(|e| format!("Failed to decode descriptor bytes: {e}"))?;
+    DescriptorPool::decode(desc_bytes.as_slice())
+        .map_err(|e| format!("Failed to create descriptor pool: {e}"))
+}
 
+pub fn find_method(
+    pool: &DescriptorPool,
+    service_name: &str,
+    method_name: &str,
+) -> Result<MethodDescriptor, String> {
     let service = pool
-        .get_service_by_name(&payload.service_name)
-        .ok_or_else(|| format!("Service '{}' not found", payload.service_name))?;
-
+        .get_service_by_name(service_name)
+        .ok_or_else(|| format!("Service '{service_name}' not found"))?;
     let method = service
         .methods()
-        .find(|m| m.name() == payload.method_name)
-        .ok_or_else(|| format!("Method '{}' not found in service", payload.method_name))?;
+        .find(|m| m.name() == method_name)
+        .ok_or_else(|| format!("Method '{method_name}' not found in service"))?;
+    Ok(method)
+}
+
+/// Nested message fields are expanded this many levels below the request.
+const TEMPLATE_NESTED_DEPTH: usize = 1;
+
+fn template_for_well_known(message: &MessageDescriptor) -> Option<serde_json::Value> {
+    use serde_json::{json, Value};
+    let value = match message.full_name() {
+        "google.protobuf.Timestamp" => json!("1970-01-01T00:00:00Z"),
+        "google.protobuf.Duration" => json!("0s"),
+        "google.protobuf.FieldMask" | "google.protobuf.StringValue" => json!(""),
+        "google.protobuf.BytesValue" => json!(""),
+        "google.protobuf.BoolValue" => json!(false),
+        "google.protobuf.DoubleValue"
+        | "google.protobuf.FloatValue"
+        | "google.protobuf.Int32Value"
+        | "google.protobuf.UInt32Value"
+        | "google.protobuf.Int64Value"
+        | "google.protobuf.UInt64Value" => json!(0),
+        "google.protobuf.Struct" | "google.protobuf.Any" | "google.protobuf.Empty" => json!({}),
+        "google.protobuf.ListValue" => json!([]),
+        "google.protobuf.Value" => Value::Null,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn template_field_value(
+    field: &prost_reflect::FieldDescriptor,
+    depth: usize,
+    stack: &mut Vec<String>,
+) -> serde_json::Value {
+    use prost_reflect::Kind;
+    use serde_json::{json, Value};
+    match field.kind() {
+        Kind::Double | Kind::Float => json!(0.0),
+        Kind::Int32
+        | Kind::Int64
+        | Kind::Uint32
+        | Kind::Uint64
+        | Kind::Sint32
+        | Kind::Sint64
+        | Kind::Fixed32
+        | Kind::Fixed64
+        | Kind::Sfixed32
+        | Kind::Sfixed64 => json!(0),
+        Kind::Bool => json!(false),
+        Kind::String | Kind::Bytes => json!(""),
+        Kind::Enum(descriptor) => descriptor
+            .values()
+            .next()
+            .map(|value| json!(value.name()))
+            .unwrap_or(Value::Null),
+        Kind::Message(message) => template_for_message(&message, depth + 1, stack),
+    }
+}
+
+fn template_for_message(
+    message: &MessageDescriptor,
+    depth: usize,
+    stack: &mut Vec<String>,
+) -> serde_json::Value {
+    if let Some(value) = template_for_well_known(message) {
+        return value;
+    }
+    let mut object = serde_json::Map::new();
+    // Self-referential messages and anything past the expansion depth stay `{}`.
+    if depth > TEMPLATE_NESTED_DEPTH || stack.iter().any(|name| name == message.full_name()) {
+        return serde_json::Value::Object(object);
+    }
+
+    stack.push(message.full_name().to_string());
+    let mut seen_oneofs = Vec::new();
+    for field in message.fields() {
+        // Only the first member of a real oneof is included; setting several
+        // members would make the template itself invalid.
+        if let Some(oneof) = field.containing_oneof().filter(|oneof| !oneof.is_synthetic()) {
+            if seen_oneofs.contains(&oneof.name().to_string()) {
+                continue;
+            }
+            seen_oneofs.push(oneof.name().to_string());
+        }
+
+        let value = if field.is_map() {
+            serde_json::Value::Object(serde_json::Map::new())
+        } else if field.is_list() {
+            serde_json::Value::Array(Vec::new())
+        } else {
+            template_field_value(&field, depth, stack)
+        };
+        object.insert(field.json_name().to_string(), value);
+    }
+    stack.pop();
+    serde_json::Value::Object(object)
+}
+
+/// Build a request skeleton for a method with every field set to its zero value.
+pub fn build_grpc_request_template_impl(
+    proto_content: &str,
+    descriptor_bytes: Option<&str>,
+    service_name: &str,
+    method_name: &str,
+) -> Result<serde_json::Value, String> {
+    let pool = load_descriptor_pool(proto_content, descriptor_bytes)?;
+    let method = find_method(&pool, service_name, method_name)?;
+    Ok(template_for_message(&method.input(), 0, &mut Vec::new()))
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    use serde_json::Value;
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn json_child_path(path: &str, key: &str) -> String {
+    let plain = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        format!("{path}.{key}")
+    } else {
+        format!("{path}[{}]", serde_json::Value::String(key.to_string()))
+    }
+}
+
+fn json_integer(value: &serde_json::Value) -> Option<i128> {
+    match value {
+        serde_json::Value::Number(number) => number
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| number.as_u64().map(i128::from))
+            .or_else(|| {
+                number
+                    .as_f64()
+                    .filter(|f| f.is_finite() && f.fract() == 0.0)
+                    .map(|f| f as i128)
+            }),
+        // Proto3 JSON allows integers (notably 64-bit ones) as strings.
+        serde_json::Value::String(text) => text.trim().parse::<i128>().ok(),
+        _ => None,
+    }
+}
+
+/// Check a single (non-repeated) value against a field kind, returning the
+/// expected type when it does not fit.
+fn check_scalar_kind(kind: &prost_reflect::Kind, value: &serde_json::Value) -> Option<String> {
+    use prost_reflect::Kind;
+    use serde_json::Value;
+    let integer_range = |min: i128, max: i128| match json_integer(value) {
+        Some(number) if (min..=max).contains(&number) => None,
+        Some(_) => Some(format!("integer between {min} and {max}")),
+        None => Some("integer".to_string()),
+    };
+    match kind {
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => {
+            integer_range(i32::MIN.into(), i32::MAX.into())
+        }
+        Kind::Uint32 | Kind::Fixed32 => integer_range(0, u32::MAX.into()),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => {
+            integer_range(i64::MIN.into(), i64::MAX.into())
+        }
+        Kind::Uint64 | Kind::Fixed64 => integer_range(0, u64::MAX.into()),
+        Kind::Double | Kind::Float => match value {
+            Value::Number(_) => None,
+            Value::String(text)
+                if matches!(text.as_str(), "NaN" | "Infinity" | "-Infinity")
+                    || text.parse::<f64>().is_ok() =>
+            {
+                None
+            }
+            _ => Some("number".to_string()),
+        },
+        Kind::Bool => (!value.is_boolean()).then(|| "boolean".to_string()),
+        Kind::String | Kind::Bytes => (!value.is_string()).then(|| "string".to_string()),
+        Kind::Enum(descriptor) => match value {
+            Value::String(name) if descriptor.get_value_by_name(name).is_some() => None,
+            Value::Number(_) if json_integer(value).is_some() => None,
+            _ => {
+                let names: Vec<String> =
+                    descriptor.values().map(|v| v.name().to_string()).collect();
+                Some(format!("one of {}", names.join(", ")))
+            }
+        },
+        Kind::Message(_) => (!value.is_object()).then(|| "object".to_string()),
+    }
+}
+
+fn validate_field_value(
+    field: &prost_reflect::FieldDescriptor,
+    kind: &prost_reflect::Kind,
+    value: &serde_json::Value,
+    path: &str,
+    issues: &mut Vec<GrpcValidationIssue>,
+) {
+    if value.is_null() {
+        return;
+    }
+    if let prost_reflect::Kind::Message(message) = kind {
+        // Well-known types have their own JSON forms; the final decode checks them.
+        if message.full_name().starts_with("google.protobuf.") {
+            return;
+        }
+    }
+    if let Some(expected) = check_scalar_kind(kind, value) {
+        issues.push(GrpcValidationIssue {
+            path: path.to_string(),
+            kind: GrpcValidationKind::TypeMismatch,
+            message: format!(
+                "Field '{}' expects {expected}, got {}",
+                field.name(),
+                json_type_name(value)
+            ),
+        });
+        return;
+    }
+    if let (prost_reflect::Kind::Message(message), serde_json::Value::Object(object)) =
+        (kind, value)
+    {
+        validate_message_json(message, object, path, issues);
+    }
+}
+
+fn validate_message_json(
+    message: &MessageDescriptor,
+    object: &serde_json::Map<String, serde_json::Value>,
+    path: &str,
+    issues: &mut Vec<GrpcValidationIssue>,
+) {
+    for (key, value) in object {
+        let child = json_child_path(path, key);
+        let Some(field) = message
+            .get_field_by_json_name(key)
+            .or_else(|| message.get_field_by_name(key))
+        else {
+            issues.push(GrpcValidationIssue {
+                path: child,
+                kind: GrpcValidationKind::UnknownField,
+                message: format!("Unknown field '{key}' in {}", message.full_name()),
+            });
+            continue;
+        };
+
+        let kind = field.kind();
+        if field.is_map() {
+            let prost_reflect::Kind::Message(entry) = &kind else {
+                continue;
+            };
+            let value_field = entry.map_entry_value_field();
+            match value {
+                serde_json::Value::Object(entries) => {
+                    for (map_key, map_value) in entries {
+                        let entry_path = format!(
+                            "{child}[{}]",
+                            serde_json::Value::String(map_key.clone())
+                        );
+                        validate_field_value(
+                            &field,
+                            &value_field.kind(),
+                            map_value,
+                            &entry_path,
+                            issues,
+                        );
+                    }
+                }
+                serde_json::Value::Null => {}
+                other => issues.push(GrpcValidationIssue {
+                    path: child,
+                    kind: GrpcValidationKind::TypeMismatch,
+                    message: format!(
+                        "Field '{}' expects object, got {}",
+                        field.name(),
+                        json_type_name(other)
+                    ),
+                }),
+            }
+        } else if field.is_list() {
+            match value {
+                serde_json::Value::Array(items) => {
+                    for (index, item) in items.iter().enumerate() {
+                        let item_path = format!("{child}[{index}]");
+                        validate_field_value(&field, &kind, item, &item_path, issues);
+                    }
+                }
+                serde_json::Value::Null => {}
+                other => issues.push(GrpcValidationIssue {
+                    path: child,
+                    kind: GrpcValidationKind::TypeMismatch,
+                    message: format!(
+                        "Field '{}' expects array, got {}",
+                        field.name(),
+                        json_type_name(other)
+                    ),
+                }),
+            }
+        } else {
+            validate_field_value(&field, &kind, value, &child, issues);
+        }
+    }
+
+    for field in message.fields() {
+        if field.cardinality() != prost_reflect::Cardinality::Required {
+            continue;
+        }
+        let present = [field.json_name(), field.name()]
+            .iter()
+            .any(|key| object.get(*key).is_some_and(|value| !value.is_null()));
+        if !present {
+            issues.push(GrpcValidationIssue {
+                path: json_child_path(path, field.json_name()),
+                kind: GrpcValidationKind::MissingRequired,
+                message: format!("Missing required field '{}'", field.name()),
+            });
+        }
+    }
+}
+
+/// Check request JSON against the method's input message without sending it.
+/// Returns an empty list when the message would encode successfully.
+pub fn validate_grpc_request_impl(
+    proto_content: &str,
+    descriptor_bytes: Option<&str>,
+    service_name: &str,
+    method_name: &str,
+    request_json: &str,
+) -> Result<Vec<GrpcValidationIssue>, String> {
+    let pool = load_descriptor_pool(proto_content, descriptor_bytes)?;
+    let input = find_method(&pool, service_name, method_name)?.input();
+
+    let json: serde_json::Value = match serde_json::from_str(request_json) {
+        Ok(json) => json,
+        Err(err) => {
+            return Ok(vec![GrpcValidationIssue {
+                path: "$".into(),
+                kind: GrpcValidationKind::InvalidJson,
+                message: format!("Invalid JSON: {err}"),
+            }]);
+        }
+    };
+    let serde_json::Value::Object(object) = &json else {
+        return Ok(vec![GrpcValidationIssue {
+            path: "$".into(),
+            kind: GrpcValidationKind::TypeMismatch,
+            message: format!(
+                "{} expects an object, got {}",
+                input.full_name(),
+                json_type_name(&json)
+            ),
+        }]);
+    };
+
+    let mut issues = Vec::new();
+    validate_message_json(&input, object, "$", &mut issues);
+    if issues.is_empty() {
+        // Anything the structural pass cannot see (oneof conflicts, bad
+        // base64, well-known type formats) surfaces from the real decoder.
+        let mut deserializer = serde_json::Deserializer::from_str(request_json);
+        if let Err(err) = DynamicMessage::deserialize(input, &mut deserializer) {
+            issues.push(GrpcValidationIssue {
+                path: "$".into(),
+                kind: GrpcValidationKind::Invalid,
+                message: err.to_string(),
+            });
+        }
+    }
+    Ok(issues)
+}
+
+const GRPC_TIMEOUT_HEADER: &str = "grpc-timeout";
+
+/// Format a deadline as a `grpc-timeout` value: at most eight digits followed
+/// by a unit, using the finest unit that fits (rounded up so the server never
+/// sees a shorter deadline than the client enforces).
+fn grpc_timeout_value(timeout: Duration) -> String {
+    const MAX_DIGITS: u128 = 99_999_999;
+    let millis = timeout.as_millis().max(1);
+    let units: [(u128, &str); 4] = [(1, "m"), (1_000, "S"), (60_000, "M"), (3_600_000, "H")];
+    for (factor, unit) in units {
+        let value = millis.div_ceil(factor);
+        if value <= MAX_DIGITS {
+            return format!("{value}{unit}");
+        }
+    }
+    format!("{MAX_DIGITS}H")
+}
+
+/// Build an outgoing request with user metadata and, when a timeout is set,
+/// the `grpc-timeout` deadline (unless the user supplied one).
+pub(crate) fn build_grpc_request<T>(
+    message: T,
+    metadata: &HashMap<String, String>,
+    timeout: Option<Duration>,
+) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    for (key, value) in metadata {
+        if key.is_empty() {
+            continue;
+        }
+        if let (Ok(name), Ok(val)) = (
+            tonic::metadata::MetadataKey::from_bytes(key.as_bytes()),
+            tonic::metadata::MetadataValue::try_from(value.as_str()),
+        ) {
+            request.metadata_mut().insert(name, val);
+        }
+    }
+
+    if let Some(timeout) = timeout {
+        if !request.metadata().contains_key(GRPC_TIMEOUT_HEADER) {
+            if let Ok(value) = grpc_timeout_value(timeout).parse() {
+                request.metadata_mut().insert(GRPC_TIMEOUT_HEADER, value);
+            }
+        }
+    }
+    request
+}
+
+/// Expand `{{name}}` placeholders in the endpoint, metadata values and
+/// request JSON from the payload's variables, as HTTP requests are resolved.
+pub fn interpolate_grpc_payload(payload: &mut GrpcRequestPayload) {
+    if payload.variables.is_empty() {
+        return;
+    }
+    let variables = std::mem::take(&mut payload.variables);
+    payload.endpoint = interpolate(&payload.endpoint, &variables);
+    payload.request_json = interpolate(&payload.request_json, &variables);
+    for value in payload.metadata.values_mut() {
+        *value = interpolate(value, &variables);
+    }
+}
+
+/// ASCII metadata entries as a plain map; binary (`-bin`) entries are skipped.
+pub(crate) fn metadata_to_map(metadata: &tonic::metadata::MetadataMap) -> HashMap<String, String> {
+    metadata
+        .iter()
+        .filter_map(|kv| match kv {
+            tonic::metadata::KeyAndValueRef::Ascii(key, value) => Some((
+                key.as_str().to_string(),
+                value.to_str().unwrap_or_default().to_string(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+pub async fn send_grpc_request_impl(
+    payload: GrpcRequestPayload,
+    cancel_rx: &mut broadcast::Receiver<()>,
+) -> Result<GrpcResponsePayload, String> {
+    // 1. Build descriptor pool from proto content or reflection descriptor bytes
+    let pool = load_descriptor_pool(&payload.proto_content, payload.descriptor_bytes.as_deref())?;
+    let method = find_method(&pool, &payload.service_name, &payload.method_name)?;
 
     // 2. Encode request JSON to protobuf bytes
     let input_desc: MessageDescriptor = method.input();
@@ -217,22 +679,16 @@ pub async fn send_grpc_request_impl(
     };
 
     // 4. Build gRPC path and request
-    let path: http::uri::PathAndQuery = format!("/{}/{}", service.full_name(), method.name())
+    let service_path = method.parent_service().full_name().to_string();
+    let path: http::uri::PathAndQuery = format!("/{}/{}", service_path, method.name())
         .parse()
         .map_err(|e: http::uri::InvalidUri| format!("Invalid gRPC path: {e}"))?;
 
-    let mut request = tonic::Request::new(request_bytes);
-    for (key, value) in &payload.metadata {
-        if key.is_empty() {
-            continue;
-        }
-        if let (Ok(name), Ok(val)) = (
-            tonic::metadata::MetadataKey::from_bytes(key.as_bytes()),
-            tonic::metadata::MetadataValue::try_from(value.as_str()),
-        ) {
-            request.metadata_mut().insert(name, val);
-        }
-    }
+    let timeout = payload
+        .timeout_ms
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis);
+    let request = build_grpc_request(request_bytes, &payload.metadata, timeout);
 
     // 5. Send gRPC request
     let mut grpc_client = tonic::client::Grpc::new(channel);
@@ -243,27 +699,33 @@ pub async fn send_grpc_request_impl(
 
     let start = Instant::now();
 
+    // The server is told the deadline via `grpc-timeout`; enforce it here too
+    // in case it ignores the header.
+    let call = grpc_client.unary(request, path, RawBytesCodec);
+    let deadline = async {
+        match timeout {
+            Some(timeout) => tokio::time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
     let response = tokio::select! {
-        res = grpc_client.unary(request, path, RawBytesCodec) => {
+        res = call => {
             res.map_err(|status| format!("gRPC error ({}): {}", status.code(), status.message()))?
         },
+        _ = deadline => {
+            return Err(format!(
+                "gRPC error ({}): Deadline of {} ms exceeded",
+                tonic::Code::DeadlineExceeded,
+                timeout.unwrap_or_default().as_millis()
+            ));
+        },
         _ = cancel_rx.recv() => return Err("Request cancelled".into()),
     };
 
     let elapsed = start.elapsed().as_millis() as u64;
 
     // 6. Extract response metadata
-    let response_metadata: HashMap<String, String> = response
-        .metadata()
-        .iter()
-        .filter_map(|kv| match kv {
-            tonic::metadata::KeyAndValueRef::Ascii(key, value) => Some((
-                key.as_str().to_string(),
-                value.to_str().unwrap_or_default().to_string(),
-            )),
-            _ => None,
-        })
-        .collect();
+    let response_metadata = metadata_to_map(response.metadata());
 
     // 7. Decode response protobuf to JSON
     let response_bytes = response.into_inner();
@@ -730,3 +1192,167 @@ pub async fn fetch_grpc_reflection_impl(
         descriptor_bytes: descriptor_bytes_b64,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PROTO: &str = r#"
+syntax = "proto3";
+package demo;
+
+service Users {
+  rpc Create (CreateUserRequest) returns (User);
+}
+
+enum Role {
+  ROLE_VIEWER = 0;
+  ROLE_ADMIN = 1;
+}
+
+message Address {
+  string city = 1;
+  Geo geo = 2;
+}
+
+message Geo {
+  double lat = 1;
+}
+
+message Node {
+  string id = 1;
+  Node parent = 2;
+}
+
+message CreateUserRequest {
+  string user_name = 1;
+  int64 age = 2;
+  bool active = 3;
+  repeated string tags = 4;
+  Role role = 5;
+  Address address = 6;
+  Node node = 7;
+  map<string, string> labels = 8;
+  CreateUserRequest retry = 11;
+  oneof contact {
+    string email = 9;
+    string phone = 10;
+  }
+}
+
+message User {
+  string id = 1;
+}
+"#;
+
+    #[test]
+    fn template_defaults_every_field() {
+        let template =
+            build_grpc_request_template_impl(PROTO, None, "demo.Users", "Create").unwrap();
+        assert_eq!(
+            template,
+            json!({
+                "userName": "",
+                "age": 0,
+                "active": false,
+                "tags": [],
+                "role": "ROLE_VIEWER",
+                "address": { "city": "", "geo": {} },
+                "node": { "id": "", "parent": {} },
+                "labels": {},
+                "retry": {},
+                "email": "",
+            })
+        );
+    }
+
+    #[test]
+    fn timeout_is_sent_as_grpc_timeout_metadata() {
+        let metadata = HashMap::from([("x-trace".to_string(), "abc".to_string())]);
+        let request = build_grpc_request(
+            Bytes::new(),
+            &metadata,
+            Some(Duration::from_millis(30_000)),
+        );
+        let sent = request.metadata();
+        assert_eq!(sent.get("grpc-timeout").unwrap().to_str().unwrap(), "30000m");
+        assert_eq!(sent.get("x-trace").unwrap().to_str().unwrap(), "abc");
+
+        let untimed = build_grpc_request(Bytes::new(), &metadata, None);
+        assert!(!untimed.metadata().contains_key("grpc-timeout"));
+    }
+
+    #[test]
+    fn grpc_timeout_uses_coarser_units_for_long_deadlines() {
+        assert_eq!(grpc_timeout_value(Duration::from_micros(10)), "1m");
+        assert_eq!(grpc_timeout_value(Duration::from_millis(99_999_999)), "99999999m");
+        assert_eq!(grpc_timeout_value(Duration::from_millis(100_000_500)), "100001S");
+    }
+
+    fn validate(request_json: &str) -> Vec<GrpcValidationIssue> {
+        validate_grpc_request_impl(PROTO, None, "demo.Users", "Create", request_json).unwrap()
+    }
+
+    #[test]
+    fn validation_reports_unknown_fields_with_paths() {
+        let issues = validate(r#"{"userName": "ada", "address": {"city": "x", "zip": "1"}}"#);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, GrpcValidationKind::UnknownField);
+        assert_eq!(issues[0].path, "$.address.zip");
+    }
+
+    #[test]
+    fn validation_reports_wrong_typed_fields() {
+        let issues = validate(r#"{"age": "old", "tags": ["a", 2], "role": "ROLE_OWNER"}"#);
+        let found: Vec<(&str, GrpcValidationKind)> =
+            issues.iter().map(|issue| (issue.path.as_str(), issue.kind)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("$.age", GrpcValidationKind::TypeMismatch),
+                ("$.role", GrpcValidationKind::TypeMismatch),
+                ("$.tags[1]", GrpcValidationKind::TypeMismatch),
+            ]
+        );
+    }
+
+    #[test]
+    fn validation_accepts_valid_requests_and_checks_oneofs() {
+        assert!(validate(r#"{"user_name": "ada", "age": "42", "labels": {"a": "b"}}"#).is_empty());
+
+        let issues = validate(r#"{"email": "a@b.c", "phone": "123"}"#);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, GrpcValidationKind::Invalid);
+    }
+
+    #[test]
+    fn template_is_accepted_by_the_message_descriptor() {
+        let template =
+            build_grpc_request_template_impl(PROTO, None, "demo.Users", "Create").unwrap();
+        let pool = compile_proto(PROTO).unwrap();
+        let method = find_method(&pool, "demo.Users", "Create").unwrap();
+        let json = template.to_string();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        assert!(DynamicMessage::deserialize(method.input(), &mut deserializer).is_ok());
+    }
+
+    #[test]
+    fn variables_are_expanded_in_endpoint_metadata_and_body() {
+        let mut payload: GrpcRequestPayload = serde_json::from_value(json!({
+            "endpoint": "http://{{host}}:50051",
+            "protoContent": PROTO,
+            "serviceName": "demo.Users",
+            "methodName": "Create",
+            "requestJson": "{\"name\": \"{{user}}\"}",
+            "metadata": { "authorization": "Bearer {{auth}}", "x-missing": "{{nope}}" },
+            "variables": { "host": "localhost", "user": "ada", "auth": "t0k3n" },
+        }))
+        .unwrap();
+        interpolate_grpc_payload(&mut payload);
+        assert_eq!(payload.endpoint, "http://localhost:50051");
+        assert_eq!(payload.request_json, "{\"name\": \"ada\"}");
+        assert_eq!(payload.metadata["authorization"], "Bearer t0k3n");
+        assert_eq!(payload.metadata["x-missing"], "{{nope}}");
+    }
+}
@@ -0,0 +1,344 @@
+use crate::domain::{
+    GrpcRequestPayload, GrpcStreamDirection, GrpcStreamMessage, GrpcStreamSnapshot,
+    GrpcStreamStatus,
+};
+use crate::engine::grpc::{
+    build_grpc_request, find_method, load_descriptor_pool, metadata_to_map, RawBytesCodec,
+};
+use bytes::Bytes;
+use prost::Message as ProstMessage;
+use prost_reflect::{DynamicMessage, MessageDescriptor};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// Messages kept per stream; the oldest are dropped past this so a chatty
+/// bidirectional stream cannot grow without bound between polls.
+const MAX_STREAM_MESSAGES: usize = 2000;
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+struct StreamSession {
+    input: MessageDescriptor,
+    /// Outgoing half of the stream; `None` once the send side is closed.
+    sender: Option<mpsc::UnboundedSender<Bytes>>,
+    cancel: broadcast::Sender<()>,
+    status: GrpcStreamStatus,
+    status_code: Option<i32>,
+    status_message: Option<String>,
+    response_metadata: HashMap<String, String>,
+    messages: Vec<GrpcStreamMessage>,
+    next_seq: u64,
+}
+
+impl StreamSession {
+    fn push(&mut self, direction: GrpcStreamDirection, json: String) {
+        self.next_seq += 1;
+        self.messages.push(GrpcStreamMessage {
+            seq: self.next_seq,
+            direction,
+            json,
+            timestamp: now_ms(),
+        });
+        if self.messages.len() > MAX_STREAM_MESSAGES {
+            let excess = self.messages.len() - MAX_STREAM_MESSAGES;
+            self.messages.drain(..excess);
+        }
+    }
+}
+
+/// Open client-streaming and bidirectional calls, keyed by stream id. The UI
+/// sends messages through it and polls for responses.
+#[derive(Clone)]
+pub struct GrpcStreamRegistry {
+    sessions: Arc<Mutex<HashMap<String, StreamSession>>>,
+}
+
+impl GrpcStreamRegistry {
+    pub fn new() -> Self {
+        Self {
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn open(
+        &self,
+        id: &str,
+        input: MessageDescriptor,
+    ) -> (mpsc::UnboundedReceiver<Bytes>, broadcast::Receiver<()>) {
+        let (sender, outbound) = mpsc::unbounded_channel();
+        let (cancel, cancel_rx) = broadcast::channel(1);
+        let session = StreamSession {
+            input,
+            sender: Some(sender),
+            cancel,
+            status: GrpcStreamStatus::Open,
+            status_code: None,
+            status_message: None,
+            response_metadata: HashMap::new(),
+            messages: Vec::new(),
+            next_seq: 0,
+        };
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(id.to_string(), session);
+        (outbound, cancel_rx)
+    }
+
+    fn with_session<T>(
+        &self,
+        id: &str,
+        f: impl FnOnce(&mut StreamSession) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions
+            .get_mut(id)
+            .ok_or_else(|| format!("gRPC stream '{id}' not found"))?;
+        f(session)
+    }
+
+    /// Encode a JSON message with the method's input type and queue it.
+    pub fn send_message(&self, id: &str, request_json: &str) -> Result<(), String> {
+        self.with_session(id, |session| {
+            let sender = session
+                .sender
+                .as_ref()
+                .ok_or_else(|| "The send side of this stream is closed".to_string())?;
+            let mut deserializer = serde_json::Deserializer::from_str(request_json);
+            let message = DynamicMessage::deserialize(session.input.clone(), &mut deserializer)
+                .map_err(|e| format!("Failed to encode request message: {e}"))?;
+            sender
+                .send(Bytes::from(message.encode_to_vec()))
+                .map_err(|_| "The stream is no longer accepting messages".to_string())?;
+            let json = serde_json::to_string(&message)
+                .map_err(|e| format!("Failed to serialize request message: {e}"))?;
+            session.push(GrpcStreamDirection::Sent, json);
+            Ok(())
+        })
+    }
+
+    /// Half-close the stream so the server sees the end of the request messages.
+    pub fn close_send(&self, id: &str) -> Result<(), String> {
+        self.with_session(id, |session| {
+            session.sender = None;
+            Ok(())
+        })
+    }
+
+    /// Cancel the call if it is still running and forget the stream.
+    pub fn cancel(&self, id: &str) -> bool {
+        let Some(session) = self.sessions.lock().unwrap().remove(id) else {
+            return false;
+        };
+        let _ = session.cancel.send(());
+        true
+    }
+
+    /// Current state plus messages with a sequence number above `after_seq`.
+    pub fn snapshot(&self, id: &str, after_seq: u64) -> Result<GrpcStreamSnapshot, String> {
+        self.with_session(id, |session| {
+            Ok(GrpcStreamSnapshot {
+                stream_id: id.to_string(),
+                status: session.status,
+                send_closed: session.sender.is_none(),
+                status_code: session.status_code,
+                status_message: session.status_message.clone(),
+                response_metadata: session.response_metadata.clone(),
+                messages: session
+                    .messages
+                    .iter()
+                    .filter(|message| message.seq > after_seq)
+                    .cloned()
+                    .collect(),
+            })
+        })
+    }
+
+    fn record_received(&self, id: &str, json: String) {
+        let _ = self.with_session(id, |session| {
+            session.push(GrpcStreamDirection::Received, json);
+            Ok(())
+        });
+    }
+
+    fn record_metadata(&self, id: &str, metadata: HashMap<String, String>) {
+        let _ = self.with_session(id, |session| {
+            session.response_metadata = metadata;
+            Ok(())
+        });
+    }
+
+    fn finish(&self, id: &str, status: GrpcStreamStatus, code: i32, message: String) {
+        let _ = self.with_session(id, |session| {
+            session.status = status;
+            session.status_code = Some(code);
+            session.status_message = Some(message);
+            session.sender = None;
+            Ok(())
+        });
+    }
+}
+
+/// Connect and open a streaming call. Messages are sent later through
+/// [`GrpcStreamRegistry::send_message`]; responses are collected in the background.
+pub async fn start_grpc_stream_impl(
+    payload: GrpcRequestPayload,
+    stream_id: &str,
+    registry: GrpcStreamRegistry,
+) -> Result<(), String> {
+    let pool = load_descriptor_pool(&payload.proto_content, payload.descriptor_bytes.as_deref())?;
+    let method = find_method(&pool, &payload.service_name, &payload.method_name)?;
+    if !method.is_client_streaming() && !method.is_server_streaming() {
+        return Err(format!(
+            "Method '{}' is unary; send it as a normal request",
+            method.name()
+        ));
+    }
+
+    let endpoint = tonic::transport::Endpoint::from_shared(payload.endpoint.clone())
+        .map_err(|e| format!("Invalid endpoint: {e}"))?;
+    // `Endpoint::timeout` would cap the whole call; a stream only bounds connecting.
+    let endpoint = match payload.timeout_ms.filter(|ms| *ms > 0) {
+        Some(ms) => endpoint.connect_timeout(Duration::from_millis(ms)),
+        None => endpoint,
+    };
+    let channel = endpoint
+        .connect()
+        .await
+        .map_err(|e| format!("Failed to connect: {e}"))?;
+
+    let service_path = method.parent_service().full_name().to_string();
+    let path: http::uri::PathAndQuery = format!("/{}/{}", service_path, method.name())
+        .parse()
+        .map_err(|e: http::uri::InvalidUri| format!("Invalid gRPC path: {e}"))?;
+
+    let (outbound, mut cancel_rx) = registry.open(stream_id, method.input());
+    let output = method.output();
+    let id = stream_id.to_string();
+
+    tauri::async_runtime::spawn(async move {
+        let mut grpc_client = tonic::client::Grpc::new(channel);
+        let call = async {
+            grpc_client
+                .ready()
+                .await
+                .map_err(|e| tonic::Status::unavailable(format!("Service not ready: {e}")))?;
+            let request = build_grpc_request(
+                UnboundedReceiverStream::new(outbound),
+                &payload.metadata,
+                None,
+            );
+            let response = grpc_client.streaming(request, path, RawBytesCodec).await?;
+            registry.record_metadata(&id, metadata_to_map(response.metadata()));
+
+            let mut inbound = response.into_inner();
+            while let Some(bytes) = inbound.message().await? {
+                let message = DynamicMessage::decode(output.clone(), &bytes[..]).map_err(|e| {
+                    tonic::Status::internal(format!("Failed to decode response: {e}"))
+                })?;
+                let json = serde_json::to_string(&message).map_err(|e| {
+                    tonic::Status::internal(format!("Failed to serialize response: {e}"))
+                })?;
+                registry.record_received(&id, json);
+            }
+            Ok::<(), tonic::Status>(())
+        };
+
+        // Cancelling drops the call, which resets the HTTP/2 stream.
+        tokio::select! {
+            result = call => match result {
+                Ok(()) => registry.finish(&id, GrpcStreamStatus::Completed, 0, "OK".into()),
+                Err(status) => registry.finish(
+                    &id,
+                    GrpcStreamStatus::Failed,
+                    status.code() as i32,
+                    format!("gRPC error ({}): {}", status.code(), status.message()),
+                ),
+            },
+            _ = cancel_rx.recv() => {}
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::grpc::compile_proto;
+
+    const PROTO: &str = r#"
+syntax = "proto3";
+package chat;
+
+service Chat {
+  rpc Talk (stream Line) returns (stream Line);
+}
+
+message Line {
+  string text = 1;
+}
+"#;
+
+    fn open_session(registry: &GrpcStreamRegistry) -> mpsc::UnboundedReceiver<Bytes> {
+        let pool = compile_proto(PROTO).unwrap();
+        let method = find_method(&pool, "chat.Chat", "Talk").unwrap();
+        registry.open("s1", method.input()).0
+    }
+
+    #[test]
+    fn queued_messages_are_encoded_and_logged() {
+        let registry = GrpcStreamRegistry::new();
+        let mut outbound = open_session(&registry);
+
+        registry.send_message("s1", r#"{"text": "hi"}"#).unwrap();
+        registry.send_message("s1", r#"{"text": "there"}"#).unwrap();
+        assert!(registry.send_message("s1", r#"{"nope": 1}"#).is_err());
+        registry.record_received("s1", r#"{"text":"hello"}"#.into());
+
+        let first = outbound.try_recv().unwrap();
+        assert_eq!(&first[..], b"\x0a\x02hi");
+
+        let snapshot = registry.snapshot("s1", 1).unwrap();
+        let logged: Vec<(u64, GrpcStreamDirection)> = snapshot
+            .messages
+            .iter()
+            .map(|message| (message.seq, message.direction))
+            .collect();
+        assert_eq!(
+            logged,
+            vec![
+                (2, GrpcStreamDirection::Sent),
+                (3, GrpcStreamDirection::Received)
+            ]
+        );
+        assert_eq!(snapshot.messages[0].json, r#"{"text":"there"}"#);
+    }
+
+    #[test]
+    fn closing_send_side_ends_outbound_stream() {
+        let registry = GrpcStreamRegistry::new();
+        let mut outbound = open_session(&registry);
+
+        registry.send_message("s1", r#"{"text": "last"}"#).unwrap();
+        registry.close_send("s1").unwrap();
+        assert!(outbound.try_recv().is_ok());
+        assert_eq!(
+            outbound.try_recv(),
+            Err(mpsc::error::TryRecvError::Disconnected)
+        );
+        assert!(registry.send_message("s1", r#"{"text": "late"}"#).is_err());
+        assert!(registry.snapshot("s1", 0).unwrap().send_closed);
+
+        assert!(registry.cancel("s1"));
+        assert!(registry.snapshot("s1", 0).is_err());
+    }
+}
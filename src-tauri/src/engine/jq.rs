@@ -0,0 +1,149 @@
+use hifijson::token::Lex;
+use hifijson::SliceLexer;
+use jaq_core::load::{self, Arena, File, Loader};
+use jaq_core::{Compiler, Ctx, RcIter};
+use jaq_json::Val;
+
+/// Most outputs a filter may produce, so `repeat(.)` and the like end.
+const MAX_OUTPUTS: usize = 10_000;
+/// Most text all outputs of a filter may add up to.
+const MAX_OUTPUT_BYTES: usize = 16 * 1024 * 1024;
+
+/// Run the jq `filter` over a JSON `body` and return each output as compact
+/// JSON, in order. Object keys keep the order they have in the body. Fails
+/// past [`MAX_OUTPUTS`] outputs or [`MAX_OUTPUT_BYTES`] of output.
+pub fn apply_jq(body: &str, filter: &str) -> Result<Vec<String>, String> {
+    let input = SliceLexer::new(body.as_bytes())
+        .exactly_one(Val::parse)
+        .map_err(|err| format!("Response body is not JSON: {err}"))?;
+
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+    let arena = Arena::default();
+    let program = File {
+        code: filter,
+        path: (),
+    };
+    let modules = loader
+        .load(&arena, program)
+        .map_err(|errors| invalid_filter(filter, errors.into_iter().map(|(_, err)| err)))?;
+    let compiled = Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(|errors| {
+            let undefined = errors.into_iter().flat_map(|(_, errs)| errs);
+            let messages: Vec<String> = undefined
+                .map(|(name, kind)| format!("undefined {} `{name}`", kind.as_str()))
+                .collect();
+            format!("Invalid filter: {}", messages.join("; "))
+        })?;
+
+    let inputs = RcIter::new(core::iter::empty());
+    let mut outputs = Vec::new();
+    let mut output_bytes = 0;
+    for output in compiled.run((Ctx::new([], &inputs), input)) {
+        let value = output.map_err(|err| format!("Filter failed: {err}"))?;
+        if outputs.len() == MAX_OUTPUTS {
+            return Err(format!("Filter produced more than {MAX_OUTPUTS} outputs"));
+        }
+        let text = value.to_string();
+        output_bytes += text.len();
+        if output_bytes > MAX_OUTPUT_BYTES {
+            return Err(format!(
+                "Filter output is larger than {} MB",
+                MAX_OUTPUT_BYTES / (1024 * 1024)
+            ));
+        }
+        outputs.push(text);
+    }
+    Ok(outputs)
+}
+
+fn invalid_filter<'a>(filter: &str, errors: impl Iterator<Item = load::Error<&'a str>>) -> String {
+    let mut messages = Vec::new();
+    for error in errors {
+        match error {
+            load::Error::Io(errs) => {
+                messages.extend(errs.into_iter().map(|(path, err)| format!("{path}: {err}")))
+            }
+            load::Error::Lex(errs) => messages.extend(errs.into_iter().map(|(expect, at)| {
+                format!(
+                    "expected {} at position {}",
+                    expect.as_str(),
+                    position(filter, at)
+                )
+            })),
+            load::Error::Parse(errs) => messages.extend(errs.into_iter().map(|(expect, found)| {
+                if found.is_empty() {
+                    format!("expected {}, found end of filter", expect.as_str())
+                } else {
+                    format!(
+                        "expected {}, found `{found}` at position {}",
+                        expect.as_str(),
+                        position(filter, found)
+                    )
+                }
+            })),
+        }
+    }
+    format!("Invalid filter: {}", messages.join("; "))
+}
+
+/// 1-based character position of `part`, a slice of `filter`.
+fn position(filter: &str, part: &str) -> usize {
+    let start = load::span(filter, part).start;
+    filter[..start].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{"data":{"total":2,"items":[{"id":7,"name":"a"},{"id":9,"name":"b"}]}}"#;
+
+    #[test]
+    fn extracts_each_item_id() {
+        assert_eq!(apply_jq(BODY, ".data.items[].id").unwrap(), vec!["7", "9"]);
+    }
+
+    #[test]
+    fn reshapes_keeping_key_order() {
+        assert_eq!(
+            apply_jq(BODY, "{count: .data.total, names: [.data.items[].name]}").unwrap(),
+            vec![r#"{"count":2,"names":["a","b"]}"#]
+        );
+        assert_eq!(
+            apply_jq(BODY, ".data.items[0]").unwrap(),
+            vec![r#"{"id":7,"name":"a"}"#]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_filters_and_bodies() {
+        let unclosed = apply_jq(BODY, ".data.items[").unwrap_err();
+        assert!(unclosed.starts_with("Invalid filter: "), "{unclosed}");
+
+        let unknown = apply_jq(BODY, "frobnicate").unwrap_err();
+        assert_eq!(unknown, "Invalid filter: undefined filter `frobnicate`");
+
+        let failed = apply_jq(BODY, ".data.total[]").unwrap_err();
+        assert!(failed.starts_with("Filter failed: "), "{failed}");
+
+        let not_json = apply_jq("<html>", ".").unwrap_err();
+        assert!(
+            not_json.starts_with("Response body is not JSON"),
+            "{not_json}"
+        );
+    }
+
+    #[test]
+    fn endless_and_oversized_output_is_an_error() {
+        let endless = apply_jq("1", "repeat(.)").unwrap_err();
+        assert_eq!(endless, "Filter produced more than 10000 outputs");
+
+        let items = apply_jq("1", "range(10000)").unwrap();
+        assert_eq!(items.len(), MAX_OUTPUTS);
+
+        let huge = apply_jq("\"x\"", "range(300) | \"x\" * 65536").unwrap_err();
+        assert_eq!(huge, "Filter output is larger than 16 MB");
+    }
+}
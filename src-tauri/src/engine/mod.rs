@@ -1,5 +1,28 @@
 pub mod benchmark;
+pub mod browser_preview;
 pub mod cancel;
+pub mod charset;
+pub mod client_pool;
+pub mod conditional;
+pub mod connect_hook;
+#[cfg(feature = "echo-server")]
+pub mod echo_server;
 pub mod env;
+pub mod expect_continue;
 pub mod grpc;
+pub mod grpc_stream;
 pub mod http;
+pub mod jq;
+pub mod pagination;
+pub mod preview;
+pub mod progress;
+pub mod redirects;
+pub mod reflection_cache;
+pub mod replay;
+pub mod request_log;
+pub mod response_diff;
+pub mod retry_after;
+#[cfg(test)]
+pub mod test_server;
+pub mod window;
+pub mod wire;
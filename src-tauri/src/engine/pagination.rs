@@ -0,0 +1,366 @@
+use crate::domain::{GetmanError, SendRequestPayload, SendResponsePayload};
+use crate::engine::client_pool::ClientPool;
+use crate::engine::conditional::strip_validators;
+use crate::engine::http::send_http_request_impl;
+use crate::engine::redirects::same_origin;
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+
+/// Page limit used when the request does not set one.
+pub const DEFAULT_MAX_PAGES: u32 = 10;
+
+/// Entries of a `Link` value: split on newlines between repeated headers and
+/// on commas outside `<...>` targets and quoted values, which may hold commas.
+fn link_entries(value: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let (mut in_target, mut in_quotes) = (false, false);
+    let mut start = 0;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '<' if !in_quotes => in_target = true,
+            '>' if !in_quotes => in_target = false,
+            '"' if !in_target => in_quotes = !in_quotes,
+            // Each repeated header starts afresh, even after a malformed one.
+            '\n' => {
+                entries.push(&value[start..index]);
+                start = index + 1;
+                (in_target, in_quotes) = (false, false);
+            }
+            ',' if !in_target && !in_quotes => {
+                entries.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&value[start..]);
+    entries
+}
+
+/// Target of the `rel="next"` entry in RFC 8288 (formerly RFC 5988) `Link`
+/// headers, resolved against `base`. Repeated headers arrive joined by
+/// newlines, as in [`SendResponsePayload::headers`].
+fn next_link(headers: &HashMap<String, String>, base: &str) -> Option<String> {
+    let value = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("link"))
+        .map(|(_, value)| value)?;
+    let target = link_entries(value)
+        .into_iter()
+        .filter_map(|entry| {
+            let entry = entry.trim();
+            let (target, params) = entry.strip_prefix('<')?.split_once('>')?;
+            let is_next = params.split(';').any(|param| {
+                param.trim().split_once('=').is_some_and(|(name, value)| {
+                    name.trim().eq_ignore_ascii_case("rel")
+                        && value
+                            .trim()
+                            .trim_matches('"')
+                            .split_ascii_whitespace()
+                            .any(|rel| rel.eq_ignore_ascii_case("next"))
+                })
+            });
+            is_next.then_some(target.trim())
+        })
+        .next()?;
+    let base = url::Url::parse(base).ok()?;
+    base.join(target).ok().map(String::from)
+}
+
+/// Items of a JSON array body, each kept as its original text so merging
+/// pages cannot round large integers or long decimals.
+fn json_array(body: &str) -> Option<Vec<Box<RawValue>>> {
+    serde_json::from_str(body).ok()
+}
+
+/// Merged pages so far; the first response supplies status and headers.
+struct Pages {
+    combined: SendResponsePayload,
+    items: Vec<Box<RawValue>>,
+}
+
+impl Pages {
+    fn add(&mut self, page: &SendResponsePayload, items: Vec<Box<RawValue>>) {
+        self.items.extend(items);
+        self.combined.pages += 1;
+        self.combined.time += page.time;
+        self.combined.wire_size += page.wire_size;
+        self.combined.header_size += page.header_size;
+        self.combined.body_size += page.body_size;
+        self.combined.bytes_out += page.bytes_out;
+        self.combined.truncated |= page.truncated;
+    }
+
+    fn finish(mut self, warning: Option<String>) -> SendResponsePayload {
+        let items: Vec<&str> = self.items.iter().map(|item| item.get()).collect();
+        let body = format!("[{}]", items.join(","));
+        self.combined.size = body.len() as u64;
+        self.combined.decoded_size = body.len() as u64;
+        self.combined.total_size = None;
+        self.combined.body = body;
+        self.combined.pagination_warning = warning;
+        self.combined
+    }
+}
+
+/// Follow `Link: <...>; rel="next"` from `first`, concatenating the JSON
+/// array bodies of every page into one response. Stops with a warning at
+/// the page limit, on a failed page, or on a body that is not an array.
+pub async fn follow_pagination(
+    first: SendResponsePayload,
+    payload: &SendRequestPayload,
+    clients: &ClientPool,
+    cancel_rx: &mut broadcast::Receiver<()>,
+) -> Result<SendResponsePayload, GetmanError> {
+    if !(200..300).contains(&first.status) {
+        return Ok(first);
+    }
+    let Some(mut next) = next_link(&first.headers, &payload.url) else {
+        return Ok(first);
+    };
+    let Some(items) = json_array(&first.body) else {
+        let mut first = first;
+        first.pages = 1;
+        first.pagination_warning =
+            Some("The first page is not a JSON array; pagination was not followed".into());
+        return Ok(first);
+    };
+
+    let max_pages = payload.max_pages.unwrap_or(DEFAULT_MAX_PAGES).max(1);
+    let mut visited = vec![payload.url.clone()];
+    let mut pages = Pages {
+        combined: SendResponsePayload {
+            pages: 0,
+            time: 0,
+            wire_size: 0,
+            header_size: 0,
+            body_size: 0,
+            bytes_out: 0,
+            ..first.clone()
+        },
+        items: Vec::new(),
+    };
+    pages.add(&first, items);
+
+    loop {
+        if pages.combined.pages >= max_pages {
+            let warning =
+                format!("Stopped at the {max_pages}-page limit; more pages are available");
+            return Ok(pages.finish(Some(warning)));
+        }
+        if visited.contains(&next) {
+            let warning = format!("Stopped because the next link repeats a page: {next}");
+            return Ok(pages.finish(Some(warning)));
+        }
+        // Pages are fetched with the request's headers and credentials, which
+        // must not reach an origin the user did not send them to.
+        if !same_origin(&payload.url, &next) {
+            let warning = format!("Stopped because the next link leads to another origin: {next}");
+            return Ok(pages.finish(Some(warning)));
+        }
+
+        let mut request = payload.clone();
+        request.url = next.clone();
+        request.method = "GET".into();
+        request.body = None;
+        request.body_file = None;
+        request.form_fields = None;
+        // Validators added for the first page would make the server answer
+        // the other pages with a bodiless 304.
+        strip_validators(&mut request.headers);
+        let page = send_http_request_impl(request, clients, cancel_rx).await;
+        let page_number = pages.combined.pages + 1;
+        let page = match page {
+            Ok(page) if (200..300).contains(&page.status) => page,
+            Ok(page) => {
+                let warning = format!(
+                    "Page {page_number} returned {} {}; stopped",
+                    page.status, page.status_text
+                );
+                return Ok(pages.finish(Some(warning)));
+            }
+            Err(err @ GetmanError::Cancelled(_)) => return Err(err),
+            Err(err) => {
+                let warning = format!("Page {page_number} failed: {err}");
+                return Ok(pages.finish(Some(warning)));
+            }
+        };
+        let Some(items) = json_array(&page.body) else {
+            let warning = format!("Page {page_number} is not a JSON array; stopped");
+            return Ok(pages.finish(Some(warning)));
+        };
+        pages.add(&page, items);
+        visited.push(next.clone());
+
+        match next_link(&page.headers, &next) {
+            Some(link) => next = link,
+            None => return Ok(pages.finish(None)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_server;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    fn link_headers(value: &str) -> HashMap<String, String> {
+        HashMap::from([("link".to_string(), value.to_string())])
+    }
+
+    #[test]
+    fn finds_next_link_among_relations() {
+        let headers = link_headers(concat!(
+            r#"<https://api.test/items?page=1>; rel="prev", "#,
+            r#"<https://api.test/items?page=3>; rel="next""#,
+        ));
+        assert_eq!(
+            next_link(&headers, "https://api.test/items?page=2").as_deref(),
+            Some("https://api.test/items?page=3")
+        );
+
+        let headers = link_headers("</items?page=2>; rel=\"last next\"\n</x>; rel=first");
+        assert_eq!(
+            next_link(&headers, "https://api.test/items").as_deref(),
+            Some("https://api.test/items?page=2")
+        );
+        let headers = link_headers(concat!(
+            r#"<https://api.test/items?ids=1,2&page=1>; rel="prev"; title="a, b", "#,
+            r#"<https://api.test/items?ids=1,2&page=3>; rel="next""#,
+        ));
+        assert_eq!(
+            next_link(&headers, "https://api.test/items").as_deref(),
+            Some("https://api.test/items?ids=1,2&page=3")
+        );
+        let prev_only = link_headers("<https://api.test/a>; rel=\"prev\"");
+        assert_eq!(next_link(&prev_only, "https://api.test"), None);
+        assert_eq!(next_link(&HashMap::new(), "https://api.test"), None);
+    }
+
+    /// Serve `pages` in order, linking every page to the next.
+    fn spawn_paged_server(pages: &'static [&'static str]) -> String {
+        let served = AtomicUsize::new(0);
+        test_server::serve(move |_, stream| {
+            let index = served.fetch_add(1, Ordering::SeqCst);
+            let link = format!("</items?page={}>; rel=\"next\"", index + 2);
+            let reply = test_server::reply("200 OK", &[("Link", &link)], pages[index]);
+            test_server::send(stream, &reply);
+        })
+        .url("/items")
+    }
+
+    #[test]
+    fn array_items_keep_their_number_text() {
+        let items =
+            json_array("[18446744073709551615, 0.10000000000000000555, {\"a\": 1e400}]").unwrap();
+        let items: Vec<&str> = items.iter().map(|item| item.get()).collect();
+        assert_eq!(
+            items,
+            [
+                "18446744073709551615",
+                "0.10000000000000000555",
+                "{\"a\": 1e400}"
+            ]
+        );
+        assert!(json_array("{\"items\": []}").is_none());
+    }
+
+    #[tokio::test]
+    async fn stops_before_a_next_link_to_another_origin() {
+        let payload: SendRequestPayload = serde_json::from_value(serde_json::json!({
+            "method": "GET",
+            "url": "https://api.test/items",
+            "headers": { "Authorization": "Bearer secret" },
+            "followPagination": true,
+        }))
+        .unwrap();
+        let mut first = crate::engine::http::error_response("");
+        first.status = 200;
+        first.body = "[1,2]".into();
+        first.headers = link_headers("<https://elsewhere.test/items?page=2>; rel=\"next\"");
+
+        let clients = ClientPool::new();
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let merged = follow_pagination(first, &payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+        assert_eq!((merged.body.as_str(), merged.pages), ("[1,2]", 1));
+        assert_eq!(
+            merged.pagination_warning.as_deref(),
+            Some(
+                "Stopped because the next link leads to another origin: \
+                 https://elsewhere.test/items?page=2"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn later_pages_are_fetched_without_the_first_pages_validators() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let record = seen.clone();
+        let server = test_server::serve(move |request, stream| {
+            let mut seen = record.lock().unwrap();
+            seen.push(request.header("if-none-match").map(str::to_string));
+            let link = format!("</items?page={}>; rel=\"next\"", seen.len() + 1);
+            let body = if seen.len() < 3 { "[1]" } else { "[]" };
+            test_server::send(
+                stream,
+                &test_server::reply("200 OK", &[("Link", &link)], body),
+            );
+        });
+        let payload: SendRequestPayload = serde_json::from_value(serde_json::json!({
+            "method": "GET",
+            "url": server.url("/items"),
+            "headers": { "If-None-Match": "\"page-1\"" },
+            "followPagination": true,
+            "maxPages": 3,
+        }))
+        .unwrap();
+        let clients = ClientPool::new();
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let first = send_http_request_impl(payload.clone(), &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+        let merged = follow_pagination(first, &payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+
+        assert_eq!(merged.pages, 3);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [Some("\"page-1\"".to_string()), None, None]
+        );
+    }
+
+    #[tokio::test]
+    async fn merges_array_pages_and_warns_on_non_array_page() {
+        let url = spawn_paged_server(&["[1,2]", "[3]", "{\"done\":true}"]);
+        let payload: SendRequestPayload = serde_json::from_value(serde_json::json!({
+            "method": "GET",
+            "url": url,
+            "headers": {},
+            "followPagination": true,
+        }))
+        .unwrap();
+        let clients = ClientPool::new();
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let first = send_http_request_impl(payload.clone(), &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+
+        let merged = follow_pagination(first, &payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+        assert_eq!(merged.body, "[1,2,3]");
+        assert_eq!(merged.pages, 2);
+        assert_eq!(merged.body_size, 8);
+        assert!(merged.header_size > 0);
+        assert_eq!(
+            merged.pagination_warning.as_deref(),
+            Some("Page 3 is not a JSON array; stopped")
+        );
+    }
+}
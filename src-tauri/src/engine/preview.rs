@@ -0,0 +1,296 @@
+use crate::domain::{
+    ApiKeyLocation, EnvVariable, Environment, PreviewAuth, PreviewRequestPayload, RequestPreview,
+};
+use crate::engine::env::{apply_path_params, interpolate, request_variables, resolve_request};
+use crate::engine::http::effective_user_agent;
+use std::collections::{BTreeSet, HashMap};
+
+/// Shown in place of secret values. Left untouched by URL encoding.
+pub const MASK: &str = "********";
+
+/// Variable and header names containing any of these are treated as secrets.
+const SENSITIVE_NAME_PARTS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "apikey",
+    "api_key",
+    "api-key",
+    "credential",
+    "authorization",
+    "cookie",
+    "session",
+];
+
+pub(crate) fn is_sensitive_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_NAME_PARTS.iter().any(|part| name.contains(part))
+}
+
+fn mask_variables(variables: &[EnvVariable]) -> Vec<EnvVariable> {
+    variables
+        .iter()
+        .map(|v| EnvVariable {
+            value: if is_sensitive_name(&v.key) {
+                MASK.to_string()
+            } else {
+                v.value.clone()
+            },
+            ..v.clone()
+        })
+        .collect()
+}
+
+/// Mask a header value, keeping an auth scheme such as `Bearer` visible.
+pub(crate) fn mask_header_value(value: &str) -> String {
+    match value.split_once(' ') {
+        Some((scheme, _))
+            if scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-') =>
+        {
+            format!("{scheme} {MASK}")
+        }
+        _ => MASK.to_string(),
+    }
+}
+
+/// Names of `{{name}}` placeholders left in `text`. Dynamic `{{$...}}`
+/// placeholders are filled in at send time and are not reported.
+fn collect_unresolved(text: &str, out: &mut BTreeSet<String>) {
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else { break };
+        let name = after[..end].trim();
+        if !name.is_empty() && !name.starts_with('$') {
+            out.insert(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+}
+
+fn append_query(url: &str, params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return url.to_string();
+    }
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish();
+    let (base, fragment) = match url.split_once('#') {
+        Some((base, fragment)) => (base, Some(fragment)),
+        None => (url, None),
+    };
+    let separator = match base.find('?') {
+        Some(index) if index + 1 < base.len() && !base.ends_with('&') => "&",
+        Some(_) => "",
+        None => "?",
+    };
+    match fragment {
+        Some(fragment) => format!("{base}{separator}{query}#{fragment}"),
+        None => format!("{base}{separator}{query}"),
+    }
+}
+
+/// Resolve a request without sending it: interpolate variables, fill path
+/// params, append query params and apply auth, masking secret variables, auth values and
+/// credential-bearing headers.
+pub fn preview_request(
+    payload: &PreviewRequestPayload,
+    global_variables: &[EnvVariable],
+    environments: &[Environment],
+) -> RequestPreview {
+    let global_variables = mask_variables(global_variables);
+    let environments: Vec<Environment> = environments
+        .iter()
+        .map(|env| Environment {
+            variables: mask_variables(&env.variables),
+            ..env.clone()
+        })
+        .collect();
+    let mut request = payload.request.clone();
+    request.scoped_variables = mask_variables(&request.scoped_variables);
+
+    let resolved = resolve_request(&request, &global_variables, &environments);
+    let variables = request_variables(&request, &global_variables, &environments);
+    let mut unresolved = BTreeSet::new();
+    let mut resolve = |value: &str| {
+        let value = interpolate(value, &variables);
+        collect_unresolved(&value, &mut unresolved);
+        value
+    };
+
+    let path_params: Vec<(String, String)> = payload
+        .path_params
+        .iter()
+        .map(|param| (param.key.clone(), resolve(&param.value)))
+        .collect();
+    let mut params: Vec<(String, String)> = payload
+        .params
+        .iter()
+        .map(|param| (resolve(&param.key), resolve(&param.value)))
+        .collect();
+    let mut headers: HashMap<String, String> = resolved
+        .headers
+        .into_iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive_name(&name) {
+                mask_header_value(&value)
+            } else {
+                value
+            };
+            (name, value)
+        })
+        .collect();
+
+    match &payload.auth {
+        Some(PreviewAuth::Bearer { token }) => {
+            resolve(token);
+            headers.insert("Authorization".into(), format!("Bearer {MASK}"));
+        }
+        Some(PreviewAuth::Basic { username, password }) => {
+            resolve(username);
+            resolve(password);
+            headers.insert("Authorization".into(), format!("Basic {MASK}"));
+        }
+        Some(PreviewAuth::ApiKey { key, value, add_to }) => {
+            let key = resolve(key);
+            resolve(value);
+            match add_to {
+                ApiKeyLocation::Header => {
+                    headers.insert(key, MASK.to_string());
+                }
+                ApiKeyLocation::Query => params.push((key, MASK.to_string())),
+            }
+        }
+        Some(PreviewAuth::Computed { scheme }) => {
+            headers.insert(
+                "Authorization".into(),
+                format!("{scheme} (computed when sent)"),
+            );
+        }
+        None => {}
+    }
+
+    if !headers
+        .keys()
+        .any(|name| name.eq_ignore_ascii_case("user-agent"))
+    {
+        let user_agent = effective_user_agent(payload.user_agent.as_deref());
+        headers.insert("User-Agent".into(), user_agent.to_string());
+    }
+
+    let url = apply_path_params(&resolved.url, &path_params);
+    collect_unresolved(&url, &mut unresolved);
+    for (name, value) in &headers {
+        collect_unresolved(name, &mut unresolved);
+        collect_unresolved(value, &mut unresolved);
+    }
+    if let Some(body) = &resolved.body {
+        collect_unresolved(body, &mut unresolved);
+    }
+
+    RequestPreview {
+        url: append_query(&url, &params),
+        method: resolved.method,
+        headers,
+        body: resolved.body,
+        unresolved_variables: unresolved.into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryParam, ResolveRequestPayload};
+
+    fn var(key: &str, value: &str) -> EnvVariable {
+        EnvVariable {
+            id: key.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+            enabled: true,
+        }
+    }
+
+    fn payload(url: &str, auth: Option<PreviewAuth>) -> PreviewRequestPayload {
+        PreviewRequestPayload {
+            request: ResolveRequestPayload {
+                url: url.to_string(),
+                method: "POST".to_string(),
+                headers: HashMap::from([
+                    ("X-Trace".to_string(), "{{trace}}".to_string()),
+                    ("Cookie".to_string(), "sid=abc".to_string()),
+                ]),
+                body: Some(r#"{"secret": "{{client_secret}}", "user": "{{user}}"}"#.into()),
+                environment_id: Some("dev".to_string()),
+                scoped_variables: vec![var("user", "ada")],
+            },
+            params: vec![QueryParam {
+                key: "q".to_string(),
+                value: "a b".to_string(),
+            }],
+            path_params: vec![QueryParam {
+                key: "id".to_string(),
+                value: "{{user}} 1".to_string(),
+            }],
+            auth,
+            user_agent: None,
+        }
+    }
+
+    #[test]
+    fn masks_secrets_and_reports_unresolved_variables() {
+        let globals = vec![var("client_secret", "s3cr3t"), var("user", "global-user")];
+        let envs = vec![Environment {
+            id: "dev".to_string(),
+            name: "Dev".to_string(),
+            variables: vec![var("host", "https://dev.example.com")],
+            base_url: None,
+        }];
+        let auth = PreviewAuth::Bearer {
+            token: "{{missing_token}}".to_string(),
+        };
+
+        let request = payload("{{host}}/items/:id", Some(auth));
+        let preview = preview_request(&request, &globals, &envs);
+
+        assert_eq!(preview.url, "https://dev.example.com/items/ada%201?q=a+b");
+        assert_eq!(preview.headers["Authorization"], "Bearer ********");
+        assert_eq!(preview.headers["Cookie"], MASK);
+        assert_eq!(
+            preview.headers["User-Agent"],
+            crate::engine::http::DEFAULT_USER_AGENT
+        );
+        assert_eq!(
+            preview.body.as_deref(),
+            Some(r#"{"secret": "********", "user": "ada"}"#)
+        );
+        assert_eq!(preview.unresolved_variables, vec!["missing_token", "trace"]);
+    }
+
+    #[test]
+    fn api_key_auth_is_added_to_query_or_headers() {
+        let query_auth = PreviewAuth::ApiKey {
+            key: "api_key".to_string(),
+            value: "k-123".to_string(),
+            add_to: ApiKeyLocation::Query,
+        };
+        let request = payload("http://x.test/?a=1#top", Some(query_auth));
+        let preview = preview_request(&request, &[], &[]);
+        assert_eq!(preview.url, "http://x.test/?a=1&q=a+b&api_key=********#top");
+
+        let header_auth = PreviewAuth::ApiKey {
+            key: "X-Key".to_string(),
+            value: "k-123".to_string(),
+            add_to: ApiKeyLocation::Header,
+        };
+        let mut request = payload("http://x.test", Some(header_auth));
+        request.user_agent = Some("strict-waf-test/1.0".to_string());
+        let preview = preview_request(&request, &[], &[]);
+        assert_eq!(preview.headers["X-Key"], MASK);
+        assert_eq!(preview.headers["User-Agent"], "strict-waf-test/1.0");
+        assert_eq!(preview.unresolved_variables, vec!["client_secret", "trace"]);
+    }
+}
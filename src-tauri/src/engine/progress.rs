@@ -0,0 +1,261 @@
+use crate::domain::{SendPhase, SendProgressSnapshot};
+use crate::engine::connect_hook::ConnectHook;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+tokio::task_local! {
+    /// Progress of the send being driven on this task, if anyone is watching.
+    static CURRENT: Arc<SendProgress>;
+}
+
+struct ProgressState {
+    phase: SendPhase,
+    bytes_received: u64,
+    total_bytes: Option<u64>,
+}
+
+/// Live state of one HTTP send, updated by the client as it goes.
+pub struct SendProgress {
+    started: Instant,
+    state: Mutex<ProgressState>,
+}
+
+impl SendProgress {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            state: Mutex::new(ProgressState {
+                phase: SendPhase::Waiting,
+                bytes_received: 0,
+                total_bytes: None,
+            }),
+        }
+    }
+
+    pub fn snapshot(&self) -> SendProgressSnapshot {
+        let state = self.state.lock().unwrap();
+        SendProgressSnapshot {
+            phase: state.phase,
+            bytes_received: state.bytes_received,
+            total_bytes: state.total_bytes,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+        }
+    }
+
+    fn set_phase(&self, phase: SendPhase) {
+        self.state.lock().unwrap().phase = phase;
+    }
+
+    /// Move on from `from` only. A connection finishing in the background
+    /// must not pull a send that is already downloading back to waiting.
+    fn advance(&self, from: &[SendPhase], to: SendPhase) {
+        let mut state = self.state.lock().unwrap();
+        if from.contains(&state.phase) {
+            state.phase = to;
+        }
+    }
+}
+
+/// Sends in flight that the UI can poll, keyed by request id.
+pub struct SendProgressRegistry {
+    sends: Mutex<HashMap<String, Arc<SendProgress>>>,
+}
+
+impl SendProgressRegistry {
+    pub fn new() -> Self {
+        Self {
+            sends: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn register(&self, id: &str) -> Arc<SendProgress> {
+        let progress = Arc::new(SendProgress::new());
+        self.sends
+            .lock()
+            .unwrap()
+            .insert(id.to_string(), progress.clone());
+        progress
+    }
+
+    pub fn snapshot(&self, id: &str) -> Option<SendProgressSnapshot> {
+        self.sends
+            .lock()
+            .unwrap()
+            .get(id)
+            .map(|progress| progress.snapshot())
+    }
+
+    pub fn remove(&self, id: &str) {
+        self.sends.lock().unwrap().remove(id);
+    }
+}
+
+/// Drive `future` with `progress` receiving the phases of the sends in it.
+pub async fn with_progress<F: Future>(progress: Arc<SendProgress>, future: F) -> F::Output {
+    CURRENT.scope(progress, future).await
+}
+
+fn current() -> Option<Arc<SendProgress>> {
+    CURRENT.try_with(Arc::clone).ok()
+}
+
+/// The request is about to go out on a pooled or new connection.
+pub fn sending() {
+    if let Some(progress) = current() {
+        progress.set_phase(SendPhase::Waiting);
+    }
+}
+
+/// Response headers are in; the body of `total` bytes, if known, follows.
+pub fn start_download(total: Option<u64>) {
+    if let Some(progress) = current() {
+        let mut state = progress.state.lock().unwrap();
+        state.phase = SendPhase::Downloading;
+        state.bytes_received = 0;
+        state.total_bytes = total;
+    }
+}
+
+pub fn received(bytes: usize) {
+    if let Some(progress) = current() {
+        progress.state.lock().unwrap().bytes_received += bytes as u64;
+    }
+}
+
+/// System DNS lookup that reports the resolving phase. reqwest only calls
+/// it for host names that need resolving and not for `--resolve` overrides.
+pub struct PhaseResolver;
+
+impl Resolve for PhaseResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let progress = current();
+        Box::pin(async move {
+            if let Some(progress) = &progress {
+                progress.advance(&[SendPhase::Connecting], SendPhase::Resolving);
+            }
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?;
+            if let Some(progress) = &progress {
+                progress.advance(&[SendPhase::Resolving], SendPhase::Connecting);
+            }
+            let addrs: Addrs = Box::new(addrs.collect::<Vec<_>>().into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Reports the connecting phase, from the start of a new connection until it
+/// is ready. The TLS handshake is part of it: reqwest does not expose the
+/// moment TCP is connected, so it cannot be reported on its own.
+pub struct ConnectPhases;
+
+impl ConnectHook for ConnectPhases {
+    type Token = Option<Arc<SendProgress>>;
+
+    fn started(&self) -> Self::Token {
+        let progress = current();
+        if let Some(progress) = &progress {
+            progress.advance(&[SendPhase::Waiting], SendPhase::Connecting);
+        }
+        progress
+    }
+
+    fn finished(&self, progress: Self::Token, _connected: bool) {
+        if let Some(progress) = progress {
+            progress.advance(
+                &[SendPhase::Resolving, SendPhase::Connecting],
+                SendPhase::Waiting,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::SendRequestPayload;
+    use crate::engine::client_pool::ClientPool;
+    use crate::engine::http::send_http_request_impl;
+    use crate::engine::test_server;
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use tokio::sync::broadcast;
+
+    /// Answer with a 10-byte body in two halves, each released by a message
+    /// on the returned channel. The URL names `localhost` so DNS runs too.
+    fn spawn_stepped_server() -> (String, mpsc::Sender<()>) {
+        let (step_tx, step_rx) = mpsc::channel::<()>();
+        let step_rx = Mutex::new(step_rx);
+        let server = test_server::serve(move |_, stream| {
+            let step_rx = step_rx.lock().unwrap();
+            step_rx.recv().unwrap();
+            test_server::send(
+                stream,
+                b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nhello",
+            );
+            step_rx.recv().unwrap();
+            test_server::send(stream, b"world");
+        });
+        (server.url("/").replace("127.0.0.1", "localhost"), step_tx)
+    }
+
+    async fn wait_for(
+        progress: &SendProgress,
+        check: impl Fn(&SendProgressSnapshot) -> bool,
+    ) -> SendProgressSnapshot {
+        for _ in 0..200 {
+            let snapshot = progress.snapshot();
+            if check(&snapshot) {
+                return snapshot;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("progress stuck at {:?}", progress.snapshot());
+    }
+
+    #[tokio::test]
+    async fn reports_waiting_then_download_progress() {
+        let (url, step) = spawn_stepped_server();
+        let payload: SendRequestPayload = serde_json::from_value(serde_json::json!({
+            "method": "GET",
+            "url": url,
+            "headers": {},
+        }))
+        .unwrap();
+        let progress = Arc::new(SendProgress::new());
+        let send = tokio::spawn(with_progress(progress.clone(), async move {
+            let clients = ClientPool::new();
+            let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+            send_http_request_impl(payload, &clients, &mut cancel_rx).await
+        }));
+
+        wait_for(&progress, |snapshot| snapshot.phase == SendPhase::Waiting).await;
+        step.send(()).unwrap();
+        let partial = wait_for(&progress, |snapshot| snapshot.bytes_received > 0).await;
+        assert_eq!(partial.phase, SendPhase::Downloading);
+        assert_eq!(partial.bytes_received, 5);
+        assert_eq!(partial.total_bytes, Some(10));
+
+        step.send(()).unwrap();
+        let response = send.await.unwrap().unwrap();
+        assert_eq!(response.body, "helloworld");
+        assert_eq!(progress.snapshot().bytes_received, 10);
+    }
+
+    #[test]
+    fn phases_only_advance_from_the_expected_phase() {
+        let progress = SendProgress::new();
+        progress.advance(&[SendPhase::Waiting], SendPhase::Connecting);
+        progress.advance(&[SendPhase::Connecting], SendPhase::Resolving);
+        assert_eq!(progress.snapshot().phase, SendPhase::Resolving);
+
+        progress.set_phase(SendPhase::Downloading);
+        progress.advance(
+            &[SendPhase::Resolving, SendPhase::Connecting],
+            SendPhase::Waiting,
+        );
+        assert_eq!(progress.snapshot().phase, SendPhase::Downloading);
+    }
+}
@@ -0,0 +1,284 @@
+use crate::domain::{GetmanError, RedirectHop, SendRequestPayload, SendResponsePayload};
+use crate::engine::client_pool::ClientPool;
+use crate::engine::http::{error_response, send_http_request_impl};
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+
+/// Redirect limit used when the request does not set one, as for untraced sends.
+pub const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+/// Credentials that are only sent back to the origin they were meant for.
+const ORIGIN_BOUND_HEADERS: [&str; 3] = ["authorization", "cookie", "proxy-authorization"];
+
+/// Headers describing a body, dropped along with it when a redirect switches to GET.
+const BODY_HEADERS: [&str; 3] = ["content-type", "content-length", "content-encoding"];
+
+fn is_redirect(status: u16) -> bool {
+    matches!(status, 301 | 302 | 303 | 307 | 308)
+}
+
+/// Target of the `Location` header, resolved against the URL that was requested.
+fn redirect_location(headers: &HashMap<String, String>, base: &str) -> Option<String> {
+    let value = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value)?;
+    let base = url::Url::parse(base).ok()?;
+    base.join(value.trim()).ok().map(String::from)
+}
+
+/// Method of the next hop. `303` turns everything but HEAD into GET, and
+/// `301`/`302` turn POST into GET as browsers and reqwest do; `307` and
+/// `308` repeat the request unchanged.
+fn redirected_method(status: u16, method: &str) -> String {
+    let to_get = match status {
+        303 => !method.eq_ignore_ascii_case("HEAD"),
+        301 | 302 => method.eq_ignore_ascii_case("POST"),
+        _ => false,
+    };
+    if to_get {
+        "GET".into()
+    } else {
+        method.to_string()
+    }
+}
+
+pub fn same_origin(a: &str, b: &str) -> bool {
+    match (url::Url::parse(a), url::Url::parse(b)) {
+        (Ok(a), Ok(b)) => a.origin() == b.origin(),
+        _ => false,
+    }
+}
+
+fn remove_headers(payload: &mut SendRequestPayload, names: &[&str]) {
+    let keep = |name: &String| !names.iter().any(|drop| name.eq_ignore_ascii_case(drop));
+    payload.headers.retain(|name, _| keep(name));
+    payload.default_headers.retain(|name, _| keep(name));
+}
+
+/// The request for the redirect from `request` to `location`.
+fn next_request(request: &SendRequestPayload, status: u16, location: &str) -> SendRequestPayload {
+    let mut next = request.clone();
+    next.method = redirected_method(status, &request.method);
+    if next.method != request.method {
+        next.body = None;
+        next.body_file = None;
+        next.form_fields = None;
+        remove_headers(&mut next, &BODY_HEADERS);
+    }
+    if !same_origin(&request.url, location) {
+        remove_headers(&mut next, &ORIGIN_BOUND_HEADERS);
+        next.digest_username = None;
+        next.digest_password = None;
+        next.ntlm_username = None;
+        next.ntlm_password = None;
+    }
+    next.url = location.to_string();
+    next
+}
+
+/// Send `payload` with automatic redirects off and follow each `Location`
+/// by hand, returning the final response with every hop in
+/// `redirect_chain`. Stops with a warning at the redirect limit, on a loop,
+/// or on a target that is not HTTP(S). A failed hop after the first is
+/// returned as an error response that still carries the chain.
+pub async fn trace_redirects(
+    payload: SendRequestPayload,
+    clients: &ClientPool,
+    cancel_rx: &mut broadcast::Receiver<()>,
+) -> Result<SendResponsePayload, GetmanError> {
+    let max_redirects = payload.max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS);
+    let mut request = payload;
+    let mut chain: Vec<RedirectHop> = Vec::new();
+    let mut time = 0;
+    let mut bytes_out = 0;
+
+    loop {
+        let mut response = match send_http_request_impl(request.clone(), clients, cancel_rx).await {
+            Ok(response) => response,
+            Err(err @ GetmanError::Cancelled(_)) => return Err(err),
+            Err(err) if chain.is_empty() => return Err(err),
+            Err(err) => {
+                let mut response = error_response(err);
+                response.time = time;
+                response.redirect_chain = chain;
+                return Ok(response);
+            }
+        };
+        time += response.time;
+        bytes_out += response.bytes_out;
+
+        let location = is_redirect(response.status)
+            .then(|| redirect_location(&response.headers, &request.url))
+            .flatten();
+        chain.push(RedirectHop {
+            method: request.method.clone(),
+            url: request.url.clone(),
+            status: response.status,
+            location: location.clone(),
+        });
+
+        let warning = match location {
+            None => None,
+            Some(location) if chain.len() > max_redirects as usize => Some(format!(
+                "Stopped at the {max_redirects}-redirect limit; {location} was not followed"
+            )),
+            Some(location)
+                if !location.starts_with("http://") && !location.starts_with("https://") =>
+            {
+                Some(format!(
+                    "Stopped at a redirect to {location}, which is not an HTTP URL"
+                ))
+            }
+            Some(location) => {
+                let next = next_request(&request, response.status, &location);
+                let repeated = chain
+                    .iter()
+                    .any(|hop| hop.url == next.url && hop.method == next.method);
+                if repeated {
+                    Some(format!(
+                        "Redirect loop: {} {location} was already requested",
+                        next.method
+                    ))
+                } else {
+                    request = next;
+                    continue;
+                }
+            }
+        };
+
+        response.time = time;
+        response.bytes_out = bytes_out;
+        response.redirect_chain = chain;
+        response.redirect_warning = warning;
+        return Ok(response);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_server;
+
+    /// Answer each path with its `(status, location)` route, or `200 done`.
+    fn spawn_redirect_server(routes: &'static [(&'static str, u16, &'static str)]) -> String {
+        test_server::serve(move |request, stream| {
+            let reply = match routes.iter().find(|(from, _, _)| *from == request.path) {
+                Some((_, status, location)) => {
+                    test_server::reply(&format!("{status} Redirect"), &[("Location", location)], "")
+                }
+                None => test_server::reply("200 OK", &[], "done"),
+            };
+            test_server::send(stream, &reply);
+        })
+        .url("")
+    }
+
+    fn traced_payload(url: String, method: &str, max_redirects: Option<u32>) -> SendRequestPayload {
+        serde_json::from_value(serde_json::json!({
+            "method": method,
+            "url": url,
+            "headers": { "Content-Type": "text/plain" },
+            "body": "hello",
+            "traceRedirects": true,
+            "maxRedirects": max_redirects,
+        }))
+        .unwrap()
+    }
+
+    fn hops(response: &SendResponsePayload) -> Vec<(String, String, u16)> {
+        response
+            .redirect_chain
+            .iter()
+            .map(|hop| {
+                let path = hop.url.rsplit('/').next().unwrap_or_default().to_string();
+                (hop.method.clone(), path, hop.status)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn redirect_method_and_credentials_follow_browser_rules() {
+        assert_eq!(redirected_method(302, "POST"), "GET");
+        assert_eq!(redirected_method(302, "PUT"), "PUT");
+        assert_eq!(redirected_method(303, "PUT"), "GET");
+        assert_eq!(redirected_method(303, "HEAD"), "HEAD");
+        assert_eq!(redirected_method(307, "POST"), "POST");
+
+        let mut request = traced_payload("https://a.test/x".into(), "POST", None);
+        request
+            .headers
+            .insert("Authorization".into(), "Bearer t".into());
+        let same = next_request(&request, 307, "https://a.test/y");
+        assert_eq!(same.body.as_deref(), Some("hello"));
+        assert!(same.headers.contains_key("Authorization"));
+
+        let other = next_request(&request, 303, "https://b.test/y");
+        assert_eq!(other.method, "GET");
+        assert_eq!(other.body, None);
+        assert!(other.headers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn records_each_hop_until_the_final_response() {
+        let base = spawn_redirect_server(&[("/a", 302, "/b"), ("/b", 307, "/c")]);
+        let clients = ClientPool::new();
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let payload = traced_payload(format!("{base}/a"), "POST", None);
+        let response = trace_redirects(payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "done");
+        assert_eq!(response.redirect_warning, None);
+        assert_eq!(
+            hops(&response),
+            [
+                ("POST".into(), "a".into(), 302),
+                ("GET".into(), "b".into(), 307),
+                ("GET".into(), "c".into(), 200),
+            ]
+        );
+        assert_eq!(
+            response.redirect_chain[0].location,
+            Some(format!("{base}/b"))
+        );
+        assert_eq!(response.redirect_chain[2].location, None);
+    }
+
+    #[tokio::test]
+    async fn stops_on_loops_and_at_the_redirect_limit() {
+        let base = spawn_redirect_server(&[
+            ("/a", 302, "/b"),
+            ("/b", 307, "/c"),
+            ("/loop", 301, "/again"),
+            ("/again", 308, "/loop"),
+        ]);
+        let clients = ClientPool::new();
+
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let payload = traced_payload(format!("{base}/loop"), "GET", None);
+        let looped = trace_redirects(payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+        assert_eq!(looped.status, 308);
+        assert_eq!(looped.redirect_chain.len(), 2);
+        let warning = looped.redirect_warning.unwrap();
+        assert!(warning.starts_with("Redirect loop: GET"), "{warning}");
+
+        let (_cancel_tx, mut cancel_rx) = broadcast::channel(1);
+        let payload = traced_payload(format!("{base}/a"), "GET", Some(1));
+        let limited = trace_redirects(payload, &clients, &mut cancel_rx)
+            .await
+            .unwrap();
+        assert_eq!(limited.status, 307);
+        assert_eq!(limited.redirect_chain.len(), 2);
+        assert_eq!(
+            limited.redirect_warning,
+            Some(format!(
+                "Stopped at the 1-redirect limit; {base}/c was not followed"
+            ))
+        );
+    }
+}
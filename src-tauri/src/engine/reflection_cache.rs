@@ -0,0 +1,151 @@
+use crate::domain::GrpcReflectionResponse;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a reflection result is reused before the server is asked again.
+pub const REFLECTION_TTL: Duration = Duration::from_secs(5 * 60);
+
+struct CachedReflection {
+    fetched_at: Instant,
+    response: GrpcReflectionResponse,
+}
+
+/// Per-endpoint slot. The async lock is held while reflecting so concurrent
+/// requests for the same endpoint wait for one fetch instead of starting their own.
+#[derive(Default)]
+struct EndpointSlot {
+    entry: tokio::sync::Mutex<Option<CachedReflection>>,
+}
+
+/// Caches server reflection results (services plus descriptor bytes) per endpoint.
+pub struct ReflectionCache {
+    slots: Mutex<HashMap<String, Arc<EndpointSlot>>>,
+    ttl: Duration,
+}
+
+impl ReflectionCache {
+    pub fn new() -> Self {
+        Self::with_ttl(REFLECTION_TTL)
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    fn slot(&self, endpoint: &str) -> Arc<EndpointSlot> {
+        let mut slots = self.slots.lock().unwrap();
+        slots.entry(endpoint.to_string()).or_default().clone()
+    }
+
+    /// Return the cached result for `endpoint`, or run `fetch` when it is
+    /// missing, expired or `force` is set. A forced refresh that waited behind
+    /// a fetch started after it was requested reuses that fresh result.
+    pub async fn get_or_fetch<F, Fut>(
+        &self,
+        endpoint: &str,
+        force: bool,
+        fetch: F,
+    ) -> Result<GrpcReflectionResponse, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<GrpcReflectionResponse, String>>,
+    {
+        let requested_at = Instant::now();
+        let slot = self.slot(endpoint);
+        let mut entry = slot.entry.lock().await;
+
+        if let Some(cached) = entry.as_ref() {
+            let fresh = cached.fetched_at.elapsed() < self.ttl;
+            if fresh && (!force || cached.fetched_at >= requested_at) {
+                return Ok(cached.response.clone());
+            }
+        }
+
+        let response = fetch().await?;
+        *entry = Some(CachedReflection {
+            fetched_at: Instant::now(),
+            response: response.clone(),
+        });
+        Ok(response)
+    }
+
+    /// Descriptor bytes from an unexpired reflection of `endpoint`, if any.
+    /// Never waits on an in-flight fetch.
+    pub fn cached_descriptor(&self, endpoint: &str) -> Option<String> {
+        let slot = self.slots.lock().unwrap().get(endpoint)?.clone();
+        let entry = slot.entry.try_lock().ok()?;
+        entry
+            .as_ref()
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .map(|cached| cached.response.descriptor_bytes.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const ENDPOINT: &str = "http://localhost:50051";
+
+    async fn fake_fetch(calls: &AtomicUsize) -> Result<GrpcReflectionResponse, String> {
+        let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+        // Give concurrent callers a chance to queue up behind this fetch.
+        tokio::task::yield_now().await;
+        Ok(GrpcReflectionResponse {
+            services: Vec::new(),
+            descriptor_bytes: format!("fetch-{call}"),
+        })
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_one_fetch() {
+        let cache = ReflectionCache::new();
+        let calls = AtomicUsize::new(0);
+
+        let (first, second) = tokio::join!(
+            cache.get_or_fetch(ENDPOINT, false, || fake_fetch(&calls)),
+            cache.get_or_fetch(ENDPOINT, false, || fake_fetch(&calls)),
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.unwrap().descriptor_bytes, "fetch-1");
+        assert_eq!(second.unwrap().descriptor_bytes, "fetch-1");
+        assert_eq!(
+            cache.cached_descriptor(ENDPOINT).as_deref(),
+            Some("fetch-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_and_expiry_fetch_again() {
+        let calls = AtomicUsize::new(0);
+        let cache = ReflectionCache::new();
+        cache
+            .get_or_fetch(ENDPOINT, false, || fake_fetch(&calls))
+            .await
+            .unwrap();
+        let refreshed = cache
+            .get_or_fetch(ENDPOINT, true, || fake_fetch(&calls))
+            .await
+            .unwrap();
+        assert_eq!(refreshed.descriptor_bytes, "fetch-2");
+
+        let expired = ReflectionCache::with_ttl(Duration::ZERO);
+        expired
+            .get_or_fetch(ENDPOINT, false, || fake_fetch(&calls))
+            .await
+            .unwrap();
+        expired
+            .get_or_fetch(ENDPOINT, false, || fake_fetch(&calls))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(expired.cached_descriptor(ENDPOINT), None);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::domain::SendRequestPayload;
+use std::collections::BTreeMap;
+
+/// Key of a request in the record/replay cache: a hash of the method, URL,
+/// headers and body. Header names are compared case-insensitively and in any
+/// order, and default headers count unless the request overrides them.
+pub fn request_signature(payload: &SendRequestPayload) -> String {
+    let mut headers = BTreeMap::new();
+    for (name, value) in payload.default_headers.iter().chain(&payload.headers) {
+        headers.insert(name.to_ascii_lowercase(), value.as_str());
+    }
+
+    // Length-prefix every part so no value can run into the next one.
+    let mut signature = String::new();
+    let mut push = |part: &str| {
+        signature.push_str(&part.len().to_string());
+        signature.push(':');
+        signature.push_str(part);
+    };
+    push(&payload.method.to_ascii_uppercase());
+    push(&payload.url);
+    for (name, value) in &headers {
+        push(name);
+        push(value);
+    }
+    push("body");
+    if let Some(path) = &payload.body_file {
+        push("file");
+        push(path);
+    } else if let Some(fields) = &payload.form_fields {
+        push("form");
+        for field in fields {
+            push(&field.key);
+            push(&field.value);
+        }
+    } else if let Some(body) = &payload.body {
+        push("text");
+        push(body);
+    }
+    format!("{:x}", md5::compute(signature.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(value: serde_json::Value) -> SendRequestPayload {
+        let mut request = serde_json::json!({
+            "method": "POST",
+            "url": "https://api.test/items",
+            "headers": { "Content-Type": "application/json" },
+            "body": "{\"a\":1}",
+        });
+        request
+            .as_object_mut()
+            .unwrap()
+            .extend(value.as_object().unwrap().clone());
+        serde_json::from_value(request).unwrap()
+    }
+
+    #[test]
+    fn signature_ignores_header_case_and_method_case() {
+        let base = request_signature(&payload(serde_json::json!({})));
+        let same = request_signature(&payload(serde_json::json!({
+            "method": "post",
+            "headers": { "content-type": "application/json" },
+        })));
+        assert_eq!(base, same);
+
+        let defaults = request_signature(&payload(serde_json::json!({
+            "headers": {},
+            "defaultHeaders": { "CONTENT-TYPE": "application/json" },
+        })));
+        assert_eq!(base, defaults);
+    }
+
+    #[test]
+    fn signature_changes_with_method_url_headers_and_body() {
+        let base = request_signature(&payload(serde_json::json!({})));
+        for change in [
+            serde_json::json!({ "method": "PUT" }),
+            serde_json::json!({ "url": "https://api.test/items?page=2" }),
+            serde_json::json!({ "headers": { "Content-Type": "text/plain" } }),
+            serde_json::json!({ "body": "{\"a\":2}" }),
+            serde_json::json!({ "body": null }),
+            serde_json::json!({ "formFields": [{ "key": "a", "value": "1" }] }),
+        ] {
+            assert_ne!(
+                base,
+                request_signature(&payload(change.clone())),
+                "{change}"
+            );
+        }
+    }
+}
@@ -0,0 +1,375 @@
+use crate::domain::{DiffEntry, DiffKind, DiffSection, ResponseDiff, ResponseSnapshot};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Entries reported before the diff is cut off.
+const MAX_DIFF_ENTRIES: usize = 1000;
+
+/// Text bodies whose differing lines would need a larger comparison table
+/// than this are reported as a single change.
+const MAX_LINE_DIFF_CELLS: usize = 4_000_000;
+
+/// Headers that change on every response; comparing them is only noise.
+const VOLATILE_HEADERS: &[&str] = &["date", "age"];
+
+#[derive(Default)]
+struct Entries {
+    entries: Vec<DiffEntry>,
+    truncated: bool,
+}
+
+impl Entries {
+    fn push(
+        &mut self,
+        section: DiffSection,
+        kind: DiffKind,
+        path: String,
+        before: Option<String>,
+        after: Option<String>,
+    ) {
+        if self.entries.len() >= MAX_DIFF_ENTRIES {
+            self.truncated = true;
+            return;
+        }
+        self.entries.push(DiffEntry {
+            section,
+            kind,
+            path,
+            before,
+            after,
+        });
+    }
+}
+
+/// Added, removed or changed depending on which side has a value.
+fn push_pair(
+    out: &mut Entries,
+    section: DiffSection,
+    path: String,
+    before: Option<String>,
+    after: Option<String>,
+) {
+    let kind = match (&before, &after) {
+        (Some(_), Some(_)) => DiffKind::Changed,
+        (Some(_), None) => DiffKind::Removed,
+        _ => DiffKind::Added,
+    };
+    out.push(section, kind, path, before, after);
+}
+
+fn comparable_headers(headers: &HashMap<String, String>) -> BTreeMap<String, &str> {
+    headers
+        .iter()
+        .map(|(name, value)| (name.to_ascii_lowercase(), value.as_str()))
+        .filter(|(name, _)| !VOLATILE_HEADERS.contains(&name.as_str()))
+        .collect()
+}
+
+fn diff_headers(
+    before: &HashMap<String, String>,
+    after: &HashMap<String, String>,
+    out: &mut Entries,
+) {
+    let before = comparable_headers(before);
+    let after = comparable_headers(after);
+    let names: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    for name in names {
+        let (old, new) = (before.get(name), after.get(name));
+        if old != new {
+            let to_owned = |value: Option<&&str>| value.map(|value| value.to_string());
+            push_pair(
+                out,
+                DiffSection::Header,
+                name.clone(),
+                to_owned(old),
+                to_owned(new),
+            );
+        }
+    }
+}
+
+/// `parent.key`, or `parent["key"]` when the key is not a plain identifier.
+fn json_path(parent: &str, key: &str) -> String {
+    let plain = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        format!("{parent}.{key}")
+    } else {
+        format!("{parent}[{}]", Value::String(key.to_string()))
+    }
+}
+
+/// Compare by structure: object keys are matched by name, array items by index.
+fn diff_json(path: &str, before: &Value, after: &Value, out: &mut Entries) {
+    let mut child = |path: String, old: Option<&Value>, new: Option<&Value>| match (old, new) {
+        (Some(old), Some(new)) => diff_json(&path, old, new, out),
+        _ => push_pair(
+            out,
+            DiffSection::Body,
+            path,
+            old.map(Value::to_string),
+            new.map(Value::to_string),
+        ),
+    };
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                child(json_path(path, key), old.get(key), new.get(key));
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                child(format!("{path}[{index}]"), old.get(index), new.get(index));
+            }
+        }
+        _ if before != after => out.push(
+            DiffSection::Body,
+            DiffKind::Changed,
+            path.to_string(),
+            Some(before.to_string()),
+            Some(after.to_string()),
+        ),
+        _ => {}
+    }
+}
+
+/// Pair removed lines with the added lines that replaced them as changes.
+/// Removed lines are numbered as in the baseline, the others as in the current body.
+fn flush_lines(
+    removed: &mut Vec<(usize, &str)>,
+    added: &mut Vec<(usize, &str)>,
+    out: &mut Entries,
+) {
+    for index in 0..removed.len().max(added.len()) {
+        let (old, new) = (removed.get(index), added.get(index));
+        let Some(&(line, _)) = new.or(old) else {
+            continue;
+        };
+        let text = |entry: Option<&(usize, &str)>| entry.map(|(_, text)| text.to_string());
+        push_pair(
+            out,
+            DiffSection::Body,
+            format!("line {line}"),
+            text(old),
+            text(new),
+        );
+    }
+    removed.clear();
+    added.clear();
+}
+
+/// Line diff of two text bodies based on their longest common subsequence.
+fn diff_lines(before: &str, after: &str, out: &mut Entries) {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+    let (rows, cols) = (old.len(), new.len());
+    if rows == 0 && cols == 0 {
+        return;
+    }
+    if (rows + 1) * (cols + 1) > MAX_LINE_DIFF_CELLS {
+        let path = format!("lines {}-{}", prefix + 1, prefix + rows.max(cols));
+        let (before, after) = (old.join("\n"), new.join("\n"));
+        out.push(
+            DiffSection::Body,
+            DiffKind::Changed,
+            path,
+            Some(before),
+            Some(after),
+        );
+        return;
+    }
+
+    // common[i * width + j] is the common subsequence length of old[i..] and new[j..].
+    let width = cols + 1;
+    let mut common = vec![0u32; (rows + 1) * width];
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            common[i * width + j] = if old[i] == new[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < rows || j < cols {
+        if i < rows && j < cols && old[i] == new[j] {
+            flush_lines(&mut removed, &mut added, out);
+            i += 1;
+            j += 1;
+        } else if j < cols
+            && (i == rows || common[i * width + j + 1] >= common[(i + 1) * width + j])
+        {
+            added.push((prefix + j + 1, new[j]));
+            j += 1;
+        } else {
+            removed.push((prefix + i + 1, old[i]));
+            i += 1;
+        }
+    }
+    flush_lines(&mut removed, &mut added, out);
+}
+
+fn parse_json(body: &str) -> Option<Value> {
+    if body.trim().is_empty() {
+        return None;
+    }
+    serde_json::from_str(body).ok()
+}
+
+/// Compare `current` against `baseline`: the status, headers by
+/// case-insensitive name, and the body by JSON structure when both bodies
+/// parse (so reordered keys are not a difference), otherwise line by line.
+pub fn diff_responses(baseline: &ResponseSnapshot, current: &ResponseSnapshot) -> ResponseDiff {
+    let mut out = Entries::default();
+    if baseline.status != current.status {
+        out.push(
+            DiffSection::Status,
+            DiffKind::Changed,
+            "status".into(),
+            Some(baseline.status.to_string()),
+            Some(current.status.to_string()),
+        );
+    }
+    diff_headers(&baseline.headers, &current.headers, &mut out);
+    let json_body = match (parse_json(&baseline.body), parse_json(&current.body)) {
+        (Some(before), Some(after)) => {
+            diff_json("$", &before, &after, &mut out);
+            true
+        }
+        _ => {
+            diff_lines(&baseline.body, &current.body, &mut out);
+            false
+        }
+    };
+    ResponseDiff {
+        entries: out.entries,
+        json_body,
+        truncated: out.truncated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(status: u16, headers: &[(&str, &str)], body: &str) -> ResponseSnapshot {
+        ResponseSnapshot {
+            status,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.to_string(),
+        }
+    }
+
+    fn summary(diff: &ResponseDiff) -> Vec<(DiffKind, &str, Option<&str>, Option<&str>)> {
+        diff.entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.kind,
+                    entry.path.as_str(),
+                    entry.before.as_deref(),
+                    entry.after.as_deref(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn json_bodies_are_compared_by_structure() {
+        let baseline = snapshot(
+            200,
+            &[],
+            r#"{"id": 1, "tags": ["a", "b"], "meta": {"x y": 1}}"#,
+        );
+        let reordered = snapshot(
+            200,
+            &[],
+            r#"{"meta": {"x y": 1}, "tags": ["a", "b"], "id": 1}"#,
+        );
+        let diff = diff_responses(&baseline, &reordered);
+        assert!(diff.json_body);
+        assert!(diff.entries.is_empty());
+
+        let current = snapshot(
+            200,
+            &[],
+            r#"{"id": 2, "tags": ["a"], "meta": {"x y": 1, "n": null}}"#,
+        );
+        let diff = diff_responses(&baseline, &current);
+        assert_eq!(
+            summary(&diff),
+            [
+                (DiffKind::Changed, "$.id", Some("1"), Some("2")),
+                (DiffKind::Added, "$.meta.n", None, Some("null")),
+                (DiffKind::Removed, "$.tags[1]", Some("\"b\""), None),
+            ]
+        );
+        assert_eq!(json_path("$", "x y"), "$[\"x y\"]");
+    }
+
+    #[test]
+    fn status_and_headers_ignore_name_case_and_volatile_headers() {
+        let baseline = snapshot(
+            200,
+            &[
+                ("Content-Type", "application/json"),
+                ("Date", "Mon"),
+                ("ETag", "\"1\""),
+            ],
+            "",
+        );
+        let current = snapshot(
+            404,
+            &[
+                ("content-type", "application/json"),
+                ("date", "Tue"),
+                ("X-Trace", "t"),
+            ],
+            "",
+        );
+        let diff = diff_responses(&baseline, &current);
+        assert!(!diff.json_body);
+        assert_eq!(
+            summary(&diff),
+            [
+                (DiffKind::Changed, "status", Some("200"), Some("404")),
+                (DiffKind::Removed, "etag", Some("\"1\""), None),
+                (DiffKind::Added, "x-trace", None, Some("t")),
+            ]
+        );
+    }
+
+    #[test]
+    fn text_bodies_are_diffed_line_by_line() {
+        let baseline = snapshot(200, &[], "a\nb\nc\nd\ne");
+        let current = snapshot(200, &[], "a\nB\nc\ne\nf");
+        let diff = diff_responses(&baseline, &current);
+        assert_eq!(
+            summary(&diff),
+            [
+                (DiffKind::Changed, "line 2", Some("b"), Some("B")),
+                (DiffKind::Removed, "line 4", Some("d"), None),
+                (DiffKind::Added, "line 5", None, Some("f")),
+            ]
+        );
+    }
+}
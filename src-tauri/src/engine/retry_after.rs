@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// Statuses whose `Retry-After` says when the request may be sent again.
+fn honors_retry_after(status: u16) -> bool {
+    matches!(status, 429 | 503)
+}
+
+/// Wait in milliseconds asked for by a `Retry-After` value, either a delay
+/// in seconds or an HTTP-date measured from `now`. A date already passed
+/// asks for no wait.
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds.saturating_mul(1000));
+    }
+    let at = httpdate::parse_http_date(value).ok()?;
+    let wait = at.duration_since(now).unwrap_or_default();
+    Some(u64::try_from(wait.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Wait requested by a rate-limited (`429`) or unavailable (`503`)
+/// response, if it sent a `Retry-After` that parses.
+pub fn retry_after_ms(
+    status: u16,
+    headers: &HashMap<String, String>,
+    now: SystemTime,
+) -> Option<u64> {
+    if !honors_retry_after(status) {
+        return None;
+    }
+    let value = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .map(|(_, value)| value)?;
+    // Repeated headers are joined with newlines; the first one wins.
+    parse_retry_after(value.lines().next().unwrap_or_default(), now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn delay_seconds_are_converted_to_milliseconds() {
+        assert_eq!(parse_retry_after("120", at(0)), Some(120_000));
+        assert_eq!(parse_retry_after(" 0 ", at(0)), Some(0));
+        assert_eq!(parse_retry_after("-5", at(0)), None);
+        assert_eq!(parse_retry_after("soon", at(0)), None);
+    }
+
+    #[test]
+    fn http_dates_are_measured_from_now() {
+        // Sun, 06 Nov 1994 08:49:37 GMT
+        let date = 784_111_777;
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", at(date - 30)),
+            Some(30_000)
+        );
+        assert_eq!(
+            parse_retry_after("Sunday, 06-Nov-94 08:49:37 GMT", at(date - 1)),
+            Some(1_000)
+        );
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", at(date + 60)),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn only_rate_limited_and_unavailable_responses_ask_to_wait() {
+        let headers = HashMap::from([("retry-after".to_string(), "7\n9".to_string())]);
+        assert_eq!(retry_after_ms(429, &headers, at(0)), Some(7_000));
+        assert_eq!(retry_after_ms(503, &headers, at(0)), Some(7_000));
+        assert_eq!(retry_after_ms(301, &headers, at(0)), None);
+        assert_eq!(retry_after_ms(429, &HashMap::new(), at(0)), None);
+    }
+}
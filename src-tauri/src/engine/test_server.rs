@@ -0,0 +1,151 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A request as read off the connection.
+pub struct TestRequest {
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    /// Empty while the client waits for `100 Continue`; see `read_body`.
+    pub body: Vec<u8>,
+}
+
+impl TestRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn content_length(&self) -> usize {
+        self.header("content-length")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn expects_continue(&self) -> bool {
+        self.header("expect")
+            .is_some_and(|value| value.eq_ignore_ascii_case("100-continue"))
+    }
+}
+
+pub struct TestServer {
+    base: String,
+    connections: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+    /// `http://127.0.0.1:{port}` followed by `path`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base)
+    }
+
+    /// Connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+/// Raw HTTP/1.1 server for tests that control the exact bytes of a reply.
+/// Listens on a free port and lets `handle` write the reply to every request,
+/// one thread per connection. Connections stay open for further requests
+/// until the client closes them, which it does after a `Connection: close`.
+/// A request sent with `Expect: 100-continue` reaches `handle` before its
+/// body is read, so the handler can refuse it unread or take it with
+/// `read_body`; its connection is closed afterwards.
+pub fn serve(handle: impl Fn(&TestRequest, &mut TcpStream) + Send + Sync + 'static) -> TestServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicUsize::new(0));
+    let accepted = connections.clone();
+    let handle = Arc::new(handle);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { return };
+            accepted.fetch_add(1, Ordering::SeqCst);
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                let mut stream = stream;
+                while let Some(request) = read_request(&mut stream) {
+                    handle(&request, &mut stream);
+                    let close = request
+                        .header("connection")
+                        .is_some_and(|value| value.eq_ignore_ascii_case("close"));
+                    if close || request.expects_continue() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    TestServer { base, connections }
+}
+
+/// Reply bytes for `status` (e.g. `200 OK`) with `headers`, a
+/// `Content-Length` and `body`.
+pub fn reply(status: &str, headers: &[(&str, &str)], body: impl AsRef<[u8]>) -> Vec<u8> {
+    let body = body.as_ref();
+    let mut out = format!("HTTP/1.1 {status}\r\n");
+    for (name, value) in headers {
+        out.push_str(&format!("{name}: {value}\r\n"));
+    }
+    out.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+    let mut out = out.into_bytes();
+    out.extend_from_slice(body);
+    out
+}
+
+/// Write `bytes`, ignoring a client that already hung up.
+pub fn send(stream: &mut TcpStream, bytes: &[u8]) {
+    let _ = stream.write_all(bytes).and_then(|_| stream.flush());
+}
+
+/// Read the body of a request that was waiting for `100 Continue`.
+pub fn read_body(request: &TestRequest, stream: &mut TcpStream) -> Vec<u8> {
+    let mut body = vec![0; request.content_length()];
+    stream.read_exact(&mut body).unwrap();
+    body
+}
+
+/// Read a line byte by byte, so nothing past the head is consumed and a
+/// handler can still read the body from the stream.
+fn read_line(stream: &mut TcpStream) -> Option<String> {
+    let mut line = Vec::new();
+    let mut byte = [0];
+    while !line.ends_with(b"\n") {
+        if stream.read(&mut byte).ok()? == 0 {
+            return None;
+        }
+        line.push(byte[0]);
+    }
+    String::from_utf8(line).ok()
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<TestRequest> {
+    let request_line = read_line(stream)?;
+    let path = request_line.split_whitespace().nth(1)?.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line(stream)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    let mut request = TestRequest {
+        path,
+        headers,
+        body: Vec::new(),
+    };
+    if !request.expects_continue() {
+        request.body = vec![0; request.content_length()];
+        stream.read_exact(&mut request.body).ok()?;
+    }
+    Some(request)
+}
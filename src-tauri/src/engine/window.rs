@@ -0,0 +1,167 @@
+use crate::domain::WindowState;
+use crate::store::sqlite;
+use std::sync::Mutex;
+use tauri::{AppHandle, LogicalSize, Manager, Window, WindowEvent};
+
+pub const MAIN_WINDOW_LABEL: &str = "main";
+
+const MIN_WIDTH: f64 = 960.0;
+const MIN_HEIGHT: f64 = 640.0;
+const MAX_WIDTH: f64 = 7680.0;
+const MAX_HEIGHT: f64 = 4320.0;
+
+/// Tracks the latest main window geometry between resize events so it can be
+/// persisted once when the window closes instead of on every resize tick.
+pub struct WindowStateTracker {
+    latest: Mutex<Option<WindowState>>,
+}
+
+impl WindowStateTracker {
+    pub fn new() -> Self {
+        Self {
+            latest: Mutex::new(None),
+        }
+    }
+}
+
+/// Clamp a saved window size to sane bounds. Returns `None` when the stored
+/// values are unusable (non-finite or non-positive).
+pub fn clamp_window_state(state: WindowState) -> Option<WindowState> {
+    if !state.width.is_finite()
+        || !state.height.is_finite()
+        || state.width <= 0.0
+        || state.height <= 0.0
+    {
+        return None;
+    }
+
+    Some(WindowState {
+        width: state.width.clamp(MIN_WIDTH, MAX_WIDTH),
+        height: state.height.clamp(MIN_HEIGHT, MAX_HEIGHT),
+        maximized: state.maximized,
+    })
+}
+
+fn load_window_state(app: &AppHandle) -> Result<Option<WindowState>, String> {
+    let conn = sqlite::open_db(app)?;
+    let Some(raw) = sqlite::load_state_entry(&conn, sqlite::WINDOW_STATE_KEY)? else {
+        return Ok(None);
+    };
+
+    let state: WindowState = serde_json::from_str(&raw)
+        .map_err(|err| format!("Failed to parse saved window state: {err}"))?;
+    Ok(clamp_window_state(state))
+}
+
+fn save_window_state(app: &AppHandle, state: &WindowState) -> Result<(), String> {
+    let json = serde_json::to_string(state)
+        .map_err(|err| format!("Failed to serialize window state: {err}"))?;
+    let conn = sqlite::open_db(app)?;
+    sqlite::upsert_state_entry(&conn, sqlite::WINDOW_STATE_KEY, &json)
+}
+
+/// Apply the persisted window size to the main window on startup.
+pub fn restore_window_state(app: &AppHandle) -> Result<(), String> {
+    let Some(state) = load_window_state(app)? else {
+        return Ok(());
+    };
+    let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) else {
+        return Ok(());
+    };
+
+    window
+        .set_size(LogicalSize::new(state.width, state.height))
+        .map_err(|err| format!("Failed to restore window size: {err}"))?;
+    if state.maximized {
+        window
+            .maximize()
+            .map_err(|err| format!("Failed to restore maximized window: {err}"))?;
+    }
+
+    *app.state::<WindowStateTracker>().latest.lock().unwrap() = Some(state);
+    Ok(())
+}
+
+/// Record resizes of the main window and persist the last size when it closes.
+pub fn handle_window_event(window: &Window, event: &WindowEvent) {
+    if window.label() != MAIN_WINDOW_LABEL {
+        return;
+    }
+
+    match event {
+        WindowEvent::Resized(size) => {
+            // Minimizing reports a zero size; keep the last real geometry.
+            if size.width == 0 || size.height == 0 {
+                return;
+            }
+
+            let tracker = window.state::<WindowStateTracker>();
+            let mut latest = tracker.latest.lock().unwrap();
+            let scale = window.scale_factor().unwrap_or(1.0);
+            let logical: LogicalSize<f64> = size.to_logical(scale);
+            let maximized = window.is_maximized().unwrap_or(false);
+            if maximized {
+                // Keep the restored (unmaximized) size so un-maximizing after
+                // relaunch returns to the user's layout. Without one yet (the
+                // window opened maximized), fall back to the current size so
+                // the maximized flag is still saved.
+                let state = latest.get_or_insert(WindowState {
+                    width: logical.width,
+                    height: logical.height,
+                    maximized: true,
+                });
+                state.maximized = true;
+                return;
+            }
+
+            *latest = Some(WindowState {
+                width: logical.width,
+                height: logical.height,
+                maximized: false,
+            });
+        }
+        WindowEvent::CloseRequested { .. } | WindowEvent::Destroyed => {
+            let latest = *window.state::<WindowStateTracker>().latest.lock().unwrap();
+            if let Some(state) = latest {
+                let _ = save_window_state(window.app_handle(), &state);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(width: f64, height: f64) -> WindowState {
+        WindowState {
+            width,
+            height,
+            maximized: false,
+        }
+    }
+
+    #[test]
+    fn clamp_keeps_reasonable_sizes() {
+        assert_eq!(
+            clamp_window_state(state(1220.0, 840.0)),
+            Some(state(1220.0, 840.0))
+        );
+    }
+
+    #[test]
+    fn clamp_bounds_absurd_sizes() {
+        assert_eq!(
+            clamp_window_state(state(100.0, 50_000.0)),
+            Some(state(960.0, 4320.0))
+        );
+    }
+
+    #[test]
+    fn clamp_rejects_invalid_sizes() {
+        assert_eq!(clamp_window_state(state(0.0, 840.0)), None);
+        assert_eq!(clamp_window_state(state(f64::NAN, 840.0)), None);
+        assert_eq!(clamp_window_state(state(1220.0, f64::INFINITY)), None);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::engine::http::http_version_label;
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, CONTENT_LENGTH, HOST, TRANSFER_ENCODING, USER_AGENT,
+};
+use reqwest::{Method, Request, StatusCode, Version};
+
+/// Request bodies longer than this are cut in the wire view.
+const RAW_BODY_LIMIT: usize = 64 * 1024;
+
+fn push_headers(out: &mut String, headers: &HeaderMap) {
+    for (name, value) in headers {
+        out.push_str(name.as_str());
+        out.push_str(": ");
+        out.push_str(&String::from_utf8_lossy(value.as_bytes()));
+        out.push_str("\r\n");
+    }
+}
+
+/// A request as handed to the connection, captured before sending so it can
+/// be shown once the negotiated protocol version is known.
+pub struct WireRequest {
+    method: Method,
+    target: String,
+    authority: String,
+    headers: HeaderMap,
+    /// The client's default `User-Agent`, sent when `headers` has none.
+    user_agent: HeaderValue,
+    body: Option<Vec<u8>>,
+    body_len: Option<usize>,
+}
+
+impl WireRequest {
+    pub fn capture(request: &Request, user_agent: &HeaderValue) -> Self {
+        let url = request.url();
+        let mut target = url.path().to_string();
+        if let Some(query) = url.query() {
+            target.push('?');
+            target.push_str(query);
+        }
+        let authority = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            _ => String::new(),
+        };
+        let bytes = request.body().and_then(|body| body.as_bytes());
+        Self {
+            method: request.method().clone(),
+            target,
+            authority,
+            headers: request.headers().clone(),
+            user_agent: user_agent.clone(),
+            body: bytes.map(|bytes| bytes[..bytes.len().min(RAW_BODY_LIMIT)].to_vec()),
+            body_len: bytes.map(<[u8]>::len),
+        }
+    }
+
+    /// Render the request as written on an HTTP/1 connection. Header names
+    /// are lowercase because that is how they are sent; headers the client
+    /// adds itself (`host`, `user-agent`, `accept`, `content-length`) are
+    /// filled in when the request did not set them. HTTP/2 sends the same fields as frames.
+    pub fn render(&self, version: Version) -> String {
+        let mut out = format!(
+            "{} {} {}\r\n",
+            self.method,
+            self.target,
+            http_version_label(version)
+        );
+        if !self.headers.contains_key(HOST) && !self.authority.is_empty() {
+            out.push_str(&format!("host: {}\r\n", self.authority));
+        }
+        push_headers(&mut out, &self.headers);
+        if !self.headers.contains_key(USER_AGENT) {
+            out.push_str("user-agent: ");
+            out.push_str(&String::from_utf8_lossy(self.user_agent.as_bytes()));
+            out.push_str("\r\n");
+        }
+        if !self.headers.contains_key(ACCEPT) {
+            out.push_str("accept: */*\r\n");
+        }
+        let sets_length = self.headers.contains_key(CONTENT_LENGTH)
+            || self.headers.contains_key(TRANSFER_ENCODING);
+        if let Some(len) = self.body_len.filter(|len| *len > 0 && !sets_length) {
+            out.push_str(&format!("content-length: {len}\r\n"));
+        }
+        out.push_str("\r\n");
+
+        match (&self.body, self.body_len) {
+            (Some(body), Some(len)) => match std::str::from_utf8(body) {
+                Ok(text) => {
+                    out.push_str(text);
+                    if len > body.len() {
+                        out.push_str(&format!("\n… ({len} bytes total)"));
+                    }
+                }
+                Err(_) => out.push_str(&format!("[{len} bytes of binary data]")),
+            },
+            _ if sets_length => out.push_str("[streamed body]"),
+            _ => {}
+        }
+        out
+    }
+}
+
+/// Status line and header block of a response, rebuilt from what reqwest
+/// keeps of it. The reason phrase is the server's own when it sent a
+/// non-standard one, but header names come lowercased and repeated headers
+/// grouped together, so the server's casing and order are not reproduced.
+pub fn response_head(
+    version: Version,
+    status: StatusCode,
+    reason: Option<&[u8]>,
+    headers: &HeaderMap,
+) -> String {
+    let mut out = format!("{} {}", http_version_label(version), status.as_u16());
+    let reason = match reason {
+        Some(reason) => Some(String::from_utf8_lossy(reason)),
+        None => status.canonical_reason().map(Into::into),
+    };
+    if let Some(reason) = reason {
+        out.push(' ');
+        out.push_str(&reason);
+    }
+    out.push_str("\r\n");
+    push_headers(&mut out, headers);
+    out.push_str("\r\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_request_line_headers_and_body() {
+        let request = reqwest::Client::new()
+            .post("http://api.test:8080/items?page=2")
+            .header("X-Trace", "abc")
+            .body(r#"{"a":1}"#)
+            .build()
+            .unwrap();
+        let agent = HeaderValue::from_static("Getman/test");
+        let raw = WireRequest::capture(&request, &agent).render(Version::HTTP_11);
+        assert_eq!(
+            raw,
+            "POST /items?page=2 HTTP/1.1\r\nhost: api.test:8080\r\nx-trace: abc\r\n\
+             user-agent: Getman/test\r\naccept: */*\r\ncontent-length: 7\r\n\r\n{\"a\":1}"
+        );
+
+        let binary = reqwest::Client::new()
+            .put("http://api.test/blob")
+            .header("User-Agent", "curl/8")
+            .body(vec![0xff, 0x00, 0xfe])
+            .build()
+            .unwrap();
+        let raw = WireRequest::capture(&binary, &agent).render(Version::HTTP_2);
+        assert!(raw.starts_with("PUT /blob HTTP/2\r\nhost: api.test\r\nuser-agent: curl/8\r\n"));
+        assert!(!raw.contains("Getman/test"));
+        assert!(raw.ends_with("\r\n\r\n[3 bytes of binary data]"));
+    }
+
+    #[test]
+    fn response_head_keeps_repeated_headers() {
+        let mut headers = HeaderMap::new();
+        headers.append("set-cookie", HeaderValue::from_static("a=1"));
+        headers.append("set-cookie", HeaderValue::from_static("b=2"));
+        let head = response_head(Version::HTTP_11, StatusCode::NOT_FOUND, None, &headers);
+        assert_eq!(
+            head,
+            "HTTP/1.1 404 Not Found\r\nset-cookie: a=1\r\nset-cookie: b=2\r\n\r\n"
+        );
+
+        let head = response_head(Version::HTTP_11, StatusCode::OK, Some(b"Fine"), &headers);
+        assert!(head.starts_with("HTTP/1.1 200 Fine\r\n"), "{head}");
+    }
+}
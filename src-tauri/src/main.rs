@@ -3,35 +3,119 @@ mod domain;
 mod engine;
 mod store;
 
+use commands::baseline_commands::{
+    clear_response_baseline, diff_responses, load_response_baseline, pin_response_baseline,
+};
 use commands::benchmark_commands::{
     cancel_benchmark_run, export_benchmark_run, get_benchmark_run, list_benchmark_runs,
-    start_benchmark,
+    start_benchmark, validate_benchmark_spec,
+};
+use commands::echo_server_commands::echo_server_available;
+#[cfg(feature = "echo-server")]
+use commands::echo_server_commands::{echo_server_port, start_echo_server, stop_echo_server};
+use commands::env_commands::{preview_request, resolve_request};
+use commands::grpc_commands::{
+    build_grpc_request_template, cancel_grpc_stream, close_grpc_stream_send, fetch_grpc_reflection,
+    parse_proto_content, poll_grpc_stream, refresh_grpc_reflection, send_grpc_request,
+    send_grpc_stream_message, start_grpc_stream, validate_grpc_request,
+};
+use commands::history_commands::{
+    clear_history, delete_history_entry, delete_matching_history, query_history,
+    record_history_entry, set_request_logging,
+};
+use commands::http_commands::{
+    cancel_http_request, check_proxy_url, clear_response_cache, default_user_agent,
+    inspect_body_file, poll_send_progress, send_http_request, set_connection_pool_settings,
 };
-use commands::env_commands::resolve_request;
-use commands::grpc_commands::{fetch_grpc_reflection, parse_proto_content, send_grpc_request};
-use commands::http_commands::{cancel_http_request, send_http_request};
-use commands::state_commands::{load_app_state, save_app_state};
+use commands::response_commands::{apply_jq, open_html_in_browser};
+use commands::state_commands::{load_app_state, load_settings, save_app_state, save_settings};
 use engine::benchmark::BenchmarkRegistry;
 use engine::cancel::CancelRegistry;
+use engine::client_pool::ClientPool;
+use engine::conditional::ConditionalCache;
+#[cfg(feature = "echo-server")]
+use engine::echo_server::EchoServer;
+use engine::grpc_stream::GrpcStreamRegistry;
+use engine::progress::SendProgressRegistry;
+use engine::reflection_cache::ReflectionCache;
+use engine::request_log::RequestLog;
+use engine::window::{handle_window_event, restore_window_state, WindowStateTracker};
+use tauri::Manager;
 
 fn main() {
-    tauri::Builder::default()
+    let builder = tauri::Builder::default();
+    #[cfg(feature = "echo-server")]
+    let builder = builder.manage(EchoServer::new());
+    builder
         .manage(CancelRegistry::new())
+        .manage(SendProgressRegistry::new())
+        .manage(ClientPool::new())
+        .manage(ConditionalCache::new())
+        .manage(ReflectionCache::new())
+        .manage(GrpcStreamRegistry::new())
+        .manage(RequestLog::new())
         .manage(BenchmarkRegistry::new())
+        .manage(WindowStateTracker::new())
+        .setup(|app| {
+            // A missing or unreadable saved layout falls back to the configured size.
+            let _ = restore_window_state(app.handle());
+            if let Ok(dir) = store::sqlite::app_data_dir(app.handle()) {
+                app.state::<RequestLog>().set_dir(dir.join("logs"));
+            }
+            Ok(())
+        })
+        .on_window_event(handle_window_event)
         .invoke_handler(tauri::generate_handler![
             send_http_request,
             cancel_http_request,
+            poll_send_progress,
+            inspect_body_file,
+            default_user_agent,
+            set_connection_pool_settings,
+            check_proxy_url,
+            clear_response_cache,
             load_app_state,
             save_app_state,
+            load_settings,
+            save_settings,
             parse_proto_content,
+            build_grpc_request_template,
+            validate_grpc_request,
             send_grpc_request,
             fetch_grpc_reflection,
+            refresh_grpc_reflection,
+            start_grpc_stream,
+            send_grpc_stream_message,
+            close_grpc_stream_send,
+            poll_grpc_stream,
+            cancel_grpc_stream,
             resolve_request,
+            preview_request,
             start_benchmark,
+            validate_benchmark_spec,
             list_benchmark_runs,
             get_benchmark_run,
             cancel_benchmark_run,
-            export_benchmark_run
+            export_benchmark_run,
+            record_history_entry,
+            query_history,
+            clear_history,
+            delete_history_entry,
+            delete_matching_history,
+            set_request_logging,
+            pin_response_baseline,
+            load_response_baseline,
+            clear_response_baseline,
+            diff_responses,
+            apply_jq,
+            open_html_in_browser,
+            echo_server_available,
+            #[cfg(feature = "echo-server")]
+            start_echo_server,
+            #[cfg(feature = "echo-server")]
+            stop_echo_server,
+            #[cfg(feature = "echo-server")]
+            echo_server_port
         ])
         .run(tauri::generate_context!())
         .expect("failed to run getman");
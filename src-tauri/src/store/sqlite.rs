@@ -1,10 +1,17 @@
-use crate::domain::{BenchmarkErrorSample, BenchmarkHistogramBucket, BenchmarkTimeseriesPoint};
-use rusqlite::{params, Connection, OptionalExtension};
+use crate::domain::{
+    AppSettings, BenchmarkErrorSample, BenchmarkHistogramBucket, BenchmarkSuccessSample,
+    BenchmarkTimeseriesPoint, HistoryEntry, HistoryQuery, HistoryStatusClass, ResponseBaseline,
+    SendResponsePayload,
+};
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
 use std::fs;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
 pub const APP_STATE_KEY: &str = "root";
+pub const WINDOW_STATE_KEY: &str = "window";
+pub const SETTINGS_STATE_KEY: &str = "settings";
 
 pub fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
     let app_dir = app
@@ -33,6 +40,11 @@ pub fn open_db(app: &AppHandle) -> Result<Connection, String> {
     conn.pragma_update(None, "foreign_keys", "ON")
         .map_err(|err| format!("Failed to enable SQLite foreign keys: {err}"))?;
 
+    init_schema(&conn)?;
+    Ok(conn)
+}
+
+fn init_schema(conn: &Connection) -> Result<(), String> {
     conn.execute_batch(
         "CREATE TABLE IF NOT EXISTS app_state (
          state_key TEXT PRIMARY KEY,
@@ -89,14 +101,89 @@ pub fn open_db(app: &AppHandle) -> Result<Connection, String> {
          sample_body TEXT,
          PRIMARY KEY(run_id, error_type, status_code, message),
          FOREIGN KEY(run_id) REFERENCES benchmark_runs(run_id) ON DELETE CASCADE
+       );
+       CREATE TABLE IF NOT EXISTS benchmark_success_samples (
+         run_id TEXT NOT NULL,
+         status_code INTEGER NOT NULL,
+         body TEXT NOT NULL,
+         count INTEGER NOT NULL,
+         PRIMARY KEY(run_id, status_code, body),
+         FOREIGN KEY(run_id) REFERENCES benchmark_runs(run_id) ON DELETE CASCADE
+       );
+       CREATE TABLE IF NOT EXISTS request_history (
+         id TEXT PRIMARY KEY,
+         method TEXT NOT NULL,
+         url TEXT NOT NULL,
+         status INTEGER NOT NULL,
+         time_ms INTEGER NOT NULL,
+         timestamp INTEGER NOT NULL,
+         request_type TEXT,
+         count INTEGER NOT NULL DEFAULT 1,
+         last_timestamp INTEGER,
+         request_json TEXT,
+         cancelled INTEGER NOT NULL DEFAULT 0,
+         grpc_status INTEGER
+       );
+       CREATE INDEX IF NOT EXISTS idx_request_history_timestamp
+         ON request_history(timestamp DESC);
+       CREATE INDEX IF NOT EXISTS idx_request_history_last_sent
+         ON request_history(COALESCE(last_timestamp, timestamp) DESC);
+       CREATE INDEX IF NOT EXISTS idx_request_history_url
+         ON request_history(url);
+       CREATE TABLE IF NOT EXISTS response_baselines (
+         request_id TEXT PRIMARY KEY,
+         saved_at INTEGER NOT NULL,
+         response_json TEXT NOT NULL
+       );
+       CREATE TABLE IF NOT EXISTS response_cache (
+         signature TEXT PRIMARY KEY,
+         saved_at INTEGER NOT NULL,
+         response_json TEXT NOT NULL
        );",
     )
     .map_err(|err| format!("Failed to initialize SQLite schema: {err}"))?;
+    add_missing_column(
+        conn,
+        "request_history",
+        "cancelled",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+    add_missing_column(conn, "request_history", "grpc_status", "INTEGER")
+}
 
-    Ok(conn)
+/// Add a column that databases created by older versions lack.
+fn add_missing_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let exists = conn
+        .prepare(&format!(
+            "SELECT 1 FROM pragma_table_info('{table}') WHERE name = ?1"
+        ))
+        .and_then(|mut stmt| stmt.exists(params![column]))
+        .map_err(|err| format!("Failed to inspect SQLite table {table}: {err}"))?;
+    if exists {
+        return Ok(());
+    }
+    conn.execute(
+        &format!("ALTER TABLE {table} ADD COLUMN {column} {definition};"),
+        [],
+    )
+    .map(|_| ())
+    .map_err(|err| format!("Failed to add column {table}.{column}: {err}"))
 }
 
 pub fn upsert_state(conn: &Connection, state_json: &str) -> Result<(), String> {
+    upsert_state_entry(conn, APP_STATE_KEY, state_json)
+}
+
+pub fn load_state(conn: &Connection) -> Result<Option<String>, String> {
+    load_state_entry(conn, APP_STATE_KEY)
+}
+
+pub fn upsert_state_entry(conn: &Connection, key: &str, state_json: &str) -> Result<(), String> {
     conn.execute(
         "INSERT INTO app_state (state_key, state_json, updated_at)
        VALUES (?1, ?2, strftime('%s','now'))
@@ -104,23 +191,38 @@ pub fn upsert_state(conn: &Connection, state_json: &str) -> Result<(), String> {
        DO UPDATE SET
          state_json = excluded.state_json,
          updated_at = excluded.updated_at;",
-        params![APP_STATE_KEY, state_json],
+        params![key, state_json],
     )
     .map_err(|err| format!("Failed to save app state to SQLite: {err}"))?;
 
     Ok(())
 }
 
-pub fn load_state(conn: &Connection) -> Result<Option<String>, String> {
+pub fn load_state_entry(conn: &Connection, key: &str) -> Result<Option<String>, String> {
     conn.query_row(
         "SELECT state_json FROM app_state WHERE state_key = ?1 LIMIT 1;",
-        params![APP_STATE_KEY],
+        params![key],
         |row| row.get(0),
     )
     .optional()
     .map_err(|err| format!("Failed to load app state from SQLite: {err}"))
 }
 
+pub fn save_settings(conn: &Connection, settings: &AppSettings) -> Result<(), String> {
+    let json = serde_json::to_string(settings)
+        .map_err(|err| format!("Failed to serialize settings: {err}"))?;
+    upsert_state_entry(conn, SETTINGS_STATE_KEY, &json)
+}
+
+/// The saved settings, or `None` before they are first saved.
+pub fn load_settings(conn: &Connection) -> Result<Option<AppSettings>, String> {
+    load_state_entry(conn, SETTINGS_STATE_KEY)?
+        .map(|json| {
+            serde_json::from_str(&json).map_err(|err| format!("Failed to parse settings: {err}"))
+        })
+        .transpose()
+}
+
 #[derive(Debug, Clone)]
 pub struct StoredBenchmarkRunRow {
     pub run_id: String,
@@ -155,6 +257,7 @@ pub fn upsert_benchmark_spec(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn insert_benchmark_run(
     conn: &Connection,
     run_id: &str,
@@ -247,7 +350,7 @@ pub fn list_benchmark_runs(
     request_id: Option<&str>,
     limit: u32,
 ) -> Result<Vec<StoredBenchmarkRunRow>, String> {
-    let limit = (limit.max(1).min(200)) as i64;
+    let limit = limit.clamp(1, 200) as i64;
     let mut items = Vec::new();
 
     if let Some(request_id) = request_id {
@@ -415,6 +518,34 @@ pub fn replace_benchmark_error_samples(
     Ok(())
 }
 
+pub fn replace_benchmark_success_samples(
+    conn: &Connection,
+    run_id: &str,
+    samples: &[BenchmarkSuccessSample],
+) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM benchmark_success_samples WHERE run_id = ?1;",
+        params![run_id],
+    )
+    .map_err(|err| format!("Failed to clear benchmark success samples: {err}"))?;
+
+    for sample in samples {
+        conn.execute(
+            "INSERT INTO benchmark_success_samples (run_id, status_code, body, count)
+             VALUES (?1, ?2, ?3, ?4);",
+            params![
+                run_id,
+                sample.status_code as i64,
+                &sample.body,
+                sample.count as i64
+            ],
+        )
+        .map_err(|err| format!("Failed to insert benchmark success sample: {err}"))?;
+    }
+
+    Ok(())
+}
+
 pub fn load_benchmark_timeseries(
     conn: &Connection,
     run_id: &str,
@@ -510,3 +641,754 @@ pub fn load_benchmark_error_samples(
     }
     Ok(items)
 }
+
+pub fn load_benchmark_success_samples(
+    conn: &Connection,
+    run_id: &str,
+) -> Result<Vec<BenchmarkSuccessSample>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT status_code, body, count
+             FROM benchmark_success_samples
+             WHERE run_id = ?1
+             ORDER BY count DESC, status_code ASC;",
+        )
+        .map_err(|err| format!("Failed to query benchmark success samples: {err}"))?;
+
+    let rows = stmt
+        .query_map(params![run_id], |row| {
+            Ok(BenchmarkSuccessSample {
+                status_code: row.get::<_, i64>(0)? as u16,
+                body: row.get(1)?,
+                count: row.get::<_, i64>(2)? as u64,
+            })
+        })
+        .map_err(|err| format!("Failed to map benchmark success samples: {err}"))?;
+
+    let mut items = Vec::new();
+    for row in rows {
+        items.push(row.map_err(|err| format!("Failed to read benchmark success sample: {err}"))?);
+    }
+    Ok(items)
+}
+
+pub const DEFAULT_HISTORY_LIMIT: u32 = 100;
+pub const MAX_HISTORY_LIMIT: u32 = 5000;
+
+pub fn upsert_history_entry(conn: &Connection, entry: &HistoryEntry) -> Result<(), String> {
+    let request_json = entry
+        .request
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|err| format!("Failed to serialize history request: {err}"))?;
+
+    conn.execute(
+        "INSERT INTO request_history (
+           id, method, url, status, time_ms, timestamp, request_type, count, last_timestamp,
+           request_json, cancelled, grpc_status
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+         ON CONFLICT(id)
+         DO UPDATE SET
+           status = excluded.status,
+           time_ms = excluded.time_ms,
+           cancelled = excluded.cancelled,
+           grpc_status = excluded.grpc_status,
+           count = excluded.count,
+           last_timestamp = excluded.last_timestamp,
+           request_json = COALESCE(excluded.request_json, request_history.request_json);",
+        params![
+            entry.id,
+            entry.method,
+            entry.url,
+            entry.status,
+            entry.time as i64,
+            entry.timestamp as i64,
+            entry.request_type,
+            entry.count.unwrap_or(1),
+            entry.last_timestamp.map(|value| value as i64),
+            request_json,
+            entry.cancelled,
+            entry.grpc_status,
+        ],
+    )
+    .map_err(|err| format!("Failed to save history entry: {err}"))?;
+    Ok(())
+}
+
+/// When an entry was last sent: folded repeats move it to their time.
+const LAST_SENT: &str = "COALESCE(last_timestamp, timestamp)";
+
+/// Delete all but the `max_entries` most recently sent history rows. `0`
+/// keeps everything.
+pub fn prune_history(conn: &Connection, max_entries: u32) -> Result<(), String> {
+    if max_entries == 0 {
+        return Ok(());
+    }
+    conn.execute(
+        &format!(
+            "DELETE FROM request_history
+             WHERE id NOT IN (
+               SELECT id FROM request_history ORDER BY {LAST_SENT} DESC LIMIT ?1
+             );"
+        ),
+        params![max_entries as i64],
+    )
+    .map_err(|err| format!("Failed to prune history: {err}"))?;
+    Ok(())
+}
+
+pub fn clear_history(conn: &Connection) -> Result<(), String> {
+    conn.execute("DELETE FROM request_history;", [])
+        .map_err(|err| format!("Failed to clear history: {err}"))?;
+    Ok(())
+}
+
+/// Delete one history row. Returns whether it existed.
+pub fn delete_history_entry(conn: &Connection, id: &str) -> Result<bool, String> {
+    let deleted = conn
+        .execute("DELETE FROM request_history WHERE id = ?1;", params![id])
+        .map_err(|err| format!("Failed to delete history entry: {err}"))?;
+    Ok(deleted > 0)
+}
+
+/// Delete every row matching the filters in `query`, ignoring its limit.
+/// At least one filter is required; use [`clear_history`] to remove everything.
+pub fn delete_matching_history(conn: &Connection, query: &HistoryQuery) -> Result<usize, String> {
+    let (clauses, values) = history_filter(query);
+    if clauses.is_empty() {
+        return Err("Failed to delete history: no filter given".into());
+    }
+    let sql = format!(
+        "DELETE FROM request_history WHERE {};",
+        clauses.join(" AND ")
+    );
+    conn.execute(&sql, params_from_iter(values))
+        .map_err(|err| format!("Failed to delete history: {err}"))
+}
+
+/// Pin `baseline` for its request, replacing any earlier one.
+pub fn upsert_response_baseline(
+    conn: &Connection,
+    baseline: &ResponseBaseline,
+) -> Result<(), String> {
+    let response_json = serde_json::to_string(&baseline.response)
+        .map_err(|err| format!("Failed to serialize response baseline: {err}"))?;
+    conn.execute(
+        "INSERT INTO response_baselines (request_id, saved_at, response_json)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(request_id)
+         DO UPDATE SET
+           saved_at = excluded.saved_at,
+           response_json = excluded.response_json;",
+        params![baseline.request_id, baseline.saved_at as i64, response_json],
+    )
+    .map_err(|err| format!("Failed to save response baseline: {err}"))?;
+    Ok(())
+}
+
+pub fn load_response_baseline(
+    conn: &Connection,
+    request_id: &str,
+) -> Result<Option<ResponseBaseline>, String> {
+    let row: Option<(i64, String)> = conn
+        .query_row(
+            "SELECT saved_at, response_json FROM response_baselines WHERE request_id = ?1;",
+            params![request_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+        .map_err(|err| format!("Failed to load response baseline: {err}"))?;
+    row.map(|(saved_at, response_json)| {
+        let response = serde_json::from_str(&response_json)
+            .map_err(|err| format!("Failed to parse response baseline: {err}"))?;
+        Ok(ResponseBaseline {
+            request_id: request_id.to_string(),
+            saved_at: saved_at as u64,
+            response,
+        })
+    })
+    .transpose()
+}
+
+/// Unpin the baseline of a request. Returns whether one existed.
+pub fn delete_response_baseline(conn: &Connection, request_id: &str) -> Result<bool, String> {
+    let deleted = conn
+        .execute(
+            "DELETE FROM response_baselines WHERE request_id = ?1;",
+            params![request_id],
+        )
+        .map_err(|err| format!("Failed to delete response baseline: {err}"))?;
+    Ok(deleted > 0)
+}
+
+/// Record the response to the request with `signature` for later replay.
+pub fn upsert_cached_response(
+    conn: &Connection,
+    signature: &str,
+    saved_at: u64,
+    response: &SendResponsePayload,
+) -> Result<(), String> {
+    let response_json = serde_json::to_string(response)
+        .map_err(|err| format!("Failed to serialize cached response: {err}"))?;
+    conn.execute(
+        "INSERT INTO response_cache (signature, saved_at, response_json)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(signature)
+         DO UPDATE SET
+           saved_at = excluded.saved_at,
+           response_json = excluded.response_json;",
+        params![signature, saved_at as i64, response_json],
+    )
+    .map_err(|err| format!("Failed to save cached response: {err}"))?;
+    Ok(())
+}
+
+pub fn load_cached_response(
+    conn: &Connection,
+    signature: &str,
+) -> Result<Option<SendResponsePayload>, String> {
+    let response_json: Option<String> = conn
+        .query_row(
+            "SELECT response_json FROM response_cache WHERE signature = ?1;",
+            params![signature],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|err| format!("Failed to load cached response: {err}"))?;
+    response_json
+        .map(|json| {
+            serde_json::from_str(&json)
+                .map_err(|err| format!("Failed to parse cached response: {err}"))
+        })
+        .transpose()
+}
+
+/// Drop every recorded response. Returns how many there were.
+pub fn clear_response_cache(conn: &Connection) -> Result<usize, String> {
+    conn.execute("DELETE FROM response_cache;", [])
+        .map_err(|err| format!("Failed to clear response cache: {err}"))
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn status_class_range(class: HistoryStatusClass) -> (i64, i64) {
+    match class {
+        HistoryStatusClass::Success => (200, 299),
+        HistoryStatusClass::Redirect => (300, 399),
+        HistoryStatusClass::ClientError => (400, 499),
+        HistoryStatusClass::ServerError => (500, 599),
+        HistoryStatusClass::NetworkError => (0, 0),
+    }
+}
+
+/// `WHERE` conditions and bound values for the filters set in `query`.
+/// The limit is not part of the filter.
+fn history_filter(query: &HistoryQuery) -> (Vec<String>, Vec<Value>) {
+    let mut clauses: Vec<String> = Vec::new();
+    let mut values: Vec<Value> = Vec::new();
+
+    if let Some(search) = query
+        .search
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        values.push(Value::Text(format!("%{}%", escape_like(search))));
+        clauses.push(format!("url LIKE ?{} ESCAPE '\\'", values.len()));
+    }
+
+    if let Some(method) = query
+        .method
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        values.push(Value::Text(method.to_ascii_uppercase()));
+        clauses.push(format!("method = ?{}", values.len()));
+    }
+
+    if let Some(class) = query.status_class {
+        let (min, max) = status_class_range(class);
+        values.push(Value::Integer(min));
+        values.push(Value::Integer(max));
+        clauses.push(format!(
+            "status BETWEEN ?{} AND ?{}",
+            values.len() - 1,
+            values.len()
+        ));
+    }
+
+    if let Some(from_ms) = query.from_ms {
+        values.push(Value::Integer(from_ms as i64));
+        clauses.push(format!("{LAST_SENT} >= ?{}", values.len()));
+    }
+
+    if let Some(to_ms) = query.to_ms {
+        values.push(Value::Integer(to_ms as i64));
+        clauses.push(format!("{LAST_SENT} <= ?{}", values.len()));
+    }
+
+    (clauses, values)
+}
+
+pub fn query_history(conn: &Connection, query: &HistoryQuery) -> Result<Vec<HistoryEntry>, String> {
+    let (clauses, mut values) = history_filter(query);
+    let where_clause = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", clauses.join(" AND "))
+    };
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT);
+    values.push(Value::Integer(limit as i64));
+
+    let sql = format!(
+        "SELECT id, method, url, status, time_ms, timestamp, request_type, count, last_timestamp,
+                request_json, cancelled, grpc_status
+         FROM request_history
+         {where_clause}
+         ORDER BY {LAST_SENT} DESC
+         LIMIT ?{};",
+        values.len()
+    );
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|err| format!("Failed to query history: {err}"))?;
+    let rows = stmt
+        .query_map(params_from_iter(values), |row| {
+            Ok(HistoryEntry {
+                id: row.get(0)?,
+                method: row.get(1)?,
+                url: row.get(2)?,
+                status: row.get(3)?,
+                time: row.get::<_, i64>(4)? as u64,
+                timestamp: row.get::<_, i64>(5)? as u64,
+                request_type: row.get(6)?,
+                count: Some(row.get(7)?),
+                last_timestamp: row.get::<_, Option<i64>>(8)?.map(|value| value as u64),
+                request: row
+                    .get::<_, Option<String>>(9)?
+                    .and_then(|raw| serde_json::from_str(&raw).ok()),
+                cancelled: row.get(10)?,
+                grpc_status: row.get(11)?,
+            })
+        })
+        .map_err(|err| format!("Failed to map history entries: {err}"))?;
+
+    let mut items = Vec::new();
+    for row in rows {
+        items.push(row.map_err(|err| format!("Failed to read history entry: {err}"))?);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DefaultHeader, RequestNaming, ResponseSnapshot, Theme};
+    use std::collections::HashMap;
+
+    fn memory_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+        conn
+    }
+
+    fn entry(id: &str, method: &str, url: &str, status: u16, timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            id: id.to_string(),
+            method: method.to_string(),
+            url: url.to_string(),
+            status,
+            time: 12,
+            timestamp,
+            request_type: None,
+            count: None,
+            last_timestamp: None,
+            request: None,
+            cancelled: false,
+            grpc_status: None,
+        }
+    }
+
+    #[test]
+    fn query_history_filters_by_url_method_status_and_time() {
+        let conn = memory_db();
+        upsert_history_entry(
+            &conn,
+            &entry("a", "GET", "https://api.test/orders", 200, 1_000),
+        )
+        .unwrap();
+        upsert_history_entry(
+            &conn,
+            &entry("b", "POST", "https://api.test/orders", 500, 2_000),
+        )
+        .unwrap();
+        upsert_history_entry(
+            &conn,
+            &entry("c", "POST", "https://api.test/users", 503, 3_000),
+        )
+        .unwrap();
+
+        let query = HistoryQuery {
+            search: Some("/orders".to_string()),
+            method: Some("post".to_string()),
+            status_class: Some(HistoryStatusClass::ServerError),
+            from_ms: Some(1_500),
+            to_ms: Some(2_500),
+            limit: None,
+        };
+        let items = query_history(&conn, &query).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "b");
+    }
+
+    #[test]
+    fn folded_entries_are_dated_by_their_last_send() {
+        let conn = memory_db();
+        let mut resent = entry("a", "GET", "https://api.test/a", 200, 1_000);
+        resent.count = Some(2);
+        resent.last_timestamp = Some(5_000);
+        upsert_history_entry(&conn, &resent).unwrap();
+        upsert_history_entry(&conn, &entry("b", "GET", "https://api.test/b", 200, 3_000)).unwrap();
+
+        let ids = |query: &HistoryQuery| -> Vec<String> {
+            query_history(&conn, query)
+                .unwrap()
+                .into_iter()
+                .map(|item| item.id)
+                .collect()
+        };
+        assert_eq!(ids(&HistoryQuery::default()), ["a", "b"]);
+        let today = HistoryQuery {
+            from_ms: Some(4_000),
+            ..HistoryQuery::default()
+        };
+        assert_eq!(ids(&today), ["a"]);
+
+        prune_history(&conn, 1).unwrap();
+        assert_eq!(ids(&HistoryQuery::default()), ["a"]);
+    }
+
+    #[test]
+    fn query_history_orders_newest_first_and_escapes_wildcards() {
+        let conn = memory_db();
+        upsert_history_entry(
+            &conn,
+            &entry("a", "GET", "https://api.test/a_b", 200, 1_000),
+        )
+        .unwrap();
+        upsert_history_entry(
+            &conn,
+            &entry("b", "GET", "https://api.test/axb", 200, 2_000),
+        )
+        .unwrap();
+
+        let all = query_history(&conn, &HistoryQuery::default()).unwrap();
+        assert_eq!(
+            all.iter().map(|item| item.id.as_str()).collect::<Vec<_>>(),
+            ["b", "a"]
+        );
+
+        let query = HistoryQuery {
+            search: Some("a_b".to_string()),
+            ..HistoryQuery::default()
+        };
+        let items = query_history(&conn, &query).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "a");
+    }
+
+    #[test]
+    fn prune_history_keeps_newest_entries_when_cap_is_lowered() {
+        let conn = memory_db();
+        for index in 0..5u64 {
+            let id = format!("e{index}");
+            upsert_history_entry(&conn, &entry(&id, "GET", "https://api.test", 200, index))
+                .unwrap();
+        }
+
+        prune_history(&conn, 0).unwrap();
+        assert_eq!(
+            query_history(&conn, &HistoryQuery::default())
+                .unwrap()
+                .len(),
+            5
+        );
+
+        prune_history(&conn, 10).unwrap();
+        assert_eq!(
+            query_history(&conn, &HistoryQuery::default())
+                .unwrap()
+                .len(),
+            5
+        );
+
+        prune_history(&conn, 2).unwrap();
+        let items = query_history(&conn, &HistoryQuery::default()).unwrap();
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<_>>(),
+            ["e4", "e3"]
+        );
+    }
+
+    fn history_ids(conn: &Connection) -> Vec<String> {
+        query_history(conn, &HistoryQuery::default())
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id)
+            .collect()
+    }
+
+    #[test]
+    fn delete_history_entry_removes_one_row_and_keeps_order() {
+        let conn = memory_db();
+        for index in 0..4u64 {
+            let id = format!("e{index}");
+            upsert_history_entry(&conn, &entry(&id, "GET", "https://api.test", 200, index))
+                .unwrap();
+        }
+
+        assert!(delete_history_entry(&conn, "e2").unwrap());
+        assert!(!delete_history_entry(&conn, "e2").unwrap());
+        assert_eq!(history_ids(&conn), ["e3", "e1", "e0"]);
+    }
+
+    #[test]
+    fn delete_matching_history_only_removes_filtered_rows() {
+        let conn = memory_db();
+        upsert_history_entry(&conn, &entry("a", "GET", "https://api.test/a", 200, 1_000)).unwrap();
+        upsert_history_entry(&conn, &entry("b", "POST", "https://api.test/b", 500, 2_000)).unwrap();
+        upsert_history_entry(&conn, &entry("c", "GET", "https://api.test/c", 503, 3_000)).unwrap();
+        upsert_history_entry(&conn, &entry("d", "GET", "https://noise.test", 200, 4_000)).unwrap();
+
+        assert!(delete_matching_history(&conn, &HistoryQuery::default()).is_err());
+
+        let server_errors = HistoryQuery {
+            status_class: Some(HistoryStatusClass::ServerError),
+            method: Some("get".to_string()),
+            ..HistoryQuery::default()
+        };
+        assert_eq!(delete_matching_history(&conn, &server_errors).unwrap(), 1);
+
+        let noise = HistoryQuery {
+            search: Some("noise".to_string()),
+            limit: Some(1),
+            ..HistoryQuery::default()
+        };
+        assert_eq!(delete_matching_history(&conn, &noise).unwrap(), 1);
+        assert_eq!(history_ids(&conn), ["b", "a"]);
+    }
+
+    #[test]
+    fn history_request_snapshot_round_trips_and_survives_count_updates() {
+        let conn = memory_db();
+        let mut first = entry("a", "POST", "https://api.test/orders", 201, 1_000);
+        first.request = Some(serde_json::json!({
+            "method": "POST",
+            "headers": [{ "key": "X-Trace", "value": "1" }],
+            "bodyContent": "{\"id\":1}"
+        }));
+        upsert_history_entry(&conn, &first).unwrap();
+
+        let mut repeat = entry("a", "POST", "https://api.test/orders", 201, 1_000);
+        repeat.count = Some(2);
+        upsert_history_entry(&conn, &repeat).unwrap();
+
+        let items = query_history(&conn, &HistoryQuery::default()).unwrap();
+        assert_eq!(items[0].count, Some(2));
+        assert_eq!(items[0].request, first.request);
+    }
+
+    #[test]
+    fn grpc_history_round_trips_status_and_request() {
+        let conn = memory_db();
+        let mut call = entry(
+            "g",
+            "POST",
+            "grpc://localhost:50051/shop.Orders/Get",
+            404,
+            1,
+        );
+        call.request_type = Some("grpc".to_string());
+        call.grpc_status = Some(5);
+        call.request = Some(serde_json::json!({
+            "requestType": "grpc",
+            "url": "grpc://localhost:50051",
+            "grpcServiceName": "shop.Orders",
+            "grpcMethodName": "Get",
+            "grpcRequestBody": "{\"id\": 7}",
+        }));
+        upsert_history_entry(&conn, &call).unwrap();
+
+        let items = query_history(&conn, &HistoryQuery::default()).unwrap();
+        assert_eq!(items[0].request_type.as_deref(), Some("grpc"));
+        assert_eq!(items[0].grpc_status, Some(5));
+        assert_eq!(items[0].request, call.request);
+
+        call.status = 200;
+        call.grpc_status = Some(0);
+        call.count = Some(2);
+        call.request = None;
+        upsert_history_entry(&conn, &call).unwrap();
+        let items = query_history(&conn, &HistoryQuery::default()).unwrap();
+        assert_eq!(items[0].grpc_status, Some(0));
+        assert_eq!(items[0].request.as_ref().unwrap()["grpcMethodName"], "Get");
+
+        let query = HistoryQuery {
+            status_class: Some(HistoryStatusClass::Success),
+            ..HistoryQuery::default()
+        };
+        assert_eq!(query_history(&conn, &query).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn history_from_older_schema_gains_cancelled_flag() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE request_history (
+               id TEXT PRIMARY KEY, method TEXT NOT NULL, url TEXT NOT NULL,
+               status INTEGER NOT NULL, time_ms INTEGER NOT NULL, timestamp INTEGER NOT NULL,
+               request_type TEXT, count INTEGER NOT NULL DEFAULT 1, last_timestamp INTEGER,
+               request_json TEXT
+             );
+             INSERT INTO request_history VALUES ('old', 'GET', 'https://a.test', 200, 5, 1, NULL, 1,
+               NULL, NULL);",
+        )
+        .unwrap();
+        init_schema(&conn).unwrap();
+        init_schema(&conn).unwrap();
+
+        let mut cancelled = entry("new", "GET", "https://a.test", 0, 2);
+        cancelled.cancelled = true;
+        upsert_history_entry(&conn, &cancelled).unwrap();
+        let items = query_history(&conn, &HistoryQuery::default()).unwrap();
+        let flags: Vec<_> = items
+            .iter()
+            .map(|item| (item.id.as_str(), item.cancelled))
+            .collect();
+        assert_eq!(flags, [("new", true), ("old", false)]);
+    }
+
+    #[test]
+    fn settings_round_trip_and_fill_missing_fields_with_defaults() {
+        let conn = memory_db();
+        assert_eq!(load_settings(&conn).unwrap(), None);
+
+        let settings = AppSettings {
+            theme: Theme::Dark,
+            history_limit: 0,
+            request_naming: RequestNaming::HostPath,
+            default_headers: vec![DefaultHeader {
+                id: "h1".into(),
+                key: "X-Team".into(),
+                value: "api".into(),
+                enabled: true,
+            }],
+            user_agent: "probe/1".into(),
+            restore_drafts: false,
+            ..AppSettings::default()
+        };
+        save_settings(&conn, &settings).unwrap();
+        assert_eq!(load_settings(&conn).unwrap(), Some(settings));
+
+        upsert_state_entry(&conn, SETTINGS_STATE_KEY, r#"{"theme":"dark"}"#).unwrap();
+        let partial = load_settings(&conn).unwrap().unwrap();
+        assert_eq!(partial.theme, Theme::Dark);
+        assert_eq!(partial.history_limit, AppSettings::default().history_limit);
+        assert!(partial.restore_drafts);
+    }
+
+    #[test]
+    fn unsaved_tab_draft_round_trips_through_app_state() {
+        let conn = memory_db();
+        let draft = serde_json::json!({
+            "version": 1,
+            "activeTabId": "t2",
+            "tabs": [
+                { "id": "t1", "method": "GET", "url": "https://api.test/saved" },
+                {
+                    "id": "t2",
+                    "method": "PATCH",
+                    "url": "https://api.test/orders/{{id}}",
+                    "bodyContent": "{\"state\": \"draft\"}",
+                    "authType": "bearer",
+                    "authToken": "{{token}}",
+                },
+            ],
+        })
+        .to_string();
+        upsert_state(&conn, &draft).unwrap();
+        upsert_state(&conn, &draft).unwrap();
+
+        let restored: serde_json::Value =
+            serde_json::from_str(&load_state(&conn).unwrap().unwrap()).unwrap();
+        assert_eq!(restored["activeTabId"], "t2");
+        assert_eq!(restored["tabs"][1]["method"], "PATCH");
+        assert_eq!(restored["tabs"][1]["bodyContent"], "{\"state\": \"draft\"}");
+        assert_eq!(restored["tabs"][1]["authToken"], "{{token}}");
+    }
+
+    #[test]
+    fn response_baseline_is_replaced_and_deleted_per_request() {
+        let conn = memory_db();
+        let baseline = |saved_at, body: &str| ResponseBaseline {
+            request_id: "req-1".to_string(),
+            saved_at,
+            response: ResponseSnapshot {
+                status: 200,
+                headers: HashMap::from([("content-type".to_string(), "text/plain".to_string())]),
+                body: body.to_string(),
+            },
+        };
+        assert_eq!(load_response_baseline(&conn, "req-1").unwrap(), None);
+
+        upsert_response_baseline(&conn, &baseline(1_000, "first")).unwrap();
+        upsert_response_baseline(&conn, &baseline(2_000, "second")).unwrap();
+        assert_eq!(
+            load_response_baseline(&conn, "req-1").unwrap(),
+            Some(baseline(2_000, "second"))
+        );
+        assert_eq!(load_response_baseline(&conn, "req-2").unwrap(), None);
+
+        assert!(delete_response_baseline(&conn, "req-1").unwrap());
+        assert!(!delete_response_baseline(&conn, "req-1").unwrap());
+        assert_eq!(load_response_baseline(&conn, "req-1").unwrap(), None);
+    }
+
+    #[test]
+    fn cached_responses_hit_by_signature_until_cleared() {
+        let conn = memory_db();
+        let mut response = crate::engine::http::error_response("unused");
+        response.status = 201;
+        response.body = "{\"id\":7}".to_string();
+        response.error = None;
+        assert!(load_cached_response(&conn, "sig-a").unwrap().is_none());
+
+        upsert_cached_response(&conn, "sig-a", 1_000, &response).unwrap();
+        let hit = load_cached_response(&conn, "sig-a").unwrap().unwrap();
+        assert_eq!((hit.status, hit.body.as_str()), (201, "{\"id\":7}"));
+        assert!(load_cached_response(&conn, "sig-b").unwrap().is_none());
+
+        let failed = crate::engine::http::error_response("connection refused");
+        upsert_cached_response(&conn, "sig-b", 2_000, &failed).unwrap();
+        let hit = load_cached_response(&conn, "sig-b").unwrap().unwrap();
+        assert_eq!(hit.error, failed.error);
+
+        assert_eq!(clear_response_cache(&conn).unwrap(), 2);
+        assert!(load_cached_response(&conn, "sig-a").unwrap().is_none());
+    }
+}